@@ -0,0 +1,550 @@
+use crate::error::Error;
+use crate::metrics::{Labels, MetricSet};
+use libvirt_remote::binding::RemoteAuthType;
+use libvirt_remote::client::{Client, Libvirt};
+use libvirt_remote::stats::*;
+use libvirt_remote::uri::ConnectUri;
+use log::{trace, warn};
+use std::collections::HashSet;
+
+const VIR_NODE_CPU_STATS_ALL_CPUS: i32 = -1;
+const VIR_NODE_MEMORY_STATS_ALL_CELLS: i32 = -1;
+
+pub const STAT_GROUPS: [(&str, u32); 10] = [
+    ("state", VIR_DOMAIN_STATS_STATE),
+    ("cpu", VIR_DOMAIN_STATS_CPU_TOTAL),
+    ("balloon", VIR_DOMAIN_STATS_BALLOON),
+    ("vcpu", VIR_DOMAIN_STATS_VCPU),
+    ("interface", VIR_DOMAIN_STATS_INTERFACE),
+    ("block", VIR_DOMAIN_STATS_BLOCK),
+    ("perf", VIR_DOMAIN_STATS_PERF),
+    ("iothread", VIR_DOMAIN_STATS_IOTHREAD),
+    ("memory", VIR_DOMAIN_STATS_MEMORY),
+    ("dirtyrate", VIR_DOMAIN_STATS_DIRTYRATE),
+];
+
+pub struct Collector {
+    uri: ConnectUri,
+    stats: u32,
+    client: Option<Client>,
+}
+
+impl Collector {
+    pub fn new(uri: ConnectUri, stats: u32) -> Self {
+        Collector {
+            uri,
+            stats,
+            client: None,
+        }
+    }
+
+    // Never fails; a broken connection is reported as `libvirt_up 0` and
+    // reopened on the next call.
+    pub fn collect(&mut self) -> MetricSet {
+        let mut metrics = MetricSet::new();
+
+        match self.try_collect(&mut metrics) {
+            Ok(()) => {
+                metrics.gauge("libvirt_up", "Whether the daemon is reachable.", vec![], 1);
+            }
+            Err(e) => {
+                warn!("collect failed: {e}");
+                self.disconnect();
+                metrics = MetricSet::new();
+                metrics.gauge("libvirt_up", "Whether the daemon is reachable.", vec![], 0);
+            }
+        }
+
+        metrics
+    }
+
+    fn try_collect(&mut self, metrics: &mut MetricSet) -> Result<(), Error> {
+        let stats = self.stats;
+        let client = self.client()?;
+
+        collect_node(client, metrics)?;
+
+        let domains = get_all_domain_stats(client, stats, 0)?;
+        trace!("collected {} domains", domains.len());
+        for domain in &domains {
+            collect_domain(domain, metrics);
+        }
+
+        Ok(())
+    }
+
+    fn client(&mut self) -> Result<&mut Client, Error> {
+        if self.client.is_none() {
+            self.client = Some(connect(&self.uri)?);
+        }
+        Ok(self.client.as_mut().unwrap())
+    }
+
+    fn disconnect(&mut self) {
+        if let Some(mut client) = self.client.take() {
+            let _ = client.connect_close();
+            let _ = client.fin();
+        }
+    }
+}
+
+impl Drop for Collector {
+    fn drop(&mut self) {
+        self.disconnect();
+    }
+}
+
+fn connect(uri: &ConnectUri) -> Result<Client, Error> {
+    let name = uri.name();
+    trace!("connecting {}", name);
+
+    let mut client = Client::connect_readonly(uri)?;
+
+    if let Some(auth) = client.auth_list()?.into_iter().next() {
+        match auth {
+            RemoteAuthType::RemoteAuthNone => {}
+            RemoteAuthType::RemoteAuthPolkit => {
+                client.auth_polkit()?;
+            }
+            RemoteAuthType::RemoteAuthSasl => {
+                return Err(Error::NotSupported);
+            }
+        }
+    }
+
+    client.connect_open(Some(name), 1)?;
+
+    Ok(client)
+}
+
+fn collect_node(client: &mut Client, metrics: &mut MetricSet) -> Result<(), Error> {
+    let (_, nparams) = client.node_get_cpu_stats(VIR_NODE_CPU_STATS_ALL_CPUS, 0, 0)?;
+    let (cpu, _) = client.node_get_cpu_stats(VIR_NODE_CPU_STATS_ALL_CPUS, nparams, 0)?;
+    for stat in cpu {
+        metrics.counter(
+            "libvirt_node_cpu_seconds",
+            "Host CPU time spent in each mode.",
+            vec![("mode", stat.field)],
+            stat.value as f64 / 1e9,
+        );
+    }
+
+    let (_, nparams) = client.node_get_memory_stats(0, VIR_NODE_MEMORY_STATS_ALL_CELLS, 0)?;
+    let (memory, _) = client.node_get_memory_stats(nparams, VIR_NODE_MEMORY_STATS_ALL_CELLS, 0)?;
+    for stat in memory {
+        metrics.gauge(
+            "libvirt_node_memory_bytes",
+            "Host memory by type.",
+            vec![("type", stat.field)],
+            stat.value * 1024,
+        );
+    }
+
+    Ok(())
+}
+
+fn collect_domain(stats: &DomainStats, metrics: &mut MetricSet) {
+    let labels = |extra: Labels| {
+        let mut labels = vec![
+            ("domain", stats.dom.name.clone()),
+            ("uuid", uuid(&stats.dom.uuid)),
+        ];
+        labels.extend(extra);
+        labels
+    };
+
+    if let Some(state) = &stats.state {
+        metrics.gauge(
+            "libvirt_domain_state",
+            "Domain state as virDomainState.",
+            labels(vec![]),
+            state.state as i32,
+        );
+        metrics.gauge(
+            "libvirt_domain_state_reason",
+            "Reason of the domain state.",
+            labels(vec![]),
+            state.reason,
+        );
+    }
+
+    if let Some(cpu) = &stats.cpu {
+        for (name, help, value) in [
+            (
+                "libvirt_domain_cpu_time_seconds",
+                "Host CPU time used by the domain.",
+                cpu.time,
+            ),
+            (
+                "libvirt_domain_cpu_user_seconds",
+                "Host CPU time used by the domain in user mode.",
+                cpu.user,
+            ),
+            (
+                "libvirt_domain_cpu_system_seconds",
+                "Host CPU time used by the domain in kernel mode.",
+                cpu.system,
+            ),
+        ] {
+            if let Some(value) = value {
+                metrics.counter(name, help, labels(vec![]), value as f64 / 1e9);
+            }
+        }
+    }
+
+    if let Some(balloon) = &stats.balloon {
+        for (name, help, value) in [
+            (
+                "libvirt_domain_balloon_current_bytes",
+                "Current balloon size.",
+                balloon.current,
+            ),
+            (
+                "libvirt_domain_balloon_maximum_bytes",
+                "Maximum balloon size.",
+                balloon.maximum,
+            ),
+            (
+                "libvirt_domain_balloon_unused_bytes",
+                "Memory left unused by the guest.",
+                balloon.unused,
+            ),
+            (
+                "libvirt_domain_balloon_available_bytes",
+                "Memory usable by the guest.",
+                balloon.available,
+            ),
+            (
+                "libvirt_domain_balloon_usable_bytes",
+                "Memory reclaimable by the balloon without swapping.",
+                balloon.usable,
+            ),
+            (
+                "libvirt_domain_balloon_rss_bytes",
+                "Resident set size of the domain process.",
+                balloon.rss,
+            ),
+            (
+                "libvirt_domain_balloon_disk_caches_bytes",
+                "Memory used by guest disk caches.",
+                balloon.disk_caches,
+            ),
+        ] {
+            if let Some(value) = value {
+                metrics.gauge(name, help, labels(vec![]), value * 1024);
+            }
+        }
+
+        for (name, help, value) in [
+            (
+                "libvirt_domain_balloon_swap_in_bytes",
+                "Data read from guest swap.",
+                balloon.swap_in.map(|v| v * 1024),
+            ),
+            (
+                "libvirt_domain_balloon_swap_out_bytes",
+                "Data written to guest swap.",
+                balloon.swap_out.map(|v| v * 1024),
+            ),
+            (
+                "libvirt_domain_balloon_major_faults",
+                "Page faults that required disk IO.",
+                balloon.major_fault,
+            ),
+            (
+                "libvirt_domain_balloon_minor_faults",
+                "Page faults that did not require disk IO.",
+                balloon.minor_fault,
+            ),
+        ] {
+            if let Some(value) = value {
+                metrics.counter(name, help, labels(vec![]), value);
+            }
+        }
+    }
+
+    if let Some(vcpu) = &stats.vcpu {
+        if let Some(current) = vcpu.current {
+            metrics.gauge(
+                "libvirt_domain_vcpu_current",
+                "Number of online vCPUs.",
+                labels(vec![]),
+                current,
+            );
+        }
+        if let Some(maximum) = vcpu.maximum {
+            metrics.gauge(
+                "libvirt_domain_vcpu_maximum",
+                "Maximum number of vCPUs.",
+                labels(vec![]),
+                maximum,
+            );
+        }
+        for info in &vcpu.vcpus {
+            let vcpu_labels = || labels(vec![("vcpu", info.index.to_string())]);
+            if let Some(state) = info.state {
+                metrics.gauge(
+                    "libvirt_domain_vcpu_state",
+                    "vCPU state as virVcpuState.",
+                    vcpu_labels(),
+                    state,
+                );
+            }
+            for (name, help, value) in [
+                (
+                    "libvirt_domain_vcpu_time_seconds",
+                    "Host CPU time used by the vCPU.",
+                    info.time,
+                ),
+                (
+                    "libvirt_domain_vcpu_wait_seconds",
+                    "Time the vCPU wanted to run but was not scheduled.",
+                    info.wait,
+                ),
+                (
+                    "libvirt_domain_vcpu_delay_seconds",
+                    "Time the vCPU waited in the host run queue.",
+                    info.delay,
+                ),
+            ] {
+                if let Some(value) = value {
+                    metrics.counter(name, help, vcpu_labels(), value as f64 / 1e9);
+                }
+            }
+        }
+    }
+
+    for iface in &stats.interfaces {
+        for (name, help, value) in [
+            (
+                "libvirt_domain_interface_receive_bytes",
+                "Bytes received on the interface.",
+                iface.rx_bytes,
+            ),
+            (
+                "libvirt_domain_interface_receive_packets",
+                "Packets received on the interface.",
+                iface.rx_pkts,
+            ),
+            (
+                "libvirt_domain_interface_receive_errors",
+                "Receive errors on the interface.",
+                iface.rx_errs,
+            ),
+            (
+                "libvirt_domain_interface_receive_drops",
+                "Received packets dropped on the interface.",
+                iface.rx_drop,
+            ),
+            (
+                "libvirt_domain_interface_transmit_bytes",
+                "Bytes transmitted on the interface.",
+                iface.tx_bytes,
+            ),
+            (
+                "libvirt_domain_interface_transmit_packets",
+                "Packets transmitted on the interface.",
+                iface.tx_pkts,
+            ),
+            (
+                "libvirt_domain_interface_transmit_errors",
+                "Transmit errors on the interface.",
+                iface.tx_errs,
+            ),
+            (
+                "libvirt_domain_interface_transmit_drops",
+                "Transmitted packets dropped on the interface.",
+                iface.tx_drop,
+            ),
+        ] {
+            if let Some(value) = value {
+                metrics.counter(
+                    name,
+                    help,
+                    labels(vec![("interface", iface.name.clone())]),
+                    value,
+                );
+            }
+        }
+    }
+
+    // Only the top of each backing chain.
+    let mut seen = HashSet::new();
+    for block in stats.blocks.iter().filter(|b| seen.insert(b.name.clone())) {
+        let block_labels = || {
+            labels(vec![
+                ("target", block.name.clone()),
+                ("path", block.path.clone().unwrap_or_default()),
+            ])
+        };
+        for (name, help, value) in [
+            (
+                "libvirt_domain_block_read_bytes",
+                "Bytes read from the block device.",
+                block.rd_bytes.map(|v| v as f64),
+            ),
+            (
+                "libvirt_domain_block_read_requests",
+                "Read requests on the block device.",
+                block.rd_reqs.map(|v| v as f64),
+            ),
+            (
+                "libvirt_domain_block_read_time_seconds",
+                "Time spent reading from the block device.",
+                block.rd_times.map(|v| v as f64 / 1e9),
+            ),
+            (
+                "libvirt_domain_block_write_bytes",
+                "Bytes written to the block device.",
+                block.wr_bytes.map(|v| v as f64),
+            ),
+            (
+                "libvirt_domain_block_write_requests",
+                "Write requests on the block device.",
+                block.wr_reqs.map(|v| v as f64),
+            ),
+            (
+                "libvirt_domain_block_write_time_seconds",
+                "Time spent writing to the block device.",
+                block.wr_times.map(|v| v as f64 / 1e9),
+            ),
+            (
+                "libvirt_domain_block_flush_requests",
+                "Flush requests on the block device.",
+                block.fl_reqs.map(|v| v as f64),
+            ),
+            (
+                "libvirt_domain_block_flush_time_seconds",
+                "Time spent flushing the block device.",
+                block.fl_times.map(|v| v as f64 / 1e9),
+            ),
+            (
+                "libvirt_domain_block_errors",
+                "Errors on the block device.",
+                block.errors.map(|v| v as f64),
+            ),
+        ] {
+            if let Some(value) = value {
+                metrics.counter(name, help, block_labels(), value);
+            }
+        }
+        for (name, help, value) in [
+            (
+                "libvirt_domain_block_allocation_bytes",
+                "Highest written offset of the block device.",
+                block.allocation,
+            ),
+            (
+                "libvirt_domain_block_capacity_bytes",
+                "Logical size of the block device.",
+                block.capacity,
+            ),
+            (
+                "libvirt_domain_block_physical_bytes",
+                "Physical size of the block device.",
+                block.physical,
+            ),
+        ] {
+            if let Some(value) = value {
+                metrics.gauge(name, help, block_labels(), value);
+            }
+        }
+    }
+
+    for (event, value) in &stats.perf {
+        metrics.counter(
+            "libvirt_domain_perf_events",
+            "Perf event counters.",
+            labels(vec![("event", event.clone())]),
+            *value,
+        );
+    }
+
+    for iothread in &stats.iothreads {
+        for (name, help, value) in [
+            (
+                "libvirt_domain_iothread_poll_max_ns",
+                "Maximum polling time of the iothread.",
+                iothread.poll_max_ns,
+            ),
+            (
+                "libvirt_domain_iothread_poll_grow",
+                "Polling time grow factor of the iothread.",
+                iothread.poll_grow,
+            ),
+            (
+                "libvirt_domain_iothread_poll_shrink",
+                "Polling time shrink divisor of the iothread.",
+                iothread.poll_shrink,
+            ),
+        ] {
+            if let Some(value) = value {
+                metrics.gauge(
+                    name,
+                    help,
+                    labels(vec![("iothread", iothread.id.to_string())]),
+                    value,
+                );
+            }
+        }
+    }
+
+    for monitor in &stats.memory_bandwidth {
+        for node in &monitor.nodes {
+            let node_labels = || {
+                labels(vec![
+                    ("monitor", monitor.name.clone().unwrap_or_default()),
+                    ("vcpus", monitor.vcpus.clone().unwrap_or_default()),
+                    ("node", node.id.map(|v| v.to_string()).unwrap_or_default()),
+                ])
+            };
+            if let Some(value) = node.bytes_local {
+                metrics.counter(
+                    "libvirt_domain_memory_bandwidth_local_bytes",
+                    "Memory traffic to the local NUMA node.",
+                    node_labels(),
+                    value,
+                );
+            }
+            if let Some(value) = node.bytes_total {
+                metrics.counter(
+                    "libvirt_domain_memory_bandwidth_bytes",
+                    "Memory traffic to all NUMA nodes.",
+                    node_labels(),
+                    value,
+                );
+            }
+        }
+    }
+
+    if let Some(dirty_rate) = &stats.dirty_rate {
+        if let Some(value) = dirty_rate.megabytes_per_second {
+            metrics.gauge(
+                "libvirt_domain_dirty_rate_megabytes_per_second",
+                "Memory dirty rate of the last calculation.",
+                labels(vec![]),
+                value,
+            );
+        }
+        for (vcpu, value) in &dirty_rate.vcpus {
+            metrics.gauge(
+                "libvirt_domain_vcpu_dirty_rate_megabytes_per_second",
+                "Memory dirty rate of the vCPU in the last calculation.",
+                labels(vec![("vcpu", vcpu.to_string())]),
+                *value,
+            );
+        }
+    }
+}
+
+fn uuid(uuid: &[u8]) -> String {
+    let hex: String = uuid.iter().map(|b| format!("{b:02x}")).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
@@ -0,0 +1,26 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    Arg(String),
+    Libvirt(Box<libvirt_remote::error::Error>),
+    NotSupported,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Arg(msg) => write!(f, "{msg}"),
+            Error::Libvirt(e) => write!(f, "{e}"),
+            Error::NotSupported => write!(f, "not supported"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<libvirt_remote::error::Error> for Error {
+    fn from(error: libvirt_remote::error::Error) -> Self {
+        Error::Libvirt(Box::new(error))
+    }
+}
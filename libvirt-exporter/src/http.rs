@@ -0,0 +1,81 @@
+use crate::metrics::CONTENT_TYPE;
+use log::{trace, warn};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::Duration;
+
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+const INDEX: &str = "<html><head><title>libvirt exporter</title></head>\
+<body><h1>libvirt exporter</h1><p><a href=\"/metrics\">Metrics</a></p></body></html>\n";
+
+pub fn serve(listener: TcpListener, metrics: Arc<RwLock<String>>) {
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                let metrics = metrics.clone();
+                thread::spawn(move || {
+                    if let Err(e) = handle(stream, &metrics) {
+                        trace!("connection closed: {e}");
+                    }
+                });
+            }
+            Err(e) => warn!("accept failed: {e}"),
+        }
+    }
+}
+
+fn handle(stream: TcpStream, metrics: &RwLock<String>) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    // Headers are not needed, but must be consumed before replying.
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let path = parts.next().unwrap_or_default();
+    let path = path.split('?').next().unwrap_or_default();
+    trace!("{method} {path}");
+
+    let (status, content_type, body) = match (method, path) {
+        ("GET" | "HEAD", "/metrics") => (
+            "200 OK",
+            CONTENT_TYPE,
+            metrics.read().map(|m| m.clone()).unwrap_or_default(),
+        ),
+        ("GET" | "HEAD", "/") => ("200 OK", "text/html; charset=utf-8", INDEX.to_string()),
+        ("GET" | "HEAD", _) => (
+            "404 Not Found",
+            "text/plain; charset=utf-8",
+            "Not Found\n".to_string(),
+        ),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain; charset=utf-8",
+            "Method Not Allowed\n".to_string(),
+        ),
+    };
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    )?;
+    if method != "HEAD" {
+        stream.write_all(body.as_bytes())?;
+    }
+    stream.flush()
+}
@@ -0,0 +1,4 @@
+pub mod collector;
+pub mod error;
+pub mod http;
+pub mod metrics;
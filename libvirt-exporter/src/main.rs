@@ -0,0 +1,91 @@
+use clap::{Arg, ArgAction, Command};
+use libvirt_exporter::collector::{Collector, STAT_GROUPS};
+use libvirt_exporter::error::Error;
+use libvirt_exporter::http;
+use libvirt_remote::uri::ConnectUri;
+use log::info;
+use std::net::TcpListener;
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DEFAULT_STATS: &str = "state,cpu,balloon,vcpu,interface,block";
+
+fn app() -> Command {
+    Command::new("Libvirt Exporter")
+        .version("0.1.0")
+        .arg(
+            Arg::new("connect")
+                .short('c')
+                .long("connect")
+                .value_name("URI")
+                .help("hypvervisor connection URI"),
+        )
+        .arg(
+            Arg::new("listen")
+                .short('l')
+                .long("listen")
+                .value_name("ADDRESS")
+                .default_value("0.0.0.0:9177")
+                .help("address to serve metrics on"),
+        )
+        .arg(
+            Arg::new("interval")
+                .short('i')
+                .long("interval")
+                .value_name("SECONDS")
+                .default_value("15")
+                .value_parser(clap::value_parser!(u64).range(1..))
+                .help("collection interval"),
+        )
+        .arg(
+            Arg::new("stats")
+                .short('s')
+                .long("stats")
+                .value_name("GROUPS")
+                .value_delimiter(',')
+                .action(ArgAction::Append)
+                .default_value(DEFAULT_STATS)
+                .value_parser(STAT_GROUPS.map(|(name, _)| name))
+                .help("domain stat groups to collect"),
+        )
+}
+
+fn main() -> Result<(), Error> {
+    env_logger::init();
+
+    let args = app().get_matches();
+
+    let uri = ConnectUri::resolve(args.get_one::<String>("connect").map(|s| s.as_str()))?;
+
+    let stats = args
+        .get_many::<String>("stats")
+        .unwrap()
+        .filter_map(|s| STAT_GROUPS.iter().find(|(name, _)| name == s))
+        .fold(0, |acc, (_, flag)| acc | flag);
+
+    let interval = Duration::from_secs(*args.get_one::<u64>("interval").unwrap());
+
+    let listen = args.get_one::<String>("listen").unwrap();
+    let listener = TcpListener::bind(listen).map_err(|e| Error::Arg(format!("{listen}: {e}")))?;
+    info!("listening on {listen}");
+
+    let metrics = Arc::new(RwLock::new(String::new()));
+
+    let shared = metrics.clone();
+    thread::spawn(move || {
+        let mut collector = Collector::new(uri, stats);
+        loop {
+            let start = Instant::now();
+            let text = collector.collect().encode();
+            if let Ok(mut metrics) = shared.write() {
+                *metrics = text;
+            }
+            thread::sleep(interval.saturating_sub(start.elapsed()));
+        }
+    });
+
+    http::serve(listener, metrics);
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+
+pub const CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MetricType {
+    Counter,
+    Gauge,
+}
+
+impl fmt::Display for MetricType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MetricType::Counter => write!(f, "counter"),
+            MetricType::Gauge => write!(f, "gauge"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Value {
+    Unsigned(u64),
+    Signed(i64),
+    Float(f64),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Unsigned(v) => write!(f, "{v}"),
+            Value::Signed(v) => write!(f, "{v}"),
+            Value::Float(v) if v.is_nan() => write!(f, "NaN"),
+            Value::Float(v) if v.is_infinite() && v.is_sign_positive() => write!(f, "+Inf"),
+            Value::Float(v) if v.is_infinite() => write!(f, "-Inf"),
+            Value::Float(v) => write!(f, "{v:?}"),
+        }
+    }
+}
+
+impl From<u64> for Value {
+    fn from(value: u64) -> Self {
+        Value::Unsigned(value)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Signed(value)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        Value::Signed(value as i64)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Float(value)
+    }
+}
+
+pub type Labels = Vec<(&'static str, String)>;
+
+#[derive(Clone, Debug)]
+struct Family {
+    name: &'static str,
+    help: &'static str,
+    metric_type: MetricType,
+    samples: Vec<(Labels, Value)>,
+}
+
+// Samples grouped by family in the order the families were first seen,
+// since OpenMetrics does not allow a family to be interleaved.
+#[derive(Clone, Debug, Default)]
+pub struct MetricSet {
+    families: Vec<Family>,
+    index: HashMap<&'static str, usize>,
+}
+
+impl MetricSet {
+    pub fn new() -> Self {
+        MetricSet::default()
+    }
+
+    pub fn gauge<V>(&mut self, name: &'static str, help: &'static str, labels: Labels, value: V)
+    where
+        V: Into<Value>,
+    {
+        self.push(name, help, MetricType::Gauge, labels, value.into());
+    }
+
+    // `name` is the family name; samples are written with a `_total` suffix.
+    pub fn counter<V>(&mut self, name: &'static str, help: &'static str, labels: Labels, value: V)
+    where
+        V: Into<Value>,
+    {
+        self.push(name, help, MetricType::Counter, labels, value.into());
+    }
+
+    pub fn encode(&self) -> String {
+        let mut out = String::new();
+        for family in &self.families {
+            let _ = writeln!(out, "# TYPE {} {}", family.name, family.metric_type);
+            let _ = writeln!(out, "# HELP {} {}", family.name, escape_help(family.help));
+            let suffix = match family.metric_type {
+                MetricType::Counter => "_total",
+                MetricType::Gauge => "",
+            };
+            for (labels, value) in &family.samples {
+                let _ = write!(out, "{}{}", family.name, suffix);
+                if !labels.is_empty() {
+                    let labels: Vec<String> = labels
+                        .iter()
+                        .map(|(k, v)| format!("{k}=\"{}\"", escape_label(v)))
+                        .collect();
+                    let _ = write!(out, "{{{}}}", labels.join(","));
+                }
+                let _ = writeln!(out, " {value}");
+            }
+        }
+        out.push_str("# EOF\n");
+        out
+    }
+
+    fn push(
+        &mut self,
+        name: &'static str,
+        help: &'static str,
+        metric_type: MetricType,
+        labels: Labels,
+        value: Value,
+    ) {
+        let index = *self.index.entry(name).or_insert_with(|| {
+            self.families.push(Family {
+                name,
+                help,
+                metric_type,
+                samples: vec![],
+            });
+            self.families.len() - 1
+        });
+        self.families[index].samples.push((labels, value));
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn escape_help(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\n', "\\n")
+}
@@ -0,0 +1,156 @@
+#[path = "../../libvirt-remote/tests/common/mod.rs"]
+mod common;
+
+use common::{Request, domain, listen, procedures};
+use libvirt_exporter::collector::Collector;
+use libvirt_exporter::http;
+use libvirt_exporter::metrics::CONTENT_TYPE;
+use libvirt_remote::binding::*;
+use libvirt_remote::stats::{VIR_DOMAIN_STATS_INTERFACE, VIR_DOMAIN_STATS_STATE};
+use libvirt_remote::uri::ConnectUri;
+use std::env;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::{Arc, RwLock};
+use std::thread;
+
+use RemoteProcedure::*;
+
+fn socket_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("libvirt-exporter-{}-{name}.sock", process::id()))
+}
+
+fn collector(path: &Path) -> Collector {
+    let uri = ConnectUri::parse(&format!("qemu:///system?socket={}", path.display())).unwrap();
+    Collector::new(uri, VIR_DOMAIN_STATS_STATE | VIR_DOMAIN_STATS_INTERFACE)
+}
+
+fn stats(name: &str, uuid: u8, state: i32) -> RemoteDomainStatsRecord {
+    let mut dom = domain(name);
+    dom.uuid = [uuid; 16];
+    RemoteDomainStatsRecord {
+        dom,
+        params: vec![
+            RemoteTypedParam::int("state.state", state),
+            RemoteTypedParam::int("state.reason", 1),
+            RemoteTypedParam::uint("net.count", 1),
+            RemoteTypedParam::string("net.0.name", "vnet0"),
+            RemoteTypedParam::ullong("net.0.rx.bytes", 1000),
+        ],
+    }
+}
+
+// Answers the calls of one collection.
+fn libvirtd(req: &mut Request) {
+    if req.is(RemoteProcAuthList) {
+        req.reply(&RemoteAuthListRet {
+            types: vec![RemoteAuthType::RemoteAuthNone],
+        });
+    } else if req.is(RemoteProcConnectOpen) || req.is(RemoteProcConnectClose) {
+        req.reply_void();
+    } else if req.is(RemoteProcNodeGetCpuStats) {
+        let args: RemoteNodeGetCpuStatsArgs = req.args();
+        let params = match args.nparams {
+            0 => vec![],
+            _ => vec![RemoteNodeGetCpuStats {
+                field: "user".to_string(),
+                value: 2_000_000_000,
+            }],
+        };
+        req.reply(&RemoteNodeGetCpuStatsRet { params, nparams: 1 });
+    } else if req.is(RemoteProcNodeGetMemoryStats) {
+        let args: RemoteNodeGetMemoryStatsArgs = req.args();
+        let params = match args.nparams {
+            0 => vec![],
+            _ => vec![RemoteNodeGetMemoryStats {
+                field: "total".to_string(),
+                value: 1024,
+            }],
+        };
+        req.reply(&RemoteNodeGetMemoryStatsRet { params, nparams: 1 });
+    } else if req.is(RemoteProcConnectGetAllDomainStats) {
+        req.reply(&RemoteConnectGetAllDomainStatsRet {
+            ret_stats: vec![stats("guest", 1, 1), stats("other", 2, 5)],
+        });
+    }
+}
+
+fn scrape(addr: &str, path: &str) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(stream, "GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[test]
+fn scrape_domain_metrics() {
+    let path = socket_path("scrape");
+    listen(&path, libvirtd);
+
+    let metrics = Arc::new(RwLock::new(collector(&path).collect().encode()));
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    let shared = Arc::clone(&metrics);
+    thread::spawn(move || http::serve(listener, shared));
+
+    let response = scrape(&addr, "/metrics");
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{response}");
+    assert!(response.contains(&format!("Content-Type: {CONTENT_TYPE}\r\n")));
+
+    let body = response.split("\r\n\r\n").nth(1).unwrap();
+    assert!(body.contains("\nlibvirt_up 1\n"), "{body}");
+    assert!(body.contains("libvirt_node_cpu_seconds_total{mode=\"user\"} 2.0\n"));
+    assert!(body.contains("libvirt_node_memory_bytes{type=\"total\"} 1048576\n"));
+    assert!(body.contains(
+        "libvirt_domain_state{domain=\"guest\",uuid=\"01010101-0101-0101-0101-010101010101\"} 1\n"
+    ));
+    assert!(body.contains(
+        "libvirt_domain_state{domain=\"other\",uuid=\"02020202-0202-0202-0202-020202020202\"} 5\n"
+    ));
+    assert!(body.contains(
+        "libvirt_domain_interface_receive_bytes_total{domain=\"guest\",uuid=\"01010101-0101-0101-0101-010101010101\",interface=\"vnet0\"} 1000\n"
+    ));
+    assert!(body.ends_with("# EOF\n"));
+
+    assert!(scrape(&addr, "/none").starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn reconnect_after_failure() {
+    let path = socket_path("reconnect");
+    let mut failed = false;
+    let calls = listen(&path, move |req| {
+        // The first collection loses the connection midway.
+        if req.is(RemoteProcConnectGetAllDomainStats) && !failed {
+            failed = true;
+            req.hang_up();
+        } else {
+            libvirtd(req);
+        }
+    });
+
+    let mut collector = collector(&path);
+
+    let text = collector.collect().encode();
+    assert!(text.contains("libvirt_up 0\n"), "{text}");
+    assert!(!text.contains("libvirt_domain_state"));
+
+    let text = collector.collect().encode();
+    assert!(text.contains("libvirt_up 1\n"), "{text}");
+    assert!(text.contains("libvirt_domain_state{domain=\"guest\""));
+
+    // A new connection was opened for the second collection.
+    let opens = procedures(&calls)
+        .into_iter()
+        .filter(|p| *p == RemoteProcConnectOpen as i32)
+        .count();
+    assert_eq!(opens, 2);
+
+    drop(collector);
+    let _ = std::fs::remove_file(path);
+}
@@ -0,0 +1,19 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use libvirt_remote::binding::RemoteNodeGetInfoRet;
+use libvirt_remote::client::fuzz_deserialize_body;
+use libvirt_remote::protocol::VirNetMessageHeader;
+
+const HEADER_LEN: usize = 24;
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() < HEADER_LEN {
+        return;
+    }
+
+    let (header, body) = data.split_at(HEADER_LEN);
+    if let Ok(header) = serde_xdr::from_bytes::<VirNetMessageHeader>(header) {
+        let _ = fuzz_deserialize_body::<RemoteNodeGetInfoRet>(&header, body.to_vec());
+    }
+});
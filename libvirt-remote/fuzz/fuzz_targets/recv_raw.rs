@@ -0,0 +1,43 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use libvirt_remote::binding::RemoteDomainEventCallbackLifecycleMsg;
+use libvirt_remote::client::{ReadWrite, read_packet};
+use libvirt_remote::error::Error;
+use std::io::{self, Cursor, Read, Write};
+use std::net::Shutdown;
+
+struct Packet(Cursor<Vec<u8>>);
+
+impl Read for Packet {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+impl Write for Packet {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl ReadWrite for Packet {
+    fn clone(&self) -> Result<Box<dyn ReadWrite>, Error> {
+        Ok(Box::new(Packet(self.0.clone())))
+    }
+
+    fn shutdown(&self, _how: Shutdown) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let mut socket: Box<dyn ReadWrite> = Box::new(Packet(Cursor::new(data.to_vec())));
+    while let Ok(raw) = read_packet(&mut socket) {
+        let _ = RemoteDomainEventCallbackLifecycleMsg::try_from(raw);
+    }
+});
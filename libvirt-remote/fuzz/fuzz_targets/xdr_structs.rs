@@ -0,0 +1,740 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use libvirt_remote::binding::*;
+use serde::{Serialize, de::DeserializeOwned};
+fn check<T>(data: &[u8])
+where
+    T: Serialize + DeserializeOwned,
+{
+    if let Ok(value) = serde_xdr::from_bytes::<T>(data) {
+        let bytes = serde_xdr::to_bytes(&value).unwrap();
+        serde_xdr::from_bytes::<T>(&bytes).unwrap();
+    }
+}
+fuzz_target!(|data: &[u8]| {
+    if data.len() < 2 {
+        return;
+    }
+    let index = u16::from_be_bytes([data[0], data[1]]) % 717u16;
+    let data = &data[2..];
+    match index {
+        0u16 => check::<LxcDomainOpenNamespaceArgs>(data),
+        1u16 => check::<QemuConnectDomainMonitorEventDeregisterArgs>(data),
+        2u16 => check::<QemuConnectDomainMonitorEventRegisterArgs>(data),
+        3u16 => check::<QemuConnectDomainMonitorEventRegisterRet>(data),
+        4u16 => check::<QemuDomainAgentCommandArgs>(data),
+        5u16 => check::<QemuDomainAgentCommandRet>(data),
+        6u16 => check::<QemuDomainAttachArgs>(data),
+        7u16 => check::<QemuDomainAttachRet>(data),
+        8u16 => check::<QemuDomainMonitorCommandArgs>(data),
+        9u16 => check::<QemuDomainMonitorCommandRet>(data),
+        10u16 => check::<QemuDomainMonitorCommandWithFilesArgs>(data),
+        11u16 => check::<QemuDomainMonitorCommandWithFilesRet>(data),
+        12u16 => check::<QemuDomainMonitorEventMsg>(data),
+        13u16 => check::<RemoteAuthListRet>(data),
+        14u16 => check::<RemoteAuthPolkitRet>(data),
+        15u16 => check::<RemoteAuthSaslInitRet>(data),
+        16u16 => check::<RemoteAuthSaslStartArgs>(data),
+        17u16 => check::<RemoteAuthSaslStartRet>(data),
+        18u16 => check::<RemoteAuthSaslStepArgs>(data),
+        19u16 => check::<RemoteAuthSaslStepRet>(data),
+        20u16 => check::<RemoteConnectBaselineCpuArgs>(data),
+        21u16 => check::<RemoteConnectBaselineCpuRet>(data),
+        22u16 => check::<RemoteConnectBaselineHypervisorCpuArgs>(data),
+        23u16 => check::<RemoteConnectBaselineHypervisorCpuRet>(data),
+        24u16 => check::<RemoteConnectCompareCpuArgs>(data),
+        25u16 => check::<RemoteConnectCompareCpuRet>(data),
+        26u16 => check::<RemoteConnectCompareHypervisorCpuArgs>(data),
+        27u16 => check::<RemoteConnectCompareHypervisorCpuRet>(data),
+        28u16 => check::<RemoteConnectDomainEventCallbackDeregisterAnyArgs>(data),
+        29u16 => check::<RemoteConnectDomainEventCallbackRegisterAnyArgs>(data),
+        30u16 => check::<RemoteConnectDomainEventCallbackRegisterAnyRet>(data),
+        31u16 => check::<RemoteConnectDomainEventDeregisterAnyArgs>(data),
+        32u16 => check::<RemoteConnectDomainEventDeregisterRet>(data),
+        33u16 => check::<RemoteConnectDomainEventRegisterAnyArgs>(data),
+        34u16 => check::<RemoteConnectDomainEventRegisterRet>(data),
+        35u16 => check::<RemoteConnectDomainXmlFromNativeArgs>(data),
+        36u16 => check::<RemoteConnectDomainXmlFromNativeRet>(data),
+        37u16 => check::<RemoteConnectDomainXmlToNativeArgs>(data),
+        38u16 => check::<RemoteConnectDomainXmlToNativeRet>(data),
+        39u16 => check::<RemoteConnectEventConnectionClosedMsg>(data),
+        40u16 => check::<RemoteConnectFindStoragePoolSourcesArgs>(data),
+        41u16 => check::<RemoteConnectFindStoragePoolSourcesRet>(data),
+        42u16 => check::<RemoteConnectGetAllDomainStatsArgs>(data),
+        43u16 => check::<RemoteConnectGetAllDomainStatsRet>(data),
+        44u16 => check::<RemoteConnectGetCapabilitiesRet>(data),
+        45u16 => check::<RemoteConnectGetCpuModelNamesArgs>(data),
+        46u16 => check::<RemoteConnectGetCpuModelNamesRet>(data),
+        47u16 => check::<RemoteConnectGetDomainCapabilitiesArgs>(data),
+        48u16 => check::<RemoteConnectGetDomainCapabilitiesRet>(data),
+        49u16 => check::<RemoteConnectGetHostnameRet>(data),
+        50u16 => check::<RemoteConnectGetLibVersionRet>(data),
+        51u16 => check::<RemoteConnectGetMaxVcpusArgs>(data),
+        52u16 => check::<RemoteConnectGetMaxVcpusRet>(data),
+        53u16 => check::<RemoteConnectGetStoragePoolCapabilitiesArgs>(data),
+        54u16 => check::<RemoteConnectGetStoragePoolCapabilitiesRet>(data),
+        55u16 => check::<RemoteConnectGetSysinfoArgs>(data),
+        56u16 => check::<RemoteConnectGetSysinfoRet>(data),
+        57u16 => check::<RemoteConnectGetTypeRet>(data),
+        58u16 => check::<RemoteConnectGetUriRet>(data),
+        59u16 => check::<RemoteConnectGetVersionRet>(data),
+        60u16 => check::<RemoteConnectIsSecureRet>(data),
+        61u16 => check::<RemoteConnectListAllDomainsArgs>(data),
+        62u16 => check::<RemoteConnectListAllDomainsRet>(data),
+        63u16 => check::<RemoteConnectListAllInterfacesArgs>(data),
+        64u16 => check::<RemoteConnectListAllInterfacesRet>(data),
+        65u16 => check::<RemoteConnectListAllNetworksArgs>(data),
+        66u16 => check::<RemoteConnectListAllNetworksRet>(data),
+        67u16 => check::<RemoteConnectListAllNodeDevicesArgs>(data),
+        68u16 => check::<RemoteConnectListAllNodeDevicesRet>(data),
+        69u16 => check::<RemoteConnectListAllNwfilterBindingsArgs>(data),
+        70u16 => check::<RemoteConnectListAllNwfilterBindingsRet>(data),
+        71u16 => check::<RemoteConnectListAllNwfiltersArgs>(data),
+        72u16 => check::<RemoteConnectListAllNwfiltersRet>(data),
+        73u16 => check::<RemoteConnectListAllSecretsArgs>(data),
+        74u16 => check::<RemoteConnectListAllSecretsRet>(data),
+        75u16 => check::<RemoteConnectListAllStoragePoolsArgs>(data),
+        76u16 => check::<RemoteConnectListAllStoragePoolsRet>(data),
+        77u16 => check::<RemoteConnectListDefinedDomainsArgs>(data),
+        78u16 => check::<RemoteConnectListDefinedDomainsRet>(data),
+        79u16 => check::<RemoteConnectListDefinedInterfacesArgs>(data),
+        80u16 => check::<RemoteConnectListDefinedInterfacesRet>(data),
+        81u16 => check::<RemoteConnectListDefinedNetworksArgs>(data),
+        82u16 => check::<RemoteConnectListDefinedNetworksRet>(data),
+        83u16 => check::<RemoteConnectListDefinedStoragePoolsArgs>(data),
+        84u16 => check::<RemoteConnectListDefinedStoragePoolsRet>(data),
+        85u16 => check::<RemoteConnectListDomainsArgs>(data),
+        86u16 => check::<RemoteConnectListDomainsRet>(data),
+        87u16 => check::<RemoteConnectListInterfacesArgs>(data),
+        88u16 => check::<RemoteConnectListInterfacesRet>(data),
+        89u16 => check::<RemoteConnectListNetworksArgs>(data),
+        90u16 => check::<RemoteConnectListNetworksRet>(data),
+        91u16 => check::<RemoteConnectListNwfiltersArgs>(data),
+        92u16 => check::<RemoteConnectListNwfiltersRet>(data),
+        93u16 => check::<RemoteConnectListSecretsArgs>(data),
+        94u16 => check::<RemoteConnectListSecretsRet>(data),
+        95u16 => check::<RemoteConnectListStoragePoolsArgs>(data),
+        96u16 => check::<RemoteConnectListStoragePoolsRet>(data),
+        97u16 => check::<RemoteConnectNetworkEventDeregisterAnyArgs>(data),
+        98u16 => check::<RemoteConnectNetworkEventRegisterAnyArgs>(data),
+        99u16 => check::<RemoteConnectNetworkEventRegisterAnyRet>(data),
+        100u16 => check::<RemoteConnectNodeDeviceEventDeregisterAnyArgs>(data),
+        101u16 => check::<RemoteConnectNodeDeviceEventRegisterAnyArgs>(data),
+        102u16 => check::<RemoteConnectNodeDeviceEventRegisterAnyRet>(data),
+        103u16 => check::<RemoteConnectNumOfDefinedDomainsRet>(data),
+        104u16 => check::<RemoteConnectNumOfDefinedInterfacesRet>(data),
+        105u16 => check::<RemoteConnectNumOfDefinedNetworksRet>(data),
+        106u16 => check::<RemoteConnectNumOfDefinedStoragePoolsRet>(data),
+        107u16 => check::<RemoteConnectNumOfDomainsRet>(data),
+        108u16 => check::<RemoteConnectNumOfInterfacesRet>(data),
+        109u16 => check::<RemoteConnectNumOfNetworksRet>(data),
+        110u16 => check::<RemoteConnectNumOfNwfiltersRet>(data),
+        111u16 => check::<RemoteConnectNumOfSecretsRet>(data),
+        112u16 => check::<RemoteConnectNumOfStoragePoolsRet>(data),
+        113u16 => check::<RemoteConnectOpenArgs>(data),
+        114u16 => check::<RemoteConnectSecretEventDeregisterAnyArgs>(data),
+        115u16 => check::<RemoteConnectSecretEventRegisterAnyArgs>(data),
+        116u16 => check::<RemoteConnectSecretEventRegisterAnyRet>(data),
+        117u16 => check::<RemoteConnectSetIdentityArgs>(data),
+        118u16 => check::<RemoteConnectStoragePoolEventDeregisterAnyArgs>(data),
+        119u16 => check::<RemoteConnectStoragePoolEventRegisterAnyArgs>(data),
+        120u16 => check::<RemoteConnectStoragePoolEventRegisterAnyRet>(data),
+        121u16 => check::<RemoteConnectSupportsFeatureArgs>(data),
+        122u16 => check::<RemoteConnectSupportsFeatureRet>(data),
+        123u16 => check::<RemoteDomainAbortJobArgs>(data),
+        124u16 => check::<RemoteDomainAbortJobFlagsArgs>(data),
+        125u16 => check::<RemoteDomainAddIothreadArgs>(data),
+        126u16 => check::<RemoteDomainAgentSetResponseTimeoutArgs>(data),
+        127u16 => check::<RemoteDomainAgentSetResponseTimeoutRet>(data),
+        128u16 => check::<RemoteDomainAnnounceInterfaceArgs>(data),
+        129u16 => check::<RemoteDomainAttachDeviceArgs>(data),
+        130u16 => check::<RemoteDomainAttachDeviceFlagsArgs>(data),
+        131u16 => check::<RemoteDomainAuthorizedSshKeysGetArgs>(data),
+        132u16 => check::<RemoteDomainAuthorizedSshKeysGetRet>(data),
+        133u16 => check::<RemoteDomainAuthorizedSshKeysSetArgs>(data),
+        134u16 => check::<RemoteDomainBackupBeginArgs>(data),
+        135u16 => check::<RemoteDomainBackupGetXmlDescArgs>(data),
+        136u16 => check::<RemoteDomainBackupGetXmlDescRet>(data),
+        137u16 => check::<RemoteDomainBlockCommitArgs>(data),
+        138u16 => check::<RemoteDomainBlockCopyArgs>(data),
+        139u16 => check::<RemoteDomainBlockJobAbortArgs>(data),
+        140u16 => check::<RemoteDomainBlockJobSetSpeedArgs>(data),
+        141u16 => check::<RemoteDomainBlockPeekArgs>(data),
+        142u16 => check::<RemoteDomainBlockPeekRet>(data),
+        143u16 => check::<RemoteDomainBlockPullArgs>(data),
+        144u16 => check::<RemoteDomainBlockRebaseArgs>(data),
+        145u16 => check::<RemoteDomainBlockResizeArgs>(data),
+        146u16 => check::<RemoteDomainBlockStatsArgs>(data),
+        147u16 => check::<RemoteDomainBlockStatsFlagsArgs>(data),
+        148u16 => check::<RemoteDomainBlockStatsFlagsRet>(data),
+        149u16 => check::<RemoteDomainBlockStatsRet>(data),
+        150u16 => check::<RemoteDomainCheckpointCreateXmlArgs>(data),
+        151u16 => check::<RemoteDomainCheckpointCreateXmlRet>(data),
+        152u16 => check::<RemoteDomainCheckpointDeleteArgs>(data),
+        153u16 => check::<RemoteDomainCheckpointGetParentArgs>(data),
+        154u16 => check::<RemoteDomainCheckpointGetParentRet>(data),
+        155u16 => check::<RemoteDomainCheckpointGetXmlDescArgs>(data),
+        156u16 => check::<RemoteDomainCheckpointGetXmlDescRet>(data),
+        157u16 => check::<RemoteDomainCheckpointListAllChildrenArgs>(data),
+        158u16 => check::<RemoteDomainCheckpointListAllChildrenRet>(data),
+        159u16 => check::<RemoteDomainCheckpointLookupByNameArgs>(data),
+        160u16 => check::<RemoteDomainCheckpointLookupByNameRet>(data),
+        161u16 => check::<RemoteDomainCoreDumpArgs>(data),
+        162u16 => check::<RemoteDomainCoreDumpWithFormatArgs>(data),
+        163u16 => check::<RemoteDomainCreateArgs>(data),
+        164u16 => check::<RemoteDomainCreateWithFilesArgs>(data),
+        165u16 => check::<RemoteDomainCreateWithFilesRet>(data),
+        166u16 => check::<RemoteDomainCreateWithFlagsArgs>(data),
+        167u16 => check::<RemoteDomainCreateWithFlagsRet>(data),
+        168u16 => check::<RemoteDomainCreateXmlArgs>(data),
+        169u16 => check::<RemoteDomainCreateXmlRet>(data),
+        170u16 => check::<RemoteDomainCreateXmlWithFilesArgs>(data),
+        171u16 => check::<RemoteDomainCreateXmlWithFilesRet>(data),
+        172u16 => check::<RemoteDomainDefineXmlArgs>(data),
+        173u16 => check::<RemoteDomainDefineXmlFlagsArgs>(data),
+        174u16 => check::<RemoteDomainDefineXmlFlagsRet>(data),
+        175u16 => check::<RemoteDomainDefineXmlRet>(data),
+        176u16 => check::<RemoteDomainDelIothreadArgs>(data),
+        177u16 => check::<RemoteDomainDelThrottleGroupArgs>(data),
+        178u16 => check::<RemoteDomainDestroyArgs>(data),
+        179u16 => check::<RemoteDomainDestroyFlagsArgs>(data),
+        180u16 => check::<RemoteDomainDetachDeviceAliasArgs>(data),
+        181u16 => check::<RemoteDomainDetachDeviceArgs>(data),
+        182u16 => check::<RemoteDomainDetachDeviceFlagsArgs>(data),
+        183u16 => check::<RemoteDomainDiskError>(data),
+        184u16 => check::<RemoteDomainEventBalloonChangeMsg>(data),
+        185u16 => check::<RemoteDomainEventBlockJob2Msg>(data),
+        186u16 => check::<RemoteDomainEventBlockJobMsg>(data),
+        187u16 => check::<RemoteDomainEventBlockThresholdMsg>(data),
+        188u16 => check::<RemoteDomainEventCallbackAgentLifecycleMsg>(data),
+        189u16 => check::<RemoteDomainEventCallbackBalloonChangeMsg>(data),
+        190u16 => check::<RemoteDomainEventCallbackBlockJobMsg>(data),
+        191u16 => check::<RemoteDomainEventCallbackChannelLifecycleMsg>(data),
+        192u16 => check::<RemoteDomainEventCallbackControlErrorMsg>(data),
+        193u16 => check::<RemoteDomainEventCallbackDeviceAddedMsg>(data),
+        194u16 => check::<RemoteDomainEventCallbackDeviceRemovalFailedMsg>(data),
+        195u16 => check::<RemoteDomainEventCallbackDeviceRemovedMsg>(data),
+        196u16 => check::<RemoteDomainEventCallbackDiskChangeMsg>(data),
+        197u16 => check::<RemoteDomainEventCallbackGraphicsMsg>(data),
+        198u16 => check::<RemoteDomainEventCallbackIoErrorMsg>(data),
+        199u16 => check::<RemoteDomainEventCallbackIoErrorReasonMsg>(data),
+        200u16 => check::<RemoteDomainEventCallbackJobCompletedMsg>(data),
+        201u16 => check::<RemoteDomainEventCallbackLifecycleMsg>(data),
+        202u16 => check::<RemoteDomainEventCallbackMetadataChangeMsg>(data),
+        203u16 => check::<RemoteDomainEventCallbackMigrationIterationMsg>(data),
+        204u16 => check::<RemoteDomainEventCallbackPmsuspendDiskMsg>(data),
+        205u16 => check::<RemoteDomainEventCallbackPmsuspendMsg>(data),
+        206u16 => check::<RemoteDomainEventCallbackPmwakeupMsg>(data),
+        207u16 => check::<RemoteDomainEventCallbackRebootMsg>(data),
+        208u16 => check::<RemoteDomainEventCallbackRtcChangeMsg>(data),
+        209u16 => check::<RemoteDomainEventCallbackTrayChangeMsg>(data),
+        210u16 => check::<RemoteDomainEventCallbackTunableMsg>(data),
+        211u16 => check::<RemoteDomainEventCallbackWatchdogMsg>(data),
+        212u16 => check::<RemoteDomainEventControlErrorMsg>(data),
+        213u16 => check::<RemoteDomainEventDeviceRemovedMsg>(data),
+        214u16 => check::<RemoteDomainEventDiskChangeMsg>(data),
+        215u16 => check::<RemoteDomainEventGraphicsAddress>(data),
+        216u16 => check::<RemoteDomainEventGraphicsIdentity>(data),
+        217u16 => check::<RemoteDomainEventGraphicsMsg>(data),
+        218u16 => check::<RemoteDomainEventIoErrorMsg>(data),
+        219u16 => check::<RemoteDomainEventIoErrorReasonMsg>(data),
+        220u16 => check::<RemoteDomainEventLifecycleMsg>(data),
+        221u16 => check::<RemoteDomainEventMemoryDeviceSizeChangeMsg>(data),
+        222u16 => check::<RemoteDomainEventMemoryFailureMsg>(data),
+        223u16 => check::<RemoteDomainEventNicMacChangeMsg>(data),
+        224u16 => check::<RemoteDomainEventPmsuspendDiskMsg>(data),
+        225u16 => check::<RemoteDomainEventPmsuspendMsg>(data),
+        226u16 => check::<RemoteDomainEventPmwakeupMsg>(data),
+        227u16 => check::<RemoteDomainEventRebootMsg>(data),
+        228u16 => check::<RemoteDomainEventRtcChangeMsg>(data),
+        229u16 => check::<RemoteDomainEventTrayChangeMsg>(data),
+        230u16 => check::<RemoteDomainEventVcpuRemovedMsg>(data),
+        231u16 => check::<RemoteDomainEventWatchdogMsg>(data),
+        232u16 => check::<RemoteDomainFdAssociateArgs>(data),
+        233u16 => check::<RemoteDomainFsfreezeArgs>(data),
+        234u16 => check::<RemoteDomainFsfreezeRet>(data),
+        235u16 => check::<RemoteDomainFsinfo>(data),
+        236u16 => check::<RemoteDomainFsthawArgs>(data),
+        237u16 => check::<RemoteDomainFsthawRet>(data),
+        238u16 => check::<RemoteDomainFstrimArgs>(data),
+        239u16 => check::<RemoteDomainGetAutostartArgs>(data),
+        240u16 => check::<RemoteDomainGetAutostartOnceArgs>(data),
+        241u16 => check::<RemoteDomainGetAutostartOnceRet>(data),
+        242u16 => check::<RemoteDomainGetAutostartRet>(data),
+        243u16 => check::<RemoteDomainGetBlkioParametersArgs>(data),
+        244u16 => check::<RemoteDomainGetBlkioParametersRet>(data),
+        245u16 => check::<RemoteDomainGetBlockInfoArgs>(data),
+        246u16 => check::<RemoteDomainGetBlockInfoRet>(data),
+        247u16 => check::<RemoteDomainGetBlockIoTuneArgs>(data),
+        248u16 => check::<RemoteDomainGetBlockIoTuneRet>(data),
+        249u16 => check::<RemoteDomainGetBlockJobInfoArgs>(data),
+        250u16 => check::<RemoteDomainGetBlockJobInfoRet>(data),
+        251u16 => check::<RemoteDomainGetControlInfoArgs>(data),
+        252u16 => check::<RemoteDomainGetControlInfoRet>(data),
+        253u16 => check::<RemoteDomainGetCpuStatsArgs>(data),
+        254u16 => check::<RemoteDomainGetCpuStatsRet>(data),
+        255u16 => check::<RemoteDomainGetDiskErrorsArgs>(data),
+        256u16 => check::<RemoteDomainGetDiskErrorsRet>(data),
+        257u16 => check::<RemoteDomainGetEmulatorPinInfoArgs>(data),
+        258u16 => check::<RemoteDomainGetEmulatorPinInfoRet>(data),
+        259u16 => check::<RemoteDomainGetFsinfoArgs>(data),
+        260u16 => check::<RemoteDomainGetFsinfoRet>(data),
+        261u16 => check::<RemoteDomainGetGuestInfoArgs>(data),
+        262u16 => check::<RemoteDomainGetGuestInfoRet>(data),
+        263u16 => check::<RemoteDomainGetGuestVcpusArgs>(data),
+        264u16 => check::<RemoteDomainGetGuestVcpusRet>(data),
+        265u16 => check::<RemoteDomainGetHostnameArgs>(data),
+        266u16 => check::<RemoteDomainGetHostnameRet>(data),
+        267u16 => check::<RemoteDomainGetInfoArgs>(data),
+        268u16 => check::<RemoteDomainGetInfoRet>(data),
+        269u16 => check::<RemoteDomainGetInterfaceParametersArgs>(data),
+        270u16 => check::<RemoteDomainGetInterfaceParametersRet>(data),
+        271u16 => check::<RemoteDomainGetIothreadInfoArgs>(data),
+        272u16 => check::<RemoteDomainGetIothreadInfoRet>(data),
+        273u16 => check::<RemoteDomainGetJobInfoArgs>(data),
+        274u16 => check::<RemoteDomainGetJobInfoRet>(data),
+        275u16 => check::<RemoteDomainGetJobStatsArgs>(data),
+        276u16 => check::<RemoteDomainGetJobStatsRet>(data),
+        277u16 => check::<RemoteDomainGetLaunchSecurityInfoArgs>(data),
+        278u16 => check::<RemoteDomainGetLaunchSecurityInfoRet>(data),
+        279u16 => check::<RemoteDomainGetMaxMemoryArgs>(data),
+        280u16 => check::<RemoteDomainGetMaxMemoryRet>(data),
+        281u16 => check::<RemoteDomainGetMaxVcpusArgs>(data),
+        282u16 => check::<RemoteDomainGetMaxVcpusRet>(data),
+        283u16 => check::<RemoteDomainGetMemoryParametersArgs>(data),
+        284u16 => check::<RemoteDomainGetMemoryParametersRet>(data),
+        285u16 => check::<RemoteDomainGetMessagesArgs>(data),
+        286u16 => check::<RemoteDomainGetMessagesRet>(data),
+        287u16 => check::<RemoteDomainGetMetadataArgs>(data),
+        288u16 => check::<RemoteDomainGetMetadataRet>(data),
+        289u16 => check::<RemoteDomainGetNumaParametersArgs>(data),
+        290u16 => check::<RemoteDomainGetNumaParametersRet>(data),
+        291u16 => check::<RemoteDomainGetOsTypeArgs>(data),
+        292u16 => check::<RemoteDomainGetOsTypeRet>(data),
+        293u16 => check::<RemoteDomainGetPerfEventsArgs>(data),
+        294u16 => check::<RemoteDomainGetPerfEventsRet>(data),
+        295u16 => check::<RemoteDomainGetSchedulerParametersArgs>(data),
+        296u16 => check::<RemoteDomainGetSchedulerParametersFlagsArgs>(data),
+        297u16 => check::<RemoteDomainGetSchedulerParametersFlagsRet>(data),
+        298u16 => check::<RemoteDomainGetSchedulerParametersRet>(data),
+        299u16 => check::<RemoteDomainGetSchedulerTypeArgs>(data),
+        300u16 => check::<RemoteDomainGetSchedulerTypeRet>(data),
+        301u16 => check::<RemoteDomainGetSecurityLabelArgs>(data),
+        302u16 => check::<RemoteDomainGetSecurityLabelListArgs>(data),
+        303u16 => check::<RemoteDomainGetSecurityLabelListRet>(data),
+        304u16 => check::<RemoteDomainGetSecurityLabelRet>(data),
+        305u16 => check::<RemoteDomainGetStateArgs>(data),
+        306u16 => check::<RemoteDomainGetStateRet>(data),
+        307u16 => check::<RemoteDomainGetTimeArgs>(data),
+        308u16 => check::<RemoteDomainGetTimeRet>(data),
+        309u16 => check::<RemoteDomainGetVcpuPinInfoArgs>(data),
+        310u16 => check::<RemoteDomainGetVcpuPinInfoRet>(data),
+        311u16 => check::<RemoteDomainGetVcpusArgs>(data),
+        312u16 => check::<RemoteDomainGetVcpusFlagsArgs>(data),
+        313u16 => check::<RemoteDomainGetVcpusFlagsRet>(data),
+        314u16 => check::<RemoteDomainGetVcpusRet>(data),
+        315u16 => check::<RemoteDomainGetXmlDescArgs>(data),
+        316u16 => check::<RemoteDomainGetXmlDescRet>(data),
+        317u16 => check::<RemoteDomainGraphicsReloadArgs>(data),
+        318u16 => check::<RemoteDomainHasCurrentSnapshotArgs>(data),
+        319u16 => check::<RemoteDomainHasCurrentSnapshotRet>(data),
+        320u16 => check::<RemoteDomainHasManagedSaveImageArgs>(data),
+        321u16 => check::<RemoteDomainHasManagedSaveImageRet>(data),
+        322u16 => check::<RemoteDomainInjectNmiArgs>(data),
+        323u16 => check::<RemoteDomainInterface>(data),
+        324u16 => check::<RemoteDomainInterfaceAddressesArgs>(data),
+        325u16 => check::<RemoteDomainInterfaceAddressesRet>(data),
+        326u16 => check::<RemoteDomainInterfaceStatsArgs>(data),
+        327u16 => check::<RemoteDomainInterfaceStatsRet>(data),
+        328u16 => check::<RemoteDomainIothreadInfo>(data),
+        329u16 => check::<RemoteDomainIpAddr>(data),
+        330u16 => check::<RemoteDomainIsActiveArgs>(data),
+        331u16 => check::<RemoteDomainIsActiveRet>(data),
+        332u16 => check::<RemoteDomainIsPersistentArgs>(data),
+        333u16 => check::<RemoteDomainIsPersistentRet>(data),
+        334u16 => check::<RemoteDomainIsUpdatedArgs>(data),
+        335u16 => check::<RemoteDomainIsUpdatedRet>(data),
+        336u16 => check::<RemoteDomainListAllCheckpointsArgs>(data),
+        337u16 => check::<RemoteDomainListAllCheckpointsRet>(data),
+        338u16 => check::<RemoteDomainListAllSnapshotsArgs>(data),
+        339u16 => check::<RemoteDomainListAllSnapshotsRet>(data),
+        340u16 => check::<RemoteDomainLookupByIdArgs>(data),
+        341u16 => check::<RemoteDomainLookupByIdRet>(data),
+        342u16 => check::<RemoteDomainLookupByNameArgs>(data),
+        343u16 => check::<RemoteDomainLookupByNameRet>(data),
+        344u16 => check::<RemoteDomainLookupByUuidArgs>(data),
+        345u16 => check::<RemoteDomainLookupByUuidRet>(data),
+        346u16 => check::<RemoteDomainManagedSaveArgs>(data),
+        347u16 => check::<RemoteDomainManagedSaveDefineXmlArgs>(data),
+        348u16 => check::<RemoteDomainManagedSaveGetXmlDescArgs>(data),
+        349u16 => check::<RemoteDomainManagedSaveGetXmlDescRet>(data),
+        350u16 => check::<RemoteDomainManagedSaveRemoveArgs>(data),
+        351u16 => check::<RemoteDomainMemoryPeekArgs>(data),
+        352u16 => check::<RemoteDomainMemoryPeekRet>(data),
+        353u16 => check::<RemoteDomainMemoryStat>(data),
+        354u16 => check::<RemoteDomainMemoryStatsArgs>(data),
+        355u16 => check::<RemoteDomainMemoryStatsRet>(data),
+        356u16 => check::<RemoteDomainMigrateBegin3Args>(data),
+        357u16 => check::<RemoteDomainMigrateBegin3ParamsArgs>(data),
+        358u16 => check::<RemoteDomainMigrateBegin3ParamsRet>(data),
+        359u16 => check::<RemoteDomainMigrateBegin3Ret>(data),
+        360u16 => check::<RemoteDomainMigrateConfirm3Args>(data),
+        361u16 => check::<RemoteDomainMigrateConfirm3ParamsArgs>(data),
+        362u16 => check::<RemoteDomainMigrateFinish2Args>(data),
+        363u16 => check::<RemoteDomainMigrateFinish2Ret>(data),
+        364u16 => check::<RemoteDomainMigrateFinish3Args>(data),
+        365u16 => check::<RemoteDomainMigrateFinish3ParamsArgs>(data),
+        366u16 => check::<RemoteDomainMigrateFinish3ParamsRet>(data),
+        367u16 => check::<RemoteDomainMigrateFinish3Ret>(data),
+        368u16 => check::<RemoteDomainMigrateFinishArgs>(data),
+        369u16 => check::<RemoteDomainMigrateFinishRet>(data),
+        370u16 => check::<RemoteDomainMigrateGetCompressionCacheArgs>(data),
+        371u16 => check::<RemoteDomainMigrateGetCompressionCacheRet>(data),
+        372u16 => check::<RemoteDomainMigrateGetMaxDowntimeArgs>(data),
+        373u16 => check::<RemoteDomainMigrateGetMaxDowntimeRet>(data),
+        374u16 => check::<RemoteDomainMigrateGetMaxSpeedArgs>(data),
+        375u16 => check::<RemoteDomainMigrateGetMaxSpeedRet>(data),
+        376u16 => check::<RemoteDomainMigratePerform3Args>(data),
+        377u16 => check::<RemoteDomainMigratePerform3ParamsArgs>(data),
+        378u16 => check::<RemoteDomainMigratePerform3ParamsRet>(data),
+        379u16 => check::<RemoteDomainMigratePerform3Ret>(data),
+        380u16 => check::<RemoteDomainMigratePerformArgs>(data),
+        381u16 => check::<RemoteDomainMigratePrepare2Args>(data),
+        382u16 => check::<RemoteDomainMigratePrepare2Ret>(data),
+        383u16 => check::<RemoteDomainMigratePrepare3Args>(data),
+        384u16 => check::<RemoteDomainMigratePrepare3ParamsArgs>(data),
+        385u16 => check::<RemoteDomainMigratePrepare3ParamsRet>(data),
+        386u16 => check::<RemoteDomainMigratePrepare3Ret>(data),
+        387u16 => check::<RemoteDomainMigratePrepareArgs>(data),
+        388u16 => check::<RemoteDomainMigratePrepareRet>(data),
+        389u16 => check::<RemoteDomainMigratePrepareTunnel3Args>(data),
+        390u16 => check::<RemoteDomainMigratePrepareTunnel3ParamsArgs>(data),
+        391u16 => check::<RemoteDomainMigratePrepareTunnel3ParamsRet>(data),
+        392u16 => check::<RemoteDomainMigratePrepareTunnel3Ret>(data),
+        393u16 => check::<RemoteDomainMigratePrepareTunnelArgs>(data),
+        394u16 => check::<RemoteDomainMigrateSetCompressionCacheArgs>(data),
+        395u16 => check::<RemoteDomainMigrateSetMaxDowntimeArgs>(data),
+        396u16 => check::<RemoteDomainMigrateSetMaxSpeedArgs>(data),
+        397u16 => check::<RemoteDomainMigrateStartPostCopyArgs>(data),
+        398u16 => check::<RemoteDomainOpenChannelArgs>(data),
+        399u16 => check::<RemoteDomainOpenConsoleArgs>(data),
+        400u16 => check::<RemoteDomainOpenGraphicsArgs>(data),
+        401u16 => check::<RemoteDomainOpenGraphicsFdArgs>(data),
+        402u16 => check::<RemoteDomainPinEmulatorArgs>(data),
+        403u16 => check::<RemoteDomainPinIothreadArgs>(data),
+        404u16 => check::<RemoteDomainPinVcpuArgs>(data),
+        405u16 => check::<RemoteDomainPinVcpuFlagsArgs>(data),
+        406u16 => check::<RemoteDomainPmSuspendForDurationArgs>(data),
+        407u16 => check::<RemoteDomainPmWakeupArgs>(data),
+        408u16 => check::<RemoteDomainRebootArgs>(data),
+        409u16 => check::<RemoteDomainRenameArgs>(data),
+        410u16 => check::<RemoteDomainRenameRet>(data),
+        411u16 => check::<RemoteDomainResetArgs>(data),
+        412u16 => check::<RemoteDomainRestoreArgs>(data),
+        413u16 => check::<RemoteDomainRestoreFlagsArgs>(data),
+        414u16 => check::<RemoteDomainRestoreParamsArgs>(data),
+        415u16 => check::<RemoteDomainResumeArgs>(data),
+        416u16 => check::<RemoteDomainRevertToSnapshotArgs>(data),
+        417u16 => check::<RemoteDomainSaveArgs>(data),
+        418u16 => check::<RemoteDomainSaveFlagsArgs>(data),
+        419u16 => check::<RemoteDomainSaveImageDefineXmlArgs>(data),
+        420u16 => check::<RemoteDomainSaveImageGetXmlDescArgs>(data),
+        421u16 => check::<RemoteDomainSaveImageGetXmlDescRet>(data),
+        422u16 => check::<RemoteDomainSaveParamsArgs>(data),
+        423u16 => check::<RemoteDomainScreenshotArgs>(data),
+        424u16 => check::<RemoteDomainScreenshotRet>(data),
+        425u16 => check::<RemoteDomainSendKeyArgs>(data),
+        426u16 => check::<RemoteDomainSendProcessSignalArgs>(data),
+        427u16 => check::<RemoteDomainSetAutostartArgs>(data),
+        428u16 => check::<RemoteDomainSetAutostartOnceArgs>(data),
+        429u16 => check::<RemoteDomainSetBlkioParametersArgs>(data),
+        430u16 => check::<RemoteDomainSetBlockIoTuneArgs>(data),
+        431u16 => check::<RemoteDomainSetBlockThresholdArgs>(data),
+        432u16 => check::<RemoteDomainSetGuestVcpusArgs>(data),
+        433u16 => check::<RemoteDomainSetInterfaceParametersArgs>(data),
+        434u16 => check::<RemoteDomainSetIothreadParamsArgs>(data),
+        435u16 => check::<RemoteDomainSetLaunchSecurityStateArgs>(data),
+        436u16 => check::<RemoteDomainSetLifecycleActionArgs>(data),
+        437u16 => check::<RemoteDomainSetMaxMemoryArgs>(data),
+        438u16 => check::<RemoteDomainSetMemoryArgs>(data),
+        439u16 => check::<RemoteDomainSetMemoryFlagsArgs>(data),
+        440u16 => check::<RemoteDomainSetMemoryParametersArgs>(data),
+        441u16 => check::<RemoteDomainSetMemoryStatsPeriodArgs>(data),
+        442u16 => check::<RemoteDomainSetMetadataArgs>(data),
+        443u16 => check::<RemoteDomainSetNumaParametersArgs>(data),
+        444u16 => check::<RemoteDomainSetPerfEventsArgs>(data),
+        445u16 => check::<RemoteDomainSetSchedulerParametersArgs>(data),
+        446u16 => check::<RemoteDomainSetSchedulerParametersFlagsArgs>(data),
+        447u16 => check::<RemoteDomainSetThrottleGroupArgs>(data),
+        448u16 => check::<RemoteDomainSetTimeArgs>(data),
+        449u16 => check::<RemoteDomainSetUserPasswordArgs>(data),
+        450u16 => check::<RemoteDomainSetVcpuArgs>(data),
+        451u16 => check::<RemoteDomainSetVcpusArgs>(data),
+        452u16 => check::<RemoteDomainSetVcpusFlagsArgs>(data),
+        453u16 => check::<RemoteDomainShutdownArgs>(data),
+        454u16 => check::<RemoteDomainShutdownFlagsArgs>(data),
+        455u16 => check::<RemoteDomainSnapshotCreateXmlArgs>(data),
+        456u16 => check::<RemoteDomainSnapshotCreateXmlRet>(data),
+        457u16 => check::<RemoteDomainSnapshotCurrentArgs>(data),
+        458u16 => check::<RemoteDomainSnapshotCurrentRet>(data),
+        459u16 => check::<RemoteDomainSnapshotDeleteArgs>(data),
+        460u16 => check::<RemoteDomainSnapshotGetParentArgs>(data),
+        461u16 => check::<RemoteDomainSnapshotGetParentRet>(data),
+        462u16 => check::<RemoteDomainSnapshotGetXmlDescArgs>(data),
+        463u16 => check::<RemoteDomainSnapshotGetXmlDescRet>(data),
+        464u16 => check::<RemoteDomainSnapshotHasMetadataArgs>(data),
+        465u16 => check::<RemoteDomainSnapshotHasMetadataRet>(data),
+        466u16 => check::<RemoteDomainSnapshotIsCurrentArgs>(data),
+        467u16 => check::<RemoteDomainSnapshotIsCurrentRet>(data),
+        468u16 => check::<RemoteDomainSnapshotListAllChildrenArgs>(data),
+        469u16 => check::<RemoteDomainSnapshotListAllChildrenRet>(data),
+        470u16 => check::<RemoteDomainSnapshotListChildrenNamesArgs>(data),
+        471u16 => check::<RemoteDomainSnapshotListChildrenNamesRet>(data),
+        472u16 => check::<RemoteDomainSnapshotListNamesArgs>(data),
+        473u16 => check::<RemoteDomainSnapshotListNamesRet>(data),
+        474u16 => check::<RemoteDomainSnapshotLookupByNameArgs>(data),
+        475u16 => check::<RemoteDomainSnapshotLookupByNameRet>(data),
+        476u16 => check::<RemoteDomainSnapshotNumArgs>(data),
+        477u16 => check::<RemoteDomainSnapshotNumChildrenArgs>(data),
+        478u16 => check::<RemoteDomainSnapshotNumChildrenRet>(data),
+        479u16 => check::<RemoteDomainSnapshotNumRet>(data),
+        480u16 => check::<RemoteDomainStartDirtyRateCalcArgs>(data),
+        481u16 => check::<RemoteDomainStatsRecord>(data),
+        482u16 => check::<RemoteDomainSuspendArgs>(data),
+        483u16 => check::<RemoteDomainUndefineArgs>(data),
+        484u16 => check::<RemoteDomainUndefineFlagsArgs>(data),
+        485u16 => check::<RemoteDomainUpdateDeviceFlagsArgs>(data),
+        486u16 => check::<RemoteError>(data),
+        487u16 => check::<RemoteInterfaceChangeBeginArgs>(data),
+        488u16 => check::<RemoteInterfaceChangeCommitArgs>(data),
+        489u16 => check::<RemoteInterfaceChangeRollbackArgs>(data),
+        490u16 => check::<RemoteInterfaceCreateArgs>(data),
+        491u16 => check::<RemoteInterfaceDefineXmlArgs>(data),
+        492u16 => check::<RemoteInterfaceDefineXmlRet>(data),
+        493u16 => check::<RemoteInterfaceDestroyArgs>(data),
+        494u16 => check::<RemoteInterfaceGetXmlDescArgs>(data),
+        495u16 => check::<RemoteInterfaceGetXmlDescRet>(data),
+        496u16 => check::<RemoteInterfaceIsActiveArgs>(data),
+        497u16 => check::<RemoteInterfaceIsActiveRet>(data),
+        498u16 => check::<RemoteInterfaceLookupByMacStringArgs>(data),
+        499u16 => check::<RemoteInterfaceLookupByMacStringRet>(data),
+        500u16 => check::<RemoteInterfaceLookupByNameArgs>(data),
+        501u16 => check::<RemoteInterfaceLookupByNameRet>(data),
+        502u16 => check::<RemoteInterfaceUndefineArgs>(data),
+        503u16 => check::<RemoteNetworkCreateArgs>(data),
+        504u16 => check::<RemoteNetworkCreateXmlArgs>(data),
+        505u16 => check::<RemoteNetworkCreateXmlFlagsArgs>(data),
+        506u16 => check::<RemoteNetworkCreateXmlFlagsRet>(data),
+        507u16 => check::<RemoteNetworkCreateXmlRet>(data),
+        508u16 => check::<RemoteNetworkDefineXmlArgs>(data),
+        509u16 => check::<RemoteNetworkDefineXmlFlagsArgs>(data),
+        510u16 => check::<RemoteNetworkDefineXmlFlagsRet>(data),
+        511u16 => check::<RemoteNetworkDefineXmlRet>(data),
+        512u16 => check::<RemoteNetworkDestroyArgs>(data),
+        513u16 => check::<RemoteNetworkDhcpLease>(data),
+        514u16 => check::<RemoteNetworkEventCallbackMetadataChangeMsg>(data),
+        515u16 => check::<RemoteNetworkEventLifecycleMsg>(data),
+        516u16 => check::<RemoteNetworkGetAutostartArgs>(data),
+        517u16 => check::<RemoteNetworkGetAutostartRet>(data),
+        518u16 => check::<RemoteNetworkGetBridgeNameArgs>(data),
+        519u16 => check::<RemoteNetworkGetBridgeNameRet>(data),
+        520u16 => check::<RemoteNetworkGetDhcpLeasesArgs>(data),
+        521u16 => check::<RemoteNetworkGetDhcpLeasesRet>(data),
+        522u16 => check::<RemoteNetworkGetMetadataArgs>(data),
+        523u16 => check::<RemoteNetworkGetMetadataRet>(data),
+        524u16 => check::<RemoteNetworkGetXmlDescArgs>(data),
+        525u16 => check::<RemoteNetworkGetXmlDescRet>(data),
+        526u16 => check::<RemoteNetworkIsActiveArgs>(data),
+        527u16 => check::<RemoteNetworkIsActiveRet>(data),
+        528u16 => check::<RemoteNetworkIsPersistentArgs>(data),
+        529u16 => check::<RemoteNetworkIsPersistentRet>(data),
+        530u16 => check::<RemoteNetworkListAllPortsArgs>(data),
+        531u16 => check::<RemoteNetworkListAllPortsRet>(data),
+        532u16 => check::<RemoteNetworkLookupByNameArgs>(data),
+        533u16 => check::<RemoteNetworkLookupByNameRet>(data),
+        534u16 => check::<RemoteNetworkLookupByUuidArgs>(data),
+        535u16 => check::<RemoteNetworkLookupByUuidRet>(data),
+        536u16 => check::<RemoteNetworkPortCreateXmlArgs>(data),
+        537u16 => check::<RemoteNetworkPortCreateXmlRet>(data),
+        538u16 => check::<RemoteNetworkPortDeleteArgs>(data),
+        539u16 => check::<RemoteNetworkPortGetParametersArgs>(data),
+        540u16 => check::<RemoteNetworkPortGetParametersRet>(data),
+        541u16 => check::<RemoteNetworkPortGetXmlDescArgs>(data),
+        542u16 => check::<RemoteNetworkPortGetXmlDescRet>(data),
+        543u16 => check::<RemoteNetworkPortLookupByUuidArgs>(data),
+        544u16 => check::<RemoteNetworkPortLookupByUuidRet>(data),
+        545u16 => check::<RemoteNetworkPortSetParametersArgs>(data),
+        546u16 => check::<RemoteNetworkSetAutostartArgs>(data),
+        547u16 => check::<RemoteNetworkSetMetadataArgs>(data),
+        548u16 => check::<RemoteNetworkUndefineArgs>(data),
+        549u16 => check::<RemoteNetworkUpdateArgs>(data),
+        550u16 => check::<RemoteNodeAllocPagesArgs>(data),
+        551u16 => check::<RemoteNodeAllocPagesRet>(data),
+        552u16 => check::<RemoteNodeDeviceCreateArgs>(data),
+        553u16 => check::<RemoteNodeDeviceCreateXmlArgs>(data),
+        554u16 => check::<RemoteNodeDeviceCreateXmlRet>(data),
+        555u16 => check::<RemoteNodeDeviceDefineXmlArgs>(data),
+        556u16 => check::<RemoteNodeDeviceDefineXmlRet>(data),
+        557u16 => check::<RemoteNodeDeviceDestroyArgs>(data),
+        558u16 => check::<RemoteNodeDeviceDetachFlagsArgs>(data),
+        559u16 => check::<RemoteNodeDeviceDettachArgs>(data),
+        560u16 => check::<RemoteNodeDeviceEventLifecycleMsg>(data),
+        561u16 => check::<RemoteNodeDeviceEventUpdateMsg>(data),
+        562u16 => check::<RemoteNodeDeviceGetAutostartArgs>(data),
+        563u16 => check::<RemoteNodeDeviceGetAutostartRet>(data),
+        564u16 => check::<RemoteNodeDeviceGetParentArgs>(data),
+        565u16 => check::<RemoteNodeDeviceGetParentRet>(data),
+        566u16 => check::<RemoteNodeDeviceGetXmlDescArgs>(data),
+        567u16 => check::<RemoteNodeDeviceGetXmlDescRet>(data),
+        568u16 => check::<RemoteNodeDeviceIsActiveArgs>(data),
+        569u16 => check::<RemoteNodeDeviceIsActiveRet>(data),
+        570u16 => check::<RemoteNodeDeviceIsPersistentArgs>(data),
+        571u16 => check::<RemoteNodeDeviceIsPersistentRet>(data),
+        572u16 => check::<RemoteNodeDeviceListCapsArgs>(data),
+        573u16 => check::<RemoteNodeDeviceListCapsRet>(data),
+        574u16 => check::<RemoteNodeDeviceLookupByNameArgs>(data),
+        575u16 => check::<RemoteNodeDeviceLookupByNameRet>(data),
+        576u16 => check::<RemoteNodeDeviceLookupScsiHostByWwnArgs>(data),
+        577u16 => check::<RemoteNodeDeviceLookupScsiHostByWwnRet>(data),
+        578u16 => check::<RemoteNodeDeviceNumOfCapsArgs>(data),
+        579u16 => check::<RemoteNodeDeviceNumOfCapsRet>(data),
+        580u16 => check::<RemoteNodeDeviceReAttachArgs>(data),
+        581u16 => check::<RemoteNodeDeviceResetArgs>(data),
+        582u16 => check::<RemoteNodeDeviceSetAutostartArgs>(data),
+        583u16 => check::<RemoteNodeDeviceUndefineArgs>(data),
+        584u16 => check::<RemoteNodeDeviceUpdateArgs>(data),
+        585u16 => check::<RemoteNodeGetCellsFreeMemoryArgs>(data),
+        586u16 => check::<RemoteNodeGetCellsFreeMemoryRet>(data),
+        587u16 => check::<RemoteNodeGetCpuMapArgs>(data),
+        588u16 => check::<RemoteNodeGetCpuMapRet>(data),
+        589u16 => check::<RemoteNodeGetCpuStats>(data),
+        590u16 => check::<RemoteNodeGetCpuStatsArgs>(data),
+        591u16 => check::<RemoteNodeGetCpuStatsRet>(data),
+        592u16 => check::<RemoteNodeGetFreeMemoryRet>(data),
+        593u16 => check::<RemoteNodeGetFreePagesArgs>(data),
+        594u16 => check::<RemoteNodeGetFreePagesRet>(data),
+        595u16 => check::<RemoteNodeGetInfoRet>(data),
+        596u16 => check::<RemoteNodeGetMemoryParametersArgs>(data),
+        597u16 => check::<RemoteNodeGetMemoryParametersRet>(data),
+        598u16 => check::<RemoteNodeGetMemoryStats>(data),
+        599u16 => check::<RemoteNodeGetMemoryStatsArgs>(data),
+        600u16 => check::<RemoteNodeGetMemoryStatsRet>(data),
+        601u16 => check::<RemoteNodeGetSecurityModelRet>(data),
+        602u16 => check::<RemoteNodeGetSevInfoArgs>(data),
+        603u16 => check::<RemoteNodeGetSevInfoRet>(data),
+        604u16 => check::<RemoteNodeListDevicesArgs>(data),
+        605u16 => check::<RemoteNodeListDevicesRet>(data),
+        606u16 => check::<RemoteNodeNumOfDevicesArgs>(data),
+        607u16 => check::<RemoteNodeNumOfDevicesRet>(data),
+        608u16 => check::<RemoteNodeSetMemoryParametersArgs>(data),
+        609u16 => check::<RemoteNodeSuspendForDurationArgs>(data),
+        610u16 => check::<RemoteNonnullDomain>(data),
+        611u16 => check::<RemoteNonnullDomainCheckpoint>(data),
+        612u16 => check::<RemoteNonnullDomainSnapshot>(data),
+        613u16 => check::<RemoteNonnullInterface>(data),
+        614u16 => check::<RemoteNonnullNetwork>(data),
+        615u16 => check::<RemoteNonnullNetworkPort>(data),
+        616u16 => check::<RemoteNonnullNodeDevice>(data),
+        617u16 => check::<RemoteNonnullNwfilter>(data),
+        618u16 => check::<RemoteNonnullNwfilterBinding>(data),
+        619u16 => check::<RemoteNonnullSecret>(data),
+        620u16 => check::<RemoteNonnullStoragePool>(data),
+        621u16 => check::<RemoteNonnullStorageVol>(data),
+        622u16 => check::<RemoteNwfilterBindingCreateXmlArgs>(data),
+        623u16 => check::<RemoteNwfilterBindingCreateXmlRet>(data),
+        624u16 => check::<RemoteNwfilterBindingDeleteArgs>(data),
+        625u16 => check::<RemoteNwfilterBindingGetXmlDescArgs>(data),
+        626u16 => check::<RemoteNwfilterBindingGetXmlDescRet>(data),
+        627u16 => check::<RemoteNwfilterBindingLookupByPortDevArgs>(data),
+        628u16 => check::<RemoteNwfilterBindingLookupByPortDevRet>(data),
+        629u16 => check::<RemoteNwfilterDefineXmlArgs>(data),
+        630u16 => check::<RemoteNwfilterDefineXmlFlagsArgs>(data),
+        631u16 => check::<RemoteNwfilterDefineXmlFlagsRet>(data),
+        632u16 => check::<RemoteNwfilterDefineXmlRet>(data),
+        633u16 => check::<RemoteNwfilterGetXmlDescArgs>(data),
+        634u16 => check::<RemoteNwfilterGetXmlDescRet>(data),
+        635u16 => check::<RemoteNwfilterLookupByNameArgs>(data),
+        636u16 => check::<RemoteNwfilterLookupByNameRet>(data),
+        637u16 => check::<RemoteNwfilterLookupByUuidArgs>(data),
+        638u16 => check::<RemoteNwfilterLookupByUuidRet>(data),
+        639u16 => check::<RemoteNwfilterUndefineArgs>(data),
+        640u16 => check::<RemoteSecretDefineXmlArgs>(data),
+        641u16 => check::<RemoteSecretDefineXmlRet>(data),
+        642u16 => check::<RemoteSecretEventLifecycleMsg>(data),
+        643u16 => check::<RemoteSecretEventValueChangedMsg>(data),
+        644u16 => check::<RemoteSecretGetValueArgs>(data),
+        645u16 => check::<RemoteSecretGetValueRet>(data),
+        646u16 => check::<RemoteSecretGetXmlDescArgs>(data),
+        647u16 => check::<RemoteSecretGetXmlDescRet>(data),
+        648u16 => check::<RemoteSecretLookupByUsageArgs>(data),
+        649u16 => check::<RemoteSecretLookupByUsageRet>(data),
+        650u16 => check::<RemoteSecretLookupByUuidArgs>(data),
+        651u16 => check::<RemoteSecretLookupByUuidRet>(data),
+        652u16 => check::<RemoteSecretSetValueArgs>(data),
+        653u16 => check::<RemoteSecretUndefineArgs>(data),
+        654u16 => check::<RemoteStoragePoolBuildArgs>(data),
+        655u16 => check::<RemoteStoragePoolCreateArgs>(data),
+        656u16 => check::<RemoteStoragePoolCreateXmlArgs>(data),
+        657u16 => check::<RemoteStoragePoolCreateXmlRet>(data),
+        658u16 => check::<RemoteStoragePoolDefineXmlArgs>(data),
+        659u16 => check::<RemoteStoragePoolDefineXmlRet>(data),
+        660u16 => check::<RemoteStoragePoolDeleteArgs>(data),
+        661u16 => check::<RemoteStoragePoolDestroyArgs>(data),
+        662u16 => check::<RemoteStoragePoolEventLifecycleMsg>(data),
+        663u16 => check::<RemoteStoragePoolEventRefreshMsg>(data),
+        664u16 => check::<RemoteStoragePoolGetAutostartArgs>(data),
+        665u16 => check::<RemoteStoragePoolGetAutostartRet>(data),
+        666u16 => check::<RemoteStoragePoolGetInfoArgs>(data),
+        667u16 => check::<RemoteStoragePoolGetInfoRet>(data),
+        668u16 => check::<RemoteStoragePoolGetXmlDescArgs>(data),
+        669u16 => check::<RemoteStoragePoolGetXmlDescRet>(data),
+        670u16 => check::<RemoteStoragePoolIsActiveArgs>(data),
+        671u16 => check::<RemoteStoragePoolIsActiveRet>(data),
+        672u16 => check::<RemoteStoragePoolIsPersistentArgs>(data),
+        673u16 => check::<RemoteStoragePoolIsPersistentRet>(data),
+        674u16 => check::<RemoteStoragePoolListAllVolumesArgs>(data),
+        675u16 => check::<RemoteStoragePoolListAllVolumesRet>(data),
+        676u16 => check::<RemoteStoragePoolListVolumesArgs>(data),
+        677u16 => check::<RemoteStoragePoolListVolumesRet>(data),
+        678u16 => check::<RemoteStoragePoolLookupByNameArgs>(data),
+        679u16 => check::<RemoteStoragePoolLookupByNameRet>(data),
+        680u16 => check::<RemoteStoragePoolLookupByTargetPathArgs>(data),
+        681u16 => check::<RemoteStoragePoolLookupByTargetPathRet>(data),
+        682u16 => check::<RemoteStoragePoolLookupByUuidArgs>(data),
+        683u16 => check::<RemoteStoragePoolLookupByUuidRet>(data),
+        684u16 => check::<RemoteStoragePoolLookupByVolumeArgs>(data),
+        685u16 => check::<RemoteStoragePoolLookupByVolumeRet>(data),
+        686u16 => check::<RemoteStoragePoolNumOfVolumesArgs>(data),
+        687u16 => check::<RemoteStoragePoolNumOfVolumesRet>(data),
+        688u16 => check::<RemoteStoragePoolRefreshArgs>(data),
+        689u16 => check::<RemoteStoragePoolSetAutostartArgs>(data),
+        690u16 => check::<RemoteStoragePoolUndefineArgs>(data),
+        691u16 => check::<RemoteStorageVolCreateXmlArgs>(data),
+        692u16 => check::<RemoteStorageVolCreateXmlFromArgs>(data),
+        693u16 => check::<RemoteStorageVolCreateXmlFromRet>(data),
+        694u16 => check::<RemoteStorageVolCreateXmlRet>(data),
+        695u16 => check::<RemoteStorageVolDeleteArgs>(data),
+        696u16 => check::<RemoteStorageVolDownloadArgs>(data),
+        697u16 => check::<RemoteStorageVolGetInfoArgs>(data),
+        698u16 => check::<RemoteStorageVolGetInfoFlagsArgs>(data),
+        699u16 => check::<RemoteStorageVolGetInfoFlagsRet>(data),
+        700u16 => check::<RemoteStorageVolGetInfoRet>(data),
+        701u16 => check::<RemoteStorageVolGetPathArgs>(data),
+        702u16 => check::<RemoteStorageVolGetPathRet>(data),
+        703u16 => check::<RemoteStorageVolGetXmlDescArgs>(data),
+        704u16 => check::<RemoteStorageVolGetXmlDescRet>(data),
+        705u16 => check::<RemoteStorageVolLookupByKeyArgs>(data),
+        706u16 => check::<RemoteStorageVolLookupByKeyRet>(data),
+        707u16 => check::<RemoteStorageVolLookupByNameArgs>(data),
+        708u16 => check::<RemoteStorageVolLookupByNameRet>(data),
+        709u16 => check::<RemoteStorageVolLookupByPathArgs>(data),
+        710u16 => check::<RemoteStorageVolLookupByPathRet>(data),
+        711u16 => check::<RemoteStorageVolResizeArgs>(data),
+        712u16 => check::<RemoteStorageVolUploadArgs>(data),
+        713u16 => check::<RemoteStorageVolWipeArgs>(data),
+        714u16 => check::<RemoteStorageVolWipePatternArgs>(data),
+        715u16 => check::<RemoteTypedParam>(data),
+        716u16 => check::<RemoteVcpuInfo>(data),
+        _ => unreachable!(),
+    }
+});
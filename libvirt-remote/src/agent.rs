@@ -0,0 +1,560 @@
+use crate::binding::RemoteNonnullDomain;
+use crate::client::Libvirt;
+use crate::error::Error;
+use log::trace;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const VIR_DOMAIN_QEMU_AGENT_COMMAND_BLOCK: i32 = -2;
+pub const VIR_DOMAIN_QEMU_AGENT_COMMAND_DEFAULT: i32 = -1;
+pub const VIR_DOMAIN_QEMU_AGENT_COMMAND_NOWAIT: i32 = 0;
+pub const VIR_DOMAIN_QEMU_AGENT_COMMAND_SHUTDOWN: i32 = 60;
+
+const VIR_ERR_INTERNAL_ERROR: i32 = 1;
+const VIR_ERR_ARGUMENT_UNSUPPORTED: i32 = 74;
+const VIR_ERR_AGENT_UNRESPONSIVE: i32 = 86;
+const VIR_ERR_AGENT_UNSYNCED: i32 = 97;
+const VIR_ERR_AGENT_COMMAND_TIMEOUT: i32 = 112;
+const VIR_ERR_AGENT_COMMAND_FAILED: i32 = 113;
+
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(100);
+const DEFAULT_RUN_TIMEOUT: Duration = Duration::from_secs(300);
+const FILE_CHUNK_SIZE: usize = 48 * 1024;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AgentError {
+    // No guest agent channel in the domain definition.
+    NotConfigured(String),
+    // The agent is not running or not connected.
+    Unavailable(String),
+    Timeout(String),
+    // The agent rejected or failed the command.
+    CommandFailed { class: Option<String>, desc: String },
+    InvalidResponse(String),
+}
+
+impl AgentError {
+    // Libvirt reports agent problems as RPC errors, so pick them out by code.
+    pub fn from_error(error: Error) -> Error {
+        let Error::ProtocolError(e) = &error else {
+            return error;
+        };
+
+        let message = e.message.clone().unwrap_or_default();
+        let agent = match e.code {
+            VIR_ERR_ARGUMENT_UNSUPPORTED if message.contains("agent") => {
+                AgentError::NotConfigured(message)
+            }
+            VIR_ERR_AGENT_UNRESPONSIVE | VIR_ERR_AGENT_UNSYNCED => AgentError::Unavailable(message),
+            VIR_ERR_AGENT_COMMAND_TIMEOUT => AgentError::Timeout(message),
+            VIR_ERR_AGENT_COMMAND_FAILED => AgentError::CommandFailed {
+                class: None,
+                desc: message,
+            },
+            VIR_ERR_INTERNAL_ERROR if message.contains("agent command") => {
+                AgentError::CommandFailed {
+                    class: None,
+                    desc: message,
+                }
+            }
+            _ => return error,
+        };
+        Error::AgentError(agent)
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct GuestInfo {
+    pub version: String,
+    pub supported_commands: Vec<GuestCommandInfo>,
+}
+
+impl GuestInfo {
+    pub fn is_enabled(&self, command: &str) -> bool {
+        self.supported_commands
+            .iter()
+            .any(|c| c.name == command && c.enabled)
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct GuestCommandInfo {
+    pub name: String,
+    pub enabled: bool,
+    #[serde(rename = "success-response")]
+    pub success_response: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct GuestExec {
+    pub path: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub arg: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub env: Vec<String>,
+    #[serde(rename = "input-data", skip_serializing_if = "Option::is_none")]
+    pub input_data: Option<String>,
+    #[serde(rename = "capture-output")]
+    pub capture_output: bool,
+}
+
+impl GuestExec {
+    pub fn new(path: &str) -> Self {
+        GuestExec {
+            path: path.to_string(),
+            capture_output: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn arg(mut self, arg: &str) -> Self {
+        self.arg.push(arg.to_string());
+        self
+    }
+
+    pub fn args(mut self, args: &[&str]) -> Self {
+        self.arg.extend(args.iter().map(|a| a.to_string()));
+        self
+    }
+
+    pub fn env(mut self, name: &str, value: &str) -> Self {
+        self.env.push(format!("{name}={value}"));
+        self
+    }
+
+    pub fn input(mut self, data: &[u8]) -> Self {
+        self.input_data = Some(base64_encode(data));
+        self
+    }
+
+    pub fn capture_output(mut self, capture: bool) -> Self {
+        self.capture_output = capture;
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GuestExecStatus {
+    pub exited: bool,
+    pub exit_code: Option<i64>,
+    pub signal: Option<i64>,
+    pub out_data: Vec<u8>,
+    pub err_data: Vec<u8>,
+    pub out_truncated: bool,
+    pub err_truncated: bool,
+}
+
+impl GuestExecStatus {
+    pub fn success(&self) -> bool {
+        self.exited && self.exit_code == Some(0)
+    }
+}
+
+#[derive(Deserialize)]
+struct GuestExecStatusRet {
+    exited: bool,
+    exitcode: Option<i64>,
+    signal: Option<i64>,
+    #[serde(rename = "out-data")]
+    out_data: Option<String>,
+    #[serde(rename = "err-data")]
+    err_data: Option<String>,
+    #[serde(rename = "out-truncated", default)]
+    out_truncated: bool,
+    #[serde(rename = "err-truncated", default)]
+    err_truncated: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GuestFileRead {
+    pub data: Vec<u8>,
+    pub eof: bool,
+}
+
+#[derive(Deserialize)]
+struct GuestFileReadRet {
+    #[serde(rename = "buf-b64")]
+    buf_b64: String,
+    eof: bool,
+}
+
+#[derive(Deserialize)]
+struct GuestFileWriteRet {
+    count: usize,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct GuestNetworkInterface {
+    pub name: String,
+    #[serde(rename = "hardware-address")]
+    pub hardware_address: Option<String>,
+    #[serde(rename = "ip-addresses", default)]
+    pub ip_addresses: Vec<GuestIpAddress>,
+    pub statistics: Option<GuestNetworkStats>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct GuestIpAddress {
+    // "ipv4" or "ipv6".
+    #[serde(rename = "ip-address-type")]
+    pub ip_address_type: String,
+    #[serde(rename = "ip-address")]
+    pub ip_address: String,
+    pub prefix: u32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct GuestNetworkStats {
+    #[serde(rename = "rx-bytes")]
+    pub rx_bytes: u64,
+    #[serde(rename = "rx-packets")]
+    pub rx_packets: u64,
+    #[serde(rename = "rx-errs")]
+    pub rx_errs: u64,
+    #[serde(rename = "rx-dropped")]
+    pub rx_dropped: u64,
+    #[serde(rename = "tx-bytes")]
+    pub tx_bytes: u64,
+    #[serde(rename = "tx-packets")]
+    pub tx_packets: u64,
+    #[serde(rename = "tx-errs")]
+    pub tx_errs: u64,
+    #[serde(rename = "tx-dropped")]
+    pub tx_dropped: u64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct GuestOsInfo {
+    #[serde(rename = "kernel-release")]
+    pub kernel_release: Option<String>,
+    #[serde(rename = "kernel-version")]
+    pub kernel_version: Option<String>,
+    pub machine: Option<String>,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    #[serde(rename = "pretty-name")]
+    pub pretty_name: Option<String>,
+    pub version: Option<String>,
+    #[serde(rename = "version-id")]
+    pub version_id: Option<String>,
+    pub variant: Option<String>,
+    #[serde(rename = "variant-id")]
+    pub variant_id: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FsFreezeStatus {
+    Thawed,
+    Frozen,
+}
+
+pub struct GuestAgent<'a, T>
+where
+    T: Libvirt,
+{
+    client: &'a mut T,
+    dom: RemoteNonnullDomain,
+    timeout: i32,
+    interval: Duration,
+    run_timeout: Option<Duration>,
+}
+
+impl<'a, T> GuestAgent<'a, T>
+where
+    T: Libvirt,
+{
+    pub fn new(client: &'a mut T, dom: RemoteNonnullDomain) -> Self {
+        GuestAgent {
+            client,
+            dom,
+            timeout: VIR_DOMAIN_QEMU_AGENT_COMMAND_DEFAULT,
+            interval: DEFAULT_INTERVAL,
+            run_timeout: Some(DEFAULT_RUN_TIMEOUT),
+        }
+    }
+
+    // Seconds, or one of VIR_DOMAIN_QEMU_AGENT_COMMAND_*.
+    pub fn timeout(mut self, timeout: i32) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    // Polling interval of `run`.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    // How long `run` waits for the program to exit. `None` waits forever.
+    pub fn run_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.run_timeout = timeout;
+        self
+    }
+
+    pub fn execute<R>(&mut self, command: &str, arguments: Option<Value>) -> Result<R, Error>
+    where
+        R: DeserializeOwned,
+    {
+        let mut cmd = json!({ "execute": command });
+        if let Some(arguments) = arguments {
+            cmd["arguments"] = arguments;
+        }
+
+        trace!("agent command: {cmd}");
+        let output = self
+            .client
+            .domain_agent_command(self.dom.clone(), cmd.to_string(), self.timeout, 0)
+            .map_err(AgentError::from_error)?
+            .unwrap_or_default();
+
+        let mut response: Value = serde_json::from_str(&output).map_err(Error::JsonError)?;
+
+        if let Some(error) = response.get("error") {
+            return Err(Error::AgentError(AgentError::CommandFailed {
+                class: error["class"].as_str().map(|s| s.to_string()),
+                desc: error["desc"].as_str().unwrap_or_default().to_string(),
+            }));
+        }
+
+        match response.get_mut("return") {
+            Some(ret) => serde_json::from_value(ret.take()).map_err(Error::JsonError),
+            None => Err(Error::AgentError(AgentError::InvalidResponse(output))),
+        }
+    }
+
+    pub fn ping(&mut self) -> Result<(), Error> {
+        self.execute::<Value>("guest-ping", None).map(|_| ())
+    }
+
+    pub fn info(&mut self) -> Result<GuestInfo, Error> {
+        self.execute("guest-info", None)
+    }
+
+    pub fn exec(&mut self, exec: &GuestExec) -> Result<i64, Error> {
+        let args = serde_json::to_value(exec).map_err(Error::JsonError)?;
+        let ret: Value = self.execute("guest-exec", Some(args))?;
+        ret["pid"]
+            .as_i64()
+            .ok_or_else(|| Error::AgentError(AgentError::InvalidResponse(ret.to_string())))
+    }
+
+    pub fn exec_status(&mut self, pid: i64) -> Result<GuestExecStatus, Error> {
+        let ret: GuestExecStatusRet =
+            self.execute("guest-exec-status", Some(json!({ "pid": pid })))?;
+        Ok(GuestExecStatus {
+            exited: ret.exited,
+            exit_code: ret.exitcode,
+            signal: ret.signal,
+            out_data: decode(ret.out_data.as_deref())?,
+            err_data: decode(ret.err_data.as_deref())?,
+            out_truncated: ret.out_truncated,
+            err_truncated: ret.err_truncated,
+        })
+    }
+
+    // Runs the program and polls until it exits. On timeout the program is
+    // left running in the guest.
+    pub fn run(&mut self, exec: &GuestExec) -> Result<GuestExecStatus, Error> {
+        let pid = self.exec(exec)?;
+        let deadline = self.run_timeout.map(|t| Instant::now() + t);
+        loop {
+            let status = self.exec_status(pid)?;
+            if status.exited {
+                return Ok(status);
+            }
+            if deadline.is_some_and(|d| Instant::now() >= d) {
+                return Err(Error::AgentError(AgentError::Timeout(format!(
+                    "process {pid} did not exit"
+                ))));
+            }
+            thread::sleep(self.interval);
+        }
+    }
+
+    pub fn file_open(&mut self, path: &str, mode: &str) -> Result<i64, Error> {
+        self.execute(
+            "guest-file-open",
+            Some(json!({ "path": path, "mode": mode })),
+        )
+    }
+
+    pub fn file_read(&mut self, handle: i64, count: usize) -> Result<GuestFileRead, Error> {
+        let ret: GuestFileReadRet = self.execute(
+            "guest-file-read",
+            Some(json!({ "handle": handle, "count": count })),
+        )?;
+        Ok(GuestFileRead {
+            data: decode(Some(&ret.buf_b64))?,
+            eof: ret.eof,
+        })
+    }
+
+    pub fn file_write(&mut self, handle: i64, data: &[u8]) -> Result<usize, Error> {
+        let ret: GuestFileWriteRet = self.execute(
+            "guest-file-write",
+            Some(json!({ "handle": handle, "buf-b64": base64_encode(data) })),
+        )?;
+        Ok(ret.count)
+    }
+
+    pub fn file_close(&mut self, handle: i64) -> Result<(), Error> {
+        self.execute::<Value>("guest-file-close", Some(json!({ "handle": handle })))
+            .map(|_| ())
+    }
+
+    pub fn read_file(&mut self, path: &str) -> Result<Vec<u8>, Error> {
+        let handle = self.file_open(path, "r")?;
+
+        let mut data = vec![];
+        let ret = loop {
+            match self.file_read(handle, FILE_CHUNK_SIZE) {
+                Ok(chunk) => {
+                    data.extend(chunk.data);
+                    if chunk.eof {
+                        break Ok(());
+                    }
+                }
+                Err(e) => break Err(e),
+            }
+        };
+
+        // Always release the handle; the agent has a small limit of them.
+        let closed = self.file_close(handle);
+        ret.and(closed)?;
+        Ok(data)
+    }
+
+    pub fn write_file(&mut self, path: &str, data: &[u8]) -> Result<(), Error> {
+        let handle = self.file_open(path, "w")?;
+
+        let mut ret = Ok(());
+        for chunk in data.chunks(FILE_CHUNK_SIZE) {
+            let mut chunk = chunk;
+            while !chunk.is_empty() {
+                match self.file_write(handle, chunk) {
+                    Ok(0) => {
+                        ret = Err(Error::AgentError(AgentError::InvalidResponse(
+                            "no data written".to_string(),
+                        )));
+                        break;
+                    }
+                    Ok(n) => chunk = &chunk[n.min(chunk.len())..],
+                    Err(e) => {
+                        ret = Err(e);
+                        break;
+                    }
+                }
+            }
+            if ret.is_err() {
+                break;
+            }
+        }
+
+        let closed = self.file_close(handle);
+        ret.and(closed)
+    }
+
+    pub fn network_get_interfaces(&mut self) -> Result<Vec<GuestNetworkInterface>, Error> {
+        self.execute("guest-network-get-interfaces", None)
+    }
+
+    pub fn fsfreeze_status(&mut self) -> Result<FsFreezeStatus, Error> {
+        let status: String = self.execute("guest-fsfreeze-status", None)?;
+        match status.as_str() {
+            "thawed" => Ok(FsFreezeStatus::Thawed),
+            "frozen" => Ok(FsFreezeStatus::Frozen),
+            _ => Err(Error::AgentError(AgentError::InvalidResponse(status))),
+        }
+    }
+
+    // Returns the number of frozen file systems.
+    pub fn fsfreeze_freeze(&mut self) -> Result<i64, Error> {
+        self.execute("guest-fsfreeze-freeze", None)
+    }
+
+    pub fn fsfreeze_freeze_list(&mut self, mountpoints: &[&str]) -> Result<i64, Error> {
+        self.execute(
+            "guest-fsfreeze-freeze-list",
+            Some(json!({ "mountpoints": mountpoints })),
+        )
+    }
+
+    // Returns the number of thawed file systems.
+    pub fn fsfreeze_thaw(&mut self) -> Result<i64, Error> {
+        self.execute("guest-fsfreeze-thaw", None)
+    }
+
+    pub fn get_osinfo(&mut self) -> Result<GuestOsInfo, Error> {
+        self.execute("guest-get-osinfo", None)
+    }
+}
+
+fn decode(data: Option<&str>) -> Result<Vec<u8>, Error> {
+    match data {
+        Some(data) => base64_decode(data).ok_or_else(|| {
+            Error::AgentError(AgentError::InvalidResponse("invalid base64".to_string()))
+        }),
+        None => Ok(vec![]),
+    }
+}
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub fn base64_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+pub fn base64_decode(data: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(data.len() / 4 * 3);
+    let mut n = 0u32;
+    let mut bits = 0;
+    let mut padding = 0;
+    for c in data.bytes() {
+        let v = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => {
+                padding += 1;
+                continue;
+            }
+            b'\r' | b'\n' => continue,
+            _ => return None,
+        };
+        if padding > 0 {
+            return None;
+        }
+        n = n << 6 | v as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((n >> bits) as u8);
+        }
+    }
+    // A lone character in the last group cannot hold a whole byte.
+    if bits >= 6 || padding > 2 {
+        return None;
+    }
+    Some(out)
+}
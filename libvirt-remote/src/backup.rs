@@ -0,0 +1,379 @@
+use crate::binding::{RemoteNonnullDomain, RemoteNonnullDomainCheckpoint};
+use crate::client::Libvirt;
+use crate::error::Error;
+use crate::job::{JobProgress, JobType, JobWatcher};
+use crate::xml::backup::{BackupDisk, BackupDisks, BackupPath, BackupServer, DomainBackupDef};
+use crate::xml::checkpoint::{CheckpointDisk, CheckpointDisks, DomainCheckpointDef};
+use crate::xml::{FormatType, LibvirtXml, XmlDocument};
+use log::trace;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const VIR_DOMAIN_BACKUP_BEGIN_REUSE_EXTERNAL: u32 = 1 << 0;
+
+pub const VIR_DOMAIN_CHECKPOINT_CREATE_REDEFINE: u32 = 1 << 0;
+pub const VIR_DOMAIN_CHECKPOINT_CREATE_QUIESCE: u32 = 1 << 1;
+pub const VIR_DOMAIN_CHECKPOINT_CREATE_REDEFINE_VALIDATE: u32 = 1 << 2;
+
+pub const VIR_DOMAIN_CHECKPOINT_LIST_ROOTS: u32 = 1 << 0;
+pub const VIR_DOMAIN_CHECKPOINT_LIST_DESCENDANTS: u32 = 1 << 0;
+pub const VIR_DOMAIN_CHECKPOINT_LIST_TOPOLOGICAL: u32 = 1 << 1;
+pub const VIR_DOMAIN_CHECKPOINT_LIST_LEAVES: u32 = 1 << 2;
+pub const VIR_DOMAIN_CHECKPOINT_LIST_NO_LEAVES: u32 = 1 << 3;
+
+pub const VIR_DOMAIN_CHECKPOINT_DELETE_CHILDREN: u32 = 1 << 0;
+pub const VIR_DOMAIN_CHECKPOINT_DELETE_METADATA_ONLY: u32 = 1 << 1;
+pub const VIR_DOMAIN_CHECKPOINT_DELETE_CHILDREN_ONLY: u32 = 1 << 2;
+
+pub const VIR_DOMAIN_CHECKPOINT_XML_SECURE: u32 = 1 << 0;
+pub const VIR_DOMAIN_CHECKPOINT_XML_NO_DOMAIN: u32 = 1 << 1;
+pub const VIR_DOMAIN_CHECKPOINT_XML_SIZE: u32 = 1 << 2;
+
+pub const DEFAULT_CHECKPOINT_PREFIX: &str = "backup-";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BackupMode {
+    // The hypervisor writes each disk into `dir` in the given format.
+    Push { dir: PathBuf, format: String },
+    // The hypervisor exports each disk over NBD for the client to read.
+    Pull { server: NbdServer },
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NbdServer {
+    pub transport: Option<String>,
+    pub name: Option<String>,
+    pub port: Option<u16>,
+    pub socket: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BackupOptions {
+    pub mode: BackupMode,
+    pub incremental: bool,
+    pub checkpoint_prefix: String,
+    // Disk targets to back up; all writable disks when empty.
+    pub disks: Vec<String>,
+    // Number of checkpoints to keep after a successful backup. In pull mode
+    // they are pruned by `finish_pull`, once the job no longer needs them.
+    pub retention: Option<usize>,
+}
+
+impl BackupOptions {
+    pub fn push(dir: &Path) -> Self {
+        BackupOptions::new(BackupMode::Push {
+            dir: dir.to_path_buf(),
+            format: "qcow2".to_string(),
+        })
+    }
+
+    pub fn pull(server: NbdServer) -> Self {
+        BackupOptions::new(BackupMode::Pull { server })
+    }
+
+    fn new(mode: BackupMode) -> Self {
+        BackupOptions {
+            mode,
+            incremental: false,
+            checkpoint_prefix: DEFAULT_CHECKPOINT_PREFIX.to_string(),
+            disks: vec![],
+            retention: None,
+        }
+    }
+
+    pub fn incremental(mut self, incremental: bool) -> Self {
+        self.incremental = incremental;
+        self
+    }
+
+    pub fn checkpoint_prefix(mut self, prefix: &str) -> Self {
+        self.checkpoint_prefix = prefix.to_string();
+        self
+    }
+
+    pub fn disks(mut self, disks: &[&str]) -> Self {
+        self.disks = disks.iter().map(|d| d.to_string()).collect();
+        self
+    }
+
+    pub fn retention(mut self, keep: usize) -> Self {
+        self.retention = Some(keep);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BackupResult {
+    pub checkpoint: String,
+    pub incremental: Option<String>,
+    pub disks: Vec<BackupDisk>,
+    // Set in pull mode, where the job keeps running until `finish_pull`.
+    pub server: Option<NbdServer>,
+    pub job: Option<JobProgress>,
+}
+
+pub fn backup<T, F>(
+    client: &mut T,
+    dom: RemoteNonnullDomain,
+    options: &BackupOptions,
+    progress: F,
+) -> Result<BackupResult, Error>
+where
+    T: Libvirt,
+    F: FnMut(&JobProgress),
+{
+    let disks = if options.disks.is_empty() {
+        backup_disks(client, dom.clone())?
+    } else {
+        options.disks.clone()
+    };
+
+    let incremental = if options.incremental {
+        let latest = latest_checkpoint(client, dom.clone(), &options.checkpoint_prefix)?;
+        if latest.is_none() {
+            trace!("no checkpoint found, falling back to full backup");
+        }
+        latest
+    } else {
+        None
+    };
+
+    let checkpoint = checkpoint_name(&options.checkpoint_prefix);
+    let checkpoint_def = DomainCheckpointDef {
+        name: Some(checkpoint.clone()),
+        disks: Some(CheckpointDisks {
+            disk: disks
+                .iter()
+                .map(|d| CheckpointDisk {
+                    name: d.clone(),
+                    checkpoint: Some("bitmap".to_string()),
+                    ..Default::default()
+                })
+                .collect(),
+        }),
+        ..Default::default()
+    };
+
+    let backup_def = backup_definition(
+        &dom.name,
+        &disks,
+        &options.mode,
+        &checkpoint,
+        incremental.as_deref(),
+    );
+
+    trace!("backup begin: {} checkpoint={}", dom.name, checkpoint);
+    client.domain_backup_begin(
+        dom.clone(),
+        backup_def.to_xml()?,
+        Some(checkpoint_def.to_xml()?),
+        0,
+    )?;
+
+    let mut result = BackupResult {
+        checkpoint,
+        incremental,
+        disks: backup_def.disks.map(|d| d.disk).unwrap_or_default(),
+        ..Default::default()
+    };
+
+    if let BackupMode::Pull { .. } = options.mode {
+        // The daemon may pick the port and export names, so ask it back.
+        let running = client.backup_definition(dom.clone(), 0)?;
+        result.server = running.server.map(|s| NbdServer {
+            transport: s.transport,
+            name: s.name,
+            port: s.port,
+            socket: s.socket,
+        });
+        if let Some(disks) = running.disks {
+            result.disks = disks.disk;
+        }
+    } else {
+        let job = JobWatcher::new(client, dom.clone()).wait(progress)?;
+        if job.job_type != JobType::Completed {
+            return Err(Error::BackupError(
+                job.error.unwrap_or_else(|| format!("{:?}", job.job_type)),
+            ));
+        }
+        result.job = Some(job);
+
+        if let Some(keep) = options.retention {
+            prune(client, dom, &options.checkpoint_prefix, keep)?;
+        }
+    }
+
+    Ok(result)
+}
+
+// Ends a pull mode backup once the client has read all exports, then prunes
+// the checkpoints as `options` asks. Returns the deleted checkpoints.
+pub fn finish_pull<T>(
+    client: &mut T,
+    dom: RemoteNonnullDomain,
+    options: &BackupOptions,
+) -> Result<Vec<String>, Error>
+where
+    T: Libvirt,
+{
+    client.domain_abort_job(dom.clone())?;
+
+    match options.retention {
+        Some(keep) => prune(client, dom, &options.checkpoint_prefix, keep),
+        None => Ok(vec![]),
+    }
+}
+
+pub fn checkpoints<T>(
+    client: &mut T,
+    dom: RemoteNonnullDomain,
+    prefix: &str,
+) -> Result<Vec<(RemoteNonnullDomainCheckpoint, DomainCheckpointDef)>, Error>
+where
+    T: Libvirt,
+{
+    let (list, _) = client.domain_list_all_checkpoints(dom, 1, 0)?;
+
+    let mut checkpoints = vec![];
+    for checkpoint in list {
+        if !checkpoint.name.starts_with(prefix) {
+            continue;
+        }
+        let def = client
+            .checkpoint_definition(checkpoint.clone(), VIR_DOMAIN_CHECKPOINT_XML_NO_DOMAIN)?;
+        checkpoints.push((checkpoint, def));
+    }
+
+    checkpoints.sort_by_key(|(c, def)| (def.creation_time.unwrap_or_default(), c.name.clone()));
+    Ok(checkpoints)
+}
+
+pub fn latest_checkpoint<T>(
+    client: &mut T,
+    dom: RemoteNonnullDomain,
+    prefix: &str,
+) -> Result<Option<String>, Error>
+where
+    T: Libvirt,
+{
+    let checkpoints = checkpoints(client, dom, prefix)?;
+    Ok(checkpoints.last().map(|(c, _)| c.name.clone()))
+}
+
+// Deletes the oldest checkpoints so that at most `keep` remain. Their bitmaps
+// are merged into the children by the daemon, so the chain stays usable.
+pub fn prune<T>(
+    client: &mut T,
+    dom: RemoteNonnullDomain,
+    prefix: &str,
+    keep: usize,
+) -> Result<Vec<String>, Error>
+where
+    T: Libvirt,
+{
+    let checkpoints = checkpoints(client, dom, prefix)?;
+    let count = checkpoints.len().saturating_sub(keep);
+
+    let mut deleted = vec![];
+    for (checkpoint, _) in checkpoints.into_iter().take(count) {
+        trace!("delete checkpoint: {}", checkpoint.name);
+        let name = checkpoint.name.clone();
+        client.domain_checkpoint_delete(checkpoint, 0)?;
+        deleted.push(name);
+    }
+    Ok(deleted)
+}
+
+fn backup_disks<T>(client: &mut T, dom: RemoteNonnullDomain) -> Result<Vec<String>, Error>
+where
+    T: Libvirt,
+{
+    let def = client.domain_definition(dom, 0)?;
+    Ok(def
+        .disks()
+        .iter()
+        .filter(|d| d.device.as_deref().unwrap_or("disk") == "disk")
+        .filter(|d| d.readonly.is_none())
+        .map(|d| d.target.dev.clone())
+        .collect())
+}
+
+fn backup_definition(
+    dom: &str,
+    disks: &[String],
+    mode: &BackupMode,
+    checkpoint: &str,
+    incremental: Option<&str>,
+) -> DomainBackupDef {
+    let disks = disks
+        .iter()
+        .map(|name| match mode {
+            BackupMode::Push { dir, format } => {
+                let file = dir.join(format!("{dom}.{name}.{checkpoint}.{format}"));
+                BackupDisk {
+                    name: name.clone(),
+                    backup: Some("yes".to_string()),
+                    r#type: Some("file".to_string()),
+                    driver: Some(FormatType {
+                        r#type: format.clone(),
+                    }),
+                    target: Some(BackupPath {
+                        file: Some(file.to_string_lossy().to_string()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }
+            }
+            BackupMode::Pull { .. } => BackupDisk {
+                name: name.clone(),
+                backup: Some("yes".to_string()),
+                exportname: Some(name.clone()),
+                ..Default::default()
+            },
+        })
+        .collect();
+
+    let (mode, server) = match mode {
+        BackupMode::Push { .. } => ("push", None),
+        BackupMode::Pull { server } => (
+            "pull",
+            Some(BackupServer {
+                transport: server.transport.clone(),
+                name: server.name.clone(),
+                port: server.port,
+                socket: server.socket.clone(),
+                tls: None,
+            }),
+        ),
+    };
+
+    DomainBackupDef {
+        mode: Some(mode.to_string()),
+        incremental: incremental.map(|s| s.to_string()),
+        server,
+        disks: Some(BackupDisks { disk: disks }),
+    }
+}
+
+// Names the checkpoint after the time in nanoseconds, bumped past the last
+// name given out so that backups in quick succession never collide.
+fn checkpoint_name(prefix: &str) -> String {
+    static LAST: AtomicU64 = AtomicU64::new(0);
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or_default();
+    let next = |last: u64| now.max(last + 1);
+    let last = LAST
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |last| Some(next(last)))
+        .unwrap_or_else(|last| last);
+    let nanos = next(last);
+
+    format!(
+        "{prefix}{}.{:09}",
+        nanos / 1_000_000_000,
+        nanos % 1_000_000_000
+    )
+}
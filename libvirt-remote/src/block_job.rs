@@ -0,0 +1,402 @@
+use crate::binding::{
+    REMOTE_PROGRAM, RemoteDomainEventBlockJob2Msg, RemoteNonnullDomain, RemoteNonnullStoragePool,
+    RemoteNonnullStorageVol, RemoteProcedure, RemoteTypedParam,
+};
+use crate::client::Libvirt;
+use crate::error::Error;
+use crate::event::EventChannel;
+use crate::xml::LibvirtXml;
+use log::trace;
+use quick_xml::escape::escape;
+use std::time::Duration;
+
+pub use crate::event::VIR_DOMAIN_EVENT_ID_BLOCK_JOB_2;
+
+pub const VIR_DOMAIN_BLOCK_JOB_ABORT_ASYNC: u32 = 1 << 0;
+pub const VIR_DOMAIN_BLOCK_JOB_ABORT_PIVOT: u32 = 1 << 1;
+
+pub const VIR_DOMAIN_BLOCK_JOB_INFO_BANDWIDTH_BYTES: u32 = 1 << 0;
+
+pub const VIR_DOMAIN_BLOCK_PULL_BANDWIDTH_BYTES: u32 = 1 << 6;
+
+pub const VIR_DOMAIN_BLOCK_REBASE_SHALLOW: u32 = 1 << 0;
+pub const VIR_DOMAIN_BLOCK_REBASE_REUSE_EXT: u32 = 1 << 1;
+pub const VIR_DOMAIN_BLOCK_REBASE_COPY_RAW: u32 = 1 << 2;
+pub const VIR_DOMAIN_BLOCK_REBASE_COPY: u32 = 1 << 3;
+pub const VIR_DOMAIN_BLOCK_REBASE_RELATIVE: u32 = 1 << 4;
+pub const VIR_DOMAIN_BLOCK_REBASE_COPY_DEV: u32 = 1 << 5;
+pub const VIR_DOMAIN_BLOCK_REBASE_BANDWIDTH_BYTES: u32 = 1 << 6;
+
+pub const VIR_DOMAIN_BLOCK_COMMIT_SHALLOW: u32 = 1 << 0;
+pub const VIR_DOMAIN_BLOCK_COMMIT_DELETE: u32 = 1 << 1;
+pub const VIR_DOMAIN_BLOCK_COMMIT_ACTIVE: u32 = 1 << 2;
+pub const VIR_DOMAIN_BLOCK_COMMIT_RELATIVE: u32 = 1 << 3;
+pub const VIR_DOMAIN_BLOCK_COMMIT_BANDWIDTH_BYTES: u32 = 1 << 4;
+
+pub const VIR_DOMAIN_BLOCK_COPY_SHALLOW: u32 = 1 << 0;
+pub const VIR_DOMAIN_BLOCK_COPY_REUSE_EXT: u32 = 1 << 1;
+pub const VIR_DOMAIN_BLOCK_COPY_TRANSIENT_JOB: u32 = 1 << 2;
+pub const VIR_DOMAIN_BLOCK_COPY_SYNCHRONOUS_WRITES: u32 = 1 << 3;
+
+pub const VIR_DOMAIN_BLOCK_COPY_BANDWIDTH: &str = "bandwidth";
+pub const VIR_DOMAIN_BLOCK_COPY_GRANULARITY: &str = "granularity";
+pub const VIR_DOMAIN_BLOCK_COPY_BUF_SIZE: &str = "buf-size";
+
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BlockJobType {
+    #[default]
+    Unknown,
+    Pull,
+    Copy,
+    Commit,
+    ActiveCommit,
+    Backup,
+}
+
+impl From<i32> for BlockJobType {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => BlockJobType::Pull,
+            2 => BlockJobType::Copy,
+            3 => BlockJobType::Commit,
+            4 => BlockJobType::ActiveCommit,
+            5 => BlockJobType::Backup,
+            _ => BlockJobType::Unknown,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BlockJobStatus {
+    Completed,
+    Failed,
+    Cancelled,
+    Ready,
+}
+
+impl TryFrom<i32> for BlockJobStatus {
+    type Error = Error;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(BlockJobStatus::Completed),
+            1 => Ok(BlockJobStatus::Failed),
+            2 => Ok(BlockJobStatus::Cancelled),
+            3 => Ok(BlockJobStatus::Ready),
+            _ => Err(Error::BlockJobError(format!("unknown status: {value}"))),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BlockJobInfo {
+    pub job_type: BlockJobType,
+    pub bandwidth: u64,
+    pub cur: u64,
+    pub end: u64,
+}
+
+impl BlockJobInfo {
+    pub fn percent(&self) -> Option<f64> {
+        if self.end == 0 {
+            return None;
+        }
+        Some(100.0 * self.cur as f64 / self.end as f64)
+    }
+
+    // Copy and active commit never finish by themselves; once both sides
+    // are in sync they wait for pivot or abort.
+    pub fn is_ready(&self) -> bool {
+        matches!(
+            self.job_type,
+            BlockJobType::Copy | BlockJobType::ActiveCommit
+        ) && self.end != 0
+            && self.cur == self.end
+    }
+}
+
+pub struct BlockJob<'a, T>
+where
+    T: Libvirt,
+{
+    client: &'a mut T,
+    dom: RemoteNonnullDomain,
+    disk: String,
+    target: String,
+    interval: Duration,
+    events: EventChannel,
+}
+
+impl<'a, T> BlockJob<'a, T>
+where
+    T: Libvirt,
+{
+    pub fn copy(
+        client: &'a mut T,
+        dom: RemoteNonnullDomain,
+        disk: &str,
+        destxml: &str,
+        params: Vec<RemoteTypedParam>,
+        flags: u32,
+    ) -> Result<Self, Error> {
+        Self::start(client, dom, disk, |c, dom, disk| {
+            c.domain_block_copy(dom, disk, destxml.to_string(), params, flags)
+        })
+    }
+
+    pub fn copy_to_volume(
+        client: &'a mut T,
+        dom: RemoteNonnullDomain,
+        disk: &str,
+        pool: &RemoteNonnullStoragePool,
+        vol: &RemoteNonnullStorageVol,
+        params: Vec<RemoteTypedParam>,
+        flags: u32,
+    ) -> Result<Self, Error> {
+        let destxml = format!(
+            "<disk type='volume'><source pool='{}' volume='{}'/></disk>",
+            escape(&pool.name),
+            escape(&vol.name)
+        );
+        Self::copy(client, dom, disk, &destxml, params, flags)
+    }
+
+    pub fn commit(
+        client: &'a mut T,
+        dom: RemoteNonnullDomain,
+        disk: &str,
+        base: Option<&str>,
+        top: Option<&str>,
+        bandwidth: u64,
+        flags: u32,
+    ) -> Result<Self, Error> {
+        Self::start(client, dom, disk, |c, dom, disk| {
+            c.domain_block_commit(
+                dom,
+                disk,
+                base.map(|s| s.to_string()),
+                top.map(|s| s.to_string()),
+                bandwidth,
+                flags,
+            )
+        })
+    }
+
+    pub fn pull(
+        client: &'a mut T,
+        dom: RemoteNonnullDomain,
+        disk: &str,
+        bandwidth: u64,
+        flags: u32,
+    ) -> Result<Self, Error> {
+        Self::start(client, dom, disk, |c, dom, disk| {
+            c.domain_block_pull(dom, disk, bandwidth, flags)
+        })
+    }
+
+    pub fn rebase(
+        client: &'a mut T,
+        dom: RemoteNonnullDomain,
+        disk: &str,
+        base: Option<&str>,
+        bandwidth: u64,
+        flags: u32,
+    ) -> Result<Self, Error> {
+        Self::start(client, dom, disk, |c, dom, disk| {
+            c.domain_block_rebase(dom, disk, base.map(|s| s.to_string()), bandwidth, flags)
+        })
+    }
+
+    // Attach to a job which is already running.
+    pub fn attach(client: &'a mut T, dom: RemoteNonnullDomain, disk: &str) -> Result<Self, Error> {
+        Self::start(client, dom, disk, |_, _, _| Ok(()))
+    }
+
+    fn start<F>(
+        client: &'a mut T,
+        dom: RemoteNonnullDomain,
+        disk: &str,
+        f: F,
+    ) -> Result<Self, Error>
+    where
+        F: FnOnce(&mut T, RemoteNonnullDomain, String) -> Result<(), Error>,
+    {
+        let target = resolve_target(client, &dom, disk)?;
+
+        // Register first so that an early READY or COMPLETED is not missed.
+        let mut events = EventChannel::new(REMOTE_PROGRAM);
+        events.register(client, |c| {
+            c.connect_domain_event_callback_register_any(
+                VIR_DOMAIN_EVENT_ID_BLOCK_JOB_2,
+                Some(dom.clone()),
+            )
+        })?;
+
+        // Dropped, and so deregistered, if the job cannot be started.
+        let job = BlockJob {
+            client,
+            dom,
+            disk: disk.to_string(),
+            target,
+            interval: DEFAULT_INTERVAL,
+            events,
+        };
+        f(job.client, job.dom.clone(), job.disk.clone())?;
+        Ok(job)
+    }
+
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn info(&mut self) -> Result<Option<BlockJobInfo>, Error> {
+        let (found, r#type, bandwidth, cur, end) =
+            self.client
+                .domain_get_block_job_info(self.dom.clone(), self.disk.clone(), 0)?;
+        if found == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(BlockJobInfo {
+            job_type: BlockJobType::from(r#type),
+            bandwidth,
+            cur,
+            end,
+        }))
+    }
+
+    pub fn set_speed(&mut self, bandwidth: u64, flags: u32) -> Result<(), Error> {
+        self.client.domain_block_job_set_speed(
+            self.dom.clone(),
+            self.disk.clone(),
+            bandwidth,
+            flags,
+        )
+    }
+
+    // Wait until the job becomes ready, or ends in any other way.
+    pub fn wait<F>(&mut self, mut progress: F) -> Result<BlockJobStatus, Error>
+    where
+        F: FnMut(&BlockJobInfo),
+    {
+        loop {
+            if let Some(status) = self.next_event()? {
+                return Ok(status);
+            }
+
+            match self.info()? {
+                Some(info) => {
+                    progress(&info);
+                    if info.is_ready() {
+                        return Ok(BlockJobStatus::Ready);
+                    }
+                }
+                // Gone without us seeing the event.
+                None => return Ok(BlockJobStatus::Completed),
+            }
+        }
+    }
+
+    // Wait until the job is gone, ignoring the ready phase.
+    pub fn wait_finished<F>(&mut self, mut progress: F) -> Result<BlockJobStatus, Error>
+    where
+        F: FnMut(&BlockJobInfo),
+    {
+        loop {
+            match self.next_event()? {
+                Some(BlockJobStatus::Ready) | None => {}
+                Some(status) => return Ok(status),
+            }
+
+            match self.info()? {
+                Some(info) => progress(&info),
+                None => return Ok(BlockJobStatus::Completed),
+            }
+        }
+    }
+
+    pub fn pivot(&mut self) -> Result<BlockJobStatus, Error> {
+        self.client.domain_block_job_abort(
+            self.dom.clone(),
+            self.disk.clone(),
+            VIR_DOMAIN_BLOCK_JOB_ABORT_PIVOT,
+        )?;
+        self.wait_finished(|_| {})
+    }
+
+    pub fn abort(&mut self) -> Result<BlockJobStatus, Error> {
+        self.client
+            .domain_block_job_abort(self.dom.clone(), self.disk.clone(), 0)?;
+        self.wait_finished(|_| {})
+    }
+
+    // Deregisters the event callback, which dropping the job also does but
+    // without reporting failure.
+    pub fn close(mut self) -> Result<(), Error> {
+        self.deregister()
+    }
+
+    fn deregister(&mut self) -> Result<(), Error> {
+        let mut ret = Ok(());
+        for callback_id in self.events.remove(self.client) {
+            if let Err(e) = self
+                .client
+                .connect_domain_event_callback_deregister_any(callback_id)
+            {
+                ret = Err(e);
+            }
+        }
+        ret
+    }
+
+    fn next_event(&mut self) -> Result<Option<BlockJobStatus>, Error> {
+        let Some(raw) = self.events.recv(self.client, self.interval)? else {
+            return Ok(None);
+        };
+
+        if raw.procedure() != RemoteProcedure::RemoteProcDomainEventBlockJob2 as i32 {
+            return Ok(None);
+        }
+
+        // The event names the disk by its target, also for jobs started
+        // with the path of the disk.
+        let msg: RemoteDomainEventBlockJob2Msg = raw.try_into()?;
+        if msg.dst != self.target {
+            return Ok(None);
+        }
+
+        BlockJobStatus::try_from(msg.status).map(Some)
+    }
+}
+
+impl<T> Drop for BlockJob<'_, T>
+where
+    T: Libvirt,
+{
+    fn drop(&mut self) {
+        if let Err(e) = self.deregister() {
+            trace!("failed to deregister block job events {}.", e);
+        }
+    }
+}
+
+// Block job APIs accept either the target or the source path of a disk.
+fn resolve_target<T>(client: &mut T, dom: &RemoteNonnullDomain, disk: &str) -> Result<String, Error>
+where
+    T: Libvirt,
+{
+    // Targets such as `vda` or `sdb` never contain a slash.
+    if !disk.contains('/') {
+        return Ok(disk.to_string());
+    }
+
+    let def = client.domain_definition(dom.clone(), 0)?;
+    def.disks()
+        .iter()
+        .find(|d| d.source.as_ref().and_then(|s| s.path()) == Some(disk))
+        .map(|d| d.target.dev.clone())
+        .ok_or_else(|| Error::BlockJobError(format!("no disk with source: {disk}")))
+}
@@ -4,24 +4,199 @@ use crate::protocol;
 use log::trace;
 use serde::{Serialize, de::DeserializeOwned};
 use std::collections::HashMap;
-use std::io::ErrorKind;
 use std::io::{Read, Write};
-use std::net::TcpStream;
+use std::net::{Shutdown, TcpStream};
+#[cfg(target_family = "unix")]
+use std::os::unix::io::AsRawFd;
 #[cfg(target_family = "unix")]
 use std::os::unix::net::UnixStream;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
-use std::sync::mpsc::{Receiver, Sender, channel};
+use std::sync::mpsc::{Receiver, RecvTimeoutError, Sender, channel};
 use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
 use std::time::Duration;
+const CALL_TIMEOUT: Duration = Duration::from_secs(180);
+const CALLBACK_EVENTS: [(u32, i32); 39usize] = [
+    (
+        QEMU_PROGRAM,
+        QemuProcedure::QemuProcDomainMonitorEvent as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventBlockJob2 as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventBlockThreshold as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackAgentLifecycle as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackBalloonChange as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackBlockJob as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackChannelLifecycle as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackControlError as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackDeviceAdded as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackDeviceRemovalFailed as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackDeviceRemoved as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackDiskChange as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackGraphics as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackIoError as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackIoErrorReason as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackJobCompleted as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackLifecycle as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackMetadataChange as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackMigrationIteration as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackPmsuspendDisk as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackPmsuspend as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackPmwakeup as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackReboot as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackRtcChange as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackTrayChange as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackTunable as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventCallbackWatchdog as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventMemoryDeviceSizeChange as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventMemoryFailure as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventNicMacChange as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcDomainEventVcpuRemoved as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcNetworkEventCallbackMetadataChange as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcNetworkEventLifecycle as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcNodeDeviceEventLifecycle as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcNodeDeviceEventUpdate as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcSecretEventLifecycle as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcSecretEventValueChanged as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcStoragePoolEventLifecycle as i32,
+    ),
+    (
+        REMOTE_PROGRAM,
+        RemoteProcedure::RemoteProcStoragePoolEventRefresh as i32,
+    ),
+];
+type EventChannels = Arc<Mutex<EventRoutes>>;
+struct EventRoutes {
+    channels: HashMap<(u32, i32), Sender<VirNetResponseRaw>>,
+    held: HashMap<u32, (usize, Vec<VirNetResponseRaw>)>,
+    events: Option<Sender<VirNetResponseRaw>>,
+}
 pub trait ReadWrite: Read + Write + Send {
     fn clone(&self) -> Result<Box<dyn ReadWrite>, Error>;
+    fn shutdown(&self, how: Shutdown) -> Result<(), Error>;
+    fn send_fds(&self, _fds: &[i32]) -> Result<(), Error> {
+        Err(Error::UnsupportedTransportError(
+            "file descriptor passing".to_string(),
+        ))
+    }
 }
 impl ReadWrite for TcpStream {
     fn clone(&self) -> Result<Box<dyn ReadWrite>, Error> {
         let s = self.try_clone().map_err(Error::SocketError)?;
         Ok(Box::new(s))
     }
+    fn shutdown(&self, how: Shutdown) -> Result<(), Error> {
+        TcpStream::shutdown(self, how).map_err(Error::SocketError)
+    }
 }
 #[cfg(target_family = "unix")]
 impl ReadWrite for UnixStream {
@@ -29,15 +204,61 @@ impl ReadWrite for UnixStream {
         let s = self.try_clone().map_err(Error::SocketError)?;
         Ok(Box::new(s))
     }
+    fn shutdown(&self, how: Shutdown) -> Result<(), Error> {
+        UnixStream::shutdown(self, how).map_err(Error::SocketError)
+    }
+    fn send_fds(&self, fds: &[i32]) -> Result<(), Error> {
+        for fd in fds {
+            send_fd(self.as_raw_fd(), *fd).map_err(Error::SendError)?;
+        }
+        Ok(())
+    }
+}
+#[cfg(target_family = "unix")]
+fn send_fd(socket: i32, fd: i32) -> std::io::Result<()> {
+    let mut byte = [0u8; 1];
+    let mut iov = libc::iovec {
+        iov_base: byte.as_mut_ptr().cast(),
+        iov_len: byte.len(),
+    };
+    unsafe {
+        let space = libc::CMSG_SPACE(size_of::<i32>() as u32) as usize;
+        let mut control = vec![0u64; space.div_ceil(size_of::<u64>())];
+        let mut msg: libc::msghdr = std::mem::zeroed();
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = control.as_mut_ptr().cast();
+        msg.msg_controllen = space as _;
+        let cmsg = libc::CMSG_FIRSTHDR(&msg);
+        (*cmsg).cmsg_level = libc::SOL_SOCKET;
+        (*cmsg).cmsg_type = libc::SCM_RIGHTS;
+        (*cmsg).cmsg_len = libc::CMSG_LEN(size_of::<i32>() as u32) as _;
+        std::ptr::write_unaligned(libc::CMSG_DATA(cmsg).cast::<i32>(), fd);
+        loop {
+            if libc::sendmsg(socket, &msg, 0) >= 0 {
+                return Ok(());
+            }
+            let e = std::io::Error::last_os_error();
+            if e.kind() != std::io::ErrorKind::Interrupted {
+                return Err(e);
+            }
+        }
+    }
 }
 pub struct Client {
     inner: Box<dyn ReadWrite>,
     serial: Arc<AtomicU32>,
-    receiver: Arc<JoinHandle<()>>,
+    receiver: Arc<ReceiverThread>,
     receiver_run: Arc<AtomicBool>,
     channels: Arc<Mutex<HashMap<u32, Sender<VirNetResponseRaw>>>>,
+    event_channels: EventChannels,
     events: Arc<Mutex<Receiver<VirNetResponseRaw>>>,
 }
+struct ReceiverThread {
+    handle: Option<JoinHandle<()>>,
+    run: Arc<AtomicBool>,
+    socket: Mutex<Box<dyn ReadWrite>>,
+}
 pub struct VirNetStreamResponse<D>
 where
     D: DeserializeOwned,
@@ -48,6 +269,10 @@ where
     header: protocol::VirNetMessageHeader,
     body: Option<D>,
 }
+pub struct VirNetStreamSender {
+    inner: Box<dyn ReadWrite>,
+    header: protocol::VirNetMessageHeader,
+}
 pub enum VirNetRequest<S>
 where
     S: Serialize,
@@ -76,24 +301,56 @@ pub enum VirNetStream {
     Raw(Vec<u8>),
 }
 impl Client {
-    pub fn new(socket: impl ReadWrite + 'static) -> Self {
+    pub fn new(socket: impl ReadWrite + 'static) -> Result<Self, Error> {
         let (tx, rx) = channel();
         let receiver_run = Arc::new(AtomicBool::new(true));
         let channels = Arc::new(Mutex::new(HashMap::new()));
+        let event_channels = Arc::new(Mutex::new(EventRoutes {
+            channels: HashMap::new(),
+            held: HashMap::new(),
+            events: Some(tx),
+        }));
         let events = Arc::new(Mutex::new(rx));
         let t_receiver_run = Arc::clone(&receiver_run);
-        let t_socket = socket.clone().unwrap();
+        let t_socket = socket.clone()?;
         let t_channels = Arc::clone(&channels);
-        let receiver = thread::spawn(|| {
-            recv_thread(t_receiver_run, t_socket, t_channels, tx);
+        let t_event_channels = Arc::clone(&event_channels);
+        let s_socket = socket.clone()?;
+        let handle = thread::spawn(|| {
+            recv_thread(t_receiver_run, t_socket, t_channels, t_event_channels);
         });
-        Client {
+        let receiver = ReceiverThread {
+            handle: Some(handle),
+            run: Arc::clone(&receiver_run),
+            socket: Mutex::new(s_socket),
+        };
+        Ok(Client {
             inner: Box::new(socket),
             serial: Arc::new(AtomicU32::new(0)),
             receiver: Arc::new(receiver),
             receiver_run,
             channels,
+            event_channels,
             events,
+        })
+    }
+}
+impl ReceiverThread {
+    fn stop(&mut self) -> Result<(), Error> {
+        if let Some(t) = self.handle.take() {
+            self.run.fetch_and(false, Ordering::SeqCst);
+            if let Err(e) = self.socket.lock().unwrap().shutdown(Shutdown::Read) {
+                trace!("failed to shutdown {}.", e);
+            }
+            t.join().map_err(|_| Error::ReceiverStopError)?;
+        }
+        Ok(())
+    }
+}
+impl Drop for ReceiverThread {
+    fn drop(&mut self) {
+        if let Err(e) = self.stop() {
+            trace!("failed to stop receiver {}.", e);
         }
     }
 }
@@ -104,6 +361,7 @@ impl Libvirt for Client {
         let receiver = Arc::clone(&self.receiver);
         let receiver_run = Arc::clone(&self.receiver_run);
         let channels = Arc::clone(&self.channels);
+        let event_channels = Arc::clone(&self.event_channels);
         let events = Arc::clone(&self.events);
         Ok(Client {
             inner,
@@ -111,14 +369,14 @@ impl Libvirt for Client {
             receiver,
             receiver_run,
             channels,
+            event_channels,
             events,
         })
     }
     fn fin(self) -> Result<(), Error> {
-        if let Some(t) = Arc::into_inner(self.receiver) {
+        if let Some(mut receiver) = Arc::into_inner(self.receiver) {
             trace!("{}", stringify!(fin));
-            self.receiver_run.fetch_and(false, Ordering::SeqCst);
-            t.join().map_err(|_| Error::ReceiverStopError)?;
+            receiver.stop()?;
         }
         Ok(())
     }
@@ -146,13 +404,29 @@ impl Libvirt for Client {
     fn channel_clone(&self) -> Arc<Mutex<HashMap<u32, Sender<VirNetResponseRaw>>>> {
         Arc::clone(&self.channels)
     }
+    fn add_event_channel(
+        &mut self,
+        program: u32,
+        callback_id: i32,
+        sender: Sender<VirNetResponseRaw>,
+    ) {
+        let mut routes = self.event_channels.lock().unwrap();
+        routes.channels.insert((program, callback_id), sender);
+    }
+    fn remove_event_channel(&mut self, program: u32, callback_id: i32) {
+        let mut routes = self.event_channels.lock().unwrap();
+        routes.channels.remove(&(program, callback_id));
+    }
+    fn hold_events(&mut self, program: u32) {
+        let mut routes = self.event_channels.lock().unwrap();
+        routes.held.entry(program).or_default().0 += 1;
+    }
+    fn release_events(&mut self, program: u32) {
+        let mut routes = self.event_channels.lock().unwrap();
+        routes.release(program);
+    }
     fn get_event(&self, timeout: Duration) -> Result<VirNetResponseRaw, Error> {
-        let raw = self
-            .events
-            .lock()
-            .unwrap()
-            .recv_timeout(timeout)
-            .map_err(Error::ReceiveChannelError)?;
+        let raw = recv_response(&self.events.lock().unwrap(), timeout)?;
         Ok(raw)
     }
 }
@@ -166,6 +440,15 @@ pub trait Libvirt: Send + Sized + 'static {
     fn add_channel(&mut self, serial: u32, sender: Sender<VirNetResponseRaw>);
     fn remove_channel(&mut self, serial: u32);
     fn channel_clone(&self) -> Arc<Mutex<HashMap<u32, Sender<VirNetResponseRaw>>>>;
+    fn add_event_channel(
+        &mut self,
+        program: u32,
+        callback_id: i32,
+        sender: Sender<VirNetResponseRaw>,
+    );
+    fn remove_event_channel(&mut self, program: u32, callback_id: i32);
+    fn hold_events(&mut self, program: u32);
+    fn release_events(&mut self, program: u32);
     fn get_event(&self, timeout: Duration) -> Result<VirNetResponseRaw, Error>;
     fn domain_open_namespace(&mut self, dom: RemoteNonnullDomain, flags: u32) -> Result<(), Error> {
         trace!("{}", stringify!(domain_open_namespace));
@@ -177,6 +460,7 @@ pub trait Libvirt: Send + Sized + 'static {
             LXC_PROTOCOL_VERSION,
             LxcProcedure::LxcProcDomainOpenNamespace as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -196,9 +480,10 @@ pub trait Libvirt: Send + Sized + 'static {
             QEMU_PROTOCOL_VERSION,
             QemuProcedure::QemuProcDomainMonitorCommand as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let QemuDomainMonitorCommandRet { result } = res;
         Ok(result)
     }
@@ -211,9 +496,10 @@ pub trait Libvirt: Send + Sized + 'static {
             QEMU_PROTOCOL_VERSION,
             QemuProcedure::QemuProcDomainAttach as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let QemuDomainAttachRet { dom } = res;
         Ok(dom)
     }
@@ -237,9 +523,10 @@ pub trait Libvirt: Send + Sized + 'static {
             QEMU_PROTOCOL_VERSION,
             QemuProcedure::QemuProcDomainAgentCommand as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let QemuDomainAgentCommandRet { result } = res;
         Ok(result)
     }
@@ -261,9 +548,10 @@ pub trait Libvirt: Send + Sized + 'static {
             QEMU_PROTOCOL_VERSION,
             QemuProcedure::QemuProcConnectDomainMonitorEventRegister as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let QemuConnectDomainMonitorEventRegisterRet { callback_id } = res;
         Ok(callback_id)
     }
@@ -277,6 +565,7 @@ pub trait Libvirt: Send + Sized + 'static {
             QEMU_PROTOCOL_VERSION,
             QemuProcedure::QemuProcConnectDomainMonitorEventDeregister as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -290,6 +579,7 @@ pub trait Libvirt: Send + Sized + 'static {
             QEMU_PROTOCOL_VERSION,
             QemuProcedure::QemuProcDomainMonitorEvent as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -299,20 +589,25 @@ pub trait Libvirt: Send + Sized + 'static {
         dom: RemoteNonnullDomain,
         cmd: String,
         flags: u32,
+        files: &[i32],
     ) -> Result<String, Error> {
         trace!("{}", stringify!(domain_monitor_command_with_files));
         let req: Option<QemuDomainMonitorCommandWithFilesArgs> =
             Some(QemuDomainMonitorCommandWithFilesArgs { dom, cmd, flags });
-        let res =
-            call::<QemuDomainMonitorCommandWithFilesArgs, QemuDomainMonitorCommandWithFilesRet>(
-                self,
-                QEMU_PROGRAM,
-                QEMU_PROTOCOL_VERSION,
-                QemuProcedure::QemuProcDomainMonitorCommandWithFiles as i32,
-                false,
-                req,
-            )?;
-        let res = res.body.unwrap();
+        let res = call_full::<
+            QemuDomainMonitorCommandWithFilesArgs,
+            QemuDomainMonitorCommandWithFilesRet,
+        >(
+            self,
+            QEMU_PROGRAM,
+            QEMU_PROTOCOL_VERSION,
+            QemuProcedure::QemuProcDomainMonitorCommandWithFiles as i32,
+            false,
+            Some(CALL_TIMEOUT),
+            Some(files),
+            req,
+        )?;
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let QemuDomainMonitorCommandWithFilesRet { result } = res;
         Ok(result)
     }
@@ -325,6 +620,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectOpen as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -338,6 +634,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectClose as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -351,9 +648,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectGetType as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectGetTypeRet { r#type } = res;
         Ok(r#type)
     }
@@ -366,9 +664,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectGetVersion as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectGetVersionRet { hv_ver } = res;
         Ok(hv_ver)
     }
@@ -382,9 +681,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectGetMaxVcpus as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectGetMaxVcpusRet { max_vcpus } = res;
         Ok(max_vcpus)
     }
@@ -397,9 +697,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeGetInfo as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        Ok(res.body.unwrap())
+        res.body.ok_or(Error::UnexpectedMessageError(res.header))
     }
     fn connect_get_capabilities(&mut self) -> Result<String, Error> {
         trace!("{}", stringify!(connect_get_capabilities));
@@ -410,9 +711,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectGetCapabilities as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectGetCapabilitiesRet { capabilities } = res;
         Ok(capabilities)
     }
@@ -426,6 +728,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainAttachDevice as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -439,6 +742,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainCreate as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -457,9 +761,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainCreateXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainCreateXmlRet { dom } = res;
         Ok(dom)
     }
@@ -472,9 +777,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainDefineXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainDefineXmlRet { dom } = res;
         Ok(dom)
     }
@@ -487,6 +793,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainDestroy as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -501,6 +808,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainDetachDevice as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -519,9 +827,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetXmlDesc as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetXmlDescRet { xml } = res;
         Ok(xml)
     }
@@ -534,9 +843,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetAutostart as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetAutostartRet { autostart } = res;
         Ok(autostart)
     }
@@ -552,9 +862,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetInfo as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetInfoRet {
             state,
             max_mem,
@@ -573,9 +884,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetMaxMemory as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetMaxMemoryRet { memory } = res;
         Ok(memory)
     }
@@ -588,9 +900,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetMaxVcpus as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetMaxVcpusRet { num } = res;
         Ok(num)
     }
@@ -603,9 +916,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetOsType as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetOsTypeRet { r#type } = res;
         Ok(r#type)
     }
@@ -627,9 +941,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetVcpus as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetVcpusRet { info, cpumaps } = res;
         Ok((info, cpumaps))
     }
@@ -643,9 +958,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListDefinedDomains as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListDefinedDomainsRet { names } = res;
         Ok(names)
     }
@@ -658,9 +974,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainLookupById as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainLookupByIdRet { dom } = res;
         Ok(dom)
     }
@@ -673,9 +990,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainLookupByName as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainLookupByNameRet { dom } = res;
         Ok(dom)
     }
@@ -691,9 +1009,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainLookupByUuid as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainLookupByUuidRet { dom } = res;
         Ok(dom)
     }
@@ -706,9 +1025,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectNumOfDefinedDomains as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectNumOfDefinedDomainsRet { num } = res;
         Ok(num)
     }
@@ -727,6 +1047,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainPinVcpu as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -740,6 +1061,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainReboot as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -753,6 +1075,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainResume as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -771,6 +1094,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetAutostart as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -789,6 +1113,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetMaxMemory as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -803,6 +1128,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetMemory as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -816,6 +1142,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetVcpus as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -829,6 +1156,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainShutdown as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -842,6 +1170,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSuspend as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -855,6 +1184,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainUndefine as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -869,9 +1199,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListDefinedNetworks as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListDefinedNetworksRet { names } = res;
         Ok(names)
     }
@@ -885,9 +1216,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListDomains as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListDomainsRet { ids } = res;
         Ok(ids)
     }
@@ -901,9 +1233,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListNetworks as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListNetworksRet { names } = res;
         Ok(names)
     }
@@ -916,6 +1249,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkCreate as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -929,9 +1263,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkCreateXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkCreateXmlRet { net } = res;
         Ok(net)
     }
@@ -944,9 +1279,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkDefineXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkDefineXmlRet { net } = res;
         Ok(net)
     }
@@ -959,6 +1295,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkDestroy as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -977,9 +1314,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkGetXmlDesc as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkGetXmlDescRet { xml } = res;
         Ok(xml)
     }
@@ -993,9 +1331,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkGetAutostart as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkGetAutostartRet { autostart } = res;
         Ok(autostart)
     }
@@ -1009,9 +1348,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkGetBridgeName as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkGetBridgeNameRet { name } = res;
         Ok(name)
     }
@@ -1025,9 +1365,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkLookupByName as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkLookupByNameRet { net } = res;
         Ok(net)
     }
@@ -1044,9 +1385,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkLookupByUuid as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkLookupByUuidRet { net } = res;
         Ok(net)
     }
@@ -1064,6 +1406,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkSetAutostart as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -1077,6 +1420,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkUndefine as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -1090,9 +1434,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectNumOfDefinedNetworks as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectNumOfDefinedNetworksRet { num } = res;
         Ok(num)
     }
@@ -1105,9 +1450,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectNumOfDomains as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectNumOfDomainsRet { num } = res;
         Ok(num)
     }
@@ -1120,9 +1466,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectNumOfNetworks as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectNumOfNetworksRet { num } = res;
         Ok(num)
     }
@@ -1141,6 +1488,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainCoreDump as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -1154,6 +1502,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainRestore as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -1167,6 +1516,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSave as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -1184,9 +1534,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetSchedulerType as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetSchedulerTypeRet { r#type, nparams } = res;
         Ok((r#type, nparams))
     }
@@ -1205,9 +1556,10 @@ pub trait Libvirt: Send + Sized + 'static {
                 REMOTE_PROTOCOL_VERSION,
                 RemoteProcedure::RemoteProcDomainGetSchedulerParameters as i32,
                 false,
+                Some(CALL_TIMEOUT),
                 req,
             )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetSchedulerParametersRet { params } = res;
         Ok(params)
     }
@@ -1225,6 +1577,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetSchedulerParameters as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -1238,9 +1591,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectGetHostname as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectGetHostnameRet { hostname } = res;
         Ok(hostname)
     }
@@ -1254,9 +1608,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectSupportsFeature as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectSupportsFeatureRet { supported } = res;
         Ok(supported)
     }
@@ -1280,9 +1635,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigratePrepare as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMigratePrepareRet { cookie, uri_out } = res;
         Ok((cookie, uri_out))
     }
@@ -1310,6 +1666,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigratePerform as i32,
             false,
+            None,
             req,
         )?;
         Ok(())
@@ -1334,9 +1691,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigrateFinish as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMigrateFinishRet { ddom } = res;
         Ok(ddom)
     }
@@ -1354,9 +1712,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainBlockStats as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainBlockStatsRet {
             rd_req,
             rd_bytes,
@@ -1380,9 +1739,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainInterfaceStats as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        Ok(res.body.unwrap())
+        res.body.ok_or(Error::UnexpectedMessageError(res.header))
     }
     fn auth_list(&mut self) -> Result<Vec<RemoteAuthType>, Error> {
         trace!("{}", stringify!(auth_list));
@@ -1393,9 +1753,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcAuthList as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteAuthListRet { types } = res;
         Ok(types)
     }
@@ -1408,9 +1769,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcAuthSaslInit as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteAuthSaslInitRet { mechlist } = res;
         Ok(mechlist)
     }
@@ -1429,9 +1791,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcAuthSaslStart as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteAuthSaslStartRet {
             complete,
             nil,
@@ -1448,9 +1811,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcAuthSaslStep as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteAuthSaslStepRet {
             complete,
             nil,
@@ -1467,9 +1831,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcAuthPolkit as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteAuthPolkitRet { complete } = res;
         Ok(complete)
     }
@@ -1482,9 +1847,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectNumOfStoragePools as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectNumOfStoragePoolsRet { num } = res;
         Ok(num)
     }
@@ -1498,9 +1864,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListStoragePools as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListStoragePoolsRet { names } = res;
         Ok(names)
     }
@@ -1513,9 +1880,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectNumOfDefinedStoragePools as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectNumOfDefinedStoragePoolsRet { num } = res;
         Ok(num)
     }
@@ -1532,9 +1900,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListDefinedStoragePools as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListDefinedStoragePoolsRet { names } = res;
         Ok(names)
     }
@@ -1560,9 +1929,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectFindStoragePoolSources as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectFindStoragePoolSourcesRet { xml } = res;
         Ok(xml)
     }
@@ -1580,9 +1950,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolCreateXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStoragePoolCreateXmlRet { pool } = res;
         Ok(pool)
     }
@@ -1600,9 +1971,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolDefineXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStoragePoolDefineXmlRet { pool } = res;
         Ok(pool)
     }
@@ -1620,6 +1992,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolCreate as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -1638,6 +2011,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolBuild as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -1651,6 +2025,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolDestroy as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -1669,6 +2044,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolDelete as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -1683,6 +2059,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolUndefine as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -1701,6 +2078,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolRefresh as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -1718,9 +2096,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolLookupByName as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStoragePoolLookupByNameRet { pool } = res;
         Ok(pool)
     }
@@ -1737,9 +2116,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolLookupByUuid as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStoragePoolLookupByUuidRet { pool } = res;
         Ok(pool)
     }
@@ -1756,9 +2136,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolLookupByVolume as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStoragePoolLookupByVolumeRet { pool } = res;
         Ok(pool)
     }
@@ -1774,9 +2155,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolGetInfo as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStoragePoolGetInfoRet {
             state,
             capacity,
@@ -1799,9 +2181,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolGetXmlDesc as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStoragePoolGetXmlDescRet { xml } = res;
         Ok(xml)
     }
@@ -1815,9 +2198,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolGetAutostart as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStoragePoolGetAutostartRet { autostart } = res;
         Ok(autostart)
     }
@@ -1835,6 +2219,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolSetAutostart as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -1852,9 +2237,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolNumOfVolumes as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStoragePoolNumOfVolumesRet { num } = res;
         Ok(num)
     }
@@ -1872,9 +2258,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolListVolumes as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStoragePoolListVolumesRet { names } = res;
         Ok(names)
     }
@@ -1893,9 +2280,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStorageVolCreateXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStorageVolCreateXmlRet { vol } = res;
         Ok(vol)
     }
@@ -1913,6 +2301,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStorageVolDelete as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -1931,9 +2320,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStorageVolLookupByName as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStorageVolLookupByNameRet { vol } = res;
         Ok(vol)
     }
@@ -1947,9 +2337,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStorageVolLookupByKey as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStorageVolLookupByKeyRet { vol } = res;
         Ok(vol)
     }
@@ -1966,9 +2357,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStorageVolLookupByPath as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStorageVolLookupByPathRet { vol } = res;
         Ok(vol)
     }
@@ -1984,9 +2376,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStorageVolGetInfo as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStorageVolGetInfoRet {
             r#type,
             capacity,
@@ -2008,9 +2401,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStorageVolGetXmlDesc as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStorageVolGetXmlDescRet { xml } = res;
         Ok(xml)
     }
@@ -2023,9 +2417,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStorageVolGetPath as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStorageVolGetPathRet { name } = res;
         Ok(name)
     }
@@ -2046,9 +2441,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeGetCellsFreeMemory as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeGetCellsFreeMemoryRet { cells } = res;
         Ok(cells)
     }
@@ -2061,9 +2457,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeGetFreeMemory as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeGetFreeMemoryRet { free_mem } = res;
         Ok(free_mem)
     }
@@ -2089,9 +2486,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainBlockPeek as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainBlockPeekRet { buffer } = res;
         Ok(buffer)
     }
@@ -2115,9 +2513,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMemoryPeek as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMemoryPeekRet { buffer } = res;
         Ok(buffer)
     }
@@ -2130,9 +2529,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectDomainEventRegister as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectDomainEventRegisterRet { cb_registered } = res;
         Ok(cb_registered)
     }
@@ -2145,9 +2545,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectDomainEventDeregister as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectDomainEventDeregisterRet { cb_registered } = res;
         Ok(cb_registered)
     }
@@ -2160,6 +2561,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventLifecycle as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -2186,9 +2588,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigratePrepare2 as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMigratePrepare2Ret { cookie, uri_out } = res;
         Ok((cookie, uri_out))
     }
@@ -2214,9 +2617,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigrateFinish2 as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMigrateFinish2Ret { ddom } = res;
         Ok(ddom)
     }
@@ -2229,9 +2633,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectGetUri as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectGetUriRet { uri } = res;
         Ok(uri)
     }
@@ -2245,9 +2650,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeNumOfDevices as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeNumOfDevicesRet { num } = res;
         Ok(num)
     }
@@ -2269,9 +2675,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeListDevices as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeListDevicesRet { names } = res;
         Ok(names)
     }
@@ -2288,9 +2695,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceLookupByName as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeDeviceLookupByNameRet { dev } = res;
         Ok(dev)
     }
@@ -2304,9 +2712,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceGetXmlDesc as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeDeviceGetXmlDescRet { xml } = res;
         Ok(xml)
     }
@@ -2320,9 +2729,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceGetParent as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeDeviceGetParentRet { parent_name } = res;
         Ok(parent_name)
     }
@@ -2336,9 +2746,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceNumOfCaps as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeDeviceNumOfCapsRet { num } = res;
         Ok(num)
     }
@@ -2352,9 +2763,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceListCaps as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeDeviceListCapsRet { names } = res;
         Ok(names)
     }
@@ -2367,6 +2779,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceDettach as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -2380,6 +2793,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceReAttach as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -2393,6 +2807,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceReset as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -2410,9 +2825,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetSecurityLabel as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetSecurityLabelRet { label, enforcing } = res;
         Ok((label, enforcing))
     }
@@ -2425,9 +2841,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeGetSecurityModel as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeGetSecurityModelRet { model, doi } = res;
         Ok((model, doi))
     }
@@ -2445,9 +2862,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceCreateXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeDeviceCreateXmlRet { dev } = res;
         Ok(dev)
     }
@@ -2460,6 +2878,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceDestroy as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -2485,9 +2904,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStorageVolCreateXmlFrom as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStorageVolCreateXmlFromRet { vol } = res;
         Ok(vol)
     }
@@ -2500,9 +2920,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectNumOfInterfaces as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectNumOfInterfacesRet { num } = res;
         Ok(num)
     }
@@ -2516,9 +2937,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListInterfaces as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListInterfacesRet { names } = res;
         Ok(names)
     }
@@ -2532,9 +2954,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcInterfaceLookupByName as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteInterfaceLookupByNameRet { iface } = res;
         Ok(iface)
     }
@@ -2551,9 +2974,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcInterfaceLookupByMacString as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteInterfaceLookupByMacStringRet { iface } = res;
         Ok(iface)
     }
@@ -2571,9 +2995,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcInterfaceGetXmlDesc as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteInterfaceGetXmlDescRet { xml } = res;
         Ok(xml)
     }
@@ -2591,9 +3016,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcInterfaceDefineXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteInterfaceDefineXmlRet { iface } = res;
         Ok(iface)
     }
@@ -2606,6 +3032,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcInterfaceUndefine as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -2620,6 +3047,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcInterfaceCreate as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -2638,6 +3066,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcInterfaceDestroy as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -2661,9 +3090,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectDomainXmlFromNative as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectDomainXmlFromNativeRet { domain_xml } = res;
         Ok(domain_xml)
     }
@@ -2686,9 +3116,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectDomainXmlToNative as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectDomainXmlToNativeRet { native_config } = res;
         Ok(native_config)
     }
@@ -2701,9 +3132,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectNumOfDefinedInterfaces as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectNumOfDefinedInterfacesRet { num } = res;
         Ok(num)
     }
@@ -2718,9 +3150,10 @@ pub trait Libvirt: Send + Sized + 'static {
                 REMOTE_PROTOCOL_VERSION,
                 RemoteProcedure::RemoteProcConnectListDefinedInterfaces as i32,
                 false,
+                Some(CALL_TIMEOUT),
                 req,
             )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListDefinedInterfacesRet { names } = res;
         Ok(names)
     }
@@ -2733,9 +3166,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectNumOfSecrets as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectNumOfSecretsRet { num } = res;
         Ok(num)
     }
@@ -2749,9 +3183,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListSecrets as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListSecretsRet { uuids } = res;
         Ok(uuids)
     }
@@ -2767,9 +3202,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcSecretLookupByUuid as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteSecretLookupByUuidRet { secret } = res;
         Ok(secret)
     }
@@ -2782,9 +3218,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcSecretDefineXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteSecretDefineXmlRet { secret } = res;
         Ok(secret)
     }
@@ -2802,9 +3239,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcSecretGetXmlDesc as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteSecretGetXmlDescRet { xml } = res;
         Ok(xml)
     }
@@ -2826,6 +3264,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcSecretSetValue as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -2844,9 +3283,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcSecretGetValue as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteSecretGetValueRet { value } = res;
         Ok(value)
     }
@@ -2859,6 +3299,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcSecretUndefine as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -2879,9 +3320,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcSecretLookupByUsage as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteSecretLookupByUsageRet { secret } = res;
         Ok(secret)
     }
@@ -2906,6 +3348,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigratePrepareTunnel as i32,
             true,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         let res = VirNetStreamResponse {
@@ -2926,9 +3369,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectIsSecure as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectIsSecureRet { secure } = res;
         Ok(secure)
     }
@@ -2941,9 +3385,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainIsActive as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainIsActiveRet { active } = res;
         Ok(active)
     }
@@ -2956,9 +3401,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainIsPersistent as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainIsPersistentRet { persistent } = res;
         Ok(persistent)
     }
@@ -2971,9 +3417,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkIsActive as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkIsActiveRet { active } = res;
         Ok(active)
     }
@@ -2987,9 +3434,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkIsPersistent as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkIsPersistentRet { persistent } = res;
         Ok(persistent)
     }
@@ -3003,9 +3451,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolIsActive as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStoragePoolIsActiveRet { active } = res;
         Ok(active)
     }
@@ -3019,9 +3468,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolIsPersistent as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStoragePoolIsPersistentRet { persistent } = res;
         Ok(persistent)
     }
@@ -3034,9 +3484,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcInterfaceIsActive as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteInterfaceIsActiveRet { active } = res;
         Ok(active)
     }
@@ -3049,9 +3500,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectGetLibVersion as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectGetLibVersionRet { lib_ver } = res;
         Ok(lib_ver)
     }
@@ -3065,9 +3517,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectCompareCpu as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectCompareCpuRet { result } = res;
         Ok(result)
     }
@@ -3089,9 +3542,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMemoryStats as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMemoryStatsRet { stats } = res;
         Ok(stats)
     }
@@ -3110,6 +3564,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainAttachDeviceFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3129,6 +3584,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainDetachDeviceFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3143,9 +3599,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectBaselineCpu as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectBaselineCpuRet { cpu } = res;
         Ok(cpu)
     }
@@ -3161,9 +3618,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetJobInfo as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        Ok(res.body.unwrap())
+        res.body.ok_or(Error::UnexpectedMessageError(res.header))
     }
     fn domain_abort_job(&mut self, dom: RemoteNonnullDomain) -> Result<(), Error> {
         trace!("{}", stringify!(domain_abort_job));
@@ -3174,6 +3632,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainAbortJob as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3187,6 +3646,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStorageVolWipe as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3210,6 +3670,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigrateSetMaxDowntime as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3224,6 +3685,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectDomainEventRegisterAny as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3238,6 +3700,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectDomainEventDeregisterAny as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3251,6 +3714,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventReboot as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3264,6 +3728,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventRtcChange as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3277,6 +3742,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventWatchdog as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3290,6 +3756,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventIoError as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3303,6 +3770,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventGraphics as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3322,6 +3790,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainUpdateDeviceFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3336,9 +3805,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNwfilterLookupByName as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNwfilterLookupByNameRet { nwfilter } = res;
         Ok(nwfilter)
     }
@@ -3355,9 +3825,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNwfilterLookupByUuid as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNwfilterLookupByUuidRet { nwfilter } = res;
         Ok(nwfilter)
     }
@@ -3375,9 +3846,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNwfilterGetXmlDesc as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNwfilterGetXmlDescRet { xml } = res;
         Ok(xml)
     }
@@ -3390,9 +3862,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectNumOfNwfilters as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectNumOfNwfiltersRet { num } = res;
         Ok(num)
     }
@@ -3406,9 +3879,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListNwfilters as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListNwfiltersRet { names } = res;
         Ok(names)
     }
@@ -3421,9 +3895,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNwfilterDefineXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNwfilterDefineXmlRet { nwfilter } = res;
         Ok(nwfilter)
     }
@@ -3436,6 +3911,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNwfilterUndefine as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3450,6 +3926,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainManagedSave as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3468,9 +3945,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainHasManagedSaveImage as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainHasManagedSaveImageRet { result } = res;
         Ok(result)
     }
@@ -3488,6 +3966,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainManagedSaveRemove as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3511,9 +3990,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSnapshotCreateXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainSnapshotCreateXmlRet { snap } = res;
         Ok(snap)
     }
@@ -3531,9 +4011,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSnapshotGetXmlDesc as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainSnapshotGetXmlDescRet { xml } = res;
         Ok(xml)
     }
@@ -3547,9 +4028,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSnapshotNum as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainSnapshotNumRet { num } = res;
         Ok(num)
     }
@@ -3572,9 +4054,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSnapshotListNames as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainSnapshotListNamesRet { names } = res;
         Ok(names)
     }
@@ -3593,9 +4076,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSnapshotLookupByName as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainSnapshotLookupByNameRet { snap } = res;
         Ok(snap)
     }
@@ -3613,9 +4097,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainHasCurrentSnapshot as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainHasCurrentSnapshotRet { result } = res;
         Ok(result)
     }
@@ -3633,9 +4118,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSnapshotCurrent as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainSnapshotCurrentRet { snap } = res;
         Ok(snap)
     }
@@ -3653,6 +4139,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainRevertToSnapshot as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3671,6 +4158,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSnapshotDelete as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3690,9 +4178,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetBlockInfo as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetBlockInfoRet {
             allocation,
             capacity,
@@ -3709,6 +4198,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventIoErrorReason as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3727,9 +4217,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainCreateWithFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainCreateWithFlagsRet { dom } = res;
         Ok(dom)
     }
@@ -3748,6 +4239,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetMemoryParameters as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3771,9 +4263,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetMemoryParameters as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetMemoryParametersRet { params, nparams } = res;
         Ok((params, nparams))
     }
@@ -3792,6 +4285,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetVcpusFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3810,9 +4304,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetVcpusFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetVcpusFlagsRet { num } = res;
         Ok(num)
     }
@@ -3834,6 +4329,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainOpenConsole as i32,
             true,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         let res = VirNetStreamResponse {
@@ -3854,9 +4350,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainIsUpdated as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainIsUpdatedRet { updated } = res;
         Ok(updated)
     }
@@ -3869,9 +4366,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectGetSysinfo as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectGetSysinfoRet { sysinfo } = res;
         Ok(sysinfo)
     }
@@ -3890,6 +4388,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetMemoryFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3909,6 +4408,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetBlkioParameters as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3932,9 +4432,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetBlkioParameters as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetBlkioParametersRet { params, nparams } = res;
         Ok((params, nparams))
     }
@@ -3957,6 +4458,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigrateSetMaxSpeed as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -3981,6 +4483,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStorageVolUpload as i32,
             true,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         let res = VirNetStreamResponse {
@@ -4012,6 +4515,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStorageVolDownload as i32,
             true,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         let res = VirNetStreamResponse {
@@ -4032,6 +4536,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainInjectNmi as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4051,6 +4556,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainScreenshot as i32,
             true,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         let res = VirNetStreamResponse {
@@ -4075,9 +4581,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetState as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetStateRet { state, reason } = res;
         Ok((state, reason))
     }
@@ -4103,9 +4610,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigrateBegin3 as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMigrateBegin3Ret { cookie_out, xml } = res;
         Ok((cookie_out, xml))
     }
@@ -4133,9 +4641,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigratePrepare3 as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMigratePrepare3Ret {
             cookie_out,
             uri_out,
@@ -4166,6 +4675,7 @@ pub trait Libvirt: Send + Sized + 'static {
                 REMOTE_PROTOCOL_VERSION,
                 RemoteProcedure::RemoteProcDomainMigratePrepareTunnel3 as i32,
                 true,
+                Some(CALL_TIMEOUT),
                 req,
             )?;
         let res = VirNetStreamResponse {
@@ -4189,9 +4699,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigratePerform3 as i32,
             false,
+            None,
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMigratePerform3Ret { cookie_out } = res;
         Ok(cookie_out)
     }
@@ -4219,9 +4730,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigrateFinish3 as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMigrateFinish3Ret { dom, cookie_out } = res;
         Ok((dom, cookie_out))
     }
@@ -4245,6 +4757,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigrateConfirm3 as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4264,6 +4777,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetSchedulerParametersFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4278,6 +4792,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcInterfaceChangeBegin as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4292,6 +4807,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcInterfaceChangeCommit as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4306,6 +4822,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcInterfaceChangeRollback as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4332,9 +4849,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetSchedulerParametersFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetSchedulerParametersFlagsRet { params } = res;
         Ok(params)
     }
@@ -4347,6 +4865,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventControlError as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4371,6 +4890,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainPinVcpuFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4397,6 +4917,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSendKey as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4419,9 +4940,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeGetCpuStats as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeGetCpuStatsRet { params, nparams } = res;
         Ok((params, nparams))
     }
@@ -4443,9 +4965,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeGetMemoryStats as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeGetMemoryStatsRet { params, nparams } = res;
         Ok((params, nparams))
     }
@@ -4463,9 +4986,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetControlInfo as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetControlInfoRet {
             state,
             details,
@@ -4493,9 +5017,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetVcpuPinInfo as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetVcpuPinInfoRet { cpumaps, num } = res;
         Ok((cpumaps, num))
     }
@@ -4509,6 +5034,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainUndefineFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4533,6 +5059,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSaveFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4552,6 +5079,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainRestoreFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4566,6 +5094,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainDestroyFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4584,9 +5113,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSaveImageGetXmlDesc as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainSaveImageGetXmlDescRet { xml } = res;
         Ok(xml)
     }
@@ -4605,6 +5135,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSaveImageDefineXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4624,6 +5155,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainBlockJobAbort as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4643,9 +5175,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetBlockJobInfo as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetBlockJobInfoRet {
             found,
             r#type,
@@ -4676,6 +5209,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainBlockJobSetSpeed as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4700,6 +5234,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainBlockPull as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4713,6 +5248,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventBlockJob as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4731,9 +5267,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigrateGetMaxSpeed as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMigrateGetMaxSpeedRet { bandwidth } = res;
         Ok(bandwidth)
     }
@@ -4757,9 +5294,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainBlockStatsFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainBlockStatsFlagsRet { params, nparams } = res;
         Ok((params, nparams))
     }
@@ -4777,9 +5315,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSnapshotGetParent as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainSnapshotGetParentRet { snap } = res;
         Ok(snap)
     }
@@ -4792,6 +5331,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainReset as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4810,9 +5350,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSnapshotNumChildren as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainSnapshotNumChildrenRet { num } = res;
         Ok(num)
     }
@@ -4838,9 +5379,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSnapshotListChildrenNames as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainSnapshotListChildrenNamesRet { names } = res;
         Ok(names)
     }
@@ -4853,6 +5395,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventDiskChange as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4872,6 +5415,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainOpenGraphics as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4895,6 +5439,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeSuspendForDuration as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4919,6 +5464,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainBlockResize as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4943,6 +5489,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetBlockIoTune as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -4967,9 +5514,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetBlockIoTune as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetBlockIoTuneRet { params, nparams } = res;
         Ok((params, nparams))
     }
@@ -4988,6 +5536,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetNumaParameters as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5011,9 +5560,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetNumaParameters as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetNumaParametersRet { params, nparams } = res;
         Ok((params, nparams))
     }
@@ -5038,6 +5588,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetInterfaceParameters as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5064,9 +5615,10 @@ pub trait Libvirt: Send + Sized + 'static {
                 REMOTE_PROTOCOL_VERSION,
                 RemoteProcedure::RemoteProcDomainGetInterfaceParameters as i32,
                 false,
+                Some(CALL_TIMEOUT),
                 req,
             )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetInterfaceParametersRet { params, nparams } = res;
         Ok((params, nparams))
     }
@@ -5080,6 +5632,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainShutdownFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5102,6 +5655,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStorageVolWipePattern as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5124,6 +5678,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStorageVolResize as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5149,6 +5704,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainPmSuspendForDuration as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5175,9 +5731,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetCpuStats as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetCpuStatsRet { params, nparams } = res;
         Ok((params, nparams))
     }
@@ -5199,9 +5756,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetDiskErrors as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetDiskErrorsRet { errors, nerrors } = res;
         Ok((errors, nerrors))
     }
@@ -5229,6 +5787,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetMetadata as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5253,9 +5812,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetMetadata as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetMetadataRet { metadata } = res;
         Ok(metadata)
     }
@@ -5281,6 +5841,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainBlockRebase as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5294,6 +5855,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainPmWakeup as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5307,6 +5869,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventTrayChange as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5320,6 +5883,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventPmwakeup as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5333,6 +5897,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventPmsuspend as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5351,9 +5916,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSnapshotIsCurrent as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainSnapshotIsCurrentRet { current } = res;
         Ok(current)
     }
@@ -5371,9 +5937,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSnapshotHasMetadata as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainSnapshotHasMetadataRet { metadata } = res;
         Ok(metadata)
     }
@@ -5393,9 +5960,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListAllDomains as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListAllDomainsRet { domains, ret } = res;
         Ok((domains, ret))
     }
@@ -5418,9 +5986,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainListAllSnapshots as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainListAllSnapshotsRet { snapshots, ret } = res;
         Ok((snapshots, ret))
     }
@@ -5446,9 +6015,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSnapshotListAllChildren as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainSnapshotListAllChildrenRet { snapshots, ret } = res;
         Ok((snapshots, ret))
     }
@@ -5461,6 +6031,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventBalloonChange as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5479,9 +6050,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetHostname as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetHostnameRet { hostname } = res;
         Ok(hostname)
     }
@@ -5498,9 +6070,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetSecurityLabelList as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetSecurityLabelListRet { labels, ret } = res;
         Ok((labels, ret))
     }
@@ -5519,6 +6092,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainPinEmulator as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5538,9 +6112,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetEmulatorPinInfo as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetEmulatorPinInfoRet { cpumaps, ret } = res;
         Ok((cpumaps, ret))
     }
@@ -5561,9 +6136,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListAllStoragePools as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListAllStoragePoolsRet { pools, ret } = res;
         Ok((pools, ret))
     }
@@ -5586,9 +6162,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolListAllVolumes as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStoragePoolListAllVolumesRet { vols, ret } = res;
         Ok((vols, ret))
     }
@@ -5609,9 +6186,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListAllNetworks as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListAllNetworksRet { nets, ret } = res;
         Ok((nets, ret))
     }
@@ -5632,9 +6210,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListAllInterfaces as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListAllInterfacesRet { ifaces, ret } = res;
         Ok((ifaces, ret))
     }
@@ -5655,9 +6234,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListAllNodeDevices as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListAllNodeDevicesRet { devices, ret } = res;
         Ok((devices, ret))
     }
@@ -5678,9 +6258,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListAllNwfilters as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListAllNwfiltersRet { filters, ret } = res;
         Ok((filters, ret))
     }
@@ -5700,9 +6281,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListAllSecrets as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListAllSecretsRet { secrets, ret } = res;
         Ok((secrets, ret))
     }
@@ -5720,6 +6302,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeSetMemoryParameters as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5738,9 +6321,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeGetMemoryParameters as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeGetMemoryParametersRet { params, nparams } = res;
         Ok((params, nparams))
     }
@@ -5768,6 +6352,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainBlockCommit as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5796,6 +6381,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkUpdate as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5809,6 +6395,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventPmsuspendDisk as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5831,9 +6418,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeGetCpuMap as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeGetCpuMapRet {
             cpumap,
             online,
@@ -5861,6 +6449,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainFstrim as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5886,6 +6475,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSendProcessSignal as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -5905,6 +6495,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainOpenChannel as i32,
             true,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         let res = VirNetStreamResponse {
@@ -5934,9 +6525,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceLookupScsiHostByWwn as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeDeviceLookupScsiHostByWwnRet { dev } = res;
         Ok(dev)
     }
@@ -5954,9 +6546,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetJobStats as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetJobStatsRet { r#type, params } = res;
         Ok((r#type, params))
     }
@@ -5977,9 +6570,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigrateGetCompressionCache as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMigrateGetCompressionCacheRet { cache_size } = res;
         Ok(cache_size)
     }
@@ -6002,6 +6596,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigrateSetCompressionCache as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6024,6 +6619,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceDetachFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6043,9 +6639,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigrateBegin3Params as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMigrateBegin3ParamsRet { cookie_out, xml } = res;
         Ok((cookie_out, xml))
     }
@@ -6069,9 +6666,10 @@ pub trait Libvirt: Send + Sized + 'static {
                 REMOTE_PROTOCOL_VERSION,
                 RemoteProcedure::RemoteProcDomainMigratePrepare3Params as i32,
                 false,
+                Some(CALL_TIMEOUT),
                 req,
             )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMigratePrepare3ParamsRet {
             cookie_out,
             uri_out,
@@ -6100,9 +6698,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigratePrepareTunnel3Params as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMigratePrepareTunnel3ParamsRet { cookie_out } = res;
         Ok(cookie_out)
     }
@@ -6130,9 +6729,10 @@ pub trait Libvirt: Send + Sized + 'static {
                 REMOTE_PROTOCOL_VERSION,
                 RemoteProcedure::RemoteProcDomainMigratePerform3Params as i32,
                 false,
+                None,
                 req,
             )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMigratePerform3ParamsRet { cookie_out } = res;
         Ok(cookie_out)
     }
@@ -6157,9 +6757,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigrateFinish3Params as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMigrateFinish3ParamsRet { dom, cookie_out } = res;
         Ok((dom, cookie_out))
     }
@@ -6186,6 +6787,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigrateConfirm3Params as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6205,6 +6807,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetMemoryStatsPeriod as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6213,19 +6816,22 @@ pub trait Libvirt: Send + Sized + 'static {
         &mut self,
         xml_desc: String,
         flags: u32,
+        files: &[i32],
     ) -> Result<RemoteNonnullDomain, Error> {
         trace!("{}", stringify!(domain_create_xml_with_files));
         let req: Option<RemoteDomainCreateXmlWithFilesArgs> =
             Some(RemoteDomainCreateXmlWithFilesArgs { xml_desc, flags });
-        let res = call::<RemoteDomainCreateXmlWithFilesArgs, RemoteDomainCreateXmlWithFilesRet>(
+        let res = call_full::<RemoteDomainCreateXmlWithFilesArgs, RemoteDomainCreateXmlWithFilesRet>(
             self,
             REMOTE_PROGRAM,
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainCreateXmlWithFiles as i32,
             false,
+            Some(CALL_TIMEOUT),
+            Some(files),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainCreateXmlWithFilesRet { dom } = res;
         Ok(dom)
     }
@@ -6233,19 +6839,22 @@ pub trait Libvirt: Send + Sized + 'static {
         &mut self,
         dom: RemoteNonnullDomain,
         flags: u32,
+        files: &[i32],
     ) -> Result<RemoteNonnullDomain, Error> {
         trace!("{}", stringify!(domain_create_with_files));
         let req: Option<RemoteDomainCreateWithFilesArgs> =
             Some(RemoteDomainCreateWithFilesArgs { dom, flags });
-        let res = call::<RemoteDomainCreateWithFilesArgs, RemoteDomainCreateWithFilesRet>(
+        let res = call_full::<RemoteDomainCreateWithFilesArgs, RemoteDomainCreateWithFilesRet>(
             self,
             REMOTE_PROGRAM,
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainCreateWithFiles as i32,
             false,
+            Some(CALL_TIMEOUT),
+            Some(files),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainCreateWithFilesRet { dom } = res;
         Ok(dom)
     }
@@ -6258,6 +6867,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventDeviceRemoved as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6281,9 +6891,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectGetCpuModelNames as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectGetCpuModelNamesRet { models, ret } = res;
         Ok((models, ret))
     }
@@ -6304,9 +6915,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectNetworkEventRegisterAny as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectNetworkEventRegisterAnyRet { callback_id } = res;
         Ok(callback_id)
     }
@@ -6320,6 +6932,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectNetworkEventDeregisterAny as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6333,6 +6946,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkEventLifecycle as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6354,9 +6968,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectDomainEventCallbackRegisterAny as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectDomainEventCallbackRegisterAnyRet { callback_id } = res;
         Ok(callback_id)
     }
@@ -6376,6 +6991,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectDomainEventCallbackDeregisterAny as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6389,6 +7005,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackLifecycle as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6402,6 +7019,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackReboot as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6415,6 +7033,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackRtcChange as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6428,6 +7047,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackWatchdog as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6441,6 +7061,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackIoError as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6454,6 +7075,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackGraphics as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6467,6 +7089,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackIoErrorReason as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6480,6 +7103,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackControlError as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6493,6 +7117,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackBlockJob as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6506,6 +7131,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackDiskChange as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6519,6 +7145,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackTrayChange as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6532,6 +7159,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackPmwakeup as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6545,6 +7173,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackPmsuspend as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6558,6 +7187,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackBalloonChange as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6571,6 +7201,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackPmsuspendDisk as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6584,6 +7215,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackDeviceRemoved as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6609,6 +7241,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainCoreDumpWithFormat as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6631,9 +7264,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainFsfreeze as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainFsfreezeRet { filesystems } = res;
         Ok(filesystems)
     }
@@ -6655,9 +7289,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainFsthaw as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainFsthawRet { filesystems } = res;
         Ok(filesystems)
     }
@@ -6674,9 +7309,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetTime as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetTimeRet { seconds, nseconds } = res;
         Ok((seconds, nseconds))
     }
@@ -6700,6 +7336,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetTime as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6713,6 +7350,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventBlockJob2 as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6737,9 +7375,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeGetFreePages as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeGetFreePagesRet { counts } = res;
         Ok(counts)
     }
@@ -6763,9 +7402,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkGetDhcpLeases as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkGetDhcpLeasesRet { leases, ret } = res;
         Ok((leases, ret))
     }
@@ -6793,9 +7433,10 @@ pub trait Libvirt: Send + Sized + 'static {
                 REMOTE_PROTOCOL_VERSION,
                 RemoteProcedure::RemoteProcConnectGetDomainCapabilities as i32,
                 false,
+                Some(CALL_TIMEOUT),
                 req,
             )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectGetDomainCapabilitiesRet { capabilities } = res;
         Ok(capabilities)
     }
@@ -6814,6 +7455,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainOpenGraphicsFd as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6833,9 +7475,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectGetAllDomainStats as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectGetAllDomainStatsRet { ret_stats } = res;
         Ok(ret_stats)
     }
@@ -6861,6 +7504,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainBlockCopy as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6874,6 +7518,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackTunable as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6900,9 +7545,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeAllocPages as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeAllocPagesRet { ret } = res;
         Ok(ret)
     }
@@ -6915,6 +7561,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackAgentLifecycle as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -6932,9 +7579,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetFsinfo as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetFsinfoRet { info, ret } = res;
         Ok((info, ret))
     }
@@ -6952,9 +7600,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainDefineXmlFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainDefineXmlFlagsRet { dom } = res;
         Ok(dom)
     }
@@ -6972,9 +7621,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetIothreadInfo as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetIothreadInfoRet { info, ret } = res;
         Ok((info, ret))
     }
@@ -6998,6 +7648,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainPinIothread as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7017,9 +7668,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainInterfaceAddresses as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainInterfaceAddressesRet { ifaces } = res;
         Ok(ifaces)
     }
@@ -7032,6 +7684,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackDeviceAdded as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7054,6 +7707,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainAddIothread as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7076,6 +7730,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainDelIothread as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7100,6 +7755,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetUserPassword as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7122,9 +7778,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainRename as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainRenameRet { retcode } = res;
         Ok(retcode)
     }
@@ -7137,6 +7794,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackMigrationIteration as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7150,6 +7808,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectRegisterCloseCallback as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7163,6 +7822,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectUnregisterCloseCallback as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7176,6 +7836,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectEventConnectionClosed as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7189,6 +7850,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackJobCompleted as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7207,6 +7869,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainMigrateStartPostCopy as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7225,9 +7888,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetPerfEvents as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetPerfEventsRet { params } = res;
         Ok(params)
     }
@@ -7246,6 +7910,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetPerfEvents as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7262,6 +7927,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackDeviceRemovalFailed as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7283,9 +7949,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectStoragePoolEventRegisterAny as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectStoragePoolEventRegisterAnyRet { callback_id } = res;
         Ok(callback_id)
     }
@@ -7299,6 +7966,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectStoragePoolEventDeregisterAny as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7312,6 +7980,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolEventLifecycle as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7330,9 +7999,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetGuestVcpus as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetGuestVcpusRet { params } = res;
         Ok(params)
     }
@@ -7356,6 +8026,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetGuestVcpus as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7369,6 +8040,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolEventRefresh as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7390,9 +8062,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectNodeDeviceEventRegisterAny as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectNodeDeviceEventRegisterAnyRet { callback_id } = res;
         Ok(callback_id)
     }
@@ -7406,6 +8079,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectNodeDeviceEventDeregisterAny as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7419,6 +8093,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceEventLifecycle as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7432,6 +8107,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceEventUpdate as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7450,9 +8126,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStorageVolGetInfoFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStorageVolGetInfoFlagsRet {
             r#type,
             capacity,
@@ -7469,6 +8146,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackMetadataChange as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7490,9 +8168,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectSecretEventRegisterAny as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectSecretEventRegisterAnyRet { callback_id } = res;
         Ok(callback_id)
     }
@@ -7506,6 +8185,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectSecretEventDeregisterAny as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7519,6 +8199,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcSecretEventLifecycle as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7532,6 +8213,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcSecretEventValueChanged as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7556,6 +8238,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetVcpu as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7569,6 +8252,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventBlockThreshold as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7594,6 +8278,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetBlockThreshold as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7613,9 +8298,10 @@ pub trait Libvirt: Send + Sized + 'static {
                 REMOTE_PROTOCOL_VERSION,
                 RemoteProcedure::RemoteProcDomainMigrateGetMaxDowntime as i32,
                 false,
+                Some(CALL_TIMEOUT),
                 req,
             )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainMigrateGetMaxDowntimeRet { downtime } = res;
         Ok(downtime)
     }
@@ -7634,9 +8320,10 @@ pub trait Libvirt: Send + Sized + 'static {
                 REMOTE_PROTOCOL_VERSION,
                 RemoteProcedure::RemoteProcDomainManagedSaveGetXmlDesc as i32,
                 false,
+                Some(CALL_TIMEOUT),
                 req,
             )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainManagedSaveGetXmlDescRet { xml } = res;
         Ok(xml)
     }
@@ -7655,6 +8342,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainManagedSaveDefineXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7680,6 +8368,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetLifecycleAction as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7700,9 +8389,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcStoragePoolLookupByTargetPath as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteStoragePoolLookupByTargetPathRet { pool } = res;
         Ok(pool)
     }
@@ -7721,6 +8411,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainDetachDeviceAlias as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7751,9 +8442,10 @@ pub trait Libvirt: Send + Sized + 'static {
                 REMOTE_PROTOCOL_VERSION,
                 RemoteProcedure::RemoteProcConnectCompareHypervisorCpu as i32,
                 false,
+                Some(CALL_TIMEOUT),
                 req,
             )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectCompareHypervisorCpuRet { result } = res;
         Ok(result)
     }
@@ -7783,9 +8475,10 @@ pub trait Libvirt: Send + Sized + 'static {
                 REMOTE_PROTOCOL_VERSION,
                 RemoteProcedure::RemoteProcConnectBaselineHypervisorCpu as i32,
                 false,
+                Some(CALL_TIMEOUT),
                 req,
             )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectBaselineHypervisorCpuRet { cpu } = res;
         Ok(cpu)
     }
@@ -7803,9 +8496,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeGetSevInfo as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeGetSevInfoRet { params, nparams } = res;
         Ok((params, nparams))
     }
@@ -7824,9 +8518,10 @@ pub trait Libvirt: Send + Sized + 'static {
                 REMOTE_PROTOCOL_VERSION,
                 RemoteProcedure::RemoteProcDomainGetLaunchSecurityInfo as i32,
                 false,
+                Some(CALL_TIMEOUT),
                 req,
             )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetLaunchSecurityInfoRet { params } = res;
         Ok(params)
     }
@@ -7846,9 +8541,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNwfilterBindingLookupByPortDev as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNwfilterBindingLookupByPortDevRet { nwfilter } = res;
         Ok(nwfilter)
     }
@@ -7866,9 +8562,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNwfilterBindingGetXmlDesc as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNwfilterBindingGetXmlDescRet { xml } = res;
         Ok(xml)
     }
@@ -7886,9 +8583,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNwfilterBindingCreateXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNwfilterBindingCreateXmlRet { nwfilter } = res;
         Ok(nwfilter)
     }
@@ -7905,6 +8603,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNwfilterBindingDelete as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7929,9 +8628,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectListAllNwfilterBindings as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectListAllNwfilterBindingsRet { bindings, ret } = res;
         Ok((bindings, ret))
     }
@@ -7956,6 +8656,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetIothreadParams as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -7973,9 +8674,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectGetStoragePoolCapabilities as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteConnectGetStoragePoolCapabilitiesRet { capabilities } = res;
         Ok(capabilities)
     }
@@ -7997,9 +8699,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkListAllPorts as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkListAllPortsRet { ports, ret } = res;
         Ok((ports, ret))
     }
@@ -8017,9 +8720,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkPortLookupByUuid as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkPortLookupByUuidRet { port } = res;
         Ok(port)
     }
@@ -8041,9 +8745,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkPortCreateXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkPortCreateXmlRet { port } = res;
         Ok(port)
     }
@@ -8066,9 +8771,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkPortGetParameters as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkPortGetParametersRet { params, nparams } = res;
         Ok((params, nparams))
     }
@@ -8091,6 +8797,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkPortSetParameters as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8109,9 +8816,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkPortGetXmlDesc as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkPortGetXmlDescRet { xml } = res;
         Ok(xml)
     }
@@ -8129,6 +8837,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkPortDelete as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8152,9 +8861,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainCheckpointCreateXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainCheckpointCreateXmlRet { checkpoint } = res;
         Ok(checkpoint)
     }
@@ -8172,9 +8882,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainCheckpointGetXmlDesc as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainCheckpointGetXmlDescRet { xml } = res;
         Ok(xml)
     }
@@ -8197,9 +8908,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainListAllCheckpoints as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainListAllCheckpointsRet { checkpoints, ret } = res;
         Ok((checkpoints, ret))
     }
@@ -8225,9 +8937,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainCheckpointListAllChildren as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainCheckpointListAllChildrenRet { checkpoints, ret } = res;
         Ok((checkpoints, ret))
     }
@@ -8247,9 +8960,10 @@ pub trait Libvirt: Send + Sized + 'static {
                 REMOTE_PROTOCOL_VERSION,
                 RemoteProcedure::RemoteProcDomainCheckpointLookupByName as i32,
                 false,
+                Some(CALL_TIMEOUT),
                 req,
             )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainCheckpointLookupByNameRet { checkpoint } = res;
         Ok(checkpoint)
     }
@@ -8267,9 +8981,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainCheckpointGetParent as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainCheckpointGetParentRet { parent } = res;
         Ok(parent)
     }
@@ -8287,6 +9002,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainCheckpointDelete as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8306,9 +9022,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetGuestInfo as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetGuestInfoRet { params } = res;
         Ok(params)
     }
@@ -8326,6 +9043,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcConnectSetIdentity as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8352,9 +9070,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainAgentSetResponseTimeout as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainAgentSetResponseTimeoutRet { result } = res;
         Ok(result)
     }
@@ -8378,6 +9097,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainBackupBegin as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8396,9 +9116,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainBackupGetXmlDesc as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainBackupGetXmlDescRet { xml } = res;
         Ok(xml)
     }
@@ -8411,6 +9132,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventMemoryFailure as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8430,9 +9152,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainAuthorizedSshKeysGet as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainAuthorizedSshKeysGetRet { keys } = res;
         Ok(keys)
     }
@@ -8457,6 +9180,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainAuthorizedSshKeysSet as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8475,9 +9199,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetMessages as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetMessagesRet { msgs } = res;
         Ok(msgs)
     }
@@ -8500,6 +9225,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainStartDirtyRateCalc as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8518,9 +9244,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceDefineXml as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeDeviceDefineXmlRet { dev } = res;
         Ok(dev)
     }
@@ -8534,6 +9261,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceUndefine as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8548,6 +9276,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceCreate as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8566,9 +9295,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNwfilterDefineXmlFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNwfilterDefineXmlFlagsRet { nwfilter } = res;
         Ok(nwfilter)
     }
@@ -8586,9 +9316,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkDefineXmlFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkDefineXmlFlagsRet { net } = res;
         Ok(net)
     }
@@ -8602,9 +9333,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceGetAutostart as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeDeviceGetAutostartRet { autostart } = res;
         Ok(autostart)
     }
@@ -8618,6 +9350,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceSetAutostart as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8632,9 +9365,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceIsPersistent as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeDeviceIsPersistentRet { persistent } = res;
         Ok(persistent)
     }
@@ -8647,9 +9381,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceIsActive as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNodeDeviceIsActiveRet { active } = res;
         Ok(active)
     }
@@ -8667,9 +9402,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkCreateXmlFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkCreateXmlFlagsRet { net } = res;
         Ok(net)
     }
@@ -8682,6 +9418,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventMemoryDeviceSizeChange as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8701,6 +9438,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetLaunchSecurityState as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8720,6 +9458,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSaveParams as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8738,6 +9477,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainRestoreParams as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8756,6 +9496,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainAbortJobFlags as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8775,6 +9516,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainFdAssociate as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8803,6 +9545,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkSetMetadata as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8827,9 +9570,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkGetMetadata as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteNetworkGetMetadataRet { metadata } = res;
         Ok(metadata)
     }
@@ -8842,6 +9586,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNetworkEventCallbackMetadataChange as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8864,6 +9609,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcNodeDeviceUpdate as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8883,6 +9629,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGraphicsReload as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8897,9 +9644,10 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainGetAutostartOnce as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
-        let res = res.body.unwrap();
+        let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
         let RemoteDomainGetAutostartOnceRet { autostart } = res;
         Ok(autostart)
     }
@@ -8917,6 +9665,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetAutostartOnce as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8942,6 +9691,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainSetThrottleGroup as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8961,6 +9711,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainDelThrottleGroup as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8974,6 +9725,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventNicMacChange as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -8987,6 +9739,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventVcpuRemoved as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -9000,6 +9753,7 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainEventCallbackChannelLifecycle as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
@@ -9025,11 +9779,20 @@ pub trait Libvirt: Send + Sized + 'static {
             REMOTE_PROTOCOL_VERSION,
             RemoteProcedure::RemoteProcDomainAnnounceInterface as i32,
             false,
+            Some(CALL_TIMEOUT),
             req,
         )?;
         Ok(())
     }
 }
+impl VirNetResponseRaw {
+    pub fn program(&self) -> u32 {
+        self.header.prog
+    }
+    pub fn procedure(&self) -> i32 {
+        self.header.proc
+    }
+}
 impl<D> VirNetStreamResponse<D>
 where
     D: DeserializeOwned,
@@ -9057,7 +9820,16 @@ where
         self.body.as_ref()
     }
     pub fn download(&mut self) -> Result<Option<VirNetStream>, Error> {
-        download(self)
+        download(self, CALL_TIMEOUT)
+    }
+    pub fn download_timeout(&mut self, timeout: Duration) -> Result<Option<VirNetStream>, Error> {
+        download(self, timeout)
+    }
+    pub fn sender(&self) -> Result<VirNetStreamSender, Error> {
+        Ok(VirNetStreamSender {
+            inner: self.inner.clone()?,
+            header: self.header.clone(),
+        })
     }
     pub fn upload_data(&mut self, buf: &[u8]) -> Result<(), Error> {
         trace!("{}", stringify!(upload_data));
@@ -9071,16 +9843,45 @@ where
         trace!("{}", stringify!(upload_complete));
         upload_completed(self)
     }
+    pub fn abort(&mut self) -> Result<(), Error> {
+        trace!("{}", stringify!(abort));
+        send_abort(&mut self.inner, &self.header)
+    }
+}
+impl VirNetStreamSender {
+    pub fn upload_data(&mut self, buf: &[u8]) -> Result<(), Error> {
+        trace!("{}", stringify!(upload_data));
+        let bytes = VirNetStream::Raw(buf.to_vec());
+        let req: Option<VirNetRequest<()>> = Some(VirNetRequest::Stream(bytes));
+        send(
+            &mut self.inner,
+            self.header.prog,
+            self.header.vers,
+            self.header.proc,
+            protocol::VirNetMessageType::VirNetStream,
+            self.header.serial,
+            protocol::VirNetMessageStatus::VirNetContinue,
+            req,
+            &[],
+        )?;
+        Ok(())
+    }
+    pub fn abort(&mut self) -> Result<(), Error> {
+        trace!("{}", stringify!(abort));
+        send_abort(&mut self.inner, &self.header)
+    }
 }
 impl TryFrom<VirNetResponseRaw> for QemuDomainMonitorEventMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9090,11 +9891,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteConnectEventConnectionClosedMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9104,11 +9907,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventBalloonChangeMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9118,11 +9923,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventBlockJob2Msg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9132,11 +9939,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventBlockJobMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9146,11 +9955,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventBlockThresholdMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9160,11 +9971,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackAgentLifecycleMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9174,11 +9987,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackBalloonChangeMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9188,11 +10003,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackBlockJobMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9202,11 +10019,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackChannelLifecycleMsg
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9216,11 +10035,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackControlErrorMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9230,11 +10051,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackDeviceAddedMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9244,11 +10067,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackDeviceRemovalFailed
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9258,11 +10083,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackDeviceRemovedMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9272,11 +10099,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackDiskChangeMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9286,11 +10115,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackGraphicsMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9300,11 +10131,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackIoErrorMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9314,11 +10147,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackIoErrorReasonMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9328,11 +10163,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackJobCompletedMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9342,11 +10179,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackLifecycleMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9356,11 +10195,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackMetadataChangeMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9370,11 +10211,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackMigrationIterationM
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9384,11 +10227,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackPmsuspendDiskMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9398,11 +10243,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackPmsuspendMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9412,11 +10259,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackPmwakeupMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9426,11 +10275,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackRebootMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9440,11 +10291,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackRtcChangeMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9454,11 +10307,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackTrayChangeMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9468,11 +10323,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackTunableMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9482,11 +10339,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventCallbackWatchdogMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9496,11 +10355,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventControlErrorMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9510,11 +10371,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventDeviceRemovedMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9524,11 +10387,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventDiskChangeMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9538,11 +10403,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventGraphicsMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9552,11 +10419,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventIoErrorMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9566,11 +10435,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventIoErrorReasonMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9580,11 +10451,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventLifecycleMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9594,11 +10467,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventMemoryDeviceSizeChangeMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9608,11 +10483,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventMemoryFailureMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9622,11 +10499,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventNicMacChangeMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9636,11 +10515,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventPmsuspendDiskMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9650,11 +10531,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventPmsuspendMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9664,11 +10547,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventPmwakeupMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9678,11 +10563,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventRebootMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9692,11 +10579,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventRtcChangeMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9706,11 +10595,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventTrayChangeMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9720,11 +10611,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventVcpuRemovedMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9734,11 +10627,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteDomainEventWatchdogMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9748,11 +10643,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteNetworkEventCallbackMetadataChangeMsg
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9762,11 +10659,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteNetworkEventLifecycleMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9776,11 +10675,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteNodeDeviceEventLifecycleMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9790,11 +10691,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteNodeDeviceEventUpdateMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9804,11 +10707,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteSecretEventLifecycleMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9818,11 +10723,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteSecretEventValueChangedMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9832,11 +10739,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteStoragePoolEventLifecycleMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9846,11 +10755,13 @@ impl TryFrom<VirNetResponseRaw> for RemoteStoragePoolEventRefreshMsg {
     type Error = Error;
     fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
         let header = value.header;
-        let body = value.body.unwrap();
+        let Some(body) = value.body else {
+            return Err(Error::UnexpectedMessageError(header));
+        };
         match deserialize_body(&header, body) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok(body),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(header)),
             },
             Err(e) => Err(e),
         }
@@ -9862,53 +10773,79 @@ fn call<S, D>(
     version: u32,
     procedure: i32,
     stream: bool,
+    timeout: Option<Duration>,
+    args: Option<S>,
+) -> Result<VirNetResponseSet<D>, Error>
+where
+    S: Serialize,
+    D: DeserializeOwned,
+{
+    call_full(
+        client, program, version, procedure, stream, timeout, None, args,
+    )
+}
+#[allow(clippy::too_many_arguments)]
+fn call_full<S, D>(
+    client: &mut impl Libvirt,
+    program: u32,
+    version: u32,
+    procedure: i32,
+    stream: bool,
+    timeout: Option<Duration>,
+    files: Option<&[i32]>,
     args: Option<S>,
 ) -> Result<VirNetResponseSet<D>, Error>
 where
     S: Serialize,
     D: DeserializeOwned,
 {
+    let req_type = match files {
+        Some(_) => protocol::VirNetMessageType::VirNetCallWithFds,
+        None => protocol::VirNetMessageType::VirNetCall,
+    };
     let serial = client.serial_add(1);
+    let (tx, rx) = channel();
+    client.add_channel(serial, tx);
     if !client.receiver_running() {
+        client.remove_channel(serial);
         return Err(Error::ReceiverNotStartedError);
     }
-    let (tx, rx) = channel();
-    client.add_channel(serial, tx);
     let socket = client.inner();
     if let Err(e) = send(
         socket,
         program,
         version,
         procedure,
-        protocol::VirNetMessageType::VirNetCall,
+        req_type,
         serial,
         protocol::VirNetMessageStatus::VirNetOk,
         args.map(|a| VirNetRequest::Data(a)),
+        files.unwrap_or_default(),
     ) {
         client.remove_channel(serial);
         return Err(e);
     }
-    let ret = read_data::<D>(stream, client.channel_clone(), &rx, serial);
+    let ret = read_data::<D>(stream, client.channel_clone(), &rx, serial, timeout);
     ret.map(|(header, body)| VirNetResponseSet {
         receiver: Some(rx),
         header,
         body,
     })
 }
-fn download<D>(response: &mut VirNetStreamResponse<D>) -> Result<Option<VirNetStream>, Error>
+fn download<D>(
+    response: &mut VirNetStreamResponse<D>,
+    timeout: Duration,
+) -> Result<Option<VirNetStream>, Error>
 where
     D: DeserializeOwned,
 {
     let serial = response.header.serial;
-    let res = response
-        .receiver
-        .recv_timeout(Duration::from_secs(180))
-        .map_err(Error::ReceiveChannelError)?;
+    let res = recv_response(&response.receiver, timeout)?;
     if let Some(res_body_bytes) = res.body {
         match deserialize_body::<()>(&res.header, res_body_bytes) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Stream(stream) => Ok(Some(stream)),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(res.header)),
             },
             Err(e) => Err(e),
         }
@@ -9932,6 +10869,7 @@ where
         response.header.serial,
         protocol::VirNetMessageStatus::VirNetContinue,
         req,
+        &[],
     )?;
     Ok(())
 }
@@ -9954,6 +10892,25 @@ where
         response.header.serial,
         protocol::VirNetMessageStatus::VirNetContinue,
         args,
+        &[],
+    )?;
+    Ok(())
+}
+fn send_abort(
+    socket: &mut Box<dyn ReadWrite>,
+    header: &protocol::VirNetMessageHeader,
+) -> Result<(), Error> {
+    let req: Option<VirNetRequest<()>> = None;
+    send(
+        socket,
+        header.prog,
+        header.vers,
+        header.proc,
+        protocol::VirNetMessageType::VirNetStream,
+        header.serial,
+        protocol::VirNetMessageStatus::VirNetError,
+        req,
+        &[],
     )?;
     Ok(())
 }
@@ -9971,12 +10928,14 @@ where
         response.header.serial,
         protocol::VirNetMessageStatus::VirNetOk,
         req,
+        &[],
     )?;
     let (_header, _res) = read_data::<()>(
         false,
         Arc::clone(&response.channels),
         &response.receiver,
         response.header.serial,
+        Some(CALL_TIMEOUT),
     )?;
     Ok(())
 }
@@ -9990,10 +10949,18 @@ fn send<S>(
     req_serial: u32,
     req_status: protocol::VirNetMessageStatus,
     args: Option<VirNetRequest<S>>,
+    files: &[i32],
 ) -> Result<usize, Error>
 where
     S: Serialize,
 {
+    if files.len() > protocol::VIR_NET_MESSAGE_NUM_FDS_MAX as usize {
+        return Err(Error::SendError(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "too many file descriptors",
+        )));
+    }
+    let with_fds = req_type == protocol::VirNetMessageType::VirNetCallWithFds;
     let mut req_len: u32 = 4;
     let req_header = protocol::VirNetMessageHeader {
         prog: program,
@@ -10005,6 +10972,9 @@ where
     };
     let req_header_bytes = serde_xdr::to_bytes(&req_header).map_err(Error::SerializeError)?;
     req_len += req_header_bytes.len() as u32;
+    if with_fds {
+        req_len += 4;
+    }
     let mut args_bytes = None;
     match args {
         Some(VirNetRequest::Data(data)) => {
@@ -10023,20 +10993,29 @@ where
         }
         None => {}
     }
+    if req_len > protocol::VIR_NET_MESSAGE_MAX {
+        return Err(Error::PacketLengthError(req_len as usize));
+    }
     let mut bytes = vec![];
     bytes.extend(req_len.to_be_bytes());
     bytes.extend(req_header_bytes);
+    if with_fds {
+        bytes.extend((files.len() as u32).to_be_bytes());
+    }
     if let Some(args_bytes) = &args_bytes {
         bytes.extend(args_bytes);
     }
     socket.write_all(&bytes).map_err(Error::SendError)?;
+    if !files.is_empty() {
+        socket.send_fds(files)?;
+    }
     Ok(bytes.len())
 }
 fn recv_thread(
     receiver_run: Arc<AtomicBool>,
     socket: Box<dyn ReadWrite>,
     channels: Arc<Mutex<HashMap<u32, Sender<VirNetResponseRaw>>>>,
-    events: Sender<VirNetResponseRaw>,
+    event_channels: EventChannels,
 ) {
     trace!("receiver started.");
     let mut socket = socket;
@@ -10053,37 +11032,114 @@ fn recv_thread(
                         trace!("receiver failed to send {}.", e);
                     }
                 } else if raw.header.r#type == protocol::VirNetMessageType::VirNetMessage {
-                    if let Err(e) = events.send(raw) {
-                        trace!("receiver failed to send {}.", e);
-                    }
+                    event_channels.lock().unwrap().route(raw);
                 } else {
                     trace!("receiver not found for serial No.{}.", serial);
                 }
             }
-            Err(Error::ReceiveError(e)) => {
-                trace!("receiver error {}.", e);
-                if e.kind() == ErrorKind::UnexpectedEof {
-                    receiver_run.fetch_and(false, Ordering::SeqCst);
-                }
-            }
             Err(e) => {
                 trace!("receiver error {}.", e);
+                break;
             }
         }
     }
+    receiver_run.fetch_and(false, Ordering::SeqCst);
+    if let Err(e) = socket.shutdown(Shutdown::Both) {
+        trace!("receiver failed to shutdown {}.", e);
+    }
+    channels.lock().unwrap().clear();
+    {
+        let mut routes = event_channels.lock().unwrap();
+        routes.channels.clear();
+        routes.held.clear();
+        routes.events = None;
+    }
     trace!("receiver stopped.");
 }
+impl EventRoutes {
+    fn route(&mut self, raw: VirNetResponseRaw) {
+        let key = event_callback_id(&raw).map(|id| (raw.header.prog, id));
+        let tx = match key.and_then(|k| self.channels.get(&k)) {
+            Some(tx) => tx,
+            None => {
+                if key.is_some() {
+                    if let Some((_, held)) = self.held.get_mut(&raw.header.prog) {
+                        held.push(raw);
+                        return;
+                    }
+                }
+                match &self.events {
+                    Some(tx) => tx,
+                    None => return,
+                }
+            }
+        };
+        if let Err(e) = tx.send(raw) {
+            trace!("receiver failed to send {}.", e);
+        }
+    }
+    fn release(&mut self, program: u32) {
+        let Some((count, held)) = self.held.get_mut(&program) else {
+            return;
+        };
+        *count -= 1;
+        let held = std::mem::take(held);
+        if *count == 0 {
+            self.held.remove(&program);
+        }
+        for raw in held {
+            self.route(raw);
+        }
+    }
+}
+fn event_callback_id(raw: &VirNetResponseRaw) -> Option<i32> {
+    if !CALLBACK_EVENTS.contains(&(raw.header.prog, raw.header.proc)) {
+        return None;
+    }
+    let bytes = raw.body.as_ref()?.get(..4)?;
+    Some(i32::from_be_bytes(bytes.try_into().ok()?))
+}
 fn recv_raw(
     socket: &mut Box<dyn ReadWrite>,
 ) -> Result<(protocol::VirNetMessageHeader, Option<Vec<u8>>), Error> {
     let res_len = read_pkt_len(socket)?;
+    let min_len =
+        (protocol::VIR_NET_MESSAGE_LEN_MAX + protocol::VIR_NET_MESSAGE_HEADER_MAX) as usize;
+    if res_len < min_len || res_len > protocol::VIR_NET_MESSAGE_MAX as usize {
+        return Err(Error::PacketLengthError(res_len));
+    }
     let res_header = read_res_header(socket)?;
-    let body_len = res_len - 28;
+    let body_len = res_len - min_len;
+    if res_header.r#type == protocol::VirNetMessageType::VirNetReplyWithFds {
+        return recv_fds_body(socket, res_header, body_len);
+    }
     if body_len == 0 {
         return Ok((res_header, None));
     }
     Ok((res_header, Some(read_res_body(socket, body_len)?)))
 }
+fn recv_fds_body(
+    socket: &mut Box<dyn ReadWrite>,
+    res_header: protocol::VirNetMessageHeader,
+    body_len: usize,
+) -> Result<(protocol::VirNetMessageHeader, Option<Vec<u8>>), Error> {
+    if body_len < 4 {
+        return Err(Error::UnexpectedMessageError(res_header));
+    }
+    let mut body = read_res_body(socket, body_len)?;
+    let nfds = u32::from_be_bytes(body[..4].try_into().unwrap());
+    if nfds > protocol::VIR_NET_MESSAGE_NUM_FDS_MAX {
+        return Err(Error::UnexpectedMessageError(res_header));
+    }
+    if nfds > 0 {
+        read_res_body(socket, nfds as usize)?;
+    }
+    body.drain(..4);
+    if body.is_empty() {
+        return Ok((res_header, None));
+    }
+    Ok((res_header, Some(body)))
+}
 fn read_pkt_len(socket: &mut Box<dyn ReadWrite>) -> Result<usize, Error> {
     let mut res_len_bytes = [0; 4];
     socket
@@ -10094,7 +11150,7 @@ fn read_pkt_len(socket: &mut Box<dyn ReadWrite>) -> Result<usize, Error> {
 fn read_res_header(
     socket: &mut Box<dyn ReadWrite>,
 ) -> Result<protocol::VirNetMessageHeader, Error> {
-    let mut res_header_bytes = [0; 24];
+    let mut res_header_bytes = [0; protocol::VIR_NET_MESSAGE_HEADER_MAX as usize];
     socket
         .read_exact(&mut res_header_bytes)
         .map_err(Error::ReceiveError)?;
@@ -10113,18 +11169,20 @@ fn read_data<D>(
     channels: Arc<Mutex<HashMap<u32, Sender<VirNetResponseRaw>>>>,
     rx: &Receiver<VirNetResponseRaw>,
     serial: u32,
+    timeout: Option<Duration>,
 ) -> Result<(protocol::VirNetMessageHeader, Option<D>), Error>
 where
     D: DeserializeOwned,
 {
-    let res = rx
-        .recv_timeout(Duration::from_secs(180))
-        .map_err(Error::ReceiveChannelError)?;
+    let res = match timeout {
+        Some(timeout) => recv_response(rx, timeout)?,
+        None => rx.recv().map_err(|_| Error::ConnectionClosedError)?,
+    };
     let ret = if let Some(res_body_bytes) = res.body {
         match deserialize_body(&res.header, res_body_bytes) {
             Ok(res_body) => match res_body {
                 VirNetResponse::Data(body) => Ok((res.header, Some(body))),
-                _ => unreachable!(),
+                _ => Err(Error::UnexpectedMessageError(res.header)),
             },
             Err(e) => Err(e),
         }
@@ -10136,6 +11194,15 @@ where
     }
     ret
 }
+fn recv_response(
+    rx: &Receiver<VirNetResponseRaw>,
+    timeout: Duration,
+) -> Result<VirNetResponseRaw, Error> {
+    rx.recv_timeout(timeout).map_err(|e| match e {
+        RecvTimeoutError::Disconnected => Error::ConnectionClosedError,
+        e => Error::ReceiveChannelError(e),
+    })
+}
 fn deserialize_body<D>(
     res_header: &protocol::VirNetMessageHeader,
     res_body_bytes: Vec<u8>,
@@ -10150,6 +11217,7 @@ where
     } else {
         match res_header.r#type {
             protocol::VirNetMessageType::VirNetReply
+            | protocol::VirNetMessageType::VirNetReplyWithFds
             | protocol::VirNetMessageType::VirNetMessage => {
                 let data =
                     serde_xdr::from_bytes::<D>(&res_body_bytes).map_err(Error::DeserializeError)?;
@@ -10165,7 +11233,21 @@ where
                 let stream = VirNetStream::Hole(hole);
                 Ok(VirNetResponse::Stream(stream))
             }
-            _ => unreachable!(),
+            _ => Err(Error::UnexpectedMessageError(res_header.clone())),
         }
     }
 }
+pub fn read_packet(socket: &mut Box<dyn ReadWrite>) -> Result<VirNetResponseRaw, Error> {
+    let (header, body) = recv_raw(socket)?;
+    Ok(VirNetResponseRaw { header, body })
+}
+#[cfg(fuzzing)]
+pub fn fuzz_deserialize_body<D>(
+    res_header: &protocol::VirNetMessageHeader,
+    res_body_bytes: Vec<u8>,
+) -> Result<VirNetResponse<D>, Error>
+where
+    D: DeserializeOwned,
+{
+    deserialize_body(res_header, res_body_bytes)
+}
@@ -0,0 +1,185 @@
+use crate::binding::RemoteNonnullDomain;
+use crate::client::{Libvirt, VirNetStream, VirNetStreamResponse, VirNetStreamSender};
+use crate::error::Error;
+use log::trace;
+use std::io::{ErrorKind, Read, Write};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{RecvTimeoutError, channel};
+use std::thread;
+use std::time::Duration;
+
+pub const VIR_DOMAIN_CONSOLE_FORCE: u32 = 1 << 0;
+pub const VIR_DOMAIN_CONSOLE_SAFE: u32 = 1 << 1;
+
+pub const VIR_DOMAIN_CHANNEL_FORCE: u32 = 1 << 0;
+
+// Ctrl-]
+pub const DEFAULT_ESCAPE: u8 = 0x1d;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+const BUFFER_SIZE: usize = 4096;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConsoleExit {
+    // The escape character was typed.
+    Escape,
+    // The input reached end of file.
+    InputClosed,
+    // The daemon closed the stream, e.g. because the domain stopped.
+    StreamClosed,
+}
+
+enum InputEvent {
+    Escape,
+    Closed,
+}
+
+pub struct ConsoleSession {
+    stream: VirNetStreamResponse<()>,
+    escape: Option<u8>,
+}
+
+impl ConsoleSession {
+    pub fn new(stream: VirNetStreamResponse<()>) -> Self {
+        ConsoleSession {
+            stream,
+            escape: Some(DEFAULT_ESCAPE),
+        }
+    }
+
+    pub fn open_console<T>(
+        client: &mut T,
+        dom: RemoteNonnullDomain,
+        dev_name: Option<&str>,
+        flags: u32,
+    ) -> Result<Self, Error>
+    where
+        T: Libvirt,
+    {
+        let stream = client.domain_open_console(dom, dev_name.map(|s| s.to_string()), flags)?;
+        Ok(ConsoleSession::new(stream))
+    }
+
+    pub fn open_channel<T>(
+        client: &mut T,
+        dom: RemoteNonnullDomain,
+        name: Option<&str>,
+        flags: u32,
+    ) -> Result<Self, Error>
+    where
+        T: Libvirt,
+    {
+        let stream = client.domain_open_channel(dom, name.map(|s| s.to_string()), flags)?;
+        Ok(ConsoleSession::new(stream))
+    }
+
+    // `None` passes every byte through, so only end of input ends the session.
+    pub fn escape(mut self, escape: Option<u8>) -> Self {
+        self.escape = escape;
+        self
+    }
+
+    // Copies `input` to the stream and the stream to `output` until one of
+    // them is closed or the escape character is read.
+    //
+    // Input is read on a thread of its own, which is joined before returning.
+    // So that the session can end while no input arrives, a read should give
+    // up after a while with `ErrorKind::TimedOut`, or fail with
+    // `ErrorKind::WouldBlock` if `input` is non-blocking. A read which blocks
+    // keeps this from returning until it does.
+    pub fn run<R, W>(mut self, input: R, mut output: W) -> Result<ConsoleExit, Error>
+    where
+        R: Read + Send + 'static,
+        W: Write,
+    {
+        let sender = self.stream.sender()?;
+        let escape = self.escape;
+        let stop = Arc::new(AtomicBool::new(false));
+        let t_stop = Arc::clone(&stop);
+        let (tx, rx) = channel();
+        let pump = thread::spawn(move || {
+            let event = pump_input(input, sender, escape, &t_stop);
+            let _ = tx.send(event);
+        });
+
+        let ret = loop {
+            if let Ok(event) = rx.try_recv() {
+                match event {
+                    Ok(InputEvent::Escape) => break Ok(ConsoleExit::Escape),
+                    Ok(InputEvent::Closed) => break Ok(ConsoleExit::InputClosed),
+                    Err(e) => break Err(e),
+                }
+            }
+
+            match self.stream.download_timeout(POLL_INTERVAL) {
+                Ok(Some(VirNetStream::Raw(buf))) => {
+                    if let Err(e) = output.write_all(&buf).and_then(|_| output.flush()) {
+                        break Err(Error::SendError(e));
+                    }
+                }
+                Ok(Some(VirNetStream::Hole(_))) => {}
+                Ok(None) => break Ok(ConsoleExit::StreamClosed),
+                Err(Error::ReceiveChannelError(RecvTimeoutError::Timeout)) => {}
+                Err(e) => break Err(e),
+            }
+        };
+
+        trace!("console closed: {ret:?}");
+        stop.store(true, Ordering::SeqCst);
+        if !matches!(ret, Ok(ConsoleExit::StreamClosed)) {
+            let _ = self.stream.abort();
+        }
+        self.stream.fin();
+        let _ = pump.join();
+        ret
+    }
+}
+
+fn pump_input<R>(
+    mut input: R,
+    mut sender: VirNetStreamSender,
+    escape: Option<u8>,
+    stop: &AtomicBool,
+) -> Result<InputEvent, Error>
+where
+    R: Read,
+{
+    let mut buf = [0; BUFFER_SIZE];
+    loop {
+        // The session ended while no input arrived.
+        if stop.load(Ordering::SeqCst) {
+            return Ok(InputEvent::Closed);
+        }
+
+        let n = match input.read(&mut buf) {
+            Ok(0) => return Ok(InputEvent::Closed),
+            Ok(n) => n,
+            Err(e) => match e.kind() {
+                ErrorKind::Interrupted | ErrorKind::TimedOut => continue,
+                ErrorKind::WouldBlock => {
+                    thread::sleep(POLL_INTERVAL);
+                    continue;
+                }
+                _ => return Err(Error::ReceiveError(e)),
+            },
+        };
+
+        // Discards what was read after the session ended.
+        if stop.load(Ordering::SeqCst) {
+            return Ok(InputEvent::Closed);
+        }
+
+        let data = &buf[..n];
+        let pos = escape.and_then(|e| data.iter().position(|b| *b == e));
+        let data = &data[..pos.unwrap_or(n)];
+
+        if !data.is_empty() {
+            sender.upload_data(data)?;
+        }
+
+        if pos.is_some() {
+            return Ok(InputEvent::Escape);
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use crate::binding::{RemoteNonnullDomain, RemoteNonnullStorageVol};
+use crate::client::Libvirt;
+use crate::error::Error;
+use crate::xml::LibvirtXml;
+
+pub const VIR_CONNECT_LIST_DOMAINS_ACTIVE: u32 = 1 << 0;
+pub const VIR_CONNECT_LIST_DOMAINS_INACTIVE: u32 = 1 << 1;
+pub const VIR_CONNECT_LIST_DOMAINS_PERSISTENT: u32 = 1 << 2;
+pub const VIR_CONNECT_LIST_DOMAINS_TRANSIENT: u32 = 1 << 3;
+pub const VIR_CONNECT_LIST_DOMAINS_RUNNING: u32 = 1 << 4;
+pub const VIR_CONNECT_LIST_DOMAINS_PAUSED: u32 = 1 << 5;
+pub const VIR_CONNECT_LIST_DOMAINS_SHUTOFF: u32 = 1 << 6;
+pub const VIR_CONNECT_LIST_DOMAINS_OTHER: u32 = 1 << 7;
+pub const VIR_CONNECT_LIST_DOMAINS_MANAGEDSAVE: u32 = 1 << 8;
+pub const VIR_CONNECT_LIST_DOMAINS_NO_MANAGEDSAVE: u32 = 1 << 9;
+pub const VIR_CONNECT_LIST_DOMAINS_AUTOSTART: u32 = 1 << 10;
+pub const VIR_CONNECT_LIST_DOMAINS_NO_AUTOSTART: u32 = 1 << 11;
+pub const VIR_CONNECT_LIST_DOMAINS_HAS_SNAPSHOT: u32 = 1 << 12;
+pub const VIR_CONNECT_LIST_DOMAINS_NO_SNAPSHOT: u32 = 1 << 13;
+pub const VIR_CONNECT_LIST_DOMAINS_HAS_CHECKPOINT: u32 = 1 << 14;
+pub const VIR_CONNECT_LIST_DOMAINS_NO_CHECKPOINT: u32 = 1 << 15;
+
+pub const VIR_DOMAIN_NONE: u32 = 0;
+pub const VIR_DOMAIN_START_PAUSED: u32 = 1 << 0;
+pub const VIR_DOMAIN_START_AUTODESTROY: u32 = 1 << 1;
+pub const VIR_DOMAIN_START_BYPASS_CACHE: u32 = 1 << 2;
+pub const VIR_DOMAIN_START_FORCE_BOOT: u32 = 1 << 3;
+pub const VIR_DOMAIN_START_VALIDATE: u32 = 1 << 4;
+pub const VIR_DOMAIN_START_RESET_NVRAM: u32 = 1 << 5;
+
+pub const VIR_DOMAIN_XML_SECURE: u32 = 1 << 0;
+pub const VIR_DOMAIN_XML_INACTIVE: u32 = 1 << 1;
+pub const VIR_DOMAIN_XML_UPDATE_CPU: u32 = 1 << 2;
+pub const VIR_DOMAIN_XML_MIGRATABLE: u32 = 1 << 3;
+
+pub const VIR_DOMAIN_DEFINE_VALIDATE: u32 = 1 << 0;
+
+pub const VIR_DOMAIN_SHUTDOWN_DEFAULT: u32 = 0;
+pub const VIR_DOMAIN_SHUTDOWN_ACPI_POWER_BTN: u32 = 1 << 0;
+pub const VIR_DOMAIN_SHUTDOWN_GUEST_AGENT: u32 = 1 << 1;
+pub const VIR_DOMAIN_SHUTDOWN_INITCTL: u32 = 1 << 2;
+pub const VIR_DOMAIN_SHUTDOWN_SIGNAL: u32 = 1 << 3;
+pub const VIR_DOMAIN_SHUTDOWN_PARAVIRT: u32 = 1 << 4;
+
+pub const VIR_DOMAIN_REBOOT_DEFAULT: u32 = 0;
+pub const VIR_DOMAIN_REBOOT_ACPI_POWER_BTN: u32 = 1 << 0;
+pub const VIR_DOMAIN_REBOOT_GUEST_AGENT: u32 = 1 << 1;
+pub const VIR_DOMAIN_REBOOT_INITCTL: u32 = 1 << 2;
+pub const VIR_DOMAIN_REBOOT_SIGNAL: u32 = 1 << 3;
+pub const VIR_DOMAIN_REBOOT_PARAVIRT: u32 = 1 << 4;
+
+pub const VIR_DOMAIN_UNDEFINE_MANAGED_SAVE: u32 = 1 << 0;
+pub const VIR_DOMAIN_UNDEFINE_SNAPSHOTS_METADATA: u32 = 1 << 1;
+pub const VIR_DOMAIN_UNDEFINE_NVRAM: u32 = 1 << 2;
+pub const VIR_DOMAIN_UNDEFINE_KEEP_NVRAM: u32 = 1 << 3;
+pub const VIR_DOMAIN_UNDEFINE_CHECKPOINTS_METADATA: u32 = 1 << 4;
+pub const VIR_DOMAIN_UNDEFINE_TPM: u32 = 1 << 5;
+pub const VIR_DOMAIN_UNDEFINE_KEEP_TPM: u32 = 1 << 6;
+
+pub const VIR_NODE_SUSPEND_TARGET_MEM: u32 = 0;
+pub const VIR_NODE_SUSPEND_TARGET_DISK: u32 = 1;
+pub const VIR_NODE_SUSPEND_TARGET_HYBRID: u32 = 2;
+
+pub const VIR_DOMAIN_METADATA_DESCRIPTION: i32 = 0;
+pub const VIR_DOMAIN_METADATA_TITLE: i32 = 1;
+pub const VIR_DOMAIN_METADATA_ELEMENT: i32 = 2;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DomainState {
+    #[default]
+    NoState,
+    Running,
+    Blocked,
+    Paused,
+    Shutdown,
+    Shutoff,
+    Crashed,
+    PmSuspended,
+}
+
+impl From<i32> for DomainState {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => DomainState::Running,
+            2 => DomainState::Blocked,
+            3 => DomainState::Paused,
+            4 => DomainState::Shutdown,
+            5 => DomainState::Shutoff,
+            6 => DomainState::Crashed,
+            7 => DomainState::PmSuspended,
+            _ => DomainState::NoState,
+        }
+    }
+}
+
+impl DomainState {
+    // The reason codes differ by state, so they are only meaningful together.
+    pub fn reason(&self, reason: i32) -> &'static str {
+        let reasons: &[&str] = match self {
+            DomainState::Running => &[
+                "unknown",
+                "booted",
+                "migrated",
+                "restored",
+                "from snapshot",
+                "unpaused",
+                "migration canceled",
+                "save canceled",
+                "event wakeup",
+                "crashed",
+                "post-copy",
+                "post-copy failed",
+            ],
+            DomainState::Paused => &[
+                "unknown",
+                "user",
+                "migrating",
+                "saving",
+                "dumping",
+                "I/O error",
+                "watchdog",
+                "from snapshot",
+                "shutting down",
+                "creating snapshot",
+                "starting up",
+                "post-copy",
+                "post-copy failed",
+                "api error",
+            ],
+            DomainState::Shutdown => &["unknown", "user"],
+            DomainState::Shutoff => &[
+                "unknown",
+                "shutdown",
+                "destroyed",
+                "crashed",
+                "migrated",
+                "saved",
+                "failed",
+                "from snapshot",
+                "daemon",
+            ],
+            DomainState::Crashed => &["unknown", "panicked"],
+            _ => &["unknown"],
+        };
+
+        usize::try_from(reason)
+            .ok()
+            .and_then(|r| reasons.get(r))
+            .copied()
+            .unwrap_or("unknown")
+    }
+}
+
+// A disk of a domain and the storage volume backing it, if libvirt manages it.
+#[derive(Clone, Debug)]
+pub struct DiskVolume {
+    pub target: String,
+    pub source: String,
+    pub vol: Option<RemoteNonnullStorageVol>,
+}
+
+// Resolves the disks of a domain to storage volumes. Disks
+// without a local source, such as network disks or empty CD-ROM drives, are
+// left out.
+pub fn disk_volumes<T>(client: &mut T, dom: RemoteNonnullDomain) -> Result<Vec<DiskVolume>, Error>
+where
+    T: Libvirt,
+{
+    let def = client.domain_definition(dom, 0)?;
+    let disks = def.devices.map(|d| d.disk).unwrap_or_default();
+
+    let mut volumes = vec![];
+    for disk in disks {
+        let Some(source) = disk.source else {
+            continue;
+        };
+
+        let (path, vol) = match (source.pool, source.volume, source.file.or(source.dev)) {
+            (Some(pool), Some(volume), _) => {
+                let vol = client
+                    .storage_pool_lookup_by_name(pool.clone())
+                    .and_then(|pool| client.storage_vol_lookup_by_name(pool, volume.clone()))
+                    .ok();
+                (format!("{pool}/{volume}"), vol)
+            }
+            (_, _, Some(path)) => {
+                let vol = client.storage_vol_lookup_by_path(path.clone()).ok();
+                (path, vol)
+            }
+            _ => continue,
+        };
+
+        volumes.push(DiskVolume {
+            target: disk.target.dev,
+            source: path,
+            vol,
+        });
+    }
+
+    Ok(volumes)
+}
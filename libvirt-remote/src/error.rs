@@ -1,4 +1,6 @@
+use crate::agent::AgentError;
 use crate::protocol;
+use crate::qmp::QmpError;
 use serde_xdr;
 use std::fmt;
 use std::io;
@@ -6,13 +8,27 @@ use std::sync::mpsc;
 
 #[derive(Debug)]
 pub enum Error {
+    AgentError(AgentError),
+    BackupError(String),
+    BlockJobError(String),
     DeserializeError(serde_xdr::error::Error),
+    ImageError(String),
+    InvalidUriError(String),
+    JsonError(serde_json::Error),
+    MigrationError(String),
+    PacketLengthError(usize),
     ProtocolError(protocol::VirNetMessageError),
+    QmpError(QmpError),
     ReceiveError(io::Error),
     ReceiveChannelError(mpsc::RecvTimeoutError),
     SendError(io::Error),
     SerializeError(serde_xdr::error::Error),
     SocketError(io::Error),
+    UnexpectedMessageError(protocol::VirNetMessageHeader),
+    UnsupportedTransportError(String),
+    XmlDeserializeError(quick_xml::DeError),
+    XmlSerializeError(quick_xml::SeError),
+    ConnectionClosedError,
     ReceiverNotStartedError,
     ReceiverStopError,
 }
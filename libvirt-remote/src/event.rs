@@ -0,0 +1,553 @@
+use crate::binding::*;
+use crate::client::{Libvirt, VirNetResponseRaw};
+use crate::error::Error;
+use std::sync::mpsc::{Receiver, RecvTimeoutError, Sender, channel};
+use std::time::{Duration, Instant};
+
+pub const VIR_DOMAIN_EVENT_ID_LIFECYCLE: i32 = 0;
+pub const VIR_DOMAIN_EVENT_ID_REBOOT: i32 = 1;
+pub const VIR_DOMAIN_EVENT_ID_RTC_CHANGE: i32 = 2;
+pub const VIR_DOMAIN_EVENT_ID_WATCHDOG: i32 = 3;
+pub const VIR_DOMAIN_EVENT_ID_IO_ERROR: i32 = 4;
+pub const VIR_DOMAIN_EVENT_ID_GRAPHICS: i32 = 5;
+pub const VIR_DOMAIN_EVENT_ID_IO_ERROR_REASON: i32 = 6;
+pub const VIR_DOMAIN_EVENT_ID_CONTROL_ERROR: i32 = 7;
+pub const VIR_DOMAIN_EVENT_ID_BLOCK_JOB: i32 = 8;
+pub const VIR_DOMAIN_EVENT_ID_DISK_CHANGE: i32 = 9;
+pub const VIR_DOMAIN_EVENT_ID_TRAY_CHANGE: i32 = 10;
+pub const VIR_DOMAIN_EVENT_ID_PMWAKEUP: i32 = 11;
+pub const VIR_DOMAIN_EVENT_ID_PMSUSPEND: i32 = 12;
+pub const VIR_DOMAIN_EVENT_ID_BALLOON_CHANGE: i32 = 13;
+pub const VIR_DOMAIN_EVENT_ID_PMSUSPEND_DISK: i32 = 14;
+pub const VIR_DOMAIN_EVENT_ID_DEVICE_REMOVED: i32 = 15;
+pub const VIR_DOMAIN_EVENT_ID_BLOCK_JOB_2: i32 = 16;
+pub const VIR_DOMAIN_EVENT_ID_TUNABLE: i32 = 17;
+pub const VIR_DOMAIN_EVENT_ID_AGENT_LIFECYCLE: i32 = 18;
+pub const VIR_DOMAIN_EVENT_ID_DEVICE_ADDED: i32 = 19;
+pub const VIR_DOMAIN_EVENT_ID_MIGRATION_ITERATION: i32 = 20;
+pub const VIR_DOMAIN_EVENT_ID_JOB_COMPLETED: i32 = 21;
+pub const VIR_DOMAIN_EVENT_ID_DEVICE_REMOVAL_FAILED: i32 = 22;
+pub const VIR_DOMAIN_EVENT_ID_METADATA_CHANGE: i32 = 23;
+pub const VIR_DOMAIN_EVENT_ID_BLOCK_THRESHOLD: i32 = 24;
+pub const VIR_DOMAIN_EVENT_ID_MEMORY_FAILURE: i32 = 25;
+pub const VIR_DOMAIN_EVENT_ID_MEMORY_DEVICE_SIZE_CHANGE: i32 = 26;
+pub const VIR_DOMAIN_EVENT_ID_NIC_MAC_CHANGE: i32 = 27;
+
+// Event names as used by virsh, indexed by event ID.
+pub const DOMAIN_EVENT_NAMES: [&str; 28] = [
+    "lifecycle",
+    "reboot",
+    "rtc-change",
+    "watchdog",
+    "io-error",
+    "graphics",
+    "io-error-reason",
+    "control-error",
+    "block-job",
+    "disk-change",
+    "tray-change",
+    "pm-wakeup",
+    "pm-suspend",
+    "balloon-change",
+    "pm-suspend-disk",
+    "device-removed",
+    "block-job-2",
+    "tunable",
+    "agent-lifecycle",
+    "device-added",
+    "migration-iteration",
+    "job-completed",
+    "device-removal-failed",
+    "metadata-change",
+    "block-threshold",
+    "memory-failure",
+    "memory-device-size-change",
+    "nic-mac-change",
+];
+
+pub fn domain_event_id(name: &str) -> Option<i32> {
+    DOMAIN_EVENT_NAMES
+        .iter()
+        .position(|n| *n == name)
+        .map(|id| id as i32)
+}
+
+#[derive(Clone, Debug)]
+pub enum DomainEvent {
+    Lifecycle {
+        event: i32,
+        detail: i32,
+    },
+    Reboot,
+    RtcChange {
+        offset: i64,
+    },
+    Watchdog {
+        action: i32,
+    },
+    IoError {
+        src_path: String,
+        dev_alias: String,
+        action: i32,
+    },
+    Graphics {
+        phase: i32,
+        local: RemoteDomainEventGraphicsAddress,
+        remote: RemoteDomainEventGraphicsAddress,
+        auth_scheme: String,
+        subject: Vec<RemoteDomainEventGraphicsIdentity>,
+    },
+    IoErrorReason {
+        src_path: String,
+        dev_alias: String,
+        action: i32,
+        reason: String,
+    },
+    ControlError,
+    BlockJob {
+        path: String,
+        r#type: i32,
+        status: i32,
+    },
+    DiskChange {
+        old_src_path: Option<String>,
+        new_src_path: Option<String>,
+        dev_alias: String,
+        reason: i32,
+    },
+    TrayChange {
+        dev_alias: String,
+        reason: i32,
+    },
+    PmWakeup {
+        reason: i32,
+    },
+    PmSuspend {
+        reason: i32,
+    },
+    BalloonChange {
+        actual: u64,
+    },
+    PmSuspendDisk {
+        reason: i32,
+    },
+    DeviceRemoved {
+        dev_alias: String,
+    },
+    BlockJob2 {
+        dst: String,
+        r#type: i32,
+        status: i32,
+    },
+    Tunable {
+        params: Vec<RemoteTypedParam>,
+    },
+    AgentLifecycle {
+        state: i32,
+        reason: i32,
+    },
+    DeviceAdded {
+        dev_alias: String,
+    },
+    MigrationIteration {
+        iteration: i32,
+    },
+    JobCompleted {
+        params: Vec<RemoteTypedParam>,
+    },
+    DeviceRemovalFailed {
+        dev_alias: String,
+    },
+    MetadataChange {
+        r#type: i32,
+        nsuri: Option<String>,
+    },
+    BlockThreshold {
+        dev: String,
+        path: Option<String>,
+        threshold: u64,
+        excess: u64,
+    },
+    MemoryFailure {
+        recipient: i32,
+        action: i32,
+        flags: u32,
+    },
+    MemoryDeviceSizeChange {
+        alias: String,
+        size: u64,
+    },
+    NicMacChange {
+        alias: String,
+        old_mac: String,
+        new_mac: String,
+    },
+}
+
+impl DomainEvent {
+    pub fn id(&self) -> i32 {
+        match self {
+            DomainEvent::Lifecycle { .. } => VIR_DOMAIN_EVENT_ID_LIFECYCLE,
+            DomainEvent::Reboot => VIR_DOMAIN_EVENT_ID_REBOOT,
+            DomainEvent::RtcChange { .. } => VIR_DOMAIN_EVENT_ID_RTC_CHANGE,
+            DomainEvent::Watchdog { .. } => VIR_DOMAIN_EVENT_ID_WATCHDOG,
+            DomainEvent::IoError { .. } => VIR_DOMAIN_EVENT_ID_IO_ERROR,
+            DomainEvent::Graphics { .. } => VIR_DOMAIN_EVENT_ID_GRAPHICS,
+            DomainEvent::IoErrorReason { .. } => VIR_DOMAIN_EVENT_ID_IO_ERROR_REASON,
+            DomainEvent::ControlError => VIR_DOMAIN_EVENT_ID_CONTROL_ERROR,
+            DomainEvent::BlockJob { .. } => VIR_DOMAIN_EVENT_ID_BLOCK_JOB,
+            DomainEvent::DiskChange { .. } => VIR_DOMAIN_EVENT_ID_DISK_CHANGE,
+            DomainEvent::TrayChange { .. } => VIR_DOMAIN_EVENT_ID_TRAY_CHANGE,
+            DomainEvent::PmWakeup { .. } => VIR_DOMAIN_EVENT_ID_PMWAKEUP,
+            DomainEvent::PmSuspend { .. } => VIR_DOMAIN_EVENT_ID_PMSUSPEND,
+            DomainEvent::BalloonChange { .. } => VIR_DOMAIN_EVENT_ID_BALLOON_CHANGE,
+            DomainEvent::PmSuspendDisk { .. } => VIR_DOMAIN_EVENT_ID_PMSUSPEND_DISK,
+            DomainEvent::DeviceRemoved { .. } => VIR_DOMAIN_EVENT_ID_DEVICE_REMOVED,
+            DomainEvent::BlockJob2 { .. } => VIR_DOMAIN_EVENT_ID_BLOCK_JOB_2,
+            DomainEvent::Tunable { .. } => VIR_DOMAIN_EVENT_ID_TUNABLE,
+            DomainEvent::AgentLifecycle { .. } => VIR_DOMAIN_EVENT_ID_AGENT_LIFECYCLE,
+            DomainEvent::DeviceAdded { .. } => VIR_DOMAIN_EVENT_ID_DEVICE_ADDED,
+            DomainEvent::MigrationIteration { .. } => VIR_DOMAIN_EVENT_ID_MIGRATION_ITERATION,
+            DomainEvent::JobCompleted { .. } => VIR_DOMAIN_EVENT_ID_JOB_COMPLETED,
+            DomainEvent::DeviceRemovalFailed { .. } => VIR_DOMAIN_EVENT_ID_DEVICE_REMOVAL_FAILED,
+            DomainEvent::MetadataChange { .. } => VIR_DOMAIN_EVENT_ID_METADATA_CHANGE,
+            DomainEvent::BlockThreshold { .. } => VIR_DOMAIN_EVENT_ID_BLOCK_THRESHOLD,
+            DomainEvent::MemoryFailure { .. } => VIR_DOMAIN_EVENT_ID_MEMORY_FAILURE,
+            DomainEvent::MemoryDeviceSizeChange { .. } => {
+                VIR_DOMAIN_EVENT_ID_MEMORY_DEVICE_SIZE_CHANGE
+            }
+            DomainEvent::NicMacChange { .. } => VIR_DOMAIN_EVENT_ID_NIC_MAC_CHANGE,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        DOMAIN_EVENT_NAMES[self.id() as usize]
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DomainEventMsg {
+    pub callback_id: i32,
+    pub dom: RemoteNonnullDomain,
+    pub event: DomainEvent,
+}
+
+impl DomainEventMsg {
+    // Decodes a message sent for `connect_domain_event_callback_register_any`.
+    // Returns None for messages which are not domain events.
+    pub fn from_raw(raw: VirNetResponseRaw) -> Result<Option<Self>, Error> {
+        if raw.program() != REMOTE_PROGRAM {
+            return Ok(None);
+        }
+
+        let procedure = raw.procedure();
+        let proc = |p: RemoteProcedure| p as i32 == procedure;
+
+        let (callback_id, dom, event) =
+            if proc(RemoteProcedure::RemoteProcDomainEventCallbackLifecycle) {
+                let m: RemoteDomainEventCallbackLifecycleMsg = raw.try_into()?;
+                let event = DomainEvent::Lifecycle {
+                    event: m.msg.event,
+                    detail: m.msg.detail,
+                };
+                (m.callback_id, m.msg.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackReboot) {
+                let m: RemoteDomainEventCallbackRebootMsg = raw.try_into()?;
+                (m.callback_id, m.msg.dom, DomainEvent::Reboot)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackRtcChange) {
+                let m: RemoteDomainEventCallbackRtcChangeMsg = raw.try_into()?;
+                let event = DomainEvent::RtcChange {
+                    offset: m.msg.offset,
+                };
+                (m.callback_id, m.msg.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackWatchdog) {
+                let m: RemoteDomainEventCallbackWatchdogMsg = raw.try_into()?;
+                let event = DomainEvent::Watchdog {
+                    action: m.msg.action,
+                };
+                (m.callback_id, m.msg.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackIoError) {
+                let m: RemoteDomainEventCallbackIoErrorMsg = raw.try_into()?;
+                let event = DomainEvent::IoError {
+                    src_path: m.msg.src_path,
+                    dev_alias: m.msg.dev_alias,
+                    action: m.msg.action,
+                };
+                (m.callback_id, m.msg.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackGraphics) {
+                let m: RemoteDomainEventCallbackGraphicsMsg = raw.try_into()?;
+                let event = DomainEvent::Graphics {
+                    phase: m.msg.phase,
+                    local: m.msg.local,
+                    remote: m.msg.remote,
+                    auth_scheme: m.msg.auth_scheme,
+                    subject: m.msg.subject,
+                };
+                (m.callback_id, m.msg.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackIoErrorReason) {
+                let m: RemoteDomainEventCallbackIoErrorReasonMsg = raw.try_into()?;
+                let event = DomainEvent::IoErrorReason {
+                    src_path: m.msg.src_path,
+                    dev_alias: m.msg.dev_alias,
+                    action: m.msg.action,
+                    reason: m.msg.reason,
+                };
+                (m.callback_id, m.msg.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackControlError) {
+                let m: RemoteDomainEventCallbackControlErrorMsg = raw.try_into()?;
+                (m.callback_id, m.msg.dom, DomainEvent::ControlError)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackBlockJob) {
+                let m: RemoteDomainEventCallbackBlockJobMsg = raw.try_into()?;
+                let event = DomainEvent::BlockJob {
+                    path: m.msg.path,
+                    r#type: m.msg.r#type,
+                    status: m.msg.status,
+                };
+                (m.callback_id, m.msg.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackDiskChange) {
+                let m: RemoteDomainEventCallbackDiskChangeMsg = raw.try_into()?;
+                let event = DomainEvent::DiskChange {
+                    old_src_path: m.msg.old_src_path,
+                    new_src_path: m.msg.new_src_path,
+                    dev_alias: m.msg.dev_alias,
+                    reason: m.msg.reason,
+                };
+                (m.callback_id, m.msg.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackTrayChange) {
+                let m: RemoteDomainEventCallbackTrayChangeMsg = raw.try_into()?;
+                let event = DomainEvent::TrayChange {
+                    dev_alias: m.msg.dev_alias,
+                    reason: m.msg.reason,
+                };
+                (m.callback_id, m.msg.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackPmwakeup) {
+                let m: RemoteDomainEventCallbackPmwakeupMsg = raw.try_into()?;
+                let event = DomainEvent::PmWakeup { reason: m.reason };
+                (m.callback_id, m.msg.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackPmsuspend) {
+                let m: RemoteDomainEventCallbackPmsuspendMsg = raw.try_into()?;
+                let event = DomainEvent::PmSuspend { reason: m.reason };
+                (m.callback_id, m.msg.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackBalloonChange) {
+                let m: RemoteDomainEventCallbackBalloonChangeMsg = raw.try_into()?;
+                let event = DomainEvent::BalloonChange {
+                    actual: m.msg.actual,
+                };
+                (m.callback_id, m.msg.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackPmsuspendDisk) {
+                let m: RemoteDomainEventCallbackPmsuspendDiskMsg = raw.try_into()?;
+                let event = DomainEvent::PmSuspendDisk { reason: m.reason };
+                (m.callback_id, m.msg.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackDeviceRemoved) {
+                let m: RemoteDomainEventCallbackDeviceRemovedMsg = raw.try_into()?;
+                let event = DomainEvent::DeviceRemoved {
+                    dev_alias: m.msg.dev_alias,
+                };
+                (m.callback_id, m.msg.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventBlockJob2) {
+                let m: RemoteDomainEventBlockJob2Msg = raw.try_into()?;
+                let event = DomainEvent::BlockJob2 {
+                    dst: m.dst,
+                    r#type: m.r#type,
+                    status: m.status,
+                };
+                (m.callback_id, m.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackTunable) {
+                let m: RemoteDomainEventCallbackTunableMsg = raw.try_into()?;
+                let event = DomainEvent::Tunable { params: m.params };
+                (m.callback_id, m.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackAgentLifecycle) {
+                let m: RemoteDomainEventCallbackAgentLifecycleMsg = raw.try_into()?;
+                let event = DomainEvent::AgentLifecycle {
+                    state: m.state,
+                    reason: m.reason,
+                };
+                (m.callback_id, m.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackDeviceAdded) {
+                let m: RemoteDomainEventCallbackDeviceAddedMsg = raw.try_into()?;
+                let event = DomainEvent::DeviceAdded {
+                    dev_alias: m.dev_alias,
+                };
+                (m.callback_id, m.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackMigrationIteration) {
+                let m: RemoteDomainEventCallbackMigrationIterationMsg = raw.try_into()?;
+                let event = DomainEvent::MigrationIteration {
+                    iteration: m.iteration,
+                };
+                (m.callback_id, m.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackJobCompleted) {
+                let m: RemoteDomainEventCallbackJobCompletedMsg = raw.try_into()?;
+                let event = DomainEvent::JobCompleted { params: m.params };
+                (m.callback_id, m.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackDeviceRemovalFailed) {
+                let m: RemoteDomainEventCallbackDeviceRemovalFailedMsg = raw.try_into()?;
+                let event = DomainEvent::DeviceRemovalFailed {
+                    dev_alias: m.dev_alias,
+                };
+                (m.callback_id, m.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventCallbackMetadataChange) {
+                let m: RemoteDomainEventCallbackMetadataChangeMsg = raw.try_into()?;
+                let event = DomainEvent::MetadataChange {
+                    r#type: m.r#type,
+                    nsuri: m.nsuri,
+                };
+                (m.callback_id, m.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventBlockThreshold) {
+                let m: RemoteDomainEventBlockThresholdMsg = raw.try_into()?;
+                let event = DomainEvent::BlockThreshold {
+                    dev: m.dev,
+                    path: m.path,
+                    threshold: m.threshold,
+                    excess: m.excess,
+                };
+                (m.callback_id, m.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventMemoryFailure) {
+                let m: RemoteDomainEventMemoryFailureMsg = raw.try_into()?;
+                let event = DomainEvent::MemoryFailure {
+                    recipient: m.recipient,
+                    action: m.action,
+                    flags: m.flags,
+                };
+                (m.callback_id, m.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventMemoryDeviceSizeChange) {
+                let m: RemoteDomainEventMemoryDeviceSizeChangeMsg = raw.try_into()?;
+                let event = DomainEvent::MemoryDeviceSizeChange {
+                    alias: m.alias,
+                    size: m.size,
+                };
+                (m.callback_id, m.dom, event)
+            } else if proc(RemoteProcedure::RemoteProcDomainEventNicMacChange) {
+                let m: RemoteDomainEventNicMacChangeMsg = raw.try_into()?;
+                let event = DomainEvent::NicMacChange {
+                    alias: m.alias,
+                    old_mac: m.old_mac,
+                    new_mac: m.new_mac,
+                };
+                (m.callback_id, m.dom, event)
+            } else {
+                return Ok(None);
+            };
+
+        Ok(Some(DomainEventMsg {
+            callback_id,
+            dom,
+            event,
+        }))
+    }
+}
+
+// Waits for the next event until `deadline`, or forever if it is None.
+// Returns None once the deadline has passed.
+pub fn wait_event<T>(
+    client: &mut T,
+    deadline: Option<Instant>,
+) -> Result<Option<VirNetResponseRaw>, Error>
+where
+    T: Libvirt,
+{
+    loop {
+        let timeout = match deadline {
+            Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                Some(remaining) if !remaining.is_zero() => remaining,
+                _ => return Ok(None),
+            },
+            None => Duration::from_secs(1800),
+        };
+
+        match client.get_event(timeout) {
+            Ok(raw) => return Ok(Some(raw)),
+            Err(Error::ReceiveChannelError(RecvTimeoutError::Timeout)) => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+const RECEIVER_POLL: Duration = Duration::from_secs(1);
+
+// Receives the events of a set of callbacks apart from the queue read by
+// `get_event`, so that waiting for them neither sees nor consumes the events
+// of other consumers on the same connection.
+pub struct EventChannel {
+    program: u32,
+    callback_ids: Vec<i32>,
+    tx: Sender<VirNetResponseRaw>,
+    rx: Receiver<VirNetResponseRaw>,
+}
+
+impl EventChannel {
+    pub fn new(program: u32) -> Self {
+        let (tx, rx) = channel();
+        EventChannel {
+            program,
+            callback_ids: vec![],
+            tx,
+            rx,
+        }
+    }
+
+    pub fn add<T>(&mut self, client: &mut T, callback_id: i32)
+    where
+        T: Libvirt,
+    {
+        client.add_event_channel(self.program, callback_id, self.tx.clone());
+        self.callback_ids.push(callback_id);
+    }
+
+    // Registers a callback with `f` and adds it. Events which arrive between
+    // the registration and the adding are kept for this channel.
+    pub fn register<T, F>(&mut self, client: &mut T, f: F) -> Result<i32, Error>
+    where
+        T: Libvirt,
+        F: FnOnce(&mut T) -> Result<i32, Error>,
+    {
+        client.hold_events(self.program);
+        let ret = f(client);
+        if let Ok(callback_id) = ret {
+            self.add(client, callback_id);
+        }
+        client.release_events(self.program);
+        ret
+    }
+
+    pub fn callback_ids(&self) -> &[i32] {
+        &self.callback_ids
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.callback_ids.is_empty()
+    }
+
+    // Returns None when no event arrived within `timeout`. The channel keeps
+    // a sender of its own, so a stopped receiver thread is detected by
+    // checking `client` every `RECEIVER_POLL` rather than by disconnection.
+    pub fn recv<T>(&self, client: &T, timeout: Duration) -> Result<Option<VirNetResponseRaw>, Error>
+    where
+        T: Libvirt,
+    {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match self.rx.recv_timeout(remaining.min(RECEIVER_POLL)) {
+                Ok(raw) => return Ok(Some(raw)),
+                Err(RecvTimeoutError::Timeout) if client.receiver_running() => {
+                    if remaining <= RECEIVER_POLL {
+                        return Ok(None);
+                    }
+                }
+                Err(_) => return Err(Error::ConnectionClosedError),
+            }
+        }
+    }
+
+    // Stops routing the events and returns the callback IDs, which the caller
+    // still has to deregister.
+    pub fn remove<T>(&mut self, client: &mut T) -> Vec<i32>
+    where
+        T: Libvirt,
+    {
+        for callback_id in &self.callback_ids {
+            client.remove_event_channel(self.program, *callback_id);
+        }
+        std::mem::take(&mut self.callback_ids)
+    }
+}
@@ -0,0 +1,382 @@
+use crate::binding::{
+    REMOTE_PROGRAM, RemoteDomainEventCallbackJobCompletedMsg, RemoteDomainGetJobInfoRet,
+    RemoteNonnullDomain, RemoteProcedure, RemoteTypedParam,
+};
+use crate::client::Libvirt;
+use crate::error::Error;
+use crate::event::EventChannel;
+use crate::param;
+use log::trace;
+use std::thread;
+use std::time::Duration;
+
+pub const VIR_DOMAIN_JOB_STATS_COMPLETED: u32 = 1 << 0;
+pub const VIR_DOMAIN_JOB_STATS_KEEP_COMPLETED: u32 = 1 << 1;
+
+pub const VIR_DOMAIN_ABORT_JOB_POSTCOPY: u32 = 1 << 0;
+
+pub use crate::event::{
+    VIR_DOMAIN_EVENT_ID_JOB_COMPLETED, VIR_DOMAIN_EVENT_ID_MIGRATION_ITERATION,
+};
+
+pub const VIR_DOMAIN_JOB_OPERATION: &str = "operation";
+pub const VIR_DOMAIN_JOB_TIME_ELAPSED: &str = "time_elapsed";
+pub const VIR_DOMAIN_JOB_TIME_REMAINING: &str = "time_remaining";
+pub const VIR_DOMAIN_JOB_DOWNTIME: &str = "downtime";
+pub const VIR_DOMAIN_JOB_SETUP_TIME: &str = "setup_time";
+pub const VIR_DOMAIN_JOB_DATA_TOTAL: &str = "data_total";
+pub const VIR_DOMAIN_JOB_DATA_PROCESSED: &str = "data_processed";
+pub const VIR_DOMAIN_JOB_DATA_REMAINING: &str = "data_remaining";
+pub const VIR_DOMAIN_JOB_MEMORY_TOTAL: &str = "memory_total";
+pub const VIR_DOMAIN_JOB_MEMORY_PROCESSED: &str = "memory_processed";
+pub const VIR_DOMAIN_JOB_MEMORY_REMAINING: &str = "memory_remaining";
+pub const VIR_DOMAIN_JOB_MEMORY_DIRTY_RATE: &str = "memory_dirty_rate";
+pub const VIR_DOMAIN_JOB_MEMORY_PAGE_SIZE: &str = "memory_page_size";
+pub const VIR_DOMAIN_JOB_MEMORY_ITERATION: &str = "memory_iteration";
+pub const VIR_DOMAIN_JOB_MEMORY_BPS: &str = "memory_bps";
+pub const VIR_DOMAIN_JOB_DISK_TOTAL: &str = "disk_total";
+pub const VIR_DOMAIN_JOB_DISK_PROCESSED: &str = "disk_processed";
+pub const VIR_DOMAIN_JOB_DISK_REMAINING: &str = "disk_remaining";
+pub const VIR_DOMAIN_JOB_DISK_BPS: &str = "disk_bps";
+pub const VIR_DOMAIN_JOB_ERRMSG: &str = "errmsg";
+
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum JobType {
+    #[default]
+    None,
+    Bounded,
+    Unbounded,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl From<i32> for JobType {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => JobType::Bounded,
+            2 => JobType::Unbounded,
+            3 => JobType::Completed,
+            4 => JobType::Failed,
+            5 => JobType::Cancelled,
+            _ => JobType::None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JobProgress {
+    pub job_type: JobType,
+    pub operation: Option<i32>,
+    pub time_elapsed: Option<Duration>,
+    pub time_remaining: Option<Duration>,
+    pub downtime: Option<Duration>,
+    pub setup_time: Option<Duration>,
+    pub data_total: Option<u64>,
+    pub data_processed: Option<u64>,
+    pub data_remaining: Option<u64>,
+    pub memory_total: Option<u64>,
+    pub memory_processed: Option<u64>,
+    pub memory_remaining: Option<u64>,
+    // Pages per second.
+    pub memory_dirty_rate: Option<u64>,
+    pub memory_page_size: Option<u64>,
+    pub memory_iteration: Option<u64>,
+    pub memory_bps: Option<u64>,
+    pub disk_total: Option<u64>,
+    pub disk_processed: Option<u64>,
+    pub disk_remaining: Option<u64>,
+    pub disk_bps: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl JobProgress {
+    pub fn from_stats(job_type: i32, params: &[RemoteTypedParam]) -> Self {
+        let ms = |field| param::get_u64(params, field).map(Duration::from_millis);
+        let num = |field| param::get_u64(params, field);
+        JobProgress {
+            job_type: JobType::from(job_type),
+            operation: param::get_i64(params, VIR_DOMAIN_JOB_OPERATION).map(|v| v as i32),
+            time_elapsed: ms(VIR_DOMAIN_JOB_TIME_ELAPSED),
+            time_remaining: ms(VIR_DOMAIN_JOB_TIME_REMAINING),
+            downtime: ms(VIR_DOMAIN_JOB_DOWNTIME),
+            setup_time: ms(VIR_DOMAIN_JOB_SETUP_TIME),
+            data_total: num(VIR_DOMAIN_JOB_DATA_TOTAL),
+            data_processed: num(VIR_DOMAIN_JOB_DATA_PROCESSED),
+            data_remaining: num(VIR_DOMAIN_JOB_DATA_REMAINING),
+            memory_total: num(VIR_DOMAIN_JOB_MEMORY_TOTAL),
+            memory_processed: num(VIR_DOMAIN_JOB_MEMORY_PROCESSED),
+            memory_remaining: num(VIR_DOMAIN_JOB_MEMORY_REMAINING),
+            memory_dirty_rate: num(VIR_DOMAIN_JOB_MEMORY_DIRTY_RATE),
+            memory_page_size: num(VIR_DOMAIN_JOB_MEMORY_PAGE_SIZE),
+            memory_iteration: num(VIR_DOMAIN_JOB_MEMORY_ITERATION),
+            memory_bps: num(VIR_DOMAIN_JOB_MEMORY_BPS),
+            disk_total: num(VIR_DOMAIN_JOB_DISK_TOTAL),
+            disk_processed: num(VIR_DOMAIN_JOB_DISK_PROCESSED),
+            disk_remaining: num(VIR_DOMAIN_JOB_DISK_REMAINING),
+            disk_bps: num(VIR_DOMAIN_JOB_DISK_BPS),
+            error: param::get_str(params, VIR_DOMAIN_JOB_ERRMSG).map(|v| v.to_string()),
+        }
+    }
+
+    pub fn from_info(info: &RemoteDomainGetJobInfoRet) -> Self {
+        JobProgress {
+            job_type: JobType::from(info.r#type),
+            time_elapsed: Some(Duration::from_millis(info.time_elapsed)),
+            time_remaining: Some(Duration::from_millis(info.time_remaining)),
+            data_total: Some(info.data_total),
+            data_processed: Some(info.data_processed),
+            data_remaining: Some(info.data_remaining),
+            memory_total: Some(info.mem_total),
+            memory_processed: Some(info.mem_processed),
+            memory_remaining: Some(info.mem_remaining),
+            disk_total: Some(info.file_total),
+            disk_processed: Some(info.file_processed),
+            disk_remaining: Some(info.file_remaining),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        matches!(self.job_type, JobType::Bounded | JobType::Unbounded)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        !self.is_active()
+    }
+
+    pub fn percent(&self) -> Option<f64> {
+        let total = self.data_total?;
+        let remaining = self.data_remaining?;
+        if total == 0 {
+            return None;
+        }
+        Some(100.0 * total.saturating_sub(remaining) as f64 / total as f64)
+    }
+
+    pub fn memory_dirty_bytes_rate(&self) -> Option<u64> {
+        Some(self.memory_dirty_rate? * self.memory_page_size.unwrap_or(4096))
+    }
+
+    pub fn eta(&self) -> Option<Duration> {
+        if let Some(remaining) = self.time_remaining.filter(|t| !t.is_zero()) {
+            return Some(remaining);
+        }
+
+        let remaining = self.data_remaining?;
+        let bps = self.memory_bps.unwrap_or(0) + self.disk_bps.unwrap_or(0);
+        if bps == 0 {
+            return None;
+        }
+        Some(Duration::from_secs_f64(remaining as f64 / bps as f64))
+    }
+}
+
+pub struct JobWatcher<'a, T>
+where
+    T: Libvirt,
+{
+    client: &'a mut T,
+    dom: RemoteNonnullDomain,
+    interval: Duration,
+    events: EventChannel,
+    started: bool,
+    done: bool,
+}
+
+impl<'a, T> JobWatcher<'a, T>
+where
+    T: Libvirt,
+{
+    pub fn new(client: &'a mut T, dom: RemoteNonnullDomain) -> Self {
+        JobWatcher {
+            client,
+            dom,
+            interval: DEFAULT_INTERVAL,
+            events: EventChannel::new(REMOTE_PROGRAM),
+            started: false,
+            done: false,
+        }
+    }
+
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    // Wake up on job completion and migration iteration events instead of
+    // waiting the whole interval between polls.
+    pub fn events(mut self) -> Result<Self, Error> {
+        for event_id in [
+            VIR_DOMAIN_EVENT_ID_JOB_COMPLETED,
+            VIR_DOMAIN_EVENT_ID_MIGRATION_ITERATION,
+        ] {
+            let dom = self.dom.clone();
+            self.events.register(self.client, |c| {
+                c.connect_domain_event_callback_register_any(event_id, Some(dom))
+            })?;
+        }
+        Ok(self)
+    }
+
+    pub fn poll(&mut self) -> Result<JobProgress, Error> {
+        let (job_type, params) = self.client.domain_get_job_stats(self.dom.clone(), 0)?;
+        Ok(JobProgress::from_stats(job_type, &params))
+    }
+
+    pub fn completed(&mut self) -> Result<JobProgress, Error> {
+        let (job_type, params) = self
+            .client
+            .domain_get_job_stats(self.dom.clone(), VIR_DOMAIN_JOB_STATS_COMPLETED)?;
+        Ok(JobProgress::from_stats(job_type, &params))
+    }
+
+    pub fn abort(&mut self, flags: u32) -> Result<(), Error> {
+        if flags == 0 {
+            self.client.domain_abort_job(self.dom.clone())
+        } else {
+            self.client.domain_abort_job_flags(self.dom.clone(), flags)
+        }
+    }
+
+    pub fn canceller(&self) -> Result<JobCanceller<T>, Error> {
+        Ok(JobCanceller {
+            client: self.client.try_clone()?,
+            dom: self.dom.clone(),
+        })
+    }
+
+    pub fn wait<F>(&mut self, mut progress: F) -> Result<JobProgress, Error>
+    where
+        F: FnMut(&JobProgress),
+    {
+        let mut last = JobProgress::default();
+        for p in self.by_ref() {
+            let p = p?;
+            progress(&p);
+            last = p;
+        }
+        Ok(last)
+    }
+
+    pub fn close(mut self) -> Result<(), Error> {
+        self.deregister()
+    }
+
+    fn deregister(&mut self) -> Result<(), Error> {
+        let mut ret = Ok(());
+        for callback_id in self.events.remove(self.client) {
+            if let Err(e) = self
+                .client
+                .connect_domain_event_callback_deregister_any(callback_id)
+            {
+                ret = Err(e);
+            }
+        }
+        ret
+    }
+
+    fn sleep(&mut self) -> Result<Option<JobProgress>, Error> {
+        if self.events.is_empty() {
+            thread::sleep(self.interval);
+            return Ok(None);
+        }
+
+        let Some(raw) = self.events.recv(self.client, self.interval)? else {
+            return Ok(None);
+        };
+
+        if raw.procedure() == RemoteProcedure::RemoteProcDomainEventCallbackJobCompleted as i32 {
+            let msg: RemoteDomainEventCallbackJobCompletedMsg = raw.try_into()?;
+            if msg.dom.uuid == self.dom.uuid {
+                return Ok(Some(JobProgress::from_stats(
+                    JobType::Completed as i32,
+                    &msg.params,
+                )));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<T> Drop for JobWatcher<'_, T>
+where
+    T: Libvirt,
+{
+    fn drop(&mut self) {
+        if let Err(e) = self.deregister() {
+            trace!("failed to deregister job events {}.", e);
+        }
+    }
+}
+
+impl<T> Iterator for JobWatcher<'_, T>
+where
+    T: Libvirt,
+{
+    type Item = Result<JobProgress, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if self.started {
+            match self.sleep() {
+                Ok(Some(p)) => {
+                    self.done = true;
+                    return Some(Ok(p));
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        self.started = true;
+
+        let p = match self.poll() {
+            Ok(p) => p,
+            Err(e) => {
+                self.done = true;
+                return Some(Err(e));
+            }
+        };
+
+        if p.is_active() {
+            return Some(Ok(p));
+        }
+
+        // The job is gone, so report how it ended if the daemon kept it.
+        self.done = true;
+        match p.job_type {
+            JobType::None => Some(Ok(self.completed().unwrap_or(p))),
+            _ => Some(Ok(p)),
+        }
+    }
+}
+
+pub struct JobCanceller<T>
+where
+    T: Libvirt,
+{
+    client: T,
+    dom: RemoteNonnullDomain,
+}
+
+impl<T> JobCanceller<T>
+where
+    T: Libvirt,
+{
+    pub fn cancel(&mut self, flags: u32) -> Result<(), Error> {
+        if flags == 0 {
+            self.client.domain_abort_job(self.dom.clone())
+        } else {
+            self.client.domain_abort_job_flags(self.dom.clone(), flags)
+        }
+    }
+}
@@ -1,6 +1,24 @@
 #![allow(clippy::result_large_err)]
 
+pub mod agent;
+pub mod backup;
 pub mod binding;
+pub mod block_job;
 pub mod client;
+pub mod console;
+pub mod domain;
 pub mod error;
+pub mod event;
+pub mod job;
+pub mod migrate;
+pub mod network;
+pub mod nodedev;
+pub mod param;
 pub mod protocol;
+pub mod qmp;
+pub mod screenshot;
+pub mod socket;
+pub mod stats;
+pub mod storage;
+pub mod uri;
+pub mod xml;
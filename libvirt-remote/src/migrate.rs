@@ -0,0 +1,339 @@
+use crate::binding::{RemoteNonnullDomain, RemoteTypedParam};
+use crate::client::Libvirt;
+use crate::error::Error;
+use crate::job::JobProgress;
+use crate::param;
+use log::{trace, warn};
+use std::sync::mpsc::{RecvTimeoutError, channel};
+use std::thread;
+use std::time::Duration;
+
+pub const VIR_MIGRATE_LIVE: u32 = 1 << 0;
+pub const VIR_MIGRATE_PEER2PEER: u32 = 1 << 1;
+pub const VIR_MIGRATE_TUNNELLED: u32 = 1 << 2;
+pub const VIR_MIGRATE_PERSIST_DEST: u32 = 1 << 3;
+pub const VIR_MIGRATE_UNDEFINE_SOURCE: u32 = 1 << 4;
+pub const VIR_MIGRATE_PAUSED: u32 = 1 << 5;
+pub const VIR_MIGRATE_NON_SHARED_DISK: u32 = 1 << 6;
+pub const VIR_MIGRATE_NON_SHARED_INC: u32 = 1 << 7;
+pub const VIR_MIGRATE_CHANGE_PROTECTION: u32 = 1 << 8;
+pub const VIR_MIGRATE_UNSAFE: u32 = 1 << 9;
+pub const VIR_MIGRATE_OFFLINE: u32 = 1 << 10;
+pub const VIR_MIGRATE_COMPRESSED: u32 = 1 << 11;
+pub const VIR_MIGRATE_ABORT_ON_ERROR: u32 = 1 << 12;
+pub const VIR_MIGRATE_AUTO_CONVERGE: u32 = 1 << 13;
+pub const VIR_MIGRATE_RDMA_PIN_ALL: u32 = 1 << 14;
+pub const VIR_MIGRATE_POSTCOPY: u32 = 1 << 15;
+pub const VIR_MIGRATE_TLS: u32 = 1 << 16;
+pub const VIR_MIGRATE_PARALLEL: u32 = 1 << 17;
+pub const VIR_MIGRATE_NON_SHARED_SYNCHRONOUS_WRITES: u32 = 1 << 18;
+pub const VIR_MIGRATE_POSTCOPY_RESUME: u32 = 1 << 19;
+pub const VIR_MIGRATE_ZEROCOPY: u32 = 1 << 20;
+
+pub const VIR_MIGRATE_PARAM_URI: &str = "migrate_uri";
+pub const VIR_MIGRATE_PARAM_DEST_NAME: &str = "destination_name";
+pub const VIR_MIGRATE_PARAM_DEST_XML: &str = "destination_xml";
+pub const VIR_MIGRATE_PARAM_PERSIST_XML: &str = "persistent_xml";
+pub const VIR_MIGRATE_PARAM_BANDWIDTH: &str = "bandwidth";
+pub const VIR_MIGRATE_PARAM_BANDWIDTH_POSTCOPY: &str = "bandwidth.postcopy";
+pub const VIR_MIGRATE_PARAM_GRAPHICS_URI: &str = "graphics_uri";
+pub const VIR_MIGRATE_PARAM_LISTEN_ADDRESS: &str = "listen_address";
+pub const VIR_MIGRATE_PARAM_MIGRATE_DISKS: &str = "migrate_disks";
+pub const VIR_MIGRATE_PARAM_DISKS_PORT: &str = "disks_port";
+pub const VIR_MIGRATE_PARAM_DISKS_URI: &str = "disks_uri";
+pub const VIR_MIGRATE_PARAM_COMPRESSION: &str = "compression";
+pub const VIR_MIGRATE_PARAM_AUTO_CONVERGE_INITIAL: &str = "auto_converge.initial";
+pub const VIR_MIGRATE_PARAM_AUTO_CONVERGE_INCREMENT: &str = "auto_converge.increment";
+pub const VIR_MIGRATE_PARAM_PARALLEL_CONNECTIONS: &str = "parallel.connections";
+pub const VIR_MIGRATE_PARAM_TLS_DESTINATION: &str = "tls.destination";
+
+const DEFAULT_PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Debug, Default)]
+pub struct MigrateParams {
+    pub flags: u32,
+    // Connection URI of the destination daemon as seen from the source
+    // daemon, used by the peer-to-peer and tunnelled flows. Defaults to the
+    // URI of the destination connection.
+    pub dest_uri: Option<String>,
+    pub params: Vec<RemoteTypedParam>,
+}
+
+impl MigrateParams {
+    pub fn new(flags: u32) -> Self {
+        MigrateParams {
+            flags,
+            ..Default::default()
+        }
+    }
+
+    pub fn dest_uri(mut self, uri: &str) -> Self {
+        self.dest_uri = Some(uri.to_string());
+        self
+    }
+
+    pub fn param(mut self, param: RemoteTypedParam) -> Self {
+        param::replace(&mut self.params, param);
+        self
+    }
+}
+
+pub fn migrate<S, D>(
+    src: &mut S,
+    dst: &mut D,
+    dom: RemoteNonnullDomain,
+    params: &MigrateParams,
+) -> Result<RemoteNonnullDomain, Error>
+where
+    S: Libvirt,
+    D: Libvirt,
+{
+    migrate_with_progress(src, dst, dom, params, |_| {})
+}
+
+pub fn migrate_with_progress<S, D, F>(
+    src: &mut S,
+    dst: &mut D,
+    dom: RemoteNonnullDomain,
+    params: &MigrateParams,
+    progress: F,
+) -> Result<RemoteNonnullDomain, Error>
+where
+    S: Libvirt,
+    D: Libvirt,
+    F: FnMut(&JobProgress),
+{
+    let flags = params.flags;
+    if flags & VIR_MIGRATE_TUNNELLED != 0 && flags & VIR_MIGRATE_PEER2PEER == 0 {
+        return Err(Error::MigrationError(
+            "tunnelled migration requires peer-to-peer".to_string(),
+        ));
+    }
+
+    if flags & VIR_MIGRATE_PEER2PEER != 0 {
+        migrate_peer2peer(src, dst, dom, params, progress)
+    } else {
+        migrate_direct(src, dst, dom, params, progress)
+    }
+}
+
+// The source daemon connects to the destination itself, and tunnels the
+// migration data over that connection if requested.
+fn migrate_peer2peer<S, D, F>(
+    src: &mut S,
+    dst: &mut D,
+    dom: RemoteNonnullDomain,
+    params: &MigrateParams,
+    progress: F,
+) -> Result<RemoteNonnullDomain, Error>
+where
+    S: Libvirt,
+    D: Libvirt,
+    F: FnMut(&JobProgress),
+{
+    let mut params = params.clone();
+    if params.dest_uri.is_none() {
+        params.dest_uri = Some(dst.connect_get_uri()?);
+    }
+
+    let name = param::get_str(&params.params, VIR_MIGRATE_PARAM_DEST_NAME)
+        .unwrap_or(&dom.name)
+        .to_string();
+
+    migrate_p2p(src, dom, &params, progress)?;
+
+    dst.domain_lookup_by_name(name)
+}
+
+// Peer-to-peer migration needs no connection to the destination from the
+// client, so it can be started with only the destination URI at hand.
+pub fn migrate_p2p<S, F>(
+    src: &mut S,
+    dom: RemoteNonnullDomain,
+    params: &MigrateParams,
+    progress: F,
+) -> Result<(), Error>
+where
+    S: Libvirt,
+    F: FnMut(&JobProgress),
+{
+    let flags = params.flags | VIR_MIGRATE_PEER2PEER;
+    let Some(dest_uri) = params.dest_uri.clone() else {
+        return Err(Error::MigrationError(
+            "peer-to-peer migration requires destination URI".to_string(),
+        ));
+    };
+
+    trace!("perform (p2p): {} -> {}", dom.name, dest_uri);
+    perform(
+        src,
+        dom,
+        Some(dest_uri),
+        params.params.clone(),
+        vec![],
+        flags,
+        progress,
+    )?;
+
+    Ok(())
+}
+
+// The client drives all five phases, passing cookies between both daemons.
+fn migrate_direct<S, D, F>(
+    src: &mut S,
+    dst: &mut D,
+    dom: RemoteNonnullDomain,
+    params: &MigrateParams,
+    progress: F,
+) -> Result<RemoteNonnullDomain, Error>
+where
+    S: Libvirt,
+    D: Libvirt,
+    F: FnMut(&JobProgress),
+{
+    let flags = params.flags;
+    let dest_flags = flags & !(VIR_MIGRATE_ABORT_ON_ERROR | VIR_MIGRATE_AUTO_CONVERGE);
+    let mut params = params.params.clone();
+
+    trace!("begin: {}", dom.name);
+    let (cookie, xml) = src.domain_migrate_begin3_params(dom.clone(), params.clone(), flags)?;
+
+    param::replace(
+        &mut params,
+        RemoteTypedParam::string(VIR_MIGRATE_PARAM_DEST_XML, &xml),
+    );
+
+    trace!("prepare: {}", dom.name);
+    let cookie = match dst.domain_migrate_prepare3_params(params.clone(), cookie, dest_flags) {
+        Ok((cookie, uri)) => {
+            if let Some(uri) = uri {
+                param::replace(
+                    &mut params,
+                    RemoteTypedParam::string(VIR_MIGRATE_PARAM_URI, &uri),
+                );
+            }
+            cookie
+        }
+        Err(e) => {
+            confirm(src, dom, params, vec![], flags, true);
+            return Err(e);
+        }
+    };
+
+    if flags & VIR_MIGRATE_OFFLINE == 0 && param::get_str(&params, VIR_MIGRATE_PARAM_URI).is_none()
+    {
+        let e = Error::MigrationError("destination did not set migration URI".to_string());
+        finish(dst, params.clone(), vec![], dest_flags, true).ok();
+        confirm(src, dom, params, vec![], flags, true);
+        return Err(e);
+    }
+
+    let (cookie, perform_err) = if flags & VIR_MIGRATE_OFFLINE != 0 {
+        (cookie, None)
+    } else {
+        trace!("perform: {}", dom.name);
+        match perform(
+            src,
+            dom.clone(),
+            None,
+            params.clone(),
+            cookie,
+            flags,
+            progress,
+        ) {
+            Ok(cookie) => (cookie, None),
+            Err(e) => (vec![], Some(e)),
+        }
+    };
+
+    trace!("finish: {}", dom.name);
+    let cancelled = perform_err.is_some();
+    let (ddom, cookie, finish_err) =
+        match finish(dst, params.clone(), cookie, dest_flags, cancelled) {
+            Ok((ddom, cookie)) => (Some(ddom), cookie, None),
+            Err(e) => (None, vec![], Some(e)),
+        };
+
+    trace!("confirm: {}", dom.name);
+    confirm(src, dom, params, cookie, flags, cancelled || ddom.is_none());
+
+    match (ddom, perform_err, finish_err) {
+        (_, Some(e), _) => Err(e),
+        (Some(ddom), None, _) => Ok(ddom),
+        (None, None, Some(e)) => Err(e),
+        (None, None, None) => Err(Error::MigrationError(
+            "destination domain not found".to_string(),
+        )),
+    }
+}
+
+fn perform<S, F>(
+    src: &mut S,
+    dom: RemoteNonnullDomain,
+    dest_uri: Option<String>,
+    params: Vec<RemoteTypedParam>,
+    cookie: Vec<u8>,
+    flags: u32,
+    mut progress: F,
+) -> Result<Vec<u8>, Error>
+where
+    S: Libvirt,
+    F: FnMut(&JobProgress),
+{
+    // Perform blocks until the migration completes, so it runs on a clone of
+    // the source connection while the job stats are polled here.
+    let mut worker = src.try_clone()?;
+    let d = dom.clone();
+    let (tx, rx) = channel();
+    thread::spawn(move || {
+        let ret = worker.domain_migrate_perform3_params(d, dest_uri, params, cookie, flags);
+        let _ = tx.send(ret);
+    });
+
+    loop {
+        match rx.recv_timeout(DEFAULT_PROGRESS_INTERVAL) {
+            Ok(ret) => return ret,
+            Err(RecvTimeoutError::Timeout) => {
+                if let Ok((ty, stats)) = src.domain_get_job_stats(dom.clone(), 0) {
+                    progress(&JobProgress::from_stats(ty, &stats));
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(Error::MigrationError(
+                    "perform thread terminated".to_string(),
+                ));
+            }
+        }
+    }
+}
+
+fn finish<D>(
+    dst: &mut D,
+    params: Vec<RemoteTypedParam>,
+    cookie: Vec<u8>,
+    flags: u32,
+    cancelled: bool,
+) -> Result<(RemoteNonnullDomain, Vec<u8>), Error>
+where
+    D: Libvirt,
+{
+    dst.domain_migrate_finish3_params(params, cookie, flags, cancelled as i32)
+}
+
+// Confirm resumes the source domain when cancelled, or kills it otherwise.
+// Its failure must not hide the error of the earlier phase.
+fn confirm<S>(
+    src: &mut S,
+    dom: RemoteNonnullDomain,
+    params: Vec<RemoteTypedParam>,
+    cookie: Vec<u8>,
+    flags: u32,
+    cancelled: bool,
+) where
+    S: Libvirt,
+{
+    if let Err(e) = src.domain_migrate_confirm3_params(dom, params, cookie, flags, cancelled as i32)
+    {
+        warn!("confirm failed: {e}");
+    }
+}
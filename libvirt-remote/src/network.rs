@@ -0,0 +1,65 @@
+pub const VIR_CONNECT_LIST_NETWORKS_INACTIVE: u32 = 1 << 0;
+pub const VIR_CONNECT_LIST_NETWORKS_ACTIVE: u32 = 1 << 1;
+pub const VIR_CONNECT_LIST_NETWORKS_PERSISTENT: u32 = 1 << 2;
+pub const VIR_CONNECT_LIST_NETWORKS_TRANSIENT: u32 = 1 << 3;
+pub const VIR_CONNECT_LIST_NETWORKS_AUTOSTART: u32 = 1 << 4;
+pub const VIR_CONNECT_LIST_NETWORKS_NO_AUTOSTART: u32 = 1 << 5;
+
+pub const VIR_NETWORK_XML_INACTIVE: u32 = 1 << 0;
+
+pub const VIR_NETWORK_CREATE_VALIDATE: u32 = 1 << 0;
+pub const VIR_NETWORK_DEFINE_VALIDATE: u32 = 1 << 0;
+
+pub const VIR_NETWORK_UPDATE_COMMAND_NONE: u32 = 0;
+pub const VIR_NETWORK_UPDATE_COMMAND_MODIFY: u32 = 1;
+pub const VIR_NETWORK_UPDATE_COMMAND_DELETE: u32 = 2;
+pub const VIR_NETWORK_UPDATE_COMMAND_ADD_LAST: u32 = 3;
+pub const VIR_NETWORK_UPDATE_COMMAND_ADD_FIRST: u32 = 4;
+
+// `add` is a shorthand for `add-last`.
+pub const NETWORK_UPDATE_COMMANDS: [(&str, u32); 5] = [
+    ("add", VIR_NETWORK_UPDATE_COMMAND_ADD_LAST),
+    ("add-last", VIR_NETWORK_UPDATE_COMMAND_ADD_LAST),
+    ("add-first", VIR_NETWORK_UPDATE_COMMAND_ADD_FIRST),
+    ("delete", VIR_NETWORK_UPDATE_COMMAND_DELETE),
+    ("modify", VIR_NETWORK_UPDATE_COMMAND_MODIFY),
+];
+
+pub const VIR_NETWORK_SECTION_NONE: u32 = 0;
+pub const VIR_NETWORK_SECTION_BRIDGE: u32 = 1;
+pub const VIR_NETWORK_SECTION_DOMAIN: u32 = 2;
+pub const VIR_NETWORK_SECTION_IP: u32 = 3;
+pub const VIR_NETWORK_SECTION_IP_DHCP_HOST: u32 = 4;
+pub const VIR_NETWORK_SECTION_IP_DHCP_RANGE: u32 = 5;
+pub const VIR_NETWORK_SECTION_FORWARD: u32 = 6;
+pub const VIR_NETWORK_SECTION_FORWARD_INTERFACE: u32 = 7;
+pub const VIR_NETWORK_SECTION_FORWARD_PF: u32 = 8;
+pub const VIR_NETWORK_SECTION_PORTGROUP: u32 = 9;
+pub const VIR_NETWORK_SECTION_DNS_HOST: u32 = 10;
+pub const VIR_NETWORK_SECTION_DNS_TXT: u32 = 11;
+pub const VIR_NETWORK_SECTION_DNS_SRV: u32 = 12;
+
+pub const NETWORK_SECTIONS: [(&str, u32); 12] = [
+    ("bridge", VIR_NETWORK_SECTION_BRIDGE),
+    ("domain", VIR_NETWORK_SECTION_DOMAIN),
+    ("ip", VIR_NETWORK_SECTION_IP),
+    ("ip-dhcp-host", VIR_NETWORK_SECTION_IP_DHCP_HOST),
+    ("ip-dhcp-range", VIR_NETWORK_SECTION_IP_DHCP_RANGE),
+    ("forward", VIR_NETWORK_SECTION_FORWARD),
+    ("forward-interface", VIR_NETWORK_SECTION_FORWARD_INTERFACE),
+    ("forward-pf", VIR_NETWORK_SECTION_FORWARD_PF),
+    ("portgroup", VIR_NETWORK_SECTION_PORTGROUP),
+    ("dns-host", VIR_NETWORK_SECTION_DNS_HOST),
+    ("dns-txt", VIR_NETWORK_SECTION_DNS_TXT),
+    ("dns-srv", VIR_NETWORK_SECTION_DNS_SRV),
+];
+
+pub const VIR_NETWORK_UPDATE_AFFECT_CURRENT: u32 = 0;
+pub const VIR_NETWORK_UPDATE_AFFECT_LIVE: u32 = 1 << 0;
+pub const VIR_NETWORK_UPDATE_AFFECT_CONFIG: u32 = 1 << 1;
+
+pub const VIR_IP_ADDR_TYPE_IPV4: i32 = 0;
+pub const VIR_IP_ADDR_TYPE_IPV6: i32 = 1;
+
+pub const VIR_NETWORK_PORT_CREATE_RECLAIM: u32 = 1 << 0;
+pub const VIR_NETWORK_PORT_CREATE_VALIDATE: u32 = 1 << 1;
@@ -0,0 +1,67 @@
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_SYSTEM: u32 = 1 << 0;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_PCI_DEV: u32 = 1 << 1;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_USB_DEV: u32 = 1 << 2;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_USB_INTERFACE: u32 = 1 << 3;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_NET: u32 = 1 << 4;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_SCSI_HOST: u32 = 1 << 5;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_SCSI_TARGET: u32 = 1 << 6;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_SCSI: u32 = 1 << 7;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_STORAGE: u32 = 1 << 8;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_FC_HOST: u32 = 1 << 9;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_VPORTS: u32 = 1 << 10;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_SCSI_GENERIC: u32 = 1 << 11;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_DRM: u32 = 1 << 12;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_MDEV_TYPES: u32 = 1 << 13;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_MDEV: u32 = 1 << 14;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_CCW_DEV: u32 = 1 << 15;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_CSS_DEV: u32 = 1 << 16;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_VDPA: u32 = 1 << 17;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_AP_CARD: u32 = 1 << 18;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_AP_QUEUE: u32 = 1 << 19;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_AP_MATRIX: u32 = 1 << 20;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_VPD: u32 = 1 << 21;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_CCWGROUP_DEV: u32 = 1 << 22;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_CAP_CCWGROUP_MEMBER: u32 = 1 << 23;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_PERSISTENT: u32 = 1 << 28;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_TRANSIENT: u32 = 1 << 29;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_INACTIVE: u32 = 1 << 30;
+pub const VIR_CONNECT_LIST_NODE_DEVICES_ACTIVE: u32 = 1 << 31;
+
+// Capability names as they appear in the device XML.
+pub const NODE_DEVICE_CAPS: [(&str, u32); 24] = [
+    ("system", VIR_CONNECT_LIST_NODE_DEVICES_CAP_SYSTEM),
+    ("pci", VIR_CONNECT_LIST_NODE_DEVICES_CAP_PCI_DEV),
+    ("usb_device", VIR_CONNECT_LIST_NODE_DEVICES_CAP_USB_DEV),
+    ("usb", VIR_CONNECT_LIST_NODE_DEVICES_CAP_USB_INTERFACE),
+    ("net", VIR_CONNECT_LIST_NODE_DEVICES_CAP_NET),
+    ("scsi_host", VIR_CONNECT_LIST_NODE_DEVICES_CAP_SCSI_HOST),
+    ("scsi_target", VIR_CONNECT_LIST_NODE_DEVICES_CAP_SCSI_TARGET),
+    ("scsi", VIR_CONNECT_LIST_NODE_DEVICES_CAP_SCSI),
+    ("storage", VIR_CONNECT_LIST_NODE_DEVICES_CAP_STORAGE),
+    ("fc_host", VIR_CONNECT_LIST_NODE_DEVICES_CAP_FC_HOST),
+    ("vports", VIR_CONNECT_LIST_NODE_DEVICES_CAP_VPORTS),
+    (
+        "scsi_generic",
+        VIR_CONNECT_LIST_NODE_DEVICES_CAP_SCSI_GENERIC,
+    ),
+    ("drm", VIR_CONNECT_LIST_NODE_DEVICES_CAP_DRM),
+    ("mdev_types", VIR_CONNECT_LIST_NODE_DEVICES_CAP_MDEV_TYPES),
+    ("mdev", VIR_CONNECT_LIST_NODE_DEVICES_CAP_MDEV),
+    ("ccw", VIR_CONNECT_LIST_NODE_DEVICES_CAP_CCW_DEV),
+    ("css", VIR_CONNECT_LIST_NODE_DEVICES_CAP_CSS_DEV),
+    ("vdpa", VIR_CONNECT_LIST_NODE_DEVICES_CAP_VDPA),
+    ("ap_card", VIR_CONNECT_LIST_NODE_DEVICES_CAP_AP_CARD),
+    ("ap_queue", VIR_CONNECT_LIST_NODE_DEVICES_CAP_AP_QUEUE),
+    ("ap_matrix", VIR_CONNECT_LIST_NODE_DEVICES_CAP_AP_MATRIX),
+    ("vpd", VIR_CONNECT_LIST_NODE_DEVICES_CAP_VPD),
+    ("ccwgroup", VIR_CONNECT_LIST_NODE_DEVICES_CAP_CCWGROUP_DEV),
+    (
+        "ccwgroup_member",
+        VIR_CONNECT_LIST_NODE_DEVICES_CAP_CCWGROUP_MEMBER,
+    ),
+];
+
+pub const VIR_NODE_DEVICE_XML_INACTIVE: u32 = 1 << 0;
+
+pub const VIR_NODE_DEVICE_CREATE_XML_VALIDATE: u32 = 1 << 0;
+pub const VIR_NODE_DEVICE_DEFINE_XML_VALIDATE: u32 = 1 << 0;
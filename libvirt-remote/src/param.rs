@@ -0,0 +1,112 @@
+use crate::binding::{RemoteTypedParam, RemoteTypedParamValue};
+
+impl RemoteTypedParam {
+    pub fn int(field: &str, value: i32) -> Self {
+        Self::new(field, RemoteTypedParamValue::VirTypedParamInt(value))
+    }
+
+    pub fn uint(field: &str, value: u32) -> Self {
+        Self::new(field, RemoteTypedParamValue::VirTypedParamUint(value))
+    }
+
+    pub fn llong(field: &str, value: i64) -> Self {
+        Self::new(field, RemoteTypedParamValue::VirTypedParamLlong(value))
+    }
+
+    pub fn ullong(field: &str, value: u64) -> Self {
+        Self::new(field, RemoteTypedParamValue::VirTypedParamUllong(value))
+    }
+
+    pub fn double(field: &str, value: f64) -> Self {
+        Self::new(field, RemoteTypedParamValue::VirTypedParamDouble(value))
+    }
+
+    pub fn boolean(field: &str, value: bool) -> Self {
+        Self::new(
+            field,
+            RemoteTypedParamValue::VirTypedParamBoolean(value as i32),
+        )
+    }
+
+    pub fn string(field: &str, value: &str) -> Self {
+        Self::new(
+            field,
+            RemoteTypedParamValue::VirTypedParamString(value.to_string()),
+        )
+    }
+
+    fn new(field: &str, value: RemoteTypedParamValue) -> Self {
+        RemoteTypedParam {
+            field: field.to_string(),
+            value,
+        }
+    }
+}
+
+impl RemoteTypedParamValue {
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            RemoteTypedParamValue::VirTypedParamInt(v) => Some(*v as i64),
+            RemoteTypedParamValue::VirTypedParamUint(v) => Some(*v as i64),
+            RemoteTypedParamValue::VirTypedParamLlong(v) => Some(*v),
+            RemoteTypedParamValue::VirTypedParamUllong(v) => i64::try_from(*v).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            RemoteTypedParamValue::VirTypedParamInt(v) => u64::try_from(*v).ok(),
+            RemoteTypedParamValue::VirTypedParamUint(v) => Some(*v as u64),
+            RemoteTypedParamValue::VirTypedParamLlong(v) => u64::try_from(*v).ok(),
+            RemoteTypedParamValue::VirTypedParamUllong(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            RemoteTypedParamValue::VirTypedParamDouble(v) => Some(*v),
+            _ => self.as_i64().map(|v| v as f64),
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            RemoteTypedParamValue::VirTypedParamBoolean(v) => Some(*v != 0),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            RemoteTypedParamValue::VirTypedParamString(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+pub fn get<'a>(params: &'a [RemoteTypedParam], field: &str) -> Option<&'a RemoteTypedParamValue> {
+    params.iter().find(|p| p.field == field).map(|p| &p.value)
+}
+
+pub fn get_u64(params: &[RemoteTypedParam], field: &str) -> Option<u64> {
+    get(params, field).and_then(|v| v.as_u64())
+}
+
+pub fn get_i64(params: &[RemoteTypedParam], field: &str) -> Option<i64> {
+    get(params, field).and_then(|v| v.as_i64())
+}
+
+pub fn get_f64(params: &[RemoteTypedParam], field: &str) -> Option<f64> {
+    get(params, field).and_then(|v| v.as_f64())
+}
+
+pub fn get_str<'a>(params: &'a [RemoteTypedParam], field: &str) -> Option<&'a str> {
+    get(params, field).and_then(|v| v.as_str())
+}
+
+pub fn replace(params: &mut Vec<RemoteTypedParam>, param: RemoteTypedParam) {
+    params.retain(|p| p.field != param.field);
+    params.push(param);
+}
@@ -0,0 +1,322 @@
+use crate::binding::{QEMU_PROGRAM, QemuDomainMonitorEventMsg, QemuProcedure, RemoteNonnullDomain};
+use crate::client::Libvirt;
+use crate::error::Error;
+use crate::event::EventChannel;
+use log::trace;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+pub const VIR_DOMAIN_QEMU_MONITOR_COMMAND_DEFAULT: u32 = 0;
+pub const VIR_DOMAIN_QEMU_MONITOR_COMMAND_HMP: u32 = 1 << 0;
+
+pub const VIR_CONNECT_DOMAIN_QEMU_MONITOR_EVENT_REGISTER_REGEX: u32 = 1 << 0;
+pub const VIR_CONNECT_DOMAIN_QEMU_MONITOR_EVENT_REGISTER_NOCASE: u32 = 1 << 1;
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct QmpError {
+    pub class: String,
+    pub desc: String,
+}
+
+// A QMP command whose arguments are the serialized fields of the struct.
+pub trait QmpCommand: Serialize {
+    const NAME: &'static str;
+    type Return: DeserializeOwned;
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct QueryStatus;
+
+impl QmpCommand for QueryStatus {
+    const NAME: &'static str = "query-status";
+    type Return = StatusInfo;
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct StatusInfo {
+    pub running: bool,
+    pub status: String,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct QueryVersion;
+
+impl QmpCommand for QueryVersion {
+    const NAME: &'static str = "query-version";
+    type Return = VersionInfo;
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct VersionInfo {
+    pub qemu: VersionTriple,
+    pub package: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct VersionTriple {
+    pub major: u32,
+    pub minor: u32,
+    pub micro: u32,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct QueryCommands;
+
+impl QmpCommand for QueryCommands {
+    const NAME: &'static str = "query-commands";
+    type Return = Vec<CommandInfo>;
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct CommandInfo {
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct QueryName;
+
+impl QmpCommand for QueryName {
+    const NAME: &'static str = "query-name";
+    type Return = NameInfo;
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct NameInfo {
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct QueryBlockJobs;
+
+impl QmpCommand for QueryBlockJobs {
+    const NAME: &'static str = "query-block-jobs";
+    type Return = Vec<BlockJobInfo>;
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct BlockJobInfo {
+    pub r#type: String,
+    pub device: String,
+    pub len: u64,
+    pub offset: u64,
+    pub busy: bool,
+    pub paused: bool,
+    pub speed: u64,
+    pub ready: bool,
+    pub status: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct HumanMonitorCommand {
+    #[serde(rename = "command-line")]
+    pub command_line: String,
+    #[serde(rename = "cpu-index", skip_serializing_if = "Option::is_none")]
+    pub cpu_index: Option<i64>,
+}
+
+impl QmpCommand for HumanMonitorCommand {
+    const NAME: &'static str = "human-monitor-command";
+    type Return = String;
+}
+
+pub struct Qmp<'a, T>
+where
+    T: Libvirt,
+{
+    client: &'a mut T,
+    dom: RemoteNonnullDomain,
+}
+
+impl<'a, T> Qmp<'a, T>
+where
+    T: Libvirt,
+{
+    pub fn new(client: &'a mut T, dom: RemoteNonnullDomain) -> Self {
+        Qmp { client, dom }
+    }
+
+    pub fn execute<C>(&mut self, command: &C) -> Result<C::Return, Error>
+    where
+        C: QmpCommand,
+    {
+        let arguments = serde_json::to_value(command).map_err(Error::JsonError)?;
+        let ret = self.execute_raw(C::NAME, Some(arguments))?;
+        serde_json::from_value(ret).map_err(Error::JsonError)
+    }
+
+    // For commands without a typed struct. Returns the `return` member.
+    pub fn execute_raw(&mut self, command: &str, arguments: Option<Value>) -> Result<Value, Error> {
+        let mut cmd = json!({ "execute": command });
+        match arguments {
+            // Unit structs serialize as null and empty structs as {}.
+            Some(Value::Null) | None => {}
+            Some(Value::Object(map)) if map.is_empty() => {}
+            Some(arguments) => cmd["arguments"] = arguments,
+        }
+
+        trace!("qmp command: {cmd}");
+        let output = self.client.domain_monitor_command(
+            self.dom.clone(),
+            cmd.to_string(),
+            VIR_DOMAIN_QEMU_MONITOR_COMMAND_DEFAULT,
+        )?;
+        parse_response(&output)
+    }
+
+    // Runs a command on the human monitor and returns its text output.
+    pub fn hmp(&mut self, command: &str) -> Result<String, Error> {
+        self.client.domain_monitor_command(
+            self.dom.clone(),
+            command.to_string(),
+            VIR_DOMAIN_QEMU_MONITOR_COMMAND_HMP,
+        )
+    }
+}
+
+pub fn parse_response(output: &str) -> Result<Value, Error> {
+    let mut response: Value = serde_json::from_str(output).map_err(Error::JsonError)?;
+
+    if let Some(error) = response.get_mut("error") {
+        let error = serde_json::from_value(error.take()).map_err(Error::JsonError)?;
+        return Err(Error::QmpError(error));
+    }
+
+    match response.get_mut("return") {
+        Some(ret) => Ok(ret.take()),
+        None => Err(Error::QmpError(QmpError {
+            class: "InvalidResponse".to_string(),
+            desc: output.to_string(),
+        })),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct QmpEvent {
+    pub dom: RemoteNonnullDomain,
+    pub event: String,
+    pub seconds: i64,
+    pub micros: u32,
+    pub details: Option<Value>,
+}
+
+impl QmpEvent {
+    pub fn timestamp(&self) -> SystemTime {
+        let offset = Duration::from_secs(self.seconds.unsigned_abs())
+            + Duration::from_micros(self.micros as u64);
+        if self.seconds >= 0 {
+            UNIX_EPOCH + offset
+        } else {
+            UNIX_EPOCH - offset
+        }
+    }
+
+    pub fn details_as<D>(&self) -> Result<Option<D>, Error>
+    where
+        D: DeserializeOwned,
+    {
+        match &self.details {
+            Some(details) => serde_json::from_value(details.clone())
+                .map(Some)
+                .map_err(Error::JsonError),
+            None => Ok(None),
+        }
+    }
+}
+
+impl TryFrom<QemuDomainMonitorEventMsg> for QmpEvent {
+    type Error = Error;
+
+    fn try_from(msg: QemuDomainMonitorEventMsg) -> Result<Self, Self::Error> {
+        let details = match msg.details.as_deref() {
+            Some(details) => Some(serde_json::from_str(details).map_err(Error::JsonError)?),
+            None => None,
+        };
+
+        Ok(QmpEvent {
+            dom: msg.dom,
+            event: msg.event,
+            seconds: msg.seconds,
+            micros: msg.micros,
+            details,
+        })
+    }
+}
+
+pub struct QmpEventListener<'a, T>
+where
+    T: Libvirt,
+{
+    client: &'a mut T,
+    events: EventChannel,
+}
+
+impl<'a, T> QmpEventListener<'a, T>
+where
+    T: Libvirt,
+{
+    // `event` is an event name, or a pattern with the REGEX flag. `None`
+    // listens for all events.
+    pub fn register(
+        client: &'a mut T,
+        dom: Option<RemoteNonnullDomain>,
+        event: Option<&str>,
+        flags: u32,
+    ) -> Result<Self, Error> {
+        let mut events = EventChannel::new(QEMU_PROGRAM);
+        events.register(client, |c| {
+            c.connect_domain_monitor_event_register(dom, event.map(|s| s.to_string()), flags)
+        })?;
+        Ok(QmpEventListener { client, events })
+    }
+
+    // Waits for the next event of this listener. Returns None once `timeout`
+    // has passed without one.
+    pub fn next(&mut self, timeout: Duration) -> Result<Option<QmpEvent>, Error> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let Some(raw) = self.events.recv(self.client, remaining)? else {
+                return Ok(None);
+            };
+
+            if raw.procedure() != QemuProcedure::QemuProcDomainMonitorEvent as i32 {
+                continue;
+            }
+
+            let msg: QemuDomainMonitorEventMsg = raw.try_into()?;
+            return QmpEvent::try_from(msg).map(Some);
+        }
+    }
+
+    // Deregisters the event callback, which dropping the listener also does
+    // but without reporting failure.
+    pub fn close(mut self) -> Result<(), Error> {
+        self.deregister()
+    }
+
+    fn deregister(&mut self) -> Result<(), Error> {
+        let mut ret = Ok(());
+        for callback_id in self.events.remove(self.client) {
+            if let Err(e) = self
+                .client
+                .connect_domain_monitor_event_deregister(callback_id)
+            {
+                ret = Err(e);
+            }
+        }
+        ret
+    }
+}
+
+impl<T> Drop for QmpEventListener<'_, T>
+where
+    T: Libvirt,
+{
+    fn drop(&mut self) {
+        if let Err(e) = self.deregister() {
+            trace!("failed to deregister monitor events {}.", e);
+        }
+    }
+}
@@ -0,0 +1,394 @@
+use crate::binding::RemoteNonnullDomain;
+use crate::client::{Libvirt, VirNetStream};
+use crate::error::Error;
+
+pub const MIME_PPM: &str = "image/x-portable-pixmap";
+pub const MIME_PNG: &str = "image/png";
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+const WINDOW_SIZE: usize = 32768;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+const MAX_CHAIN: usize = 32;
+const HASH_BITS: usize = 15;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Screenshot {
+    pub mime: Option<String>,
+    pub data: Vec<u8>,
+}
+
+impl Screenshot {
+    pub fn is_png(&self) -> bool {
+        self.mime.as_deref() == Some(MIME_PNG)
+    }
+
+    // Returns the image as PNG, converting from PPM if needed.
+    pub fn to_png(&self) -> Result<Vec<u8>, Error> {
+        if self.is_png() {
+            return Ok(self.data.clone());
+        }
+
+        match self.mime.as_deref() {
+            Some(MIME_PPM) | None => encode_png(&decode_ppm(&self.data)?),
+            Some(mime) => Err(Error::ImageError(format!("unsupported type: {mime}"))),
+        }
+    }
+
+    pub fn extension(&self) -> &str {
+        match self.mime.as_deref() {
+            Some(MIME_PPM) => "ppm",
+            Some(MIME_PNG) => "png",
+            _ => "bin",
+        }
+    }
+}
+
+pub fn screenshot<T>(
+    client: &mut T,
+    dom: RemoteNonnullDomain,
+    screen: u32,
+) -> Result<Screenshot, Error>
+where
+    T: Libvirt,
+{
+    let mut stream = client.domain_screenshot(dom, screen, 0)?;
+    let mime = stream.data().and_then(|r| r.mime.clone());
+
+    let mut data = vec![];
+    while let Some(chunk) = stream.download()? {
+        match chunk {
+            VirNetStream::Raw(buf) => data.extend(buf),
+            VirNetStream::Hole(hole) => data.resize(data.len() + hole.length as usize, 0),
+        }
+    }
+    stream.fin();
+
+    Ok(Screenshot { mime, data })
+}
+
+// 8-bit RGB pixels, row by row.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pub rgb: Vec<u8>,
+}
+
+// Decodes binary (P6) and plain (P3) PPM.
+pub fn decode_ppm(data: &[u8]) -> Result<Image, Error> {
+    let mut pos = 0;
+    let magic = ppm_token(data, &mut pos)?;
+    let binary = match magic {
+        b"P6" => true,
+        b"P3" => false,
+        _ => return Err(Error::ImageError("not a PPM image".to_string())),
+    };
+
+    let width = ppm_number(data, &mut pos)?;
+    let height = ppm_number(data, &mut pos)?;
+    let maxval = ppm_number(data, &mut pos)?;
+    if width == 0 || height == 0 || maxval == 0 || maxval > 65535 {
+        return Err(Error::ImageError("invalid PPM header".to_string()));
+    }
+
+    let samples = (width as usize)
+        .checked_mul(height as usize)
+        .and_then(|n| n.checked_mul(3))
+        .ok_or_else(|| Error::ImageError("PPM image too large".to_string()))?;
+    let scale = |v: u32| ((v.min(maxval) * 255 + maxval / 2) / maxval) as u8;
+
+    let rgb = if binary {
+        // Exactly one whitespace separates the header from the raster.
+        pos += 1;
+        let size = if maxval < 256 { 1 } else { 2 };
+        let raster = samples
+            .checked_mul(size)
+            .and_then(|n| data.get(pos..pos.checked_add(n)?))
+            .ok_or_else(|| Error::ImageError("truncated PPM raster".to_string()))?;
+        if size == 1 && maxval == 255 {
+            raster.to_vec()
+        } else if size == 1 {
+            raster.iter().map(|v| scale(*v as u32)).collect()
+        } else {
+            raster
+                .chunks(2)
+                .map(|v| scale((v[0] as u32) << 8 | v[1] as u32))
+                .collect()
+        }
+    } else {
+        (0..samples)
+            .map(|_| ppm_number(data, &mut pos).map(scale))
+            .collect::<Result<Vec<u8>, Error>>()?
+    };
+
+    Ok(Image { width, height, rgb })
+}
+
+fn ppm_token<'a>(data: &'a [u8], pos: &mut usize) -> Result<&'a [u8], Error> {
+    loop {
+        match data.get(*pos) {
+            Some(b'#') => {
+                while data.get(*pos).is_some_and(|c| *c != b'\n') {
+                    *pos += 1;
+                }
+            }
+            Some(c) if c.is_ascii_whitespace() => *pos += 1,
+            Some(_) => break,
+            None => return Err(Error::ImageError("truncated PPM header".to_string())),
+        }
+    }
+
+    let start = *pos;
+    while data
+        .get(*pos)
+        .is_some_and(|c| !c.is_ascii_whitespace() && *c != b'#')
+    {
+        *pos += 1;
+    }
+    Ok(&data[start..*pos])
+}
+
+fn ppm_number(data: &[u8], pos: &mut usize) -> Result<u32, Error> {
+    let token = ppm_token(data, pos)?;
+    std::str::from_utf8(token)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| Error::ImageError("invalid number in PPM".to_string()))
+}
+
+pub fn encode_png(image: &Image) -> Result<Vec<u8>, Error> {
+    // PNG limits each dimension to 2^31 - 1 and does not allow empty images.
+    if image.width == 0
+        || image.height == 0
+        || image.width > i32::MAX as u32
+        || image.height > i32::MAX as u32
+    {
+        return Err(Error::ImageError("invalid image size".to_string()));
+    }
+
+    let stride = image.width as usize * 3;
+    let size = stride.checked_mul(image.height as usize);
+    if size != Some(image.rgb.len()) {
+        return Err(Error::ImageError(
+            "image size does not match its pixels".to_string(),
+        ));
+    }
+
+    // Every row uses the Sub filter, which turns flat areas into zero runs.
+    let mut raw = Vec::with_capacity((stride + 1) * image.height as usize);
+    for row in image.rgb.chunks(stride) {
+        raw.push(1);
+        for (i, v) in row.iter().enumerate() {
+            let left = if i >= 3 { row[i - 3] } else { 0 };
+            raw.push(v.wrapping_sub(left));
+        }
+    }
+
+    let mut ihdr = vec![];
+    ihdr.extend(image.width.to_be_bytes());
+    ihdr.extend(image.height.to_be_bytes());
+    // 8-bit truecolor, deflate, adaptive filtering, no interlace.
+    ihdr.extend([8, 2, 0, 0, 0]);
+
+    let mut png = PNG_SIGNATURE.to_vec();
+    png_chunk(&mut png, b"IHDR", &ihdr);
+    png_chunk(&mut png, b"IDAT", &zlib_compress(&raw));
+    png_chunk(&mut png, b"IEND", &[]);
+    Ok(png)
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for b in data {
+        crc ^= *b as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                0xedb8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+pub fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in data.chunks(5552) {
+        for v in chunk {
+            a += *v as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    b << 16 | a
+}
+
+struct BitWriter {
+    out: Vec<u8>,
+    acc: u32,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, bits: u32) {
+        self.acc |= value << self.bits;
+        self.bits += bits;
+        while self.bits >= 8 {
+            self.out.push(self.acc as u8);
+            self.acc >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    // Huffman codes are packed starting from the most significant bit.
+    fn write_code(&mut self, code: u32, bits: u32) {
+        self.write(code.reverse_bits() >> (32 - bits), bits);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.out.push(self.acc as u8);
+        }
+        self.out
+    }
+}
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DIST_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DIST_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+fn write_literal(w: &mut BitWriter, value: u16) {
+    match value {
+        0..=143 => w.write_code(0x30 + value as u32, 8),
+        144..=255 => w.write_code(0x190 + (value - 144) as u32, 9),
+        256..=279 => w.write_code((value - 256) as u32, 7),
+        _ => w.write_code(0xc0 + (value - 280) as u32, 8),
+    }
+}
+
+fn write_match(w: &mut BitWriter, length: usize, distance: usize) {
+    let i = LENGTH_BASE
+        .iter()
+        .rposition(|b| *b as usize <= length)
+        .unwrap();
+    write_literal(w, 257 + i as u16);
+    w.write(
+        (length - LENGTH_BASE[i] as usize) as u32,
+        LENGTH_EXTRA[i] as u32,
+    );
+
+    let i = DIST_BASE
+        .iter()
+        .rposition(|b| *b as usize <= distance)
+        .unwrap();
+    w.write_code(i as u32, 5);
+    w.write(
+        (distance - DIST_BASE[i] as usize) as u32,
+        DIST_EXTRA[i] as u32,
+    );
+}
+
+fn hash(data: &[u8]) -> usize {
+    let v = (data[0] as u32) << 16 | (data[1] as u32) << 8 | data[2] as u32;
+    (v.wrapping_mul(2654435761) >> (32 - HASH_BITS)) as usize
+}
+
+// A single fixed Huffman block with greedy LZ77 matching. Small and good
+// enough for screenshots, which are mostly flat colors.
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut w = BitWriter {
+        out: vec![],
+        acc: 0,
+        bits: 0,
+    };
+    // BFINAL, fixed Huffman.
+    w.write(1, 1);
+    w.write(1, 2);
+
+    let mut head = vec![usize::MAX; 1 << HASH_BITS];
+    let mut prev = vec![usize::MAX; WINDOW_SIZE];
+    let insert = |pos: usize, head: &mut [usize], prev: &mut [usize]| {
+        if pos + MIN_MATCH <= data.len() {
+            let h = hash(&data[pos..]);
+            prev[pos % WINDOW_SIZE] = head[h];
+            head[h] = pos;
+        }
+    };
+
+    let mut pos = 0;
+    while pos < data.len() {
+        let mut best = (0, 0);
+        if pos + MIN_MATCH <= data.len() {
+            let max = MAX_MATCH.min(data.len() - pos);
+            let mut candidate = head[hash(&data[pos..])];
+            let mut chain = 0;
+            while candidate != usize::MAX && pos - candidate <= WINDOW_SIZE && chain < MAX_CHAIN {
+                let length = data[candidate..]
+                    .iter()
+                    .zip(&data[pos..pos + max])
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                if length > best.0 {
+                    best = (length, pos - candidate);
+                    if length == max {
+                        break;
+                    }
+                }
+                let next = prev[candidate % WINDOW_SIZE];
+                if next == usize::MAX || next >= candidate {
+                    break;
+                }
+                candidate = next;
+                chain += 1;
+            }
+        }
+
+        if best.0 >= MIN_MATCH {
+            write_match(&mut w, best.0, best.1);
+            for p in pos..pos + best.0 {
+                insert(p, &mut head, &mut prev);
+            }
+            pos += best.0;
+        } else {
+            write_literal(&mut w, data[pos] as u16);
+            insert(pos, &mut head, &mut prev);
+            pos += 1;
+        }
+    }
+
+    write_literal(&mut w, 256);
+    w.finish()
+}
+
+fn zlib_compress(data: &[u8]) -> Vec<u8> {
+    // 32K window, default level, no preset dictionary.
+    let mut out = vec![0x78, 0x9c];
+    out.extend(deflate(data));
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
@@ -0,0 +1,92 @@
+use crate::error::Error;
+use crate::uri::{ConnectUri, DaemonMode};
+use log::trace;
+use std::env;
+use std::io;
+use std::path::PathBuf;
+
+pub const SYSTEM_SOCKET_DIRS: [&str; 2] = ["/run/libvirt", "/var/run/libvirt"];
+
+// `libvirt-sock` is served by either virtproxyd or the monolithic libvirtd.
+pub const PROXY_SOCKET_PREFIX: &str = "libvirt";
+
+pub fn daemon_name(driver: &str) -> Option<&'static str> {
+    match driver {
+        "qemu" => Some("virtqemud"),
+        "lxc" => Some("virtlxcd"),
+        "xen" | "libxl" => Some("virtxend"),
+        "ch" => Some("virtchd"),
+        "bhyve" => Some("virtbhyved"),
+        "vbox" => Some("virtvboxd"),
+        "vz" => Some("virtvzd"),
+        "network" => Some("virtnetworkd"),
+        "storage" => Some("virtstoraged"),
+        "nodedev" => Some("virtnodedevd"),
+        "interface" => Some("virtinterfaced"),
+        "nwfilter" => Some("virtnwfilterd"),
+        "secret" => Some("virtsecretd"),
+        _ => None,
+    }
+}
+
+pub fn candidates(uri: &ConnectUri, readonly: bool) -> Vec<PathBuf> {
+    let session = uri.is_session();
+
+    let dirs = if session {
+        session_socket_dirs()
+    } else {
+        SYSTEM_SOCKET_DIRS.iter().map(PathBuf::from).collect()
+    };
+
+    let daemon = daemon_name(uri.driver());
+    let prefixes: Vec<&str> = match uri.mode() {
+        DaemonMode::Direct => daemon.into_iter().collect(),
+        DaemonMode::Legacy => vec![PROXY_SOCKET_PREFIX],
+        DaemonMode::Auto => daemon.into_iter().chain([PROXY_SOCKET_PREFIX]).collect(),
+    };
+
+    // session daemons are owned by the user, so there is no read-only socket.
+    let suffix = if readonly && !session {
+        "-sock-ro"
+    } else {
+        "-sock"
+    };
+
+    let mut paths = vec![];
+    for prefix in prefixes {
+        for dir in &dirs {
+            paths.push(dir.join(format!("{prefix}{suffix}")));
+        }
+    }
+    paths
+}
+
+pub fn discover(uri: &ConnectUri, readonly: bool) -> Result<PathBuf, Error> {
+    let paths = candidates(uri, readonly);
+    for path in &paths {
+        trace!("probing: {}", path.display());
+        if path.exists() {
+            return Ok(path.clone());
+        }
+    }
+
+    let tried: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+    Err(Error::SocketError(io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("no socket for {}: [{}]", uri.name(), tried.join(", ")),
+    )))
+}
+
+fn session_socket_dirs() -> Vec<PathBuf> {
+    let mut dirs = vec![];
+
+    if let Ok(dir) = env::var("XDG_RUNTIME_DIR") {
+        dirs.push(PathBuf::from(dir).join("libvirt"));
+    }
+
+    if let Ok(home) = env::var("HOME") {
+        dirs.push(PathBuf::from(home).join(".cache").join("libvirt"));
+    }
+
+    dirs
+}
@@ -0,0 +1,588 @@
+use crate::binding::{RemoteDomainStatsRecord, RemoteNonnullDomain, RemoteTypedParam};
+use crate::client::Libvirt;
+use crate::domain::DomainState;
+use crate::error::Error;
+use crate::param;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+pub const VIR_DOMAIN_STATS_STATE: u32 = 1 << 0;
+pub const VIR_DOMAIN_STATS_CPU_TOTAL: u32 = 1 << 1;
+pub const VIR_DOMAIN_STATS_BALLOON: u32 = 1 << 2;
+pub const VIR_DOMAIN_STATS_VCPU: u32 = 1 << 3;
+pub const VIR_DOMAIN_STATS_INTERFACE: u32 = 1 << 4;
+pub const VIR_DOMAIN_STATS_BLOCK: u32 = 1 << 5;
+pub const VIR_DOMAIN_STATS_PERF: u32 = 1 << 6;
+pub const VIR_DOMAIN_STATS_IOTHREAD: u32 = 1 << 7;
+pub const VIR_DOMAIN_STATS_MEMORY: u32 = 1 << 8;
+pub const VIR_DOMAIN_STATS_DIRTYRATE: u32 = 1 << 9;
+pub const VIR_DOMAIN_STATS_VM: u32 = 1 << 10;
+
+pub const VIR_CONNECT_GET_ALL_DOMAINS_STATS_ACTIVE: u32 = 1 << 0;
+pub const VIR_CONNECT_GET_ALL_DOMAINS_STATS_INACTIVE: u32 = 1 << 1;
+pub const VIR_CONNECT_GET_ALL_DOMAINS_STATS_PERSISTENT: u32 = 1 << 2;
+pub const VIR_CONNECT_GET_ALL_DOMAINS_STATS_TRANSIENT: u32 = 1 << 3;
+pub const VIR_CONNECT_GET_ALL_DOMAINS_STATS_RUNNING: u32 = 1 << 4;
+pub const VIR_CONNECT_GET_ALL_DOMAINS_STATS_PAUSED: u32 = 1 << 5;
+pub const VIR_CONNECT_GET_ALL_DOMAINS_STATS_SHUTOFF: u32 = 1 << 6;
+pub const VIR_CONNECT_GET_ALL_DOMAINS_STATS_OTHER: u32 = 1 << 7;
+pub const VIR_CONNECT_GET_ALL_DOMAINS_STATS_NOWAIT: u32 = 1 << 29;
+pub const VIR_CONNECT_GET_ALL_DOMAINS_STATS_BACKING: u32 = 1 << 30;
+pub const VIR_CONNECT_GET_ALL_DOMAINS_STATS_ENFORCE_STATS: u32 = 1 << 31;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StateStats {
+    pub state: DomainState,
+    pub reason: i32,
+}
+
+// Times are in nanoseconds.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CpuStats {
+    pub time: Option<u64>,
+    pub user: Option<u64>,
+    pub system: Option<u64>,
+    pub haltpoll_success_time: Option<u64>,
+    pub haltpoll_fail_time: Option<u64>,
+}
+
+// Sizes are in KiB.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BalloonStats {
+    pub current: Option<u64>,
+    pub maximum: Option<u64>,
+    pub swap_in: Option<u64>,
+    pub swap_out: Option<u64>,
+    pub major_fault: Option<u64>,
+    pub minor_fault: Option<u64>,
+    pub unused: Option<u64>,
+    pub available: Option<u64>,
+    pub usable: Option<u64>,
+    pub rss: Option<u64>,
+    pub disk_caches: Option<u64>,
+    pub hugetlb_pgalloc: Option<u64>,
+    pub hugetlb_pgfail: Option<u64>,
+    // Seconds since the epoch.
+    pub last_update: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VcpuStats {
+    pub current: Option<u64>,
+    pub maximum: Option<u64>,
+    pub vcpus: Vec<VcpuInfo>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VcpuInfo {
+    pub index: usize,
+    pub state: Option<i32>,
+    pub time: Option<u64>,
+    pub wait: Option<u64>,
+    pub halted: Option<bool>,
+    pub delay: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InterfaceStats {
+    pub name: String,
+    pub rx_bytes: Option<u64>,
+    pub rx_pkts: Option<u64>,
+    pub rx_errs: Option<u64>,
+    pub rx_drop: Option<u64>,
+    pub tx_bytes: Option<u64>,
+    pub tx_pkts: Option<u64>,
+    pub tx_errs: Option<u64>,
+    pub tx_drop: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BlockStats {
+    pub name: String,
+    // Set for backing chain entries with VIR_CONNECT_GET_ALL_DOMAINS_STATS_BACKING.
+    pub backing_index: Option<u64>,
+    pub path: Option<String>,
+    pub rd_reqs: Option<u64>,
+    pub rd_bytes: Option<u64>,
+    pub rd_times: Option<u64>,
+    pub wr_reqs: Option<u64>,
+    pub wr_bytes: Option<u64>,
+    pub wr_times: Option<u64>,
+    pub fl_reqs: Option<u64>,
+    pub fl_times: Option<u64>,
+    pub errors: Option<u64>,
+    pub allocation: Option<u64>,
+    pub capacity: Option<u64>,
+    pub physical: Option<u64>,
+    pub threshold: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IothreadStats {
+    pub id: u64,
+    pub poll_max_ns: Option<u64>,
+    pub poll_grow: Option<u64>,
+    pub poll_shrink: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MemoryBandwidthMonitor {
+    pub name: Option<String>,
+    pub vcpus: Option<String>,
+    pub nodes: Vec<MemoryBandwidthNode>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MemoryBandwidthNode {
+    pub id: Option<u64>,
+    pub bytes_local: Option<u64>,
+    pub bytes_total: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DirtyRateStats {
+    pub calc_status: Option<i32>,
+    pub calc_start_time: Option<i64>,
+    pub calc_period: Option<i32>,
+    pub calc_mode: Option<String>,
+    pub megabytes_per_second: Option<i64>,
+    pub vcpus: Vec<(usize, i64)>,
+}
+
+#[derive(Clone, Debug)]
+pub struct DomainStats {
+    pub dom: RemoteNonnullDomain,
+    pub state: Option<StateStats>,
+    pub cpu: Option<CpuStats>,
+    pub balloon: Option<BalloonStats>,
+    pub vcpu: Option<VcpuStats>,
+    pub interfaces: Vec<InterfaceStats>,
+    pub blocks: Vec<BlockStats>,
+    // Counter per perf event, e.g. "cpu_cycles".
+    pub perf: BTreeMap<String, u64>,
+    pub iothreads: Vec<IothreadStats>,
+    pub memory_bandwidth: Vec<MemoryBandwidthMonitor>,
+    pub dirty_rate: Option<DirtyRateStats>,
+}
+
+impl From<&RemoteDomainStatsRecord> for DomainStats {
+    fn from(record: &RemoteDomainStatsRecord) -> Self {
+        DomainStats::parse(record.dom.clone(), &record.params)
+    }
+}
+
+impl From<RemoteDomainStatsRecord> for DomainStats {
+    fn from(record: RemoteDomainStatsRecord) -> Self {
+        DomainStats::parse(record.dom, &record.params)
+    }
+}
+
+impl DomainStats {
+    pub fn parse(dom: RemoteNonnullDomain, params: &[RemoteTypedParam]) -> Self {
+        DomainStats {
+            dom,
+            state: parse_state(params),
+            cpu: parse_cpu(params),
+            balloon: parse_balloon(params),
+            vcpu: parse_vcpu(params),
+            interfaces: parse_interfaces(params),
+            blocks: parse_blocks(params),
+            perf: parse_perf(params),
+            iothreads: parse_iothreads(params),
+            memory_bandwidth: parse_memory_bandwidth(params),
+            dirty_rate: parse_dirty_rate(params),
+        }
+    }
+
+    pub fn interface(&self, name: &str) -> Option<&InterfaceStats> {
+        self.interfaces.iter().find(|i| i.name == name)
+    }
+
+    // The top of the chain of the given disk target, which is listed first.
+    pub fn block(&self, name: &str) -> Option<&BlockStats> {
+        self.blocks.iter().find(|b| b.name == name)
+    }
+}
+
+pub fn get_all_domain_stats<T>(
+    client: &mut T,
+    stats: u32,
+    flags: u32,
+) -> Result<Vec<DomainStats>, Error>
+where
+    T: Libvirt,
+{
+    let records = client.connect_get_all_domain_stats(vec![], stats, flags)?;
+    Ok(records.into_iter().map(DomainStats::from).collect())
+}
+
+pub fn get_domain_stats<T>(
+    client: &mut T,
+    doms: Vec<RemoteNonnullDomain>,
+    stats: u32,
+    flags: u32,
+) -> Result<Vec<DomainStats>, Error>
+where
+    T: Libvirt,
+{
+    let records = client.connect_get_all_domain_stats(doms, stats, flags)?;
+    Ok(records.into_iter().map(DomainStats::from).collect())
+}
+
+// Stats of all domains taken at one point in time.
+#[derive(Clone, Debug)]
+pub struct StatsSample {
+    pub time: Instant,
+    pub domains: Vec<DomainStats>,
+}
+
+impl StatsSample {
+    pub fn collect<T>(client: &mut T, stats: u32, flags: u32) -> Result<Self, Error>
+    where
+        T: Libvirt,
+    {
+        let domains = get_all_domain_stats(client, stats, flags)?;
+        Ok(StatsSample {
+            time: Instant::now(),
+            domains,
+        })
+    }
+
+    pub fn domain(&self, uuid: &[u8; 16]) -> Option<&DomainStats> {
+        self.domains.iter().find(|d| &d.dom.uuid == uuid)
+    }
+
+    // Rates of the domains present in both samples.
+    pub fn rates(&self, prev: &StatsSample) -> Vec<DomainRates> {
+        let elapsed = self.time.saturating_duration_since(prev.time);
+        self.domains
+            .iter()
+            .filter_map(|cur| {
+                prev.domain(&cur.dom.uuid)
+                    .map(|prev| DomainRates::between(prev, cur, elapsed))
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InterfaceRates {
+    pub name: String,
+    pub rx_bytes: Option<f64>,
+    pub rx_pkts: Option<f64>,
+    pub tx_bytes: Option<f64>,
+    pub tx_pkts: Option<f64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BlockRates {
+    pub name: String,
+    pub rd_reqs: Option<f64>,
+    pub rd_bytes: Option<f64>,
+    pub wr_reqs: Option<f64>,
+    pub wr_bytes: Option<f64>,
+    pub fl_reqs: Option<f64>,
+}
+
+// Per second values between two samples. A counter which went backwards,
+// e.g. because the domain was restarted, gives None.
+#[derive(Clone, Debug)]
+pub struct DomainRates {
+    pub dom: RemoteNonnullDomain,
+    pub elapsed: Duration,
+    // Host CPU time used per wall clock time; 2.0 means two full CPUs.
+    pub cpu: Option<f64>,
+    pub cpu_user: Option<f64>,
+    pub cpu_system: Option<f64>,
+    pub vcpus: Vec<(usize, f64)>,
+    pub interfaces: Vec<InterfaceRates>,
+    pub blocks: Vec<BlockRates>,
+}
+
+impl DomainRates {
+    pub fn between(prev: &DomainStats, cur: &DomainStats, elapsed: Duration) -> Self {
+        let secs = elapsed.as_secs_f64();
+        let rate = |p: Option<u64>, c: Option<u64>| match (p, c) {
+            (Some(p), Some(c)) if c >= p && secs > 0.0 => Some((c - p) as f64 / secs),
+            _ => None,
+        };
+        let cpu_rate = |p: Option<u64>, c: Option<u64>| rate(p, c).map(|v| v / 1e9);
+
+        let (pcpu, ccpu) = (
+            prev.cpu.clone().unwrap_or_default(),
+            cur.cpu.clone().unwrap_or_default(),
+        );
+
+        let vcpus = match (&prev.vcpu, &cur.vcpu) {
+            (Some(p), Some(c)) => c
+                .vcpus
+                .iter()
+                .filter_map(|cv| {
+                    let pv = p.vcpus.iter().find(|v| v.index == cv.index)?;
+                    cpu_rate(pv.time, cv.time).map(|r| (cv.index, r))
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        let interfaces = cur
+            .interfaces
+            .iter()
+            .filter_map(|c| {
+                let p = prev.interface(&c.name)?;
+                Some(InterfaceRates {
+                    name: c.name.clone(),
+                    rx_bytes: rate(p.rx_bytes, c.rx_bytes),
+                    rx_pkts: rate(p.rx_pkts, c.rx_pkts),
+                    tx_bytes: rate(p.tx_bytes, c.tx_bytes),
+                    tx_pkts: rate(p.tx_pkts, c.tx_pkts),
+                })
+            })
+            .collect();
+
+        let blocks = cur
+            .blocks
+            .iter()
+            .enumerate()
+            .filter(|(i, c)| cur.blocks[..*i].iter().all(|b| b.name != c.name))
+            .filter_map(|(_, c)| {
+                let p = prev.block(&c.name)?;
+                Some(BlockRates {
+                    name: c.name.clone(),
+                    rd_reqs: rate(p.rd_reqs, c.rd_reqs),
+                    rd_bytes: rate(p.rd_bytes, c.rd_bytes),
+                    wr_reqs: rate(p.wr_reqs, c.wr_reqs),
+                    wr_bytes: rate(p.wr_bytes, c.wr_bytes),
+                    fl_reqs: rate(p.fl_reqs, c.fl_reqs),
+                })
+            })
+            .collect();
+
+        DomainRates {
+            dom: cur.dom.clone(),
+            elapsed,
+            cpu: cpu_rate(pcpu.time, ccpu.time),
+            cpu_user: cpu_rate(pcpu.user, ccpu.user),
+            cpu_system: cpu_rate(pcpu.system, ccpu.system),
+            vcpus,
+            interfaces,
+            blocks,
+        }
+    }
+}
+
+fn has_prefix(params: &[RemoteTypedParam], prefix: &str) -> bool {
+    params.iter().any(|p| p.field.starts_with(prefix))
+}
+
+fn count(params: &[RemoteTypedParam], field: &str) -> usize {
+    param::get_u64(params, field).unwrap_or_default() as usize
+}
+
+fn parse_state(params: &[RemoteTypedParam]) -> Option<StateStats> {
+    let state = param::get_i64(params, "state.state")?;
+    Some(StateStats {
+        state: DomainState::from(state as i32),
+        reason: param::get_i64(params, "state.reason").unwrap_or_default() as i32,
+    })
+}
+
+fn parse_cpu(params: &[RemoteTypedParam]) -> Option<CpuStats> {
+    if !has_prefix(params, "cpu.") {
+        return None;
+    }
+    let num = |field| param::get_u64(params, field);
+    Some(CpuStats {
+        time: num("cpu.time"),
+        user: num("cpu.user"),
+        system: num("cpu.system"),
+        haltpoll_success_time: num("cpu.haltpoll.success.time"),
+        haltpoll_fail_time: num("cpu.haltpoll.fail.time"),
+    })
+}
+
+fn parse_balloon(params: &[RemoteTypedParam]) -> Option<BalloonStats> {
+    if !has_prefix(params, "balloon.") {
+        return None;
+    }
+    let num = |field| param::get_u64(params, field);
+    Some(BalloonStats {
+        current: num("balloon.current"),
+        maximum: num("balloon.maximum"),
+        swap_in: num("balloon.swap_in"),
+        swap_out: num("balloon.swap_out"),
+        major_fault: num("balloon.major_fault"),
+        minor_fault: num("balloon.minor_fault"),
+        unused: num("balloon.unused"),
+        available: num("balloon.available"),
+        usable: num("balloon.usable"),
+        rss: num("balloon.rss"),
+        disk_caches: num("balloon.disk_caches"),
+        hugetlb_pgalloc: num("balloon.hugetlb_pgalloc"),
+        hugetlb_pgfail: num("balloon.hugetlb_pgfail"),
+        last_update: num("balloon.last-update"),
+    })
+}
+
+fn parse_vcpu(params: &[RemoteTypedParam]) -> Option<VcpuStats> {
+    if !has_prefix(params, "vcpu.") {
+        return None;
+    }
+    let maximum = param::get_u64(params, "vcpu.maximum");
+
+    // Offline vcpus are skipped, so the indexes may have gaps.
+    let vcpus = (0..maximum.unwrap_or_default() as usize)
+        .filter(|i| has_prefix(params, &format!("vcpu.{i}.")))
+        .map(|i| {
+            let num = |name: &str| param::get_u64(params, &format!("vcpu.{i}.{name}"));
+            VcpuInfo {
+                index: i,
+                state: param::get_i64(params, &format!("vcpu.{i}.state")).map(|v| v as i32),
+                time: num("time"),
+                wait: num("wait"),
+                halted: param::get(params, &format!("vcpu.{i}.halted")).and_then(|v| v.as_bool()),
+                delay: num("delay"),
+            }
+        })
+        .collect();
+
+    Some(VcpuStats {
+        current: param::get_u64(params, "vcpu.current"),
+        maximum,
+        vcpus,
+    })
+}
+
+fn parse_interfaces(params: &[RemoteTypedParam]) -> Vec<InterfaceStats> {
+    (0..count(params, "net.count"))
+        .map(|i| {
+            let num = |name: &str| param::get_u64(params, &format!("net.{i}.{name}"));
+            InterfaceStats {
+                name: param::get_str(params, &format!("net.{i}.name"))
+                    .unwrap_or_default()
+                    .to_string(),
+                rx_bytes: num("rx.bytes"),
+                rx_pkts: num("rx.pkts"),
+                rx_errs: num("rx.errs"),
+                rx_drop: num("rx.drop"),
+                tx_bytes: num("tx.bytes"),
+                tx_pkts: num("tx.pkts"),
+                tx_errs: num("tx.errs"),
+                tx_drop: num("tx.drop"),
+            }
+        })
+        .collect()
+}
+
+fn parse_blocks(params: &[RemoteTypedParam]) -> Vec<BlockStats> {
+    (0..count(params, "block.count"))
+        .map(|i| {
+            let num = |name: &str| param::get_u64(params, &format!("block.{i}.{name}"));
+            let string = |name: &str| {
+                param::get_str(params, &format!("block.{i}.{name}")).map(|v| v.to_string())
+            };
+            BlockStats {
+                name: string("name").unwrap_or_default(),
+                backing_index: num("backingIndex"),
+                path: string("path"),
+                rd_reqs: num("rd.reqs"),
+                rd_bytes: num("rd.bytes"),
+                rd_times: num("rd.times"),
+                wr_reqs: num("wr.reqs"),
+                wr_bytes: num("wr.bytes"),
+                wr_times: num("wr.times"),
+                fl_reqs: num("fl.reqs"),
+                fl_times: num("fl.times"),
+                errors: num("errors"),
+                allocation: num("allocation"),
+                capacity: num("capacity"),
+                physical: num("physical"),
+                threshold: num("threshold"),
+            }
+        })
+        .collect()
+}
+
+fn parse_perf(params: &[RemoteTypedParam]) -> BTreeMap<String, u64> {
+    params
+        .iter()
+        .filter_map(|p| {
+            let event = p.field.strip_prefix("perf.")?;
+            Some((event.to_string(), p.value.as_u64()?))
+        })
+        .collect()
+}
+
+fn parse_iothreads(params: &[RemoteTypedParam]) -> Vec<IothreadStats> {
+    // Entries are keyed by iothread id rather than by position.
+    let mut ids: Vec<u64> = params
+        .iter()
+        .filter_map(|p| {
+            let rest = p.field.strip_prefix("iothread.")?;
+            rest.split_once('.')?.0.parse().ok()
+        })
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+
+    ids.into_iter()
+        .map(|id| {
+            let num = |name: &str| param::get_u64(params, &format!("iothread.{id}.{name}"));
+            IothreadStats {
+                id,
+                poll_max_ns: num("poll-max-ns"),
+                poll_grow: num("poll-grow"),
+                poll_shrink: num("poll-shrink"),
+            }
+        })
+        .collect()
+}
+
+fn parse_memory_bandwidth(params: &[RemoteTypedParam]) -> Vec<MemoryBandwidthMonitor> {
+    (0..count(params, "memory.bandwidth.monitor.count"))
+        .map(|i| {
+            let prefix = format!("memory.bandwidth.monitor.{i}");
+            let nodes = (0..count(params, &format!("{prefix}.node.count")))
+                .map(|j| {
+                    let num =
+                        |name: &str| param::get_u64(params, &format!("{prefix}.node.{j}.{name}"));
+                    MemoryBandwidthNode {
+                        id: num("id"),
+                        bytes_local: num("bytes.local"),
+                        bytes_total: num("bytes.total"),
+                    }
+                })
+                .collect();
+            MemoryBandwidthMonitor {
+                name: param::get_str(params, &format!("{prefix}.name")).map(|v| v.to_string()),
+                vcpus: param::get_str(params, &format!("{prefix}.vcpus")).map(|v| v.to_string()),
+                nodes,
+            }
+        })
+        .collect()
+}
+
+fn parse_dirty_rate(params: &[RemoteTypedParam]) -> Option<DirtyRateStats> {
+    if !has_prefix(params, "dirtyrate.") {
+        return None;
+    }
+
+    let mut vcpus: Vec<(usize, i64)> = params
+        .iter()
+        .filter_map(|p| {
+            let rest = p.field.strip_prefix("dirtyrate.vcpu.")?;
+            let index = rest.strip_suffix(".megabytes_per_second")?.parse().ok()?;
+            Some((index, p.value.as_i64()?))
+        })
+        .collect();
+    vcpus.sort_unstable();
+
+    Some(DirtyRateStats {
+        calc_status: param::get_i64(params, "dirtyrate.calc_status").map(|v| v as i32),
+        calc_start_time: param::get_i64(params, "dirtyrate.calc_start_time"),
+        calc_period: param::get_i64(params, "dirtyrate.calc_period").map(|v| v as i32),
+        calc_mode: param::get_str(params, "dirtyrate.calc_mode").map(|v| v.to_string()),
+        megabytes_per_second: param::get_i64(params, "dirtyrate.megabytes_per_second"),
+        vcpus,
+    })
+}
@@ -0,0 +1,161 @@
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_INACTIVE: u32 = 1 << 0;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_ACTIVE: u32 = 1 << 1;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_PERSISTENT: u32 = 1 << 2;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_TRANSIENT: u32 = 1 << 3;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_AUTOSTART: u32 = 1 << 4;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_NO_AUTOSTART: u32 = 1 << 5;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_DIR: u32 = 1 << 6;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_FS: u32 = 1 << 7;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_NETFS: u32 = 1 << 8;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_LOGICAL: u32 = 1 << 9;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_DISK: u32 = 1 << 10;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_ISCSI: u32 = 1 << 11;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_SCSI: u32 = 1 << 12;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_MPATH: u32 = 1 << 13;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_RBD: u32 = 1 << 14;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_SHEEPDOG: u32 = 1 << 15;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_GLUSTER: u32 = 1 << 16;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_ZFS: u32 = 1 << 17;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_VSTORAGE: u32 = 1 << 18;
+pub const VIR_CONNECT_LIST_STORAGE_POOLS_ISCSI_DIRECT: u32 = 1 << 19;
+
+pub const VIR_STORAGE_POOL_BUILD_NEW: u32 = 0;
+pub const VIR_STORAGE_POOL_BUILD_REPAIR: u32 = 1 << 0;
+pub const VIR_STORAGE_POOL_BUILD_RESIZE: u32 = 1 << 1;
+pub const VIR_STORAGE_POOL_BUILD_NO_OVERWRITE: u32 = 1 << 2;
+pub const VIR_STORAGE_POOL_BUILD_OVERWRITE: u32 = 1 << 3;
+
+pub const VIR_STORAGE_POOL_CREATE_NORMAL: u32 = 0;
+pub const VIR_STORAGE_POOL_CREATE_WITH_BUILD: u32 = 1 << 0;
+pub const VIR_STORAGE_POOL_CREATE_WITH_BUILD_OVERWRITE: u32 = 1 << 1;
+pub const VIR_STORAGE_POOL_CREATE_WITH_BUILD_NO_OVERWRITE: u32 = 1 << 2;
+
+pub const VIR_STORAGE_POOL_DELETE_NORMAL: u32 = 0;
+pub const VIR_STORAGE_POOL_DELETE_ZEROED: u32 = 1 << 0;
+
+pub const VIR_STORAGE_POOL_DEFINE_VALIDATE: u32 = 1 << 0;
+
+pub const VIR_STORAGE_XML_INACTIVE: u32 = 1 << 0;
+
+// Pool type names and the flag which lists only pools of that type.
+pub const STORAGE_POOL_TYPES: [(&str, u32); 14] = [
+    ("dir", VIR_CONNECT_LIST_STORAGE_POOLS_DIR),
+    ("fs", VIR_CONNECT_LIST_STORAGE_POOLS_FS),
+    ("netfs", VIR_CONNECT_LIST_STORAGE_POOLS_NETFS),
+    ("logical", VIR_CONNECT_LIST_STORAGE_POOLS_LOGICAL),
+    ("disk", VIR_CONNECT_LIST_STORAGE_POOLS_DISK),
+    ("iscsi", VIR_CONNECT_LIST_STORAGE_POOLS_ISCSI),
+    ("scsi", VIR_CONNECT_LIST_STORAGE_POOLS_SCSI),
+    ("mpath", VIR_CONNECT_LIST_STORAGE_POOLS_MPATH),
+    ("rbd", VIR_CONNECT_LIST_STORAGE_POOLS_RBD),
+    ("sheepdog", VIR_CONNECT_LIST_STORAGE_POOLS_SHEEPDOG),
+    ("gluster", VIR_CONNECT_LIST_STORAGE_POOLS_GLUSTER),
+    ("zfs", VIR_CONNECT_LIST_STORAGE_POOLS_ZFS),
+    ("vstorage", VIR_CONNECT_LIST_STORAGE_POOLS_VSTORAGE),
+    ("iscsi-direct", VIR_CONNECT_LIST_STORAGE_POOLS_ISCSI_DIRECT),
+];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum StoragePoolState {
+    #[default]
+    Inactive,
+    Building,
+    Running,
+    Degraded,
+    Inaccessible,
+}
+
+impl From<u8> for StoragePoolState {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => StoragePoolState::Building,
+            2 => StoragePoolState::Running,
+            3 => StoragePoolState::Degraded,
+            4 => StoragePoolState::Inaccessible,
+            _ => StoragePoolState::Inactive,
+        }
+    }
+}
+
+impl StoragePoolState {
+    // Capacity figures are only reported for pools which are up.
+    pub fn is_active(&self) -> bool {
+        matches!(self, StoragePoolState::Running | StoragePoolState::Degraded)
+    }
+}
+
+pub const VIR_STORAGE_VOL_CREATE_PREALLOC_METADATA: u32 = 1 << 0;
+pub const VIR_STORAGE_VOL_CREATE_REFLINK: u32 = 1 << 1;
+pub const VIR_STORAGE_VOL_CREATE_VALIDATE: u32 = 1 << 2;
+
+pub const VIR_STORAGE_VOL_DELETE_NORMAL: u32 = 0;
+pub const VIR_STORAGE_VOL_DELETE_ZEROED: u32 = 1 << 0;
+pub const VIR_STORAGE_VOL_DELETE_WITH_SNAPSHOTS: u32 = 1 << 1;
+
+pub const VIR_STORAGE_VOL_WIPE_ALG_ZERO: u32 = 0;
+pub const VIR_STORAGE_VOL_WIPE_ALG_NNSA: u32 = 1;
+pub const VIR_STORAGE_VOL_WIPE_ALG_DOD: u32 = 2;
+pub const VIR_STORAGE_VOL_WIPE_ALG_BSI: u32 = 3;
+pub const VIR_STORAGE_VOL_WIPE_ALG_GUTMANN: u32 = 4;
+pub const VIR_STORAGE_VOL_WIPE_ALG_SCHNEIER: u32 = 5;
+pub const VIR_STORAGE_VOL_WIPE_ALG_PFITZNER7: u32 = 6;
+pub const VIR_STORAGE_VOL_WIPE_ALG_PFITZNER33: u32 = 7;
+pub const VIR_STORAGE_VOL_WIPE_ALG_RANDOM: u32 = 8;
+pub const VIR_STORAGE_VOL_WIPE_ALG_TRIM: u32 = 9;
+
+pub const STORAGE_VOL_WIPE_ALGORITHMS: [(&str, u32); 10] = [
+    ("zero", VIR_STORAGE_VOL_WIPE_ALG_ZERO),
+    ("nnsa", VIR_STORAGE_VOL_WIPE_ALG_NNSA),
+    ("dod", VIR_STORAGE_VOL_WIPE_ALG_DOD),
+    ("bsi", VIR_STORAGE_VOL_WIPE_ALG_BSI),
+    ("gutmann", VIR_STORAGE_VOL_WIPE_ALG_GUTMANN),
+    ("schneier", VIR_STORAGE_VOL_WIPE_ALG_SCHNEIER),
+    ("pfitzner7", VIR_STORAGE_VOL_WIPE_ALG_PFITZNER7),
+    ("pfitzner33", VIR_STORAGE_VOL_WIPE_ALG_PFITZNER33),
+    ("random", VIR_STORAGE_VOL_WIPE_ALG_RANDOM),
+    ("trim", VIR_STORAGE_VOL_WIPE_ALG_TRIM),
+];
+
+pub const VIR_STORAGE_VOL_RESIZE_ALLOCATE: u32 = 1 << 0;
+pub const VIR_STORAGE_VOL_RESIZE_DELTA: u32 = 1 << 1;
+pub const VIR_STORAGE_VOL_RESIZE_SHRINK: u32 = 1 << 2;
+
+pub const VIR_STORAGE_VOL_USE_ALLOCATION: u32 = 0;
+pub const VIR_STORAGE_VOL_GET_PHYSICAL: u32 = 1 << 0;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum StorageVolType {
+    #[default]
+    File,
+    Block,
+    Dir,
+    Network,
+    NetDir,
+    Ploop,
+}
+
+impl From<i8> for StorageVolType {
+    fn from(value: i8) -> Self {
+        match value {
+            1 => StorageVolType::Block,
+            2 => StorageVolType::Dir,
+            3 => StorageVolType::Network,
+            4 => StorageVolType::NetDir,
+            5 => StorageVolType::Ploop,
+            _ => StorageVolType::File,
+        }
+    }
+}
+
+impl StorageVolType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            StorageVolType::File => "file",
+            StorageVolType::Block => "block",
+            StorageVolType::Dir => "dir",
+            StorageVolType::Network => "network",
+            StorageVolType::NetDir => "netdir",
+            StorageVolType::Ploop => "ploop",
+        }
+    }
+}
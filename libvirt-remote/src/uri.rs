@@ -0,0 +1,427 @@
+use crate::client::Client;
+use crate::error::Error;
+use crate::socket;
+use log::trace;
+use std::env;
+use std::fmt;
+use std::net::TcpStream;
+#[cfg(target_family = "unix")]
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+use std::str::FromStr;
+use url::Url;
+
+pub const LIBVIRT_DEFAULT_URI: &str = "LIBVIRT_DEFAULT_URI";
+pub const DEFAULT_TCP_PORT: u16 = 16509;
+pub const DEFAULT_TLS_PORT: u16 = 16514;
+
+pub const DEFAULT_URI: &str = if cfg!(unix) {
+    "qemu:///system"
+} else {
+    "qemu+tcp:///system"
+};
+
+const CLIENT_PARAMS: [&str; 11] = [
+    "command",
+    "netcat",
+    "keyfile",
+    "sshauth",
+    "no_verify",
+    "no_tty",
+    "known_hosts",
+    "known_hosts_verify",
+    "tls_priority",
+    "tls",
+    "auth",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Transport {
+    Unix,
+    Tls,
+    Tcp,
+    Ssh,
+    Libssh,
+    Libssh2,
+    Ext,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DaemonMode {
+    #[default]
+    Auto,
+    Direct,
+    Legacy,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ProxyMode {
+    #[default]
+    Auto,
+    Netcat,
+    Native,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConnectUri {
+    driver: String,
+    transport: Option<Transport>,
+    user: Option<String>,
+    host: Option<String>,
+    port: Option<u16>,
+    path: String,
+    name: Option<String>,
+    socket: Option<String>,
+    mode: Option<DaemonMode>,
+    proxy: Option<ProxyMode>,
+    pkipath: Option<String>,
+    params: Vec<(String, String)>,
+}
+
+impl ConnectUri {
+    pub fn parse(uri: &str) -> Result<Self, Error> {
+        let url = Url::parse(uri).map_err(|e| Error::InvalidUriError(format!("{uri}: {e}")))?;
+
+        let (driver, transport) = match url.scheme().split_once('+') {
+            Some((driver, transport)) => (driver, Some(transport.parse()?)),
+            None => (url.scheme(), None),
+        };
+
+        if driver.is_empty() {
+            return Err(Error::InvalidUriError(format!("{uri}: missing driver")));
+        }
+
+        let user = match url.username() {
+            "" => None,
+            user => Some(user.to_string()),
+        };
+
+        let host = url
+            .host_str()
+            .filter(|h| !h.is_empty())
+            .map(|h| h.to_string());
+
+        let mut name = None;
+        let mut socket = None;
+        let mut mode = None;
+        let mut proxy = None;
+        let mut pkipath = None;
+        let mut params = vec![];
+        for (k, v) in url.query_pairs() {
+            match k.as_ref() {
+                "name" => name = Some(v.into_owned()),
+                "socket" => socket = Some(v.into_owned()),
+                "mode" => mode = Some(v.parse()?),
+                "proxy" => proxy = Some(v.parse()?),
+                "pkipath" => pkipath = Some(v.into_owned()),
+                _ => params.push((k.into_owned(), v.into_owned())),
+            }
+        }
+
+        Ok(ConnectUri {
+            driver: driver.to_string(),
+            transport,
+            user,
+            host,
+            port: url.port(),
+            path: url.path().to_string(),
+            name,
+            socket,
+            mode,
+            proxy,
+            pkipath,
+            params,
+        })
+    }
+
+    pub fn from_env() -> Result<Option<Self>, Error> {
+        match env::var(LIBVIRT_DEFAULT_URI) {
+            Ok(uri) if !uri.is_empty() => Ok(Some(Self::parse(&uri)?)),
+            _ => Ok(None),
+        }
+    }
+
+    // The URI given explicitly, else `LIBVIRT_DEFAULT_URI`, else `DEFAULT_URI`.
+    pub fn resolve(uri: Option<&str>) -> Result<Self, Error> {
+        match uri {
+            Some(uri) => Self::parse(uri),
+            _ => match Self::from_env()? {
+                Some(uri) => Ok(uri),
+                _ => Self::parse(DEFAULT_URI),
+            },
+        }
+    }
+
+    pub fn driver(&self) -> &str {
+        &self.driver
+    }
+
+    pub fn transport(&self) -> Transport {
+        match (self.transport, &self.host) {
+            (Some(transport), _) => transport,
+            (None, Some(_)) => Transport::Tls,
+            (None, None) => Transport::Unix,
+        }
+    }
+
+    pub fn user(&self) -> Option<&str> {
+        self.user.as_deref()
+    }
+
+    pub fn host(&self) -> Option<&str> {
+        self.host.as_deref()
+    }
+
+    pub fn port(&self) -> u16 {
+        match (self.port, self.transport()) {
+            (Some(port), _) => port,
+            (None, Transport::Tls) => DEFAULT_TLS_PORT,
+            (None, _) => DEFAULT_TCP_PORT,
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn mode(&self) -> DaemonMode {
+        self.mode.unwrap_or_default()
+    }
+
+    pub fn proxy(&self) -> ProxyMode {
+        self.proxy.unwrap_or_default()
+    }
+
+    pub fn pkipath(&self) -> Option<&str> {
+        self.pkipath.as_deref()
+    }
+
+    pub fn param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn is_session(&self) -> bool {
+        self.path == "/session"
+    }
+
+    // URI passed to `connect_open`, which excludes the transport and the
+    // parameters consumed by the client side.
+    pub fn name(&self) -> String {
+        if let Some(name) = &self.name {
+            return name.clone();
+        }
+
+        let mut name = format!("{}://{}", self.driver, self.path);
+        let params: Vec<&(String, String)> = self
+            .params
+            .iter()
+            .filter(|(k, _)| !CLIENT_PARAMS.contains(&k.as_str()))
+            .collect();
+        if !params.is_empty() {
+            let query = url::form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(params)
+                .finish();
+            name.push('?');
+            name.push_str(&query);
+        }
+        name
+    }
+
+    pub fn socket(&self) -> Option<&str> {
+        self.socket.as_deref()
+    }
+
+    pub fn socket_path(&self, readonly: bool) -> Result<PathBuf, Error> {
+        match &self.socket {
+            Some(socket) => Ok(PathBuf::from(socket)),
+            _ => socket::discover(self, readonly),
+        }
+    }
+}
+
+impl FromStr for ConnectUri {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ConnectUri::parse(s)
+    }
+}
+
+impl fmt::Display for ConnectUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.driver)?;
+        if let Some(transport) = self.transport {
+            write!(f, "+{transport}")?;
+        }
+        write!(f, "://")?;
+        if let Some(user) = &self.user {
+            write!(f, "{user}@")?;
+        }
+        if let Some(host) = &self.host {
+            write!(f, "{host}")?;
+        }
+        if let Some(port) = self.port {
+            write!(f, ":{port}")?;
+        }
+        write!(f, "{}", self.path)?;
+
+        let mut query = url::form_urlencoded::Serializer::new(String::new());
+        if let Some(name) = &self.name {
+            query.append_pair("name", name);
+        }
+        if let Some(socket) = &self.socket {
+            query.append_pair("socket", socket);
+        }
+        if let Some(mode) = self.mode {
+            query.append_pair("mode", &mode.to_string());
+        }
+        if let Some(proxy) = self.proxy {
+            query.append_pair("proxy", &proxy.to_string());
+        }
+        if let Some(pkipath) = &self.pkipath {
+            query.append_pair("pkipath", pkipath);
+        }
+        query.extend_pairs(self.params.iter());
+        let query = query.finish();
+        if !query.is_empty() {
+            write!(f, "?{query}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Transport {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "unix" => Ok(Transport::Unix),
+            "tls" => Ok(Transport::Tls),
+            "tcp" => Ok(Transport::Tcp),
+            "ssh" => Ok(Transport::Ssh),
+            "libssh" => Ok(Transport::Libssh),
+            "libssh2" => Ok(Transport::Libssh2),
+            "ext" => Ok(Transport::Ext),
+            _ => Err(Error::InvalidUriError(format!("unknown transport: {s}"))),
+        }
+    }
+}
+
+impl fmt::Display for Transport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        let s = match self {
+            Transport::Unix => "unix",
+            Transport::Tls => "tls",
+            Transport::Tcp => "tcp",
+            Transport::Ssh => "ssh",
+            Transport::Libssh => "libssh",
+            Transport::Libssh2 => "libssh2",
+            Transport::Ext => "ext",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl FromStr for DaemonMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(DaemonMode::Auto),
+            "direct" => Ok(DaemonMode::Direct),
+            "legacy" => Ok(DaemonMode::Legacy),
+            _ => Err(Error::InvalidUriError(format!("unknown mode: {s}"))),
+        }
+    }
+}
+
+impl fmt::Display for DaemonMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        let s = match self {
+            DaemonMode::Auto => "auto",
+            DaemonMode::Direct => "direct",
+            DaemonMode::Legacy => "legacy",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl FromStr for ProxyMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(ProxyMode::Auto),
+            "netcat" => Ok(ProxyMode::Netcat),
+            "native" => Ok(ProxyMode::Native),
+            _ => Err(Error::InvalidUriError(format!("unknown proxy: {s}"))),
+        }
+    }
+}
+
+impl fmt::Display for ProxyMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        let s = match self {
+            ProxyMode::Auto => "auto",
+            ProxyMode::Netcat => "netcat",
+            ProxyMode::Native => "native",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl Client {
+    pub fn connect(uri: &ConnectUri) -> Result<Self, Error> {
+        connect(uri, false)
+    }
+
+    pub fn connect_readonly(uri: &ConnectUri) -> Result<Self, Error> {
+        connect(uri, true)
+    }
+}
+
+fn connect(uri: &ConnectUri, readonly: bool) -> Result<Client, Error> {
+    match uri.transport() {
+        Transport::Unix => connect_unix(uri, readonly),
+        Transport::Tcp => connect_tcp(uri),
+        transport => Err(unsupported(uri, transport)),
+    }
+}
+
+fn unsupported(uri: &ConnectUri, transport: Transport) -> Error {
+    // A URI with a host and no explicit transport means TLS, which is easy
+    // to miss when the URI was written as `qemu://host/system`.
+    let implicit = if uri.transport.is_none() && uri.host.is_some() {
+        " (implied by a remote host without a transport)"
+    } else {
+        ""
+    };
+    Error::UnsupportedTransportError(format!(
+        "{uri}: {transport} transport is not supported{implicit}, use {}+tcp or a local unix socket",
+        uri.driver
+    ))
+}
+
+fn connect_tcp(uri: &ConnectUri) -> Result<Client, Error> {
+    let host = format!("{}:{}", uri.host().unwrap_or("127.0.0.1"), uri.port());
+    trace!("connecting: {}", host);
+    let stream = TcpStream::connect(host).map_err(Error::SocketError)?;
+    Client::new(stream)
+}
+
+#[cfg(target_family = "unix")]
+fn connect_unix(uri: &ConnectUri, readonly: bool) -> Result<Client, Error> {
+    let socket = uri.socket_path(readonly)?;
+    trace!("connecting: {}", socket.display());
+    let stream = UnixStream::connect(&socket).map_err(Error::SocketError)?;
+    Client::new(stream)
+}
+
+#[cfg(target_family = "windows")]
+fn connect_unix(uri: &ConnectUri, _: bool) -> Result<Client, Error> {
+    Err(unsupported(uri, Transport::Unix))
+}
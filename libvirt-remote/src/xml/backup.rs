@@ -0,0 +1,67 @@
+use super::FormatType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename = "domainbackup")]
+pub struct DomainBackupDef {
+    #[serde(rename = "@mode", skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incremental: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server: Option<BackupServer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disks: Option<BackupDisks>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct BackupServer {
+    #[serde(rename = "@transport", skip_serializing_if = "Option::is_none")]
+    pub transport: Option<String>,
+    #[serde(rename = "@name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "@port", skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    #[serde(rename = "@socket", skip_serializing_if = "Option::is_none")]
+    pub socket: Option<String>,
+    #[serde(rename = "@tls", skip_serializing_if = "Option::is_none")]
+    pub tls: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct BackupDisks {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disk: Vec<BackupDisk>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct BackupDisk {
+    #[serde(rename = "@name")]
+    pub name: String,
+    #[serde(rename = "@backup", skip_serializing_if = "Option::is_none")]
+    pub backup: Option<String>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(rename = "@backupmode", skip_serializing_if = "Option::is_none")]
+    pub backupmode: Option<String>,
+    #[serde(rename = "@incremental", skip_serializing_if = "Option::is_none")]
+    pub incremental: Option<String>,
+    #[serde(rename = "@exportname", skip_serializing_if = "Option::is_none")]
+    pub exportname: Option<String>,
+    #[serde(rename = "@exportbitmap", skip_serializing_if = "Option::is_none")]
+    pub exportbitmap: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver: Option<FormatType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<BackupPath>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scratch: Option<BackupPath>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct BackupPath {
+    #[serde(rename = "@file", skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(rename = "@dev", skip_serializing_if = "Option::is_none")]
+    pub dev: Option<String>,
+}
@@ -0,0 +1,244 @@
+use super::Empty;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename = "capabilities")]
+pub struct Capabilities {
+    pub host: Host,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub guest: Vec<Guest>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Host {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    pub cpu: HostCpu,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power_management: Option<PowerManagement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iommu: Option<Iommu>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migration_features: Option<MigrationFeatures>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topology: Option<Topology>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub secmodel: Vec<SecModel>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct HostCpu {
+    pub arch: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub microcode: Option<Version>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topology: Option<CpuTopology>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub feature: Vec<Named>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pages: Vec<Pages>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Version {
+    #[serde(rename = "@version")]
+    pub version: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CpuTopology {
+    #[serde(rename = "@sockets")]
+    pub sockets: u32,
+    #[serde(rename = "@dies", skip_serializing_if = "Option::is_none")]
+    pub dies: Option<u32>,
+    #[serde(rename = "@clusters", skip_serializing_if = "Option::is_none")]
+    pub clusters: Option<u32>,
+    #[serde(rename = "@cores")]
+    pub cores: u32,
+    #[serde(rename = "@threads")]
+    pub threads: u32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Named {
+    #[serde(rename = "@name")]
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Pages {
+    #[serde(rename = "@unit", skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    #[serde(rename = "@size")]
+    pub size: u64,
+    #[serde(rename = "$text", skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PowerManagement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suspend_mem: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suspend_disk: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suspend_hybrid: Option<Empty>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Iommu {
+    #[serde(rename = "@support")]
+    pub support: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MigrationFeatures {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri_transports: Option<UriTransports>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct UriTransports {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub uri_transport: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Topology {
+    pub cells: Cells,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Cells {
+    #[serde(rename = "@num")]
+    pub num: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cell: Vec<Cell>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Cell {
+    #[serde(rename = "@id")]
+    pub id: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<super::ScaledInteger>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pages: Vec<Pages>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<CellCpus>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CellCpus {
+    #[serde(rename = "@num")]
+    pub num: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cpu: Vec<CellCpu>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CellCpu {
+    #[serde(rename = "@id")]
+    pub id: u32,
+    #[serde(rename = "@socket_id", skip_serializing_if = "Option::is_none")]
+    pub socket_id: Option<u32>,
+    #[serde(rename = "@die_id", skip_serializing_if = "Option::is_none")]
+    pub die_id: Option<u32>,
+    #[serde(rename = "@core_id", skip_serializing_if = "Option::is_none")]
+    pub core_id: Option<u32>,
+    #[serde(rename = "@siblings", skip_serializing_if = "Option::is_none")]
+    pub siblings: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SecModel {
+    pub model: String,
+    pub doi: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub baselabel: Vec<BaseLabel>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct BaseLabel {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(rename = "$text")]
+    pub label: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Guest {
+    pub os_type: String,
+    pub arch: GuestArch,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<GuestFeatures>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct GuestArch {
+    #[serde(rename = "@name")]
+    pub name: String,
+    pub wordsize: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emulator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loader: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub machine: Vec<Machine>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub domain: Vec<GuestDomain>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Machine {
+    #[serde(rename = "@canonical", skip_serializing_if = "Option::is_none")]
+    pub canonical: Option<String>,
+    #[serde(rename = "@maxCpus", skip_serializing_if = "Option::is_none")]
+    pub max_cpus: Option<u32>,
+    #[serde(rename = "$text")]
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct GuestDomain {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emulator: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub machine: Vec<Machine>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct GuestFeatures {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pae: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonpae: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acpi: Option<FeatureToggle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apic: Option<FeatureToggle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpuselection: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deviceboot: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disksnapshot: Option<FeatureToggle>,
+    #[serde(rename = "externalSnapshot", skip_serializing_if = "Option::is_none")]
+    pub external_snapshot: Option<Empty>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct FeatureToggle {
+    #[serde(rename = "@default", skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(rename = "@toggle", skip_serializing_if = "Option::is_none")]
+    pub toggle: Option<String>,
+}
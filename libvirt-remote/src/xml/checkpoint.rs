@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename = "domaincheckpoint")]
+pub struct DomainCheckpointDef {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<CheckpointParent>,
+    #[serde(rename = "creationTime", skip_serializing_if = "Option::is_none")]
+    pub creation_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disks: Option<CheckpointDisks>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CheckpointParent {
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CheckpointDisks {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disk: Vec<CheckpointDisk>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CheckpointDisk {
+    #[serde(rename = "@name")]
+    pub name: String,
+    #[serde(rename = "@checkpoint", skip_serializing_if = "Option::is_none")]
+    pub checkpoint: Option<String>,
+    #[serde(rename = "@bitmap", skip_serializing_if = "Option::is_none")]
+    pub bitmap: Option<String>,
+    #[serde(rename = "@size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+}
@@ -0,0 +1,929 @@
+use super::{Empty, MacAddress, ScaledInteger};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename = "domain")]
+pub struct DomainDef {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename = "maxMemory", skip_serializing_if = "Option::is_none")]
+    pub max_memory: Option<MaxMemory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<ScaledInteger>,
+    #[serde(rename = "currentMemory", skip_serializing_if = "Option::is_none")]
+    pub current_memory: Option<ScaledInteger>,
+    #[serde(rename = "memoryBacking", skip_serializing_if = "Option::is_none")]
+    pub memory_backing: Option<MemoryBacking>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcpu: Option<Vcpu>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iothreads: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os: Option<Os>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<Features>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<Cpu>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clock: Option<Clock>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_poweroff: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_reboot: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_crash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pm: Option<Pm>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub devices: Option<Devices>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub seclabel: Vec<SecLabel>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MaxMemory {
+    #[serde(rename = "@slots", skip_serializing_if = "Option::is_none")]
+    pub slots: Option<u32>,
+    #[serde(rename = "@unit", skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    #[serde(rename = "$text")]
+    pub value: u64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MemoryBacking {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hugepages: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nosharepages: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locked: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<MemorySource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access: Option<MemoryAccess>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MemorySource {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MemoryAccess {
+    #[serde(rename = "@mode")]
+    pub mode: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Vcpu {
+    #[serde(rename = "@placement", skip_serializing_if = "Option::is_none")]
+    pub placement: Option<String>,
+    #[serde(rename = "@cpuset", skip_serializing_if = "Option::is_none")]
+    pub cpuset: Option<String>,
+    #[serde(rename = "@current", skip_serializing_if = "Option::is_none")]
+    pub current: Option<u32>,
+    #[serde(rename = "$text")]
+    pub value: u32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Os {
+    #[serde(rename = "@firmware", skip_serializing_if = "Option::is_none")]
+    pub firmware: Option<String>,
+    pub r#type: OsType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loader: Option<Loader>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nvram: Option<Nvram>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kernel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initrd: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cmdline: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub boot: Vec<OsBoot>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bootmenu: Option<BootMenu>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smbios: Option<Smbios>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct OsType {
+    #[serde(rename = "@arch", skip_serializing_if = "Option::is_none")]
+    pub arch: Option<String>,
+    #[serde(rename = "@machine", skip_serializing_if = "Option::is_none")]
+    pub machine: Option<String>,
+    #[serde(rename = "$text")]
+    pub value: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Loader {
+    #[serde(rename = "@readonly", skip_serializing_if = "Option::is_none")]
+    pub readonly: Option<String>,
+    #[serde(rename = "@secure", skip_serializing_if = "Option::is_none")]
+    pub secure: Option<String>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(rename = "$text", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Nvram {
+    #[serde(rename = "@template", skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    #[serde(rename = "$text", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct OsBoot {
+    #[serde(rename = "@dev")]
+    pub dev: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct BootMenu {
+    #[serde(rename = "@enable")]
+    pub enable: String,
+    #[serde(rename = "@timeout", skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Smbios {
+    #[serde(rename = "@mode")]
+    pub mode: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Features {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pae: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acpi: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apic: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hap: Option<FeatureState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privnet: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pmu: Option<FeatureState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vmport: Option<FeatureState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smm: Option<FeatureState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pvspinlock: Option<FeatureState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vmcoreinfo: Option<FeatureState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ioapic: Option<IoApic>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gic: Option<Gic>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct FeatureState {
+    #[serde(rename = "@state", skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct IoApic {
+    #[serde(rename = "@driver")]
+    pub driver: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Gic {
+    #[serde(rename = "@version")]
+    pub version: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Cpu {
+    #[serde(rename = "@mode", skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    #[serde(rename = "@match", skip_serializing_if = "Option::is_none")]
+    pub r#match: Option<String>,
+    #[serde(rename = "@check", skip_serializing_if = "Option::is_none")]
+    pub check: Option<String>,
+    #[serde(rename = "@migratable", skip_serializing_if = "Option::is_none")]
+    pub migratable: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<CpuModel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topology: Option<CpuTopology>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub feature: Vec<CpuFeature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub numa: Option<CpuNuma>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CpuModel {
+    #[serde(rename = "@fallback", skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<String>,
+    #[serde(rename = "@vendor_id", skip_serializing_if = "Option::is_none")]
+    pub vendor_id: Option<String>,
+    #[serde(rename = "$text")]
+    pub value: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CpuTopology {
+    #[serde(rename = "@sockets")]
+    pub sockets: u32,
+    #[serde(rename = "@dies", skip_serializing_if = "Option::is_none")]
+    pub dies: Option<u32>,
+    #[serde(rename = "@clusters", skip_serializing_if = "Option::is_none")]
+    pub clusters: Option<u32>,
+    #[serde(rename = "@cores")]
+    pub cores: u32,
+    #[serde(rename = "@threads")]
+    pub threads: u32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CpuFeature {
+    #[serde(rename = "@policy", skip_serializing_if = "Option::is_none")]
+    pub policy: Option<String>,
+    #[serde(rename = "@name")]
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CpuNuma {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cell: Vec<NumaCell>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct NumaCell {
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    #[serde(rename = "@cpus", skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<String>,
+    #[serde(rename = "@memory")]
+    pub memory: u64,
+    #[serde(rename = "@unit", skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Clock {
+    #[serde(rename = "@offset")]
+    pub offset: String,
+    #[serde(rename = "@timezone", skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(rename = "@adjustment", skip_serializing_if = "Option::is_none")]
+    pub adjustment: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub timer: Vec<Timer>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Timer {
+    #[serde(rename = "@name")]
+    pub name: String,
+    #[serde(rename = "@present", skip_serializing_if = "Option::is_none")]
+    pub present: Option<String>,
+    #[serde(rename = "@tickpolicy", skip_serializing_if = "Option::is_none")]
+    pub tickpolicy: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Pm {
+    #[serde(rename = "suspend-to-mem", skip_serializing_if = "Option::is_none")]
+    pub suspend_to_mem: Option<PmState>,
+    #[serde(rename = "suspend-to-disk", skip_serializing_if = "Option::is_none")]
+    pub suspend_to_disk: Option<PmState>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PmState {
+    #[serde(rename = "@enabled")]
+    pub enabled: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Devices {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emulator: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disk: Vec<Disk>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub controller: Vec<Controller>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filesystem: Vec<Filesystem>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub interface: Vec<Interface>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub serial: Vec<Chardev>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub console: Vec<Chardev>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel: Vec<Chardev>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub input: Vec<Input>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tpm: Vec<Tpm>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub graphics: Vec<Graphics>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sound: Vec<Sound>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub video: Vec<Video>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hostdev: Vec<Hostdev>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redirdev: Vec<Redirdev>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub watchdog: Vec<Watchdog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memballoon: Option<MemBalloon>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rng: Vec<Rng>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub panic: Vec<Panic>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Disk {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(rename = "@device", skip_serializing_if = "Option::is_none")]
+    pub device: Option<String>,
+    #[serde(rename = "@snapshot", skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver: Option<DiskDriver>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<DiskSource>,
+    #[serde(rename = "backingStore", skip_serializing_if = "Option::is_none")]
+    pub backing_store: Option<DiskBackingStore>,
+    pub target: DiskTarget,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub readonly: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shareable: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boot: Option<DeviceBoot>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<Alias>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DiskDriver {
+    #[serde(rename = "@name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(rename = "@cache", skip_serializing_if = "Option::is_none")]
+    pub cache: Option<String>,
+    #[serde(rename = "@io", skip_serializing_if = "Option::is_none")]
+    pub io: Option<String>,
+    #[serde(rename = "@discard", skip_serializing_if = "Option::is_none")]
+    pub discard: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DiskSource {
+    #[serde(rename = "@file", skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(rename = "@dev", skip_serializing_if = "Option::is_none")]
+    pub dev: Option<String>,
+    #[serde(rename = "@dir", skip_serializing_if = "Option::is_none")]
+    pub dir: Option<String>,
+    #[serde(rename = "@pool", skip_serializing_if = "Option::is_none")]
+    pub pool: Option<String>,
+    #[serde(rename = "@volume", skip_serializing_if = "Option::is_none")]
+    pub volume: Option<String>,
+    #[serde(rename = "@protocol", skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    #[serde(rename = "@name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "@startupPolicy", skip_serializing_if = "Option::is_none")]
+    pub startup_policy: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub host: Vec<SourceHost>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SourceHost {
+    #[serde(rename = "@name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "@port", skip_serializing_if = "Option::is_none")]
+    pub port: Option<String>,
+    #[serde(rename = "@transport", skip_serializing_if = "Option::is_none")]
+    pub transport: Option<String>,
+    #[serde(rename = "@socket", skip_serializing_if = "Option::is_none")]
+    pub socket: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DiskBackingStore {
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(rename = "@index", skip_serializing_if = "Option::is_none")]
+    pub index: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<super::FormatType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<DiskSource>,
+    #[serde(rename = "backingStore", skip_serializing_if = "Option::is_none")]
+    pub backing_store: Option<Box<DiskBackingStore>>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DiskTarget {
+    #[serde(rename = "@dev")]
+    pub dev: String,
+    #[serde(rename = "@bus", skip_serializing_if = "Option::is_none")]
+    pub bus: Option<String>,
+    #[serde(rename = "@tray", skip_serializing_if = "Option::is_none")]
+    pub tray: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DeviceBoot {
+    #[serde(rename = "@order")]
+    pub order: u32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Alias {
+    #[serde(rename = "@name")]
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Address {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(rename = "@domain", skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    #[serde(rename = "@bus", skip_serializing_if = "Option::is_none")]
+    pub bus: Option<String>,
+    #[serde(rename = "@slot", skip_serializing_if = "Option::is_none")]
+    pub slot: Option<String>,
+    #[serde(rename = "@function", skip_serializing_if = "Option::is_none")]
+    pub function: Option<String>,
+    #[serde(rename = "@multifunction", skip_serializing_if = "Option::is_none")]
+    pub multifunction: Option<String>,
+    #[serde(rename = "@controller", skip_serializing_if = "Option::is_none")]
+    pub controller: Option<String>,
+    #[serde(rename = "@target", skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    #[serde(rename = "@unit", skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    #[serde(rename = "@port", skip_serializing_if = "Option::is_none")]
+    pub port: Option<String>,
+    #[serde(rename = "@uuid", skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Controller {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(rename = "@index", skip_serializing_if = "Option::is_none")]
+    pub index: Option<u32>,
+    #[serde(rename = "@model", skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(rename = "@ports", skip_serializing_if = "Option::is_none")]
+    pub ports: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<Alias>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Filesystem {
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(rename = "@accessmode", skip_serializing_if = "Option::is_none")]
+    pub accessmode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver: Option<DiskDriver>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<FilesystemPath>,
+    pub target: FilesystemPath,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub readonly: Option<Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<Alias>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct FilesystemPath {
+    #[serde(rename = "@dir", skip_serializing_if = "Option::is_none")]
+    pub dir: Option<String>,
+    #[serde(rename = "@file", skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(rename = "@name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Interface {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mac: Option<MacAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<InterfaceSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub virtualport: Option<VirtualPort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<InterfaceTarget>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<InterfaceModel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver: Option<InterfaceDriver>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<InterfaceLink>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtu: Option<InterfaceMtu>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boot: Option<DeviceBoot>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<Alias>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct InterfaceSource {
+    #[serde(rename = "@network", skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    #[serde(rename = "@portid", skip_serializing_if = "Option::is_none")]
+    pub portid: Option<String>,
+    #[serde(rename = "@bridge", skip_serializing_if = "Option::is_none")]
+    pub bridge: Option<String>,
+    #[serde(rename = "@dev", skip_serializing_if = "Option::is_none")]
+    pub dev: Option<String>,
+    #[serde(rename = "@mode", skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    #[serde(rename = "@path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct VirtualPort {
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct InterfaceTarget {
+    #[serde(rename = "@dev")]
+    pub dev: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct InterfaceModel {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct InterfaceDriver {
+    #[serde(rename = "@name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "@queues", skip_serializing_if = "Option::is_none")]
+    pub queues: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct InterfaceLink {
+    #[serde(rename = "@state")]
+    pub state: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct InterfaceMtu {
+    #[serde(rename = "@size")]
+    pub size: u32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Chardev {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<ChardevSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<ChardevProtocol>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<ChardevTarget>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<Alias>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ChardevSource {
+    #[serde(rename = "@path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(rename = "@mode", skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    #[serde(rename = "@host", skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    #[serde(rename = "@service", skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    #[serde(rename = "@channel", skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ChardevProtocol {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ChardevTarget {
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(rename = "@port", skip_serializing_if = "Option::is_none")]
+    pub port: Option<String>,
+    #[serde(rename = "@name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "@state", skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Input {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(rename = "@bus", skip_serializing_if = "Option::is_none")]
+    pub bus: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<Alias>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Tpm {
+    #[serde(rename = "@model", skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    pub backend: TpmBackend,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<Alias>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct TpmBackend {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(rename = "@version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Graphics {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(rename = "@port", skip_serializing_if = "Option::is_none")]
+    pub port: Option<i32>,
+    #[serde(rename = "@tlsPort", skip_serializing_if = "Option::is_none")]
+    pub tls_port: Option<i32>,
+    #[serde(rename = "@autoport", skip_serializing_if = "Option::is_none")]
+    pub autoport: Option<String>,
+    #[serde(rename = "@listen", skip_serializing_if = "Option::is_none")]
+    pub listen: Option<String>,
+    #[serde(rename = "@passwd", skip_serializing_if = "Option::is_none")]
+    pub passwd: Option<String>,
+    #[serde(rename = "@keymap", skip_serializing_if = "Option::is_none")]
+    pub keymap: Option<String>,
+    #[serde(rename = "listen", default, skip_serializing_if = "Vec::is_empty")]
+    pub listens: Vec<GraphicsListen>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct GraphicsListen {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(rename = "@address", skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(rename = "@network", skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    #[serde(rename = "@socket", skip_serializing_if = "Option::is_none")]
+    pub socket: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Sound {
+    #[serde(rename = "@model")]
+    pub model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<Alias>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Video {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<VideoModel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<Alias>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct VideoModel {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(rename = "@vram", skip_serializing_if = "Option::is_none")]
+    pub vram: Option<u64>,
+    #[serde(rename = "@heads", skip_serializing_if = "Option::is_none")]
+    pub heads: Option<u32>,
+    #[serde(rename = "@primary", skip_serializing_if = "Option::is_none")]
+    pub primary: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Hostdev {
+    #[serde(rename = "@mode")]
+    pub mode: String,
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(rename = "@managed", skip_serializing_if = "Option::is_none")]
+    pub managed: Option<String>,
+    #[serde(rename = "@model", skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver: Option<HostdevDriver>,
+    pub source: HostdevSource,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boot: Option<DeviceBoot>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<Alias>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct HostdevDriver {
+    #[serde(rename = "@name")]
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct HostdevSource {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<HostdevId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<HostdevId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct HostdevId {
+    #[serde(rename = "@id")]
+    pub id: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Redirdev {
+    #[serde(rename = "@bus")]
+    pub bus: String,
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<Alias>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Watchdog {
+    #[serde(rename = "@model")]
+    pub model: String,
+    #[serde(rename = "@action", skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<Alias>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MemBalloon {
+    #[serde(rename = "@model")]
+    pub model: String,
+    #[serde(rename = "@autodeflate", skip_serializing_if = "Option::is_none")]
+    pub autodeflate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<MemBalloonStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<Alias>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MemBalloonStats {
+    #[serde(rename = "@period")]
+    pub period: u32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Rng {
+    #[serde(rename = "@model")]
+    pub model: String,
+    pub backend: RngBackend,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<Alias>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct RngBackend {
+    #[serde(rename = "@model")]
+    pub model: String,
+    #[serde(rename = "$text", skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Panic {
+    #[serde(rename = "@model", skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SecLabel {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(rename = "@model", skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(rename = "@relabel", skip_serializing_if = "Option::is_none")]
+    pub relabel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imagelabel: Option<String>,
+}
+
+impl DomainDef {
+    pub fn disks(&self) -> &[Disk] {
+        self.devices
+            .as_ref()
+            .map(|d| d.disk.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub fn interfaces(&self) -> &[Interface] {
+        self.devices
+            .as_ref()
+            .map(|d| d.interface.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+impl DiskSource {
+    pub fn path(&self) -> Option<&str> {
+        self.file
+            .as_deref()
+            .or(self.dev.as_deref())
+            .or(self.dir.as_deref())
+    }
+}
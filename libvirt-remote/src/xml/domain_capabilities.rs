@@ -0,0 +1,179 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename = "domainCapabilities")]
+pub struct DomainCapabilities {
+    pub path: String,
+    pub domain: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub machine: Option<String>,
+    pub arch: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcpu: Option<Max>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iothreads: Option<Supported>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os: Option<OsCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<CpuCaps>,
+    #[serde(rename = "memoryBacking", skip_serializing_if = "Option::is_none")]
+    pub memory_backing: Option<EnumCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub devices: Option<DeviceCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<FeatureCaps>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Max {
+    #[serde(rename = "@max")]
+    pub max: u32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Supported {
+    #[serde(rename = "@supported")]
+    pub supported: String,
+}
+
+// Element carrying `supported` and a list of `<enum name='...'>` children,
+// which is how most of the domain capabilities are described.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct EnumCaps {
+    #[serde(rename = "@supported", skip_serializing_if = "Option::is_none")]
+    pub supported: Option<String>,
+    #[serde(rename = "enum", default, skip_serializing_if = "Vec::is_empty")]
+    pub enums: Vec<CapsEnum>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CapsEnum {
+    #[serde(rename = "@name")]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub value: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct OsCaps {
+    #[serde(rename = "@supported")]
+    pub supported: String,
+    #[serde(rename = "enum", default, skip_serializing_if = "Vec::is_empty")]
+    pub enums: Vec<CapsEnum>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loader: Option<LoaderCaps>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct LoaderCaps {
+    #[serde(rename = "@supported")]
+    pub supported: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub value: Vec<String>,
+    #[serde(rename = "enum", default, skip_serializing_if = "Vec::is_empty")]
+    pub enums: Vec<CapsEnum>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CpuCaps {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mode: Vec<CpuModeCaps>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CpuModeCaps {
+    #[serde(rename = "@name")]
+    pub name: String,
+    #[serde(rename = "@supported")]
+    pub supported: String,
+    #[serde(rename = "enum", default, skip_serializing_if = "Vec::is_empty")]
+    pub enums: Vec<CapsEnum>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub model: Vec<CpuModelCaps>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub feature: Vec<CpuFeatureCaps>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CpuModelCaps {
+    #[serde(rename = "@usable", skip_serializing_if = "Option::is_none")]
+    pub usable: Option<String>,
+    #[serde(rename = "@fallback", skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<String>,
+    #[serde(rename = "@vendor", skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+    #[serde(rename = "$text")]
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CpuFeatureCaps {
+    #[serde(rename = "@policy", skip_serializing_if = "Option::is_none")]
+    pub policy: Option<String>,
+    #[serde(rename = "@name")]
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DeviceCaps {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disk: Option<EnumCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub graphics: Option<EnumCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video: Option<EnumCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostdev: Option<EnumCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rng: Option<EnumCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filesystem: Option<EnumCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tpm: Option<EnumCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirdev: Option<EnumCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<EnumCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crypto: Option<EnumCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface: Option<EnumCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub panic: Option<EnumCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub console: Option<EnumCaps>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct FeatureCaps {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gic: Option<EnumCaps>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vmcoreinfo: Option<Supported>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genid: Option<Supported>,
+    #[serde(rename = "backingStoreInput", skip_serializing_if = "Option::is_none")]
+    pub backing_store_input: Option<Supported>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backup: Option<Supported>,
+    #[serde(rename = "async-teardown", skip_serializing_if = "Option::is_none")]
+    pub async_teardown: Option<Supported>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sev: Option<Supported>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sgx: Option<Supported>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hyperv: Option<EnumCaps>,
+}
+
+impl EnumCaps {
+    pub fn values(&self, name: &str) -> &[String] {
+        self.enums
+            .iter()
+            .find(|e| e.name == name)
+            .map(|e| e.value.as_slice())
+            .unwrap_or_default()
+    }
+}
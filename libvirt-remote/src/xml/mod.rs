@@ -0,0 +1,232 @@
+pub mod backup;
+pub mod capabilities;
+pub mod checkpoint;
+pub mod domain;
+pub mod domain_capabilities;
+pub mod network;
+pub mod nodedev;
+pub mod nwfilter;
+pub mod secret;
+pub mod storage;
+
+use crate::binding::*;
+use crate::client::Libvirt;
+use crate::error::Error;
+use backup::DomainBackupDef;
+use capabilities::Capabilities;
+use checkpoint::DomainCheckpointDef;
+use domain::DomainDef;
+use domain_capabilities::DomainCapabilities;
+use network::{NetworkDef, NetworkPortDef};
+use nodedev::NodeDeviceDef;
+use nwfilter::NwFilterDef;
+use quick_xml::se::Serializer;
+use secret::SecretDef;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use storage::{PoolSource, StoragePoolDef, StorageVolDef};
+
+// The models cover the commonly used parts of each schema; elements they do
+// not know about are skipped when parsing. Serializing a fetched definition
+// therefore loses that content, so redefine existing objects from their
+// original XML text rather than from a model.
+pub trait XmlDocument: Serialize + DeserializeOwned {
+    fn from_xml(xml: &str) -> Result<Self, Error> {
+        quick_xml::de::from_str(xml).map_err(Error::XmlDeserializeError)
+    }
+
+    fn to_xml(&self) -> Result<String, Error> {
+        let mut xml = String::new();
+        let mut serializer = Serializer::new(&mut xml);
+        serializer.indent(' ', 2);
+        self.serialize(serializer)
+            .map_err(Error::XmlSerializeError)?;
+        Ok(xml)
+    }
+}
+
+// Element which is meaningful by its presence, such as `<acpi/>`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Empty {}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ScaledInteger {
+    #[serde(rename = "@unit", skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    #[serde(rename = "$text")]
+    pub value: u64,
+}
+
+impl ScaledInteger {
+    pub fn new(value: u64, unit: &str) -> Self {
+        ScaledInteger {
+            unit: Some(unit.to_string()),
+            value,
+        }
+    }
+
+    pub fn bytes(&self) -> u64 {
+        let scale: u64 = match self.unit.as_deref().unwrap_or("bytes") {
+            "b" | "bytes" => 1,
+            "KB" => 1_000,
+            "k" | "K" | "KiB" => 1 << 10,
+            "MB" => 1_000_000,
+            "M" | "MiB" => 1 << 20,
+            "GB" => 1_000_000_000,
+            "G" | "GiB" => 1 << 30,
+            "TB" => 1_000_000_000_000,
+            "T" | "TiB" => 1 << 40,
+            "PB" => 1_000_000_000_000_000,
+            "P" | "PiB" => 1 << 50,
+            "EB" => 1_000_000_000_000_000_000,
+            "E" | "EiB" => 1 << 60,
+            _ => 1,
+        };
+        self.value.saturating_mul(scale)
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MacAddress {
+    #[serde(rename = "@address")]
+    pub address: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Permissions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct FormatType {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+}
+
+impl XmlDocument for Capabilities {}
+impl XmlDocument for DomainBackupDef {}
+impl XmlDocument for DomainCapabilities {}
+impl XmlDocument for DomainCheckpointDef {}
+impl XmlDocument for DomainDef {}
+impl XmlDocument for NetworkDef {}
+impl XmlDocument for NetworkPortDef {}
+impl XmlDocument for NodeDeviceDef {}
+impl XmlDocument for NwFilterDef {}
+impl XmlDocument for PoolSource {}
+impl XmlDocument for SecretDef {}
+impl XmlDocument for StoragePoolDef {}
+impl XmlDocument for StorageVolDef {}
+
+pub trait LibvirtXml: Libvirt {
+    fn capabilities(&mut self) -> Result<Capabilities, Error> {
+        let xml = self.connect_get_capabilities()?;
+        Capabilities::from_xml(&xml)
+    }
+
+    fn backup_definition(
+        &mut self,
+        dom: RemoteNonnullDomain,
+        flags: u32,
+    ) -> Result<DomainBackupDef, Error> {
+        let xml = self.domain_backup_get_xml_desc(dom, flags)?;
+        DomainBackupDef::from_xml(&xml)
+    }
+
+    fn checkpoint_definition(
+        &mut self,
+        checkpoint: RemoteNonnullDomainCheckpoint,
+        flags: u32,
+    ) -> Result<DomainCheckpointDef, Error> {
+        let xml = self.domain_checkpoint_get_xml_desc(checkpoint, flags)?;
+        DomainCheckpointDef::from_xml(&xml)
+    }
+
+    fn domain_capabilities(
+        &mut self,
+        emulatorbin: Option<String>,
+        arch: Option<String>,
+        machine: Option<String>,
+        virttype: Option<String>,
+        flags: u32,
+    ) -> Result<DomainCapabilities, Error> {
+        let xml =
+            self.connect_get_domain_capabilities(emulatorbin, arch, machine, virttype, flags)?;
+        DomainCapabilities::from_xml(&xml)
+    }
+
+    fn domain_definition(
+        &mut self,
+        dom: RemoteNonnullDomain,
+        flags: u32,
+    ) -> Result<DomainDef, Error> {
+        let xml = self.domain_get_xml_desc(dom, flags)?;
+        DomainDef::from_xml(&xml)
+    }
+
+    fn network_definition(
+        &mut self,
+        net: RemoteNonnullNetwork,
+        flags: u32,
+    ) -> Result<NetworkDef, Error> {
+        let xml = self.network_get_xml_desc(net, flags)?;
+        NetworkDef::from_xml(&xml)
+    }
+
+    fn network_port_definition(
+        &mut self,
+        port: RemoteNonnullNetworkPort,
+        flags: u32,
+    ) -> Result<NetworkPortDef, Error> {
+        let xml = self.network_port_get_xml_desc(port, flags)?;
+        NetworkPortDef::from_xml(&xml)
+    }
+
+    fn node_device_definition(&mut self, name: String, flags: u32) -> Result<NodeDeviceDef, Error> {
+        let xml = self.node_device_get_xml_desc(name, flags)?;
+        NodeDeviceDef::from_xml(&xml)
+    }
+
+    fn nwfilter_definition(
+        &mut self,
+        nwfilter: RemoteNonnullNwfilter,
+        flags: u32,
+    ) -> Result<NwFilterDef, Error> {
+        let xml = self.nwfilter_get_xml_desc(nwfilter, flags)?;
+        NwFilterDef::from_xml(&xml)
+    }
+
+    fn secret_definition(
+        &mut self,
+        secret: RemoteNonnullSecret,
+        flags: u32,
+    ) -> Result<SecretDef, Error> {
+        let xml = self.secret_get_xml_desc(secret, flags)?;
+        SecretDef::from_xml(&xml)
+    }
+
+    fn storage_pool_definition(
+        &mut self,
+        pool: RemoteNonnullStoragePool,
+        flags: u32,
+    ) -> Result<StoragePoolDef, Error> {
+        let xml = self.storage_pool_get_xml_desc(pool, flags)?;
+        StoragePoolDef::from_xml(&xml)
+    }
+
+    fn storage_vol_definition(
+        &mut self,
+        vol: RemoteNonnullStorageVol,
+        flags: u32,
+    ) -> Result<StorageVolDef, Error> {
+        let xml = self.storage_vol_get_xml_desc(vol, flags)?;
+        StorageVolDef::from_xml(&xml)
+    }
+}
+
+impl<T> LibvirtXml for T where T: Libvirt {}
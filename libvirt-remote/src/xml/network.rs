@@ -0,0 +1,231 @@
+use super::MacAddress;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename = "network")]
+pub struct NetworkDef {
+    #[serde(rename = "@connections", skip_serializing_if = "Option::is_none")]
+    pub connections: Option<u32>,
+    #[serde(rename = "@ipv6", skip_serializing_if = "Option::is_none")]
+    pub ipv6: Option<String>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward: Option<Forward>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bridge: Option<Bridge>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtu: Option<Mtu>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mac: Option<MacAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<Domain>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dns: Option<Dns>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ip: Vec<Ip>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub route: Vec<Route>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Forward {
+    #[serde(rename = "@mode", skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    #[serde(rename = "@dev", skip_serializing_if = "Option::is_none")]
+    pub dev: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nat: Option<Nat>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub interface: Vec<ForwardInterface>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pf: Vec<ForwardInterface>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Nat {
+    #[serde(rename = "@ipv6", skip_serializing_if = "Option::is_none")]
+    pub ipv6: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<NatRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<NatRange>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct NatRange {
+    #[serde(rename = "@start")]
+    pub start: String,
+    #[serde(rename = "@end")]
+    pub end: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ForwardInterface {
+    #[serde(rename = "@dev")]
+    pub dev: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Bridge {
+    #[serde(rename = "@name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "@stp", skip_serializing_if = "Option::is_none")]
+    pub stp: Option<String>,
+    #[serde(rename = "@delay", skip_serializing_if = "Option::is_none")]
+    pub delay: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Mtu {
+    #[serde(rename = "@size")]
+    pub size: u32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Domain {
+    #[serde(rename = "@name")]
+    pub name: String,
+    #[serde(rename = "@localOnly", skip_serializing_if = "Option::is_none")]
+    pub local_only: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Dns {
+    #[serde(rename = "@enable", skip_serializing_if = "Option::is_none")]
+    pub enable: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub forwarder: Vec<DnsForwarder>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub host: Vec<DnsHost>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DnsForwarder {
+    #[serde(rename = "@addr", skip_serializing_if = "Option::is_none")]
+    pub addr: Option<String>,
+    #[serde(rename = "@domain", skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DnsHost {
+    #[serde(rename = "@ip")]
+    pub ip: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hostname: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Ip {
+    #[serde(rename = "@family", skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
+    #[serde(rename = "@address", skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(rename = "@netmask", skip_serializing_if = "Option::is_none")]
+    pub netmask: Option<String>,
+    #[serde(rename = "@prefix", skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<u32>,
+    #[serde(rename = "@localPtr", skip_serializing_if = "Option::is_none")]
+    pub local_ptr: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dhcp: Option<Dhcp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tftp: Option<Tftp>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Dhcp {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub range: Vec<DhcpRange>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub host: Vec<DhcpHost>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bootp: Option<Bootp>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DhcpRange {
+    #[serde(rename = "@start")]
+    pub start: String,
+    #[serde(rename = "@end")]
+    pub end: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DhcpHost {
+    #[serde(rename = "@mac", skip_serializing_if = "Option::is_none")]
+    pub mac: Option<String>,
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "@name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "@ip", skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Bootp {
+    #[serde(rename = "@file")]
+    pub file: String,
+    #[serde(rename = "@server", skip_serializing_if = "Option::is_none")]
+    pub server: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Tftp {
+    #[serde(rename = "@root")]
+    pub root: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Route {
+    #[serde(rename = "@family", skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
+    #[serde(rename = "@address")]
+    pub address: String,
+    #[serde(rename = "@prefix", skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<u32>,
+    #[serde(rename = "@netmask", skip_serializing_if = "Option::is_none")]
+    pub netmask: Option<String>,
+    #[serde(rename = "@gateway")]
+    pub gateway: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename = "networkport")]
+pub struct NetworkPortDef {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    pub owner: PortOwner,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    pub mac: MacAddress,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plug: Option<PortPlug>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PortOwner {
+    pub name: String,
+    pub uuid: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PortPlug {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(rename = "@bridge", skip_serializing_if = "Option::is_none")]
+    pub bridge: Option<String>,
+    #[serde(rename = "@dev", skip_serializing_if = "Option::is_none")]
+    pub dev: Option<String>,
+    #[serde(rename = "@mode", skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    #[serde(rename = "@macTableManager", skip_serializing_if = "Option::is_none")]
+    pub mac_table_manager: Option<String>,
+}
@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename = "device")]
+pub struct NodeDeviceDef {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub devnode: Vec<DevNode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver: Option<NodeDeviceDriver>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub capability: Vec<NodeDeviceCapability>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DevNode {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(rename = "$text")]
+    pub path: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct NodeDeviceDriver {
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct NodeDeviceCapability {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hardware: Option<SystemHardware>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firmware: Option<SystemFirmware>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<u32>,
+    // Bus number for PCI and USB devices, bus name for storage devices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bus: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slot: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<IdName>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<IdName>,
+    #[serde(rename = "iommuGroup", skip_serializing_if = "Option::is_none")]
+    pub iommu_group: Option<IommuGroup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<NetLink>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique_id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lun: Option<u32>,
+    #[serde(rename = "type", default, skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<NodeDeviceType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drive_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logical_block_size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_blocks: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attr: Vec<MdevAttr>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub capability: Vec<NodeDeviceCapability>,
+}
+
+// `<type>` is plain text for SCSI devices, an id reference for mediated
+// devices and a full description inside `mdev_types`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct NodeDeviceType {
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "deviceAPI", skip_serializing_if = "Option::is_none")]
+    pub device_api: Option<String>,
+    #[serde(rename = "availableInstances", skip_serializing_if = "Option::is_none")]
+    pub available_instances: Option<u32>,
+    #[serde(rename = "$text", skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SystemHardware {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SystemFirmware {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct IdName {
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "$text", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct IommuGroup {
+    #[serde(rename = "@number")]
+    pub number: u32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct NetLink {
+    #[serde(rename = "@speed", skip_serializing_if = "Option::is_none")]
+    pub speed: Option<u32>,
+    #[serde(rename = "@state", skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MdevAttr {
+    #[serde(rename = "@name")]
+    pub name: String,
+    #[serde(rename = "@value")]
+    pub value: String,
+}
+
+impl NodeDeviceDef {
+    pub fn capability_types(&self) -> Vec<&str> {
+        self.capability.iter().map(|c| c.r#type.as_str()).collect()
+    }
+}
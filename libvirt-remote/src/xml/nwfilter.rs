@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename = "filter")]
+pub struct NwFilterDef {
+    #[serde(rename = "@name")]
+    pub name: String,
+    #[serde(rename = "@chain", skip_serializing_if = "Option::is_none")]
+    pub chain: Option<String>,
+    #[serde(rename = "@priority", skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filterref: Vec<FilterRef>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rule: Vec<Rule>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct FilterRef {
+    #[serde(rename = "@filter")]
+    pub filter: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameter: Vec<FilterParameter>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct FilterParameter {
+    #[serde(rename = "@name")]
+    pub name: String,
+    #[serde(rename = "@value")]
+    pub value: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Rule {
+    #[serde(rename = "@action")]
+    pub action: String,
+    #[serde(rename = "@direction")]
+    pub direction: String,
+    #[serde(rename = "@priority", skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+    #[serde(rename = "@statematch", skip_serializing_if = "Option::is_none")]
+    pub statematch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mac: Option<RuleMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vlan: Option<RuleMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arp: Option<RuleMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rarp: Option<RuleMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<RuleMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ipv6: Option<RuleMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tcp: Option<RuleMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub udp: Option<RuleMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icmp: Option<RuleMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all: Option<RuleMatch>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct RuleMatch {
+    #[serde(rename = "@match", skip_serializing_if = "Option::is_none")]
+    pub r#match: Option<String>,
+    #[serde(rename = "@srcmacaddr", skip_serializing_if = "Option::is_none")]
+    pub srcmacaddr: Option<String>,
+    #[serde(rename = "@dstmacaddr", skip_serializing_if = "Option::is_none")]
+    pub dstmacaddr: Option<String>,
+    #[serde(rename = "@srcipaddr", skip_serializing_if = "Option::is_none")]
+    pub srcipaddr: Option<String>,
+    #[serde(rename = "@srcipmask", skip_serializing_if = "Option::is_none")]
+    pub srcipmask: Option<String>,
+    #[serde(rename = "@dstipaddr", skip_serializing_if = "Option::is_none")]
+    pub dstipaddr: Option<String>,
+    #[serde(rename = "@dstipmask", skip_serializing_if = "Option::is_none")]
+    pub dstipmask: Option<String>,
+    #[serde(rename = "@protocolid", skip_serializing_if = "Option::is_none")]
+    pub protocolid: Option<String>,
+    #[serde(rename = "@protocol", skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    #[serde(rename = "@srcportstart", skip_serializing_if = "Option::is_none")]
+    pub srcportstart: Option<String>,
+    #[serde(rename = "@srcportend", skip_serializing_if = "Option::is_none")]
+    pub srcportend: Option<String>,
+    #[serde(rename = "@dstportstart", skip_serializing_if = "Option::is_none")]
+    pub dstportstart: Option<String>,
+    #[serde(rename = "@dstportend", skip_serializing_if = "Option::is_none")]
+    pub dstportend: Option<String>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(rename = "@code", skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(rename = "@state", skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(rename = "@comment", skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
@@ -0,0 +1,135 @@
+use super::{FormatType, Permissions, ScaledInteger};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename = "pool")]
+pub struct StoragePoolDef {
+    #[serde(rename = "@type")]
+    pub r#type: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<ScaledInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocation: Option<ScaledInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available: Option<ScaledInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<PoolSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<PoolTarget>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename = "source")]
+pub struct PoolSource {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub host: Vec<PoolSourceHost>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub device: Vec<PoolSourceDevice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dir: Option<PoolSourcePath>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adapter: Option<PoolSourceAdapter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<FormatType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initiator: Option<PoolSourceInitiator>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PoolSourceHost {
+    #[serde(rename = "@name")]
+    pub name: String,
+    #[serde(rename = "@port", skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PoolSourceDevice {
+    #[serde(rename = "@path")]
+    pub path: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PoolSourcePath {
+    #[serde(rename = "@path")]
+    pub path: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PoolSourceAdapter {
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(rename = "@name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "@parent", skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    #[serde(rename = "@wwnn", skip_serializing_if = "Option::is_none")]
+    pub wwnn: Option<String>,
+    #[serde(rename = "@wwpn", skip_serializing_if = "Option::is_none")]
+    pub wwpn: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PoolSourceInitiator {
+    pub iqn: PoolSourceIqn,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PoolSourceIqn {
+    #[serde(rename = "@name")]
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PoolTarget {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Permissions>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename = "volume")]
+pub struct StorageVolDef {
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<ScaledInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocation: Option<ScaledInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub physical: Option<ScaledInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<VolTarget>,
+    #[serde(rename = "backingStore", skip_serializing_if = "Option::is_none")]
+    pub backing_store: Option<VolBackingStore>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct VolTarget {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<FormatType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Permissions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compat: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct VolBackingStore {
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<FormatType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Permissions>,
+}
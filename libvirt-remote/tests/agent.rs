@@ -0,0 +1,328 @@
+mod common;
+
+use common::{Request, daemon, domain};
+use libvirt_remote::agent::*;
+use libvirt_remote::binding::*;
+use libvirt_remote::client::Client;
+use libvirt_remote::error::Error;
+use serde_json::{Value, json};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use QemuProcedure::*;
+
+// Agent commands received by the mock daemon, in order.
+type Commands = Arc<Mutex<Vec<Value>>>;
+
+// Answers each agent command with the JSON response `f` returns.
+fn agent<F>(mut f: F) -> (Client, Commands)
+where
+    F: FnMut(&Value) -> Value + Send + 'static,
+{
+    let commands = Commands::default();
+    let t_commands = Arc::clone(&commands);
+    let (client, _) = daemon(move |req| {
+        if req.is_qemu(QemuProcDomainAgentCommand) {
+            let args: QemuDomainAgentCommandArgs = req.args();
+            let cmd: Value = serde_json::from_str(&args.cmd).unwrap();
+            let response = f(&cmd);
+            t_commands.lock().unwrap().push(cmd);
+            req.reply(&QemuDomainAgentCommandRet {
+                result: Some(response.to_string()),
+            });
+        }
+    });
+    (client, commands)
+}
+
+fn executed(commands: &Commands) -> Vec<String> {
+    let commands = commands.lock().unwrap();
+    commands
+        .iter()
+        .map(|c| c["execute"].as_str().unwrap().to_string())
+        .collect()
+}
+
+// Fails every agent command with an RPC error of `code`.
+fn rpc_error(code: i32, message: &str) -> Error {
+    let message = message.to_string();
+    let (mut client, _) = daemon(move |req: &mut Request| {
+        if req.is_qemu(QemuProcDomainAgentCommand) {
+            req.error_code(code, &message);
+        }
+    });
+    GuestAgent::new(&mut client, domain("guest"))
+        .ping()
+        .unwrap_err()
+}
+
+fn agent_error(error: Error) -> Option<AgentError> {
+    match error {
+        Error::AgentError(e) => Some(e),
+        _ => None,
+    }
+}
+
+// Test vectors from RFC 4648 section 10.
+const VECTORS: &[(&str, &str)] = &[
+    ("", ""),
+    ("f", "Zg=="),
+    ("fo", "Zm8="),
+    ("foo", "Zm9v"),
+    ("foob", "Zm9vYg=="),
+    ("fooba", "Zm9vYmE="),
+    ("foobar", "Zm9vYmFy"),
+];
+
+#[test]
+fn base64_encode_vectors() {
+    for (plain, encoded) in VECTORS {
+        assert_eq!(base64_encode(plain.as_bytes()), *encoded, "{plain}");
+    }
+}
+
+#[test]
+fn base64_decode_vectors() {
+    for (plain, encoded) in VECTORS {
+        assert_eq!(
+            base64_decode(encoded).as_deref(),
+            Some(plain.as_bytes()),
+            "{encoded}"
+        );
+    }
+}
+
+#[test]
+fn base64_round_trip() {
+    let data: Vec<u8> = (0..=255).collect();
+    let encoded = base64_encode(&data);
+    assert!(encoded.contains('+') && encoded.contains('/'));
+    assert_eq!(base64_decode(&encoded), Some(data));
+}
+
+#[test]
+fn base64_decode_line_breaks() {
+    assert_eq!(
+        base64_decode("Zm9v\r\nYmFy\n").as_deref(),
+        Some(&b"foobar"[..])
+    );
+}
+
+#[test]
+fn base64_decode_invalid() {
+    assert_eq!(base64_decode("Zm9v!"), None);
+    assert_eq!(base64_decode("Zm 9v"), None);
+    assert_eq!(base64_decode("Zm9vY"), None);
+    assert_eq!(base64_decode("Zg==Zg=="), None);
+    assert_eq!(base64_decode("Zg==="), None);
+}
+
+#[test]
+fn execute_maps_rpc_errors() {
+    assert_eq!(
+        agent_error(rpc_error(
+            74,
+            "argument unsupported: QEMU guest agent is not configured"
+        )),
+        Some(AgentError::NotConfigured(
+            "argument unsupported: QEMU guest agent is not configured".to_string()
+        ))
+    );
+    assert_eq!(
+        agent_error(rpc_error(86, "agent unresponsive")),
+        Some(AgentError::Unavailable("agent unresponsive".to_string()))
+    );
+    assert_eq!(
+        agent_error(rpc_error(112, "timed out")),
+        Some(AgentError::Timeout("timed out".to_string()))
+    );
+    assert_eq!(
+        agent_error(rpc_error(
+            1,
+            "internal error: unable to execute QEMU agent command"
+        )),
+        Some(AgentError::CommandFailed {
+            class: None,
+            desc: "internal error: unable to execute QEMU agent command".to_string(),
+        })
+    );
+
+    // Errors unrelated to the agent are left as they are.
+    assert!(matches!(
+        rpc_error(1, "internal error"),
+        Error::ProtocolError(_)
+    ));
+}
+
+#[test]
+fn execute_maps_agent_responses() {
+    let (mut client, _) = agent(|cmd| match cmd["execute"].as_str().unwrap() {
+        "guest-ping" => json!({ "error": { "class": "GenericError", "desc": "failed" } }),
+        _ => json!({}),
+    });
+    let mut agent = GuestAgent::new(&mut client, domain("guest"));
+
+    assert_eq!(
+        agent_error(agent.ping().unwrap_err()),
+        Some(AgentError::CommandFailed {
+            class: Some("GenericError".to_string()),
+            desc: "failed".to_string(),
+        })
+    );
+    assert_eq!(
+        agent_error(agent.info().unwrap_err()),
+        Some(AgentError::InvalidResponse("{}".to_string()))
+    );
+}
+
+#[test]
+fn run_polls_until_exit() {
+    let mut polls = 0;
+    let (mut client, commands) = agent(move |cmd| match cmd["execute"].as_str().unwrap() {
+        "guest-exec" => json!({ "return": { "pid": 42 } }),
+        "guest-exec-status" => {
+            polls += 1;
+            if polls < 3 {
+                json!({ "return": { "exited": false } })
+            } else {
+                json!({ "return": { "exited": true, "exitcode": 0, "out-data": "aGk=" } })
+            }
+        }
+        _ => json!({}),
+    });
+
+    let status = GuestAgent::new(&mut client, domain("guest"))
+        .interval(Duration::from_millis(1))
+        .run(&GuestExec::new("/bin/echo").arg("hi"))
+        .unwrap();
+    assert!(status.success());
+    assert_eq!(status.out_data, b"hi");
+
+    assert_eq!(
+        executed(&commands),
+        [
+            "guest-exec",
+            "guest-exec-status",
+            "guest-exec-status",
+            "guest-exec-status"
+        ]
+    );
+    let commands = commands.lock().unwrap();
+    assert_eq!(commands[0]["arguments"]["arg"], json!(["hi"]));
+    assert_eq!(commands[1]["arguments"]["pid"], 42);
+}
+
+#[test]
+fn run_times_out() {
+    let (mut client, _) = agent(|cmd| match cmd["execute"].as_str().unwrap() {
+        "guest-exec" => json!({ "return": { "pid": 42 } }),
+        _ => json!({ "return": { "exited": false } }),
+    });
+
+    let err = GuestAgent::new(&mut client, domain("guest"))
+        .interval(Duration::from_millis(1))
+        .run_timeout(Some(Duration::from_millis(50)))
+        .run(&GuestExec::new("/bin/sleep").arg("inf"))
+        .unwrap_err();
+    assert!(matches!(agent_error(err), Some(AgentError::Timeout(_))));
+}
+
+#[test]
+fn read_file_chunks() {
+    let mut chunks = vec![("YmFy", true), ("Zm9v", false)];
+    let (mut client, commands) = agent(move |cmd| match cmd["execute"].as_str().unwrap() {
+        "guest-file-open" => json!({ "return": 5 }),
+        "guest-file-read" => {
+            let (data, eof) = chunks.pop().unwrap();
+            json!({ "return": { "count": 3, "buf-b64": data, "eof": eof } })
+        }
+        _ => json!({ "return": {} }),
+    });
+
+    let data = GuestAgent::new(&mut client, domain("guest"))
+        .read_file("/etc/hostname")
+        .unwrap();
+    assert_eq!(data, b"foobar");
+    assert_eq!(
+        executed(&commands),
+        [
+            "guest-file-open",
+            "guest-file-read",
+            "guest-file-read",
+            "guest-file-close"
+        ]
+    );
+    assert_eq!(commands.lock().unwrap()[3]["arguments"]["handle"], 5);
+}
+
+#[test]
+fn read_file_closes_on_error() {
+    let (mut client, commands) = agent(|cmd| match cmd["execute"].as_str().unwrap() {
+        "guest-file-open" => json!({ "return": 5 }),
+        "guest-file-read" => json!({ "return": { "buf-b64": "!", "eof": true } }),
+        _ => json!({ "return": {} }),
+    });
+
+    let err = GuestAgent::new(&mut client, domain("guest"))
+        .read_file("/etc/hostname")
+        .unwrap_err();
+    assert!(matches!(
+        agent_error(err),
+        Some(AgentError::InvalidResponse(_))
+    ));
+    assert_eq!(
+        executed(&commands),
+        ["guest-file-open", "guest-file-read", "guest-file-close"]
+    );
+}
+
+#[test]
+fn write_file_chunks() {
+    let written = Arc::new(Mutex::new(Vec::<u8>::new()));
+    let t_written = Arc::clone(&written);
+    let (mut client, commands) = agent(move |cmd| match cmd["execute"].as_str().unwrap() {
+        "guest-file-open" => json!({ "return": 5 }),
+        "guest-file-write" => {
+            // The agent may write less than it was given.
+            let buf = cmd["arguments"]["buf-b64"].as_str().unwrap();
+            let data = base64_decode(buf).unwrap();
+            let count = data.len().min(30000);
+            t_written.lock().unwrap().extend(&data[..count]);
+            json!({ "return": { "count": count, "eof": false } })
+        }
+        _ => json!({ "return": {} }),
+    });
+
+    let data: Vec<u8> = (0..100000).map(|i| i as u8).collect();
+    GuestAgent::new(&mut client, domain("guest"))
+        .write_file("/tmp/data", &data)
+        .unwrap();
+    assert_eq!(*written.lock().unwrap(), data);
+
+    // Chunks of 48 KiB, 48 KiB and the rest, written 30000 bytes at a time.
+    let executed = executed(&commands);
+    assert_eq!(executed.first().unwrap(), "guest-file-open");
+    assert_eq!(executed.last().unwrap(), "guest-file-close");
+    assert_eq!(executed.len(), 2 + 5);
+}
+
+#[test]
+fn write_file_closes_on_error() {
+    let (mut client, commands) = agent(|cmd| match cmd["execute"].as_str().unwrap() {
+        "guest-file-open" => json!({ "return": 5 }),
+        "guest-file-write" => json!({ "return": { "count": 0, "eof": false } }),
+        _ => json!({ "return": {} }),
+    });
+
+    let err = GuestAgent::new(&mut client, domain("guest"))
+        .write_file("/tmp/data", b"data")
+        .unwrap_err();
+    assert!(matches!(
+        agent_error(err),
+        Some(AgentError::InvalidResponse(_))
+    ));
+    assert_eq!(
+        executed(&commands),
+        ["guest-file-open", "guest-file-write", "guest-file-close"]
+    );
+}
@@ -0,0 +1,255 @@
+mod common;
+
+use common::{Request, daemon, domain, procedures};
+use libvirt_remote::backup::*;
+use libvirt_remote::binding::*;
+use libvirt_remote::error::Error;
+use libvirt_remote::job::{JobType, VIR_DOMAIN_JOB_ERRMSG, VIR_DOMAIN_JOB_STATS_COMPLETED};
+use libvirt_remote::xml::XmlDocument;
+use libvirt_remote::xml::backup::DomainBackupDef;
+use libvirt_remote::xml::checkpoint::DomainCheckpointDef;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use RemoteProcedure::*;
+
+type Begun = Arc<Mutex<Vec<RemoteDomainBackupBeginArgs>>>;
+
+fn domain_xml() -> String {
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/data/domain-minimal.xml"
+    ))
+    .to_string()
+}
+
+fn checkpoint_xml(name: &str, creation_time: i64) -> String {
+    format!(
+        "<domaincheckpoint><name>{name}</name><creationTime>{creation_time}</creationTime></domaincheckpoint>"
+    )
+}
+
+// Answers the backup flow. Existing checkpoints are given as name and
+// creation time; the job ends with `job_type`.
+fn backup_daemon(
+    begun: &Begun,
+    checkpoints: &'static [(&'static str, i64)],
+    job_type: i32,
+) -> impl FnMut(&mut Request) + Send + 'static {
+    let begun = Arc::clone(begun);
+    move |req| {
+        if req.is(RemoteProcDomainGetXmlDesc) {
+            req.reply(&RemoteDomainGetXmlDescRet { xml: domain_xml() });
+        } else if req.is(RemoteProcDomainListAllCheckpoints) {
+            let args: RemoteDomainListAllCheckpointsArgs = req.args();
+            req.reply(&RemoteDomainListAllCheckpointsRet {
+                checkpoints: checkpoints
+                    .iter()
+                    .map(|(name, _)| RemoteNonnullDomainCheckpoint {
+                        name: name.to_string(),
+                        dom: args.dom.clone(),
+                    })
+                    .collect(),
+                ret: checkpoints.len() as i32,
+            });
+        } else if req.is(RemoteProcDomainCheckpointGetXmlDesc) {
+            let args: RemoteDomainCheckpointGetXmlDescArgs = req.args();
+            let (name, creation_time) = checkpoints
+                .iter()
+                .find(|(name, _)| *name == args.checkpoint.name)
+                .unwrap();
+            req.reply(&RemoteDomainCheckpointGetXmlDescRet {
+                xml: checkpoint_xml(name, *creation_time),
+            });
+        } else if req.is(RemoteProcDomainBackupBegin) {
+            begun.lock().unwrap().push(req.args());
+            req.reply_void();
+        } else if req.is(RemoteProcDomainBackupGetXmlDesc) {
+            req.reply(&RemoteDomainBackupGetXmlDescRet {
+                xml: "<domainbackup mode='pull'><server transport='tcp' name='localhost' port='10809'/><disks><disk name='hda' backup='yes' exportname='hda'/></disks></domainbackup>".to_string(),
+            });
+        } else if req.is(RemoteProcDomainGetJobStats) {
+            let args: RemoteDomainGetJobStatsArgs = req.args();
+            if args.flags & VIR_DOMAIN_JOB_STATS_COMPLETED == 0 {
+                req.reply(&RemoteDomainGetJobStatsRet {
+                    r#type: 0,
+                    params: vec![],
+                });
+            } else {
+                req.reply(&RemoteDomainGetJobStatsRet {
+                    r#type: job_type,
+                    params: vec![RemoteTypedParam::string(VIR_DOMAIN_JOB_ERRMSG, "disk full")],
+                });
+            }
+        }
+    }
+}
+
+#[test]
+fn push_full() {
+    let begun = Begun::default();
+    let (mut client, calls) = daemon(backup_daemon(&begun, &[], 3));
+
+    let options = BackupOptions::push(Path::new("/backup"));
+    let result = backup(&mut client, domain("guest"), &options, |_| {}).unwrap();
+    assert!(result.checkpoint.starts_with(DEFAULT_CHECKPOINT_PREFIX));
+    assert_eq!(result.incremental, None);
+    assert_eq!(result.server, None);
+    assert_eq!(result.job.unwrap().job_type, JobType::Completed);
+    assert!(!procedures(&calls).contains(&(RemoteProcDomainListAllCheckpoints as i32)));
+
+    let begun = begun.lock().unwrap();
+    let backup_def = DomainBackupDef::from_xml(&begun[0].backup_xml).unwrap();
+    assert_eq!(backup_def.mode.as_deref(), Some("push"));
+    assert_eq!(backup_def.incremental, None);
+    let disks = backup_def.disks.unwrap().disk;
+    assert_eq!(disks.len(), 1);
+    assert_eq!(disks[0].name, "hda");
+    let file = disks[0].target.as_ref().unwrap().file.as_deref().unwrap();
+    assert_eq!(
+        file,
+        format!("/backup/guest.hda.{}.qcow2", result.checkpoint)
+    );
+
+    let checkpoint_def =
+        DomainCheckpointDef::from_xml(begun[0].checkpoint_xml.as_deref().unwrap()).unwrap();
+    assert_eq!(checkpoint_def.name, Some(result.checkpoint.clone()));
+}
+
+#[test]
+fn push_checkpoint_names_are_unique() {
+    let begun = Begun::default();
+    let (mut client, _) = daemon(backup_daemon(&begun, &[], 3));
+
+    let options = BackupOptions::push(Path::new("/backup")).disks(&["vda"]);
+    let first = backup(&mut client, domain("guest"), &options, |_| {}).unwrap();
+    let second = backup(&mut client, domain("guest"), &options, |_| {}).unwrap();
+    assert_ne!(first.checkpoint, second.checkpoint);
+}
+
+#[test]
+fn push_incremental() {
+    let begun = Begun::default();
+    let checkpoints = &[("backup-2", 20), ("backup-1", 10), ("other-3", 30)];
+    let (mut client, _) = daemon(backup_daemon(&begun, checkpoints, 3));
+
+    let options = BackupOptions::push(Path::new("/backup"))
+        .disks(&["vda"])
+        .incremental(true);
+    let result = backup(&mut client, domain("guest"), &options, |_| {}).unwrap();
+    assert_eq!(result.incremental.as_deref(), Some("backup-2"));
+
+    let begun = begun.lock().unwrap();
+    let backup_def = DomainBackupDef::from_xml(&begun[0].backup_xml).unwrap();
+    assert_eq!(backup_def.incremental.as_deref(), Some("backup-2"));
+}
+
+#[test]
+fn push_failed() {
+    let begun = Begun::default();
+    let (mut client, _) = daemon(backup_daemon(&begun, &[], 4));
+
+    let options = BackupOptions::push(Path::new("/backup")).disks(&["vda"]);
+    match backup(&mut client, domain("guest"), &options, |_| {}) {
+        Err(Error::BackupError(msg)) => assert_eq!(msg, "disk full"),
+        ret => panic!("unexpected result: {ret:?}"),
+    }
+}
+
+#[test]
+fn pull() {
+    let begun = Begun::default();
+    let (mut client, calls) = daemon(backup_daemon(&begun, &[], 3));
+
+    let options = BackupOptions::pull(NbdServer {
+        transport: Some("tcp".to_string()),
+        name: Some("localhost".to_string()),
+        ..Default::default()
+    })
+    .disks(&["hda"]);
+    let result = backup(&mut client, domain("guest"), &options, |_| {}).unwrap();
+    assert_eq!(result.job, None);
+
+    // The port picked by the daemon is read back.
+    let server = result.server.unwrap();
+    assert_eq!(server.port, Some(10809));
+    assert_eq!(result.disks[0].exportname.as_deref(), Some("hda"));
+
+    let begun = begun.lock().unwrap();
+    let backup_def = DomainBackupDef::from_xml(&begun[0].backup_xml).unwrap();
+    assert_eq!(backup_def.mode.as_deref(), Some("pull"));
+    assert_eq!(backup_def.server.unwrap().port, None);
+
+    // The job keeps running until the client is done reading.
+    assert!(!procedures(&calls).contains(&(RemoteProcDomainGetJobStats as i32)));
+}
+
+#[test]
+fn prune_oldest() {
+    let deleted = Arc::new(Mutex::new(vec![]));
+    let t_deleted = Arc::clone(&deleted);
+    let begun = Begun::default();
+    let checkpoints = &[
+        ("backup-a", 30),
+        ("backup-b", 10),
+        ("backup-c", 20),
+        ("other", 0),
+    ];
+    let mut handler = backup_daemon(&begun, checkpoints, 3);
+    let (mut client, _) = daemon(move |req| {
+        if req.is(RemoteProcDomainCheckpointDelete) {
+            let args: RemoteDomainCheckpointDeleteArgs = req.args();
+            t_deleted.lock().unwrap().push(args.checkpoint.name);
+            req.reply_void();
+        } else {
+            handler(req);
+        }
+    });
+
+    let pruned = prune(&mut client, domain("guest"), "backup-", 1).unwrap();
+    assert_eq!(pruned, vec!["backup-b", "backup-c"]);
+    assert_eq!(*deleted.lock().unwrap(), pruned);
+
+    deleted.lock().unwrap().clear();
+    let pruned = prune(&mut client, domain("guest"), "backup-", 5).unwrap();
+    assert!(pruned.is_empty());
+    assert!(deleted.lock().unwrap().is_empty());
+}
+
+#[test]
+fn pull_prunes_after_finish() {
+    let begun = Begun::default();
+    let checkpoints = &[("backup-a", 10), ("backup-b", 20)];
+    let mut handler = backup_daemon(&begun, checkpoints, 3);
+    let (mut client, calls) = daemon(move |req| {
+        if req.is(RemoteProcDomainCheckpointDelete) || req.is(RemoteProcDomainAbortJob) {
+            req.reply_void();
+        } else {
+            handler(req);
+        }
+    });
+
+    let options = BackupOptions::pull(NbdServer::default())
+        .disks(&["hda"])
+        .incremental(true)
+        .retention(1);
+    let result = backup(&mut client, domain("guest"), &options, |_| {}).unwrap();
+    assert_eq!(result.incremental.as_deref(), Some("backup-b"));
+
+    // The running job still reads from the checkpoint it is based on.
+    assert!(!procedures(&calls).contains(&(RemoteProcDomainCheckpointDelete as i32)));
+
+    let pruned = finish_pull(&mut client, domain("guest"), &options).unwrap();
+    assert_eq!(pruned, vec!["backup-a"]);
+
+    let calls = procedures(&calls);
+    let abort = calls
+        .iter()
+        .position(|p| *p == RemoteProcDomainAbortJob as i32)
+        .unwrap();
+    let delete = calls
+        .iter()
+        .position(|p| *p == RemoteProcDomainCheckpointDelete as i32)
+        .unwrap();
+    assert!(abort < delete);
+}
@@ -0,0 +1,125 @@
+mod common;
+
+use common::{daemon, domain, procedures};
+use libvirt_remote::binding::*;
+use libvirt_remote::block_job::{BlockJob, BlockJobStatus, VIR_DOMAIN_EVENT_ID_BLOCK_JOB_2};
+use libvirt_remote::client::Libvirt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use RemoteProcedure::*;
+
+const DISK_PATH: &str = "/dev/HostVG/QEMUGuest1";
+
+fn domain_xml() -> String {
+    include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/data/domain-minimal.xml"
+    ))
+    .to_string()
+}
+
+#[test]
+fn job_keeps_foreign_events() {
+    let deregistered = Arc::new(Mutex::new(vec![]));
+    let t_deregistered = Arc::clone(&deregistered);
+    let (mut client, _) = daemon(move |req| {
+        if req.is(RemoteProcDomainGetXmlDesc) {
+            req.reply(&RemoteDomainGetXmlDescRet { xml: domain_xml() });
+        } else if req.is(RemoteProcConnectDomainEventCallbackRegisterAny) {
+            let args: RemoteConnectDomainEventCallbackRegisterAnyArgs = req.args();
+            req.reply(&RemoteConnectDomainEventCallbackRegisterAnyRet {
+                callback_id: 10 + args.event_id,
+            });
+        } else if req.is(RemoteProcConnectDomainEventCallbackDeregisterAny) {
+            let args: RemoteConnectDomainEventCallbackDeregisterAnyArgs = req.args();
+            t_deregistered.lock().unwrap().push(args.callback_id);
+            req.reply_void();
+        } else if req.is(RemoteProcDomainBlockPull) {
+            let args: RemoteDomainBlockPullArgs = req.args();
+            assert_eq!(args.path, DISK_PATH);
+            req.reply_void();
+            // An event of another consumer arrives before the job ends.
+            req.event(
+                RemoteProcDomainEventCallbackLifecycle,
+                &RemoteDomainEventCallbackLifecycleMsg {
+                    callback_id: 99,
+                    msg: RemoteDomainEventLifecycleMsg {
+                        dom: domain("other"),
+                        event: 2,
+                        detail: 0,
+                    },
+                },
+            );
+            // The event names the disk by its target, not by the path the
+            // job was started with.
+            req.event(
+                RemoteProcDomainEventBlockJob2,
+                &RemoteDomainEventBlockJob2Msg {
+                    callback_id: 10 + VIR_DOMAIN_EVENT_ID_BLOCK_JOB_2,
+                    dom: domain("guest"),
+                    dst: "hda".to_string(),
+                    r#type: 1,
+                    status: 0,
+                },
+            );
+        }
+    });
+
+    {
+        let mut job = BlockJob::pull(&mut client, domain("guest"), DISK_PATH, 0, 0)
+            .unwrap()
+            .interval(Duration::from_secs(5));
+        let status = job
+            .wait(|_| panic!("polled instead of seeing the event"))
+            .unwrap();
+        assert_eq!(status, BlockJobStatus::Completed);
+    }
+
+    // Dropping the job deregistered its callback.
+    assert_eq!(
+        *deregistered.lock().unwrap(),
+        vec![10 + VIR_DOMAIN_EVENT_ID_BLOCK_JOB_2]
+    );
+
+    let raw = client.get_event(Duration::from_secs(1)).unwrap();
+    assert_eq!(
+        raw.procedure(),
+        RemoteProcDomainEventCallbackLifecycle as i32
+    );
+}
+
+#[test]
+fn job_deregisters_when_start_fails() {
+    let (mut client, calls) = daemon(|req| {
+        if req.is(RemoteProcConnectDomainEventCallbackRegisterAny) {
+            req.reply(&RemoteConnectDomainEventCallbackRegisterAnyRet { callback_id: 1 });
+        } else if req.is(RemoteProcConnectDomainEventCallbackDeregisterAny) {
+            req.reply_void();
+        } else if req.is(RemoteProcDomainBlockPull) {
+            req.error("disk is busy");
+        }
+    });
+
+    assert!(BlockJob::pull(&mut client, domain("guest"), "vda", 0, 0).is_err());
+    assert_eq!(
+        procedures(&calls),
+        vec![
+            RemoteProcConnectDomainEventCallbackRegisterAny as i32,
+            RemoteProcDomainBlockPull as i32,
+            RemoteProcConnectDomainEventCallbackDeregisterAny as i32,
+        ]
+    );
+}
+
+#[test]
+fn job_rejects_unknown_path() {
+    let (mut client, calls) = daemon(|req| {
+        if req.is(RemoteProcDomainGetXmlDesc) {
+            req.reply(&RemoteDomainGetXmlDescRet { xml: domain_xml() });
+        }
+    });
+
+    assert!(BlockJob::pull(&mut client, domain("guest"), "/no/such/disk", 0, 0).is_err());
+    assert_eq!(procedures(&calls), vec![RemoteProcDomainGetXmlDesc as i32]);
+}
@@ -0,0 +1,156 @@
+mod common;
+
+use common::{daemon, domain};
+use libvirt_remote::binding::*;
+use libvirt_remote::client::{Client, Libvirt, ReadWrite, read_packet};
+use libvirt_remote::error::Error;
+use libvirt_remote::protocol::VIR_NET_MESSAGE_MAX;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::os::unix::io::AsRawFd;
+use std::os::unix::net::UnixStream;
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::Duration;
+
+// A packet length of 8 cannot even hold the header.
+const SHORT: u32 = 8;
+const OVERSIZED: u32 = VIR_NET_MESSAGE_MAX + 1;
+
+// Returns the length a packet was rejected for, if it was.
+fn rejected_length(len: u32) -> Option<usize> {
+    let (a, mut b) = UnixStream::pair().unwrap();
+    b.write_all(&len.to_be_bytes()).unwrap();
+    let mut socket: Box<dyn ReadWrite> = Box::new(a);
+    match read_packet(&mut socket) {
+        Err(Error::PacketLengthError(len)) => Some(len),
+        _ => None,
+    }
+}
+
+#[test]
+fn read_short_packet() {
+    assert_eq!(rejected_length(SHORT), Some(SHORT as usize));
+}
+
+#[test]
+fn read_oversized_packet() {
+    assert_eq!(rejected_length(OVERSIZED), Some(OVERSIZED as usize));
+}
+
+// Answers a call with a bad packet length and checks the waiting call is
+// failed rather than left blocking.
+fn answer_with_length(len: u32) {
+    let (mut client, _) = daemon(move |req| {
+        if req.is(RemoteProcedure::RemoteProcConnectGetLibVersion) {
+            req.raw(&len.to_be_bytes());
+        }
+    });
+
+    assert!(matches!(
+        client.connect_get_lib_version(),
+        Err(Error::ConnectionClosedError)
+    ));
+    assert!(!client.receiver_running());
+    assert!(matches!(
+        client.connect_get_lib_version(),
+        Err(Error::ReceiverNotStartedError)
+    ));
+}
+
+#[test]
+fn receiver_stops_on_short_packet() {
+    answer_with_length(SHORT);
+}
+
+#[test]
+fn receiver_stops_on_oversized_packet() {
+    answer_with_length(OVERSIZED);
+}
+
+// Runs `f` on another thread and fails the test if it does not finish in time.
+fn within<F: FnOnce() + Send + 'static>(f: F) {
+    let (tx, rx) = channel();
+    thread::spawn(move || {
+        f();
+        let _ = tx.send(());
+    });
+    rx.recv_timeout(Duration::from_secs(5))
+        .expect("did not finish in time");
+}
+
+#[test]
+fn fin_with_silent_peer() {
+    // The peer keeps the connection open but never sends anything.
+    let (a, _peer) = UnixStream::pair().unwrap();
+    let client = Client::new(a).unwrap();
+    let other = client.try_clone().unwrap();
+
+    // Only the last handle stops the receiver.
+    within(move || client.fin().unwrap());
+    assert!(other.receiver_running());
+    within(move || other.fin().unwrap());
+}
+
+#[test]
+fn drop_with_silent_peer() {
+    let (a, _peer) = UnixStream::pair().unwrap();
+    let client = Client::new(a).unwrap();
+    within(move || drop(client));
+}
+
+fn monitor_with_files() -> Client {
+    let (client, _) = daemon(|req| {
+        if req.is_qemu(QemuProcedure::QemuProcDomainMonitorCommandWithFiles) {
+            let args: QemuDomainMonitorCommandWithFilesArgs = req.args();
+            // Writes the command through each descriptor the client passed.
+            for file in req.files.drain(..) {
+                File::from(file).write_all(args.cmd.as_bytes()).unwrap();
+            }
+            req.reply(&QemuDomainMonitorCommandWithFilesRet {
+                result: "{}".to_string(),
+            });
+        }
+    });
+    client
+}
+
+#[test]
+fn call_passes_files() {
+    let mut client = monitor_with_files();
+    let (mut a, b) = UnixStream::pair().unwrap();
+    let (mut c, d) = UnixStream::pair().unwrap();
+
+    let files = [b.as_raw_fd(), d.as_raw_fd()];
+    let ret = client
+        .domain_monitor_command_with_files(domain("guest"), "add-fd".to_string(), 0, &files)
+        .unwrap();
+    assert_eq!(ret, "{}");
+
+    for peer in [&mut a, &mut c] {
+        let mut buf = [0; 6];
+        peer.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"add-fd");
+    }
+}
+
+#[test]
+fn call_passes_no_files() {
+    let mut client = monitor_with_files();
+    let ret = client
+        .domain_monitor_command_with_files(domain("guest"), "query-fdsets".to_string(), 0, &[])
+        .unwrap();
+    assert_eq!(ret, "{}");
+}
+
+#[test]
+fn call_passes_too_many_files() {
+    let mut client = monitor_with_files();
+    let (_a, b) = UnixStream::pair().unwrap();
+    let files = vec![b.as_raw_fd(); 33];
+    assert!(matches!(
+        client.domain_monitor_command_with_files(domain("guest"), "add-fd".to_string(), 0, &files),
+        Err(Error::SendError(_))
+    ));
+    assert!(client.receiver_running());
+}
@@ -0,0 +1,276 @@
+#![allow(dead_code)]
+
+use libvirt_remote::binding::{
+    QEMU_PROGRAM, QEMU_PROTOCOL_VERSION, QemuProcedure, REMOTE_PROGRAM, REMOTE_PROTOCOL_VERSION,
+    RemoteNonnullDomain, RemoteProcedure,
+};
+use libvirt_remote::client::Client;
+use libvirt_remote::protocol::{
+    VIR_NET_MESSAGE_HEADER_MAX, VirNetMessageError, VirNetMessageHeader, VirNetMessageStatus,
+    VirNetMessageType,
+};
+use serde::{Serialize, de::DeserializeOwned};
+use std::io::{Read, Write};
+use std::net::Shutdown;
+use std::os::fd::{FromRawFd, OwnedFd};
+use std::os::unix::io::AsRawFd;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// Records the procedures a mock daemon received, in order.
+pub type Calls = Arc<Mutex<Vec<i32>>>;
+
+// One request received by the mock daemon, and the means to answer it.
+pub struct Request<'a> {
+    pub procedure: i32,
+    pub body: &'a [u8],
+    // Descriptors passed along with the call.
+    pub files: Vec<OwnedFd>,
+    header: VirNetMessageHeader,
+    socket: &'a mut UnixStream,
+    replied: bool,
+}
+
+impl Request<'_> {
+    pub fn is(&self, procedure: RemoteProcedure) -> bool {
+        self.header.prog == REMOTE_PROGRAM && self.procedure == procedure as i32
+    }
+
+    pub fn is_qemu(&self, procedure: QemuProcedure) -> bool {
+        self.header.prog == QEMU_PROGRAM && self.procedure == procedure as i32
+    }
+
+    pub fn args<T: DeserializeOwned>(&self) -> T {
+        serde_xdr::from_bytes(self.body).expect("decode arguments")
+    }
+
+    pub fn reply<T: Serialize>(&mut self, ret: &T) {
+        let body = serde_xdr::to_bytes(ret).expect("encode reply");
+        self.send(VirNetMessageStatus::VirNetOk, Some(body));
+    }
+
+    pub fn reply_void(&mut self) {
+        self.send(VirNetMessageStatus::VirNetOk, None);
+    }
+
+    pub fn error(&mut self, message: &str) {
+        self.error_code(1, message);
+    }
+
+    pub fn error_code(&mut self, code: i32, message: &str) {
+        let err = VirNetMessageError {
+            code,
+            message: Some(message.to_string()),
+            level: 2,
+            ..Default::default()
+        };
+        let body = serde_xdr::to_bytes(&err).expect("encode error");
+        self.send(VirNetMessageStatus::VirNetError, Some(body));
+    }
+
+    // Sends an event message which is not the answer to any call.
+    pub fn event<T: Serialize>(&mut self, procedure: RemoteProcedure, msg: &T) {
+        self.send_event(
+            REMOTE_PROGRAM,
+            REMOTE_PROTOCOL_VERSION,
+            procedure as i32,
+            msg,
+        );
+    }
+
+    pub fn qemu_event<T: Serialize>(&mut self, procedure: QemuProcedure, msg: &T) {
+        self.send_event(QEMU_PROGRAM, QEMU_PROTOCOL_VERSION, procedure as i32, msg);
+    }
+
+    fn send_event<T: Serialize>(&mut self, prog: u32, vers: u32, proc: i32, msg: &T) {
+        let header = VirNetMessageHeader {
+            prog,
+            vers,
+            proc,
+            r#type: VirNetMessageType::VirNetMessage,
+            serial: 0,
+            status: VirNetMessageStatus::VirNetOk,
+        };
+        let body = serde_xdr::to_bytes(msg).expect("encode event");
+        write_packet(self.socket, &header, Some(body));
+    }
+
+    pub fn is_stream(&self) -> bool {
+        self.header.r#type == VirNetMessageType::VirNetStream
+    }
+
+    // Sends data on the stream opened by this call.
+    pub fn stream(&mut self, data: &[u8]) {
+        self.send_stream(VirNetMessageStatus::VirNetContinue, Some(data.to_vec()));
+    }
+
+    pub fn stream_end(&mut self) {
+        self.send_stream(VirNetMessageStatus::VirNetOk, None);
+    }
+
+    fn send_stream(&mut self, status: VirNetMessageStatus, body: Option<Vec<u8>>) {
+        let header = VirNetMessageHeader {
+            r#type: VirNetMessageType::VirNetStream,
+            status,
+            ..self.header.clone()
+        };
+        write_packet(self.socket, &header, body);
+    }
+
+    // Writes bytes as they are, such as a malformed packet.
+    pub fn raw(&mut self, bytes: &[u8]) {
+        let _ = self.socket.write_all(bytes);
+        self.replied = true;
+    }
+
+    // Closes the connection as a daemon which went away would.
+    pub fn hang_up(&mut self) {
+        let _ = self.socket.shutdown(Shutdown::Both);
+        self.replied = true;
+    }
+
+    fn send(&mut self, status: VirNetMessageStatus, body: Option<Vec<u8>>) {
+        let mut r#type = VirNetMessageType::VirNetReply;
+        let mut body = body;
+        // Successful replies to calls with descriptors tell that none follow.
+        if self.header.r#type == VirNetMessageType::VirNetCallWithFds
+            && status == VirNetMessageStatus::VirNetOk
+        {
+            r#type = VirNetMessageType::VirNetReplyWithFds;
+            let mut fds_body = 0u32.to_be_bytes().to_vec();
+            fds_body.extend(body.unwrap_or_default());
+            body = Some(fds_body);
+        }
+        let header = VirNetMessageHeader {
+            r#type,
+            status,
+            ..self.header.clone()
+        };
+        write_packet(self.socket, &header, body);
+        self.replied = true;
+    }
+}
+
+// Starts a daemon on one end of a socket pair and returns a client connected
+// to it. Requests the handler leaves unanswered are rejected with an error.
+pub fn daemon<F>(mut handler: F) -> (Client, Calls)
+where
+    F: FnMut(&mut Request) + Send + 'static,
+{
+    let (client, server) = UnixStream::pair().expect("socket pair");
+    let calls = Calls::default();
+    let t_calls = Arc::clone(&calls);
+    thread::spawn(move || serve(server, &t_calls, &mut handler));
+    (Client::new(client).expect("client"), calls)
+}
+
+// Starts a daemon listening on a socket at `path`, for code which opens its
+// own connections. Connections are served one after another.
+pub fn listen<F>(path: &Path, mut handler: F) -> Calls
+where
+    F: FnMut(&mut Request) + Send + 'static,
+{
+    let _ = std::fs::remove_file(path);
+    let listener = UnixListener::bind(path).expect("bind socket");
+    let calls = Calls::default();
+    let t_calls = Arc::clone(&calls);
+    thread::spawn(move || {
+        for server in listener.incoming() {
+            let Ok(server) = server else {
+                break;
+            };
+            serve(server, &t_calls, &mut handler);
+        }
+    });
+    calls
+}
+
+pub fn domain(name: &str) -> RemoteNonnullDomain {
+    RemoteNonnullDomain {
+        name: name.to_string(),
+        uuid: [1; 16],
+        id: 1,
+    }
+}
+
+pub fn procedures(calls: &Calls) -> Vec<i32> {
+    calls.lock().unwrap().clone()
+}
+
+fn serve<F>(mut server: UnixStream, calls: &Calls, handler: &mut F)
+where
+    F: FnMut(&mut Request),
+{
+    while let Some((header, body)) = read_packet(&mut server) {
+        calls.lock().unwrap().push(header.proc);
+        let (body, files) = if header.r#type == VirNetMessageType::VirNetCallWithFds {
+            let nfds = u32::from_be_bytes(body[..4].try_into().unwrap());
+            let files = (0..nfds).map(|_| recv_fd(&server)).collect();
+            (&body[4..], files)
+        } else {
+            (&body[..], vec![])
+        };
+        let mut req = Request {
+            procedure: header.proc,
+            body,
+            files,
+            header,
+            socket: &mut server,
+            replied: false,
+        };
+        handler(&mut req);
+        // Stream packets from the client are not answered.
+        if !req.replied && !req.is_stream() {
+            req.error("unexpected procedure");
+        }
+    }
+}
+
+fn read_packet(socket: &mut UnixStream) -> Option<(VirNetMessageHeader, Vec<u8>)> {
+    let mut len = [0; 4];
+    socket.read_exact(&mut len).ok()?;
+    let len = u32::from_be_bytes(len) as usize;
+    let mut header = [0; VIR_NET_MESSAGE_HEADER_MAX as usize];
+    socket.read_exact(&mut header).ok()?;
+    let header = serde_xdr::from_bytes(&header).ok()?;
+    let mut body = vec![0; len - 4 - VIR_NET_MESSAGE_HEADER_MAX as usize];
+    socket.read_exact(&mut body).ok()?;
+    Some((header, body))
+}
+
+fn write_packet(socket: &mut UnixStream, header: &VirNetMessageHeader, body: Option<Vec<u8>>) {
+    let header = serde_xdr::to_bytes(header).expect("encode header");
+    let body = body.unwrap_or_default();
+    let len = (4 + header.len() + body.len()) as u32;
+    let mut packet = len.to_be_bytes().to_vec();
+    packet.extend(header);
+    packet.extend(body);
+    // The client may already have hung up in tests which end early.
+    let _ = socket.write_all(&packet);
+}
+
+// Receives a descriptor passed as SCM_RIGHTS ancillary data on one byte.
+fn recv_fd(socket: &UnixStream) -> OwnedFd {
+    let mut byte = [0u8; 1];
+    let mut iov = libc::iovec {
+        iov_base: byte.as_mut_ptr().cast(),
+        iov_len: byte.len(),
+    };
+    unsafe {
+        let space = libc::CMSG_SPACE(size_of::<i32>() as u32) as usize;
+        let mut control = vec![0u64; space.div_ceil(size_of::<u64>())];
+        let mut msg: libc::msghdr = std::mem::zeroed();
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = control.as_mut_ptr().cast();
+        msg.msg_controllen = space as _;
+        assert_eq!(libc::recvmsg(socket.as_raw_fd(), &mut msg, 0), 1);
+        let cmsg = libc::CMSG_FIRSTHDR(&msg);
+        assert!(!cmsg.is_null());
+        assert_eq!((*cmsg).cmsg_type, libc::SCM_RIGHTS);
+        let fd = std::ptr::read_unaligned(libc::CMSG_DATA(cmsg).cast::<i32>());
+        OwnedFd::from_raw_fd(fd)
+    }
+}
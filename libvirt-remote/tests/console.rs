@@ -0,0 +1,105 @@
+mod common;
+
+use common::{Request, daemon, domain};
+use libvirt_remote::binding::*;
+use libvirt_remote::console::*;
+use std::io::{Cursor, ErrorKind, Read};
+use std::sync::mpsc::{Receiver, RecvTimeoutError, channel};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use RemoteProcedure::*;
+
+// Input which waits a while for the test to hand it data, like a terminal.
+struct Terminal(Receiver<Vec<u8>>);
+
+impl Read for Terminal {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self.0.recv_timeout(Duration::from_millis(10)) {
+            Ok(data) => {
+                buf[..data.len()].copy_from_slice(&data);
+                Ok(data.len())
+            }
+            Err(RecvTimeoutError::Timeout) => Err(ErrorKind::TimedOut.into()),
+            Err(RecvTimeoutError::Disconnected) => Ok(0),
+        }
+    }
+}
+
+// Non-blocking input which never has data.
+struct Idle;
+
+impl Read for Idle {
+    fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+        Err(ErrorKind::WouldBlock.into())
+    }
+}
+
+// Answers the console call with `f` and records the data the client sends.
+fn console<F>(uploaded: &Arc<Mutex<Vec<u8>>>, mut f: F) -> impl FnMut(&mut Request) + Send + 'static
+where
+    F: FnMut(&mut Request) + Send + 'static,
+{
+    let uploaded = Arc::clone(uploaded);
+    move |req| {
+        if req.is_stream() {
+            uploaded.lock().unwrap().extend_from_slice(req.body);
+        } else if req.is(RemoteProcDomainOpenConsole) {
+            req.reply_void();
+            f(req);
+        }
+    }
+}
+
+fn wait_until<F: Fn() -> bool>(f: F) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while !f() && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn escape_ends_session() {
+    let uploaded = Arc::new(Mutex::new(vec![]));
+    let (mut client, _) = daemon(console(&uploaded, |_| {}));
+
+    let session = ConsoleSession::open_console(&mut client, domain("guest"), None, 0).unwrap();
+    let input = Cursor::new(b"root\x1dignored".to_vec());
+    let exit = session.run(input, std::io::sink()).unwrap();
+    assert_eq!(exit, ConsoleExit::Escape);
+
+    // Only the input before the escape character was sent.
+    wait_until(|| uploaded.lock().unwrap().len() >= 4);
+    assert_eq!(*uploaded.lock().unwrap(), b"root");
+}
+
+#[test]
+fn stream_closed_stops_input() {
+    let uploaded = Arc::new(Mutex::new(vec![]));
+    let (mut client, _) = daemon(console(&uploaded, |req| {
+        req.stream(b"bye");
+        req.stream_end();
+    }));
+
+    let (tx, rx) = channel();
+    let mut output = vec![];
+    let session = ConsoleSession::open_console(&mut client, domain("guest"), None, 0).unwrap();
+    let exit = session.run(Terminal(rx), &mut output).unwrap();
+    assert_eq!(exit, ConsoleExit::StreamClosed);
+    assert_eq!(output, b"bye");
+
+    // The input thread has ended and dropped the terminal.
+    assert!(tx.send(b"late".to_vec()).is_err());
+    assert!(uploaded.lock().unwrap().is_empty());
+}
+
+#[test]
+fn stream_closed_stops_non_blocking_input() {
+    let uploaded = Arc::new(Mutex::new(vec![]));
+    let (mut client, _) = daemon(console(&uploaded, |req| req.stream_end()));
+
+    let session = ConsoleSession::open_console(&mut client, domain("guest"), None, 0).unwrap();
+    let exit = session.run(Idle, std::io::sink()).unwrap();
+    assert_eq!(exit, ConsoleExit::StreamClosed);
+}
@@ -0,0 +1,155 @@
+mod common;
+
+use common::{daemon, domain, procedures};
+use libvirt_remote::binding::*;
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::error::Error;
+use libvirt_remote::event::VIR_DOMAIN_EVENT_ID_JOB_COMPLETED;
+use libvirt_remote::job::{JobType, JobWatcher};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use RemoteProcedure::*;
+
+#[test]
+fn watcher_keeps_foreign_events() {
+    let deregistered = Arc::new(Mutex::new(vec![]));
+    let t_deregistered = Arc::clone(&deregistered);
+    let (mut client, calls) = daemon(move |req| {
+        if req.is(RemoteProcConnectDomainEventCallbackRegisterAny) {
+            let args: RemoteConnectDomainEventCallbackRegisterAnyArgs = req.args();
+            req.reply(&RemoteConnectDomainEventCallbackRegisterAnyRet {
+                callback_id: 10 + args.event_id,
+            });
+        } else if req.is(RemoteProcConnectDomainEventCallbackDeregisterAny) {
+            let args: RemoteConnectDomainEventCallbackDeregisterAnyArgs = req.args();
+            t_deregistered.lock().unwrap().push(args.callback_id);
+            req.reply_void();
+        } else if req.is(RemoteProcDomainGetJobStats) {
+            req.reply(&RemoteDomainGetJobStatsRet {
+                r#type: 1,
+                params: vec![],
+            });
+            // An event of another consumer arrives before the job ends.
+            req.event(
+                RemoteProcDomainEventCallbackLifecycle,
+                &RemoteDomainEventCallbackLifecycleMsg {
+                    callback_id: 99,
+                    msg: RemoteDomainEventLifecycleMsg {
+                        dom: domain("other"),
+                        event: 2,
+                        detail: 0,
+                    },
+                },
+            );
+            req.event(
+                RemoteProcDomainEventCallbackJobCompleted,
+                &RemoteDomainEventCallbackJobCompletedMsg {
+                    callback_id: 10 + VIR_DOMAIN_EVENT_ID_JOB_COMPLETED,
+                    dom: domain("guest"),
+                    params: vec![],
+                },
+            );
+        }
+    });
+
+    {
+        let mut watcher = JobWatcher::new(&mut client, domain("guest"))
+            .interval(Duration::from_secs(5))
+            .events()
+            .unwrap();
+        let p = watcher.next().unwrap().unwrap();
+        assert_eq!(p.job_type, JobType::Bounded);
+        let p = watcher.next().unwrap().unwrap();
+        assert_eq!(p.job_type, JobType::Completed);
+        assert!(watcher.next().is_none());
+    }
+
+    // Dropping the watcher deregistered both of its callbacks.
+    let mut ids = deregistered.lock().unwrap().clone();
+    ids.sort();
+    assert_eq!(ids, vec![30, 31]);
+    assert!(
+        procedures(&calls).contains(&(RemoteProcConnectDomainEventCallbackDeregisterAny as i32))
+    );
+
+    let raw = client.get_event(Duration::from_secs(1)).unwrap();
+    assert_eq!(
+        raw.procedure(),
+        RemoteProcDomainEventCallbackLifecycle as i32
+    );
+}
+
+#[test]
+fn watcher_detects_closed_connection() {
+    let (mut client, _) = daemon(move |req| {
+        if req.is(RemoteProcConnectDomainEventCallbackRegisterAny) {
+            let args: RemoteConnectDomainEventCallbackRegisterAnyArgs = req.args();
+            req.reply(&RemoteConnectDomainEventCallbackRegisterAnyRet {
+                callback_id: 10 + args.event_id,
+            });
+        } else if req.is(RemoteProcDomainGetJobStats) {
+            req.reply(&RemoteDomainGetJobStatsRet {
+                r#type: 1,
+                params: vec![],
+            });
+            req.hang_up();
+        }
+    });
+
+    let mut watcher = JobWatcher::new(&mut client, domain("guest"))
+        .interval(Duration::from_millis(200))
+        .events()
+        .unwrap();
+    let p = watcher.next().unwrap().unwrap();
+    assert_eq!(p.job_type, JobType::Bounded);
+    assert!(matches!(
+        watcher.next(),
+        Some(Err(Error::ConnectionClosedError))
+    ));
+    assert!(watcher.next().is_none());
+}
+
+#[test]
+fn watcher_keeps_event_sent_with_registration() {
+    let (mut client, _) = daemon(move |req| {
+        if req.is(RemoteProcConnectDomainEventCallbackRegisterAny) {
+            let args: RemoteConnectDomainEventCallbackRegisterAnyArgs = req.args();
+            req.reply(&RemoteConnectDomainEventCallbackRegisterAnyRet {
+                callback_id: 10 + args.event_id,
+            });
+            // The job ends right after the callback is registered.
+            if args.event_id == VIR_DOMAIN_EVENT_ID_JOB_COMPLETED {
+                req.event(
+                    RemoteProcDomainEventCallbackJobCompleted,
+                    &RemoteDomainEventCallbackJobCompletedMsg {
+                        callback_id: 10 + VIR_DOMAIN_EVENT_ID_JOB_COMPLETED,
+                        dom: domain("guest"),
+                        params: vec![],
+                    },
+                );
+            }
+        } else if req.is(RemoteProcConnectDomainEventCallbackDeregisterAny) {
+            req.reply_void();
+        } else if req.is(RemoteProcDomainGetJobStats) {
+            req.reply(&RemoteDomainGetJobStatsRet {
+                r#type: 1,
+                params: vec![],
+            });
+        }
+    });
+
+    let started = Instant::now();
+    {
+        let mut watcher = JobWatcher::new(&mut client, domain("guest"))
+            .interval(Duration::from_secs(5))
+            .events()
+            .unwrap();
+        let p = watcher.next().unwrap().unwrap();
+        assert_eq!(p.job_type, JobType::Bounded);
+        let p = watcher.next().unwrap().unwrap();
+        assert_eq!(p.job_type, JobType::Completed);
+    }
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert!(client.get_event(Duration::from_millis(100)).is_err());
+}
@@ -0,0 +1,185 @@
+mod common;
+
+use common::{Request, daemon, domain, procedures};
+use libvirt_remote::binding::*;
+use libvirt_remote::migrate::*;
+use libvirt_remote::param;
+use std::sync::{Arc, Mutex};
+
+use RemoteProcedure::*;
+
+fn source(req: &mut Request) {
+    if req.is(RemoteProcDomainMigrateBegin3Params) {
+        req.reply(&RemoteDomainMigrateBegin3ParamsRet {
+            cookie_out: b"begin".to_vec(),
+            xml: "<domain/>".to_string(),
+        });
+    } else if req.is(RemoteProcDomainMigratePerform3Params) {
+        let args: RemoteDomainMigratePerform3ParamsArgs = req.args();
+        if args.dom.name == "broken" {
+            req.error("migration failed");
+        } else {
+            req.reply(&RemoteDomainMigratePerform3ParamsRet {
+                cookie_out: args.cookie_in,
+            });
+        }
+    } else if req.is(RemoteProcDomainMigrateConfirm3Params) {
+        req.reply_void();
+    } else if req.is(RemoteProcDomainGetJobStats) {
+        req.reply(&RemoteDomainGetJobStatsRet {
+            r#type: 2,
+            params: vec![],
+        });
+    }
+}
+
+// Records the `cancelled` argument of each confirm phase, which the test
+// thread checks since the orchestrator only warns about confirm errors.
+fn confirming_source(
+    cancelled: &Arc<Mutex<Vec<i32>>>,
+) -> impl FnMut(&mut Request) + Send + 'static {
+    let cancelled = Arc::clone(cancelled);
+    move |req| {
+        if req.is(RemoteProcDomainMigrateConfirm3Params) {
+            let args: RemoteDomainMigrateConfirm3ParamsArgs = req.args();
+            cancelled.lock().unwrap().push(args.cancelled);
+        }
+        source(req);
+    }
+}
+
+fn destination(req: &mut Request) {
+    if req.is(RemoteProcDomainMigratePrepare3Params) {
+        let args: RemoteDomainMigratePrepare3ParamsArgs = req.args();
+        assert_eq!(args.cookie_in, b"begin");
+        assert_eq!(
+            param::get_str(&args.params, VIR_MIGRATE_PARAM_DEST_XML),
+            Some("<domain/>")
+        );
+        req.reply(&RemoteDomainMigratePrepare3ParamsRet {
+            cookie_out: b"prepare".to_vec(),
+            uri_out: Some("tcp://dst:49152".to_string()),
+        });
+    } else if req.is(RemoteProcDomainMigrateFinish3Params) {
+        let args: RemoteDomainMigrateFinish3ParamsArgs = req.args();
+        let cancelled = args.cancelled != 0;
+        if cancelled {
+            req.error("migration cancelled");
+        } else {
+            assert_eq!(args.cookie_in, b"prepare");
+            req.reply(&RemoteDomainMigrateFinish3ParamsRet {
+                dom: domain("guest"),
+                cookie_out: b"finish".to_vec(),
+            });
+        }
+    } else if req.is(RemoteProcConnectGetUri) {
+        req.reply(&RemoteConnectGetUriRet {
+            uri: "qemu+tcp://dst/system".to_string(),
+        });
+    } else if req.is(RemoteProcDomainLookupByName) {
+        let args: RemoteDomainLookupByNameArgs = req.args();
+        req.reply(&RemoteDomainLookupByNameRet {
+            dom: domain(&args.name),
+        });
+    }
+}
+
+#[test]
+fn direct() {
+    let cancelled = Arc::new(Mutex::new(vec![]));
+    let (mut src, src_calls) = daemon(confirming_source(&cancelled));
+    let (mut dst, dst_calls) = daemon(destination);
+
+    let params = MigrateParams::new(VIR_MIGRATE_LIVE);
+    let ddom = migrate(&mut src, &mut dst, domain("guest"), &params).unwrap();
+    assert_eq!(ddom.name, "guest");
+
+    let src_calls: Vec<i32> = procedures(&src_calls)
+        .into_iter()
+        .filter(|p| *p != RemoteProcDomainGetJobStats as i32)
+        .collect();
+    assert_eq!(
+        src_calls,
+        vec![
+            RemoteProcDomainMigrateBegin3Params as i32,
+            RemoteProcDomainMigratePerform3Params as i32,
+            RemoteProcDomainMigrateConfirm3Params as i32,
+        ]
+    );
+    assert_eq!(
+        procedures(&dst_calls),
+        vec![
+            RemoteProcDomainMigratePrepare3Params as i32,
+            RemoteProcDomainMigrateFinish3Params as i32,
+        ]
+    );
+    assert_eq!(*cancelled.lock().unwrap(), vec![0]);
+}
+
+#[test]
+fn direct_perform_failure() {
+    let cancelled = Arc::new(Mutex::new(vec![]));
+    let (mut src, src_calls) = daemon(confirming_source(&cancelled));
+    let (mut dst, dst_calls) = daemon(destination);
+
+    let params = MigrateParams::new(VIR_MIGRATE_LIVE);
+    let err = migrate(&mut src, &mut dst, domain("broken"), &params).unwrap_err();
+    assert!(format!("{err:?}").contains("migration failed"));
+
+    // The destination is finished as cancelled and the source resumed.
+    assert!(procedures(&src_calls).contains(&(RemoteProcDomainMigrateConfirm3Params as i32)));
+    assert_eq!(*cancelled.lock().unwrap(), vec![1]);
+    assert_eq!(
+        procedures(&dst_calls).last(),
+        Some(&(RemoteProcDomainMigrateFinish3Params as i32))
+    );
+}
+
+#[test]
+fn peer2peer_uses_destination_uri() {
+    let (mut src, src_calls) = daemon(|req| {
+        if req.is(RemoteProcDomainMigratePerform3Params) {
+            let args: RemoteDomainMigratePerform3ParamsArgs = req.args();
+            assert_eq!(args.dconnuri.as_deref(), Some("qemu+tcp://dst/system"));
+            assert_ne!(args.flags & VIR_MIGRATE_PEER2PEER, 0);
+            req.reply(&RemoteDomainMigratePerform3ParamsRet { cookie_out: vec![] });
+        } else {
+            source(req);
+        }
+    });
+    let (mut dst, dst_calls) = daemon(destination);
+
+    let params = MigrateParams::new(VIR_MIGRATE_LIVE | VIR_MIGRATE_PEER2PEER);
+    let ddom = migrate(&mut src, &mut dst, domain("guest"), &params).unwrap();
+    assert_eq!(ddom.name, "guest");
+
+    assert!(procedures(&src_calls).contains(&(RemoteProcDomainMigratePerform3Params as i32)));
+    assert_eq!(
+        procedures(&dst_calls),
+        vec![
+            RemoteProcConnectGetUri as i32,
+            RemoteProcDomainLookupByName as i32,
+        ]
+    );
+}
+
+#[test]
+fn peer2peer_without_destination_connection() {
+    let (mut src, _) = daemon(source);
+
+    let params = MigrateParams::new(VIR_MIGRATE_LIVE | VIR_MIGRATE_PEER2PEER);
+    assert!(migrate_p2p(&mut src, domain("guest"), &params, |_| {}).is_err());
+
+    let params = params.dest_uri("qemu+tcp://dst/system");
+    migrate_p2p(&mut src, domain("guest"), &params, |_| {}).unwrap();
+}
+
+#[test]
+fn tunnelled_requires_peer2peer() {
+    let (mut src, src_calls) = daemon(source);
+    let (mut dst, _) = daemon(destination);
+
+    let params = MigrateParams::new(VIR_MIGRATE_TUNNELLED);
+    assert!(migrate(&mut src, &mut dst, domain("guest"), &params).is_err());
+    assert!(procedures(&src_calls).is_empty());
+}
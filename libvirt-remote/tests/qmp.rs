@@ -0,0 +1,106 @@
+mod common;
+
+use common::{Request, daemon, domain};
+use libvirt_remote::binding::*;
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::error::Error;
+use libvirt_remote::qmp::*;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use QemuProcedure::*;
+use RemoteProcedure::*;
+
+fn monitor_event(callback_id: i32, event: &str) -> QemuDomainMonitorEventMsg {
+    QemuDomainMonitorEventMsg {
+        callback_id,
+        dom: domain("guest"),
+        event: event.to_string(),
+        seconds: 1,
+        micros: 2,
+        details: Some(r#"{"reason":"host-qmp-stop"}"#.to_string()),
+    }
+}
+
+fn register(req: &mut Request) {
+    req.reply(&QemuConnectDomainMonitorEventRegisterRet { callback_id: 7 });
+}
+
+#[test]
+fn listener_keeps_foreign_events() {
+    let deregistered = Arc::new(Mutex::new(vec![]));
+    let t_deregistered = Arc::clone(&deregistered);
+    let (mut client, _) = daemon(move |req| {
+        if req.is_qemu(QemuProcConnectDomainMonitorEventRegister) {
+            register(req);
+        } else if req.is_qemu(QemuProcConnectDomainMonitorEventDeregister) {
+            let args: QemuConnectDomainMonitorEventDeregisterArgs = req.args();
+            t_deregistered.lock().unwrap().push(args.callback_id);
+            req.reply_void();
+        } else if req.is(RemoteProcConnectGetLibVersion) {
+            req.reply(&RemoteConnectGetLibVersionRet { lib_ver: 1 });
+            // Events of other consumers arrive before the one listened for.
+            req.event(
+                RemoteProcDomainEventCallbackLifecycle,
+                &RemoteDomainEventCallbackLifecycleMsg {
+                    callback_id: 99,
+                    msg: RemoteDomainEventLifecycleMsg {
+                        dom: domain("other"),
+                        event: 2,
+                        detail: 0,
+                    },
+                },
+            );
+            req.qemu_event(QemuProcDomainMonitorEvent, &monitor_event(8, "RESUME"));
+            req.qemu_event(QemuProcDomainMonitorEvent, &monitor_event(7, "STOP"));
+        }
+    });
+    let mut other = client.try_clone().unwrap();
+
+    {
+        let mut listener = QmpEventListener::register(&mut client, None, None, 0).unwrap();
+        other.connect_get_lib_version().unwrap();
+
+        let event = listener.next(Duration::from_secs(5)).unwrap().unwrap();
+        assert_eq!(event.event, "STOP");
+        assert_eq!(event.micros, 2);
+        assert_eq!(
+            event.details.unwrap()["reason"].as_str(),
+            Some("host-qmp-stop")
+        );
+
+        assert!(listener.next(Duration::from_millis(200)).unwrap().is_none());
+    }
+
+    // Dropping the listener deregistered its callback.
+    assert_eq!(*deregistered.lock().unwrap(), vec![7]);
+
+    let raw = client.get_event(Duration::from_secs(1)).unwrap();
+    assert_eq!(
+        raw.procedure(),
+        RemoteProcDomainEventCallbackLifecycle as i32
+    );
+    let raw = client.get_event(Duration::from_secs(1)).unwrap();
+    assert_eq!(raw.program(), QEMU_PROGRAM);
+    let msg: QemuDomainMonitorEventMsg = raw.try_into().unwrap();
+    assert_eq!(msg.callback_id, 8);
+}
+
+#[test]
+fn listener_detects_closed_connection() {
+    let (mut client, _) = daemon(move |req| {
+        if req.is_qemu(QemuProcConnectDomainMonitorEventRegister) {
+            register(req);
+        } else if req.is(RemoteProcConnectGetLibVersion) {
+            req.hang_up();
+        }
+    });
+    let mut other = client.try_clone().unwrap();
+
+    let mut listener = QmpEventListener::register(&mut client, None, None, 0).unwrap();
+    assert!(other.connect_get_lib_version().is_err());
+    assert!(matches!(
+        listener.next(Duration::from_secs(5)),
+        Err(Error::ConnectionClosedError)
+    ));
+}
@@ -0,0 +1,200 @@
+use libvirt_remote::screenshot::*;
+use miniz_oxide::inflate::decompress_to_vec_zlib;
+
+// Decodes the PNG written by `encode_png`: 8-bit RGB rows with Sub or no
+// filter.
+fn decode_png(png: &[u8]) -> Image {
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+    let mut pos = 8;
+    let mut ihdr = vec![];
+    let mut idat = vec![];
+    let mut kinds = vec![];
+    while pos < png.len() {
+        let len = u32::from_be_bytes(png[pos..pos + 4].try_into().unwrap()) as usize;
+        let chunk = &png[pos + 4..pos + 8 + len];
+        let crc = u32::from_be_bytes(png[pos + 8 + len..pos + 12 + len].try_into().unwrap());
+        assert_eq!(crc32(chunk), crc);
+
+        let (kind, data) = chunk.split_at(4);
+        match kind {
+            b"IHDR" => ihdr = data.to_vec(),
+            b"IDAT" => idat.extend(data),
+            _ => {}
+        }
+        kinds.push(kind.to_vec());
+        pos += 12 + len;
+    }
+    assert_eq!(
+        kinds,
+        vec![b"IHDR".to_vec(), b"IDAT".to_vec(), b"IEND".to_vec()]
+    );
+
+    let width = u32::from_be_bytes(ihdr[0..4].try_into().unwrap());
+    let height = u32::from_be_bytes(ihdr[4..8].try_into().unwrap());
+    assert_eq!(&ihdr[8..], &[8, 2, 0, 0, 0]);
+
+    // Also checks the Adler-32 trailer.
+    let raw = decompress_to_vec_zlib(&idat).unwrap();
+    let stride = width as usize * 3;
+    assert_eq!(raw.len(), (stride + 1) * height as usize);
+
+    let mut rgb = vec![];
+    for row in raw.chunks(stride + 1) {
+        let start = rgb.len();
+        for (i, v) in row[1..].iter().enumerate() {
+            let left = match row[0] {
+                0 => 0,
+                1 if i >= 3 => rgb[start + i - 3],
+                1 => 0,
+                filter => panic!("unexpected filter {filter}"),
+            };
+            rgb.push(v.wrapping_add(left));
+        }
+    }
+
+    Image { width, height, rgb }
+}
+
+fn ppm(width: u32, height: u32, rgb: &[u8]) -> Vec<u8> {
+    let mut data = format!("P6\n{width} {height}\n255\n").into_bytes();
+    data.extend(rgb);
+    data
+}
+
+#[test]
+fn crc32_vectors() {
+    assert_eq!(crc32(b""), 0);
+    assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    assert_eq!(crc32(b"IEND"), 0xae42_6082);
+    assert_eq!(
+        crc32(b"The quick brown fox jumps over the lazy dog"),
+        0x414f_a339
+    );
+}
+
+#[test]
+fn adler32_vectors() {
+    assert_eq!(adler32(b""), 1);
+    assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    // Long enough that the sums are reduced several times.
+    assert_eq!(adler32(&[0xff; 100_000]), 0x149a_302c);
+}
+
+#[test]
+fn p6_round_trip() {
+    let (width, height) = (300, 40);
+    let mut seed = 1u32;
+    let mut rgb = vec![];
+    for y in 0..height {
+        for x in 0..width {
+            if y < height / 2 {
+                // Flat areas and repeated rows, which are compressed to matches.
+                rgb.extend([(x / 50) as u8 * 40, 0x80, 0xff]);
+            } else {
+                // Noise, which is left as literals.
+                for _ in 0..3 {
+                    seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                    rgb.push((seed >> 16) as u8);
+                }
+            }
+        }
+    }
+
+    let image = decode_ppm(&ppm(width, height, &rgb)).unwrap();
+    assert_eq!((image.width, image.height), (width, height));
+    assert_eq!(image.rgb, rgb);
+
+    let png = encode_png(&image).unwrap();
+    assert_eq!(decode_png(&png), image);
+}
+
+#[test]
+fn p6_wide_samples() {
+    let data = [
+        b"P6 2 1 65535\n".as_slice(),
+        &[0xff, 0xff, 0x00, 0x00, 0x80, 0x00],
+        &[0x00, 0x00, 0xff, 0xff, 0x00, 0x00],
+    ]
+    .concat();
+    let image = decode_ppm(&data).unwrap();
+    assert_eq!(image.rgb, vec![255, 0, 128, 0, 255, 0]);
+    assert_eq!(decode_png(&encode_png(&image).unwrap()), image);
+}
+
+#[test]
+fn p3_round_trip() {
+    let data = b"P3\n# a comment\n2 2\n15\n15 0 0  0 15 0\n0 0 15  7 7 7\n";
+    let image = decode_ppm(data).unwrap();
+    assert_eq!((image.width, image.height), (2, 2));
+    assert_eq!(
+        image.rgb,
+        vec![255, 0, 0, 0, 255, 0, 0, 0, 255, 119, 119, 119]
+    );
+    assert_eq!(decode_png(&encode_png(&image).unwrap()), image);
+}
+
+#[test]
+fn screenshot_to_png() {
+    let rgb = [1, 2, 3, 4, 5, 6];
+    let shot = Screenshot {
+        mime: Some(MIME_PPM.to_string()),
+        data: ppm(2, 1, &rgb),
+    };
+    assert_eq!(shot.extension(), "ppm");
+    assert_eq!(decode_png(&shot.to_png().unwrap()).rgb, rgb);
+
+    let shot = Screenshot {
+        mime: Some("image/bmp".to_string()),
+        data: vec![],
+    };
+    assert!(shot.to_png().is_err());
+}
+
+#[test]
+fn malformed_ppm() {
+    let cases: &[&[u8]] = &[
+        b"",
+        b"P5 1 1 255\n\0",
+        b"P6",
+        b"P6 1",
+        b"P6 1 1",
+        b"P6 1 1 255",
+        b"P6 1 1 255\n\x01\x02",
+        b"P6 0 1 255\n",
+        b"P6 1 1 0\n",
+        b"P6 1 1 65536\n",
+        b"P6 -1 1 255\n",
+        b"P6 x 1 255\n",
+        b"P6 99999999999 1 255\n",
+        b"P6 4294967295 4294967295 255\n",
+        b"P6 4294967295 4294967295 65535\n",
+        b"P3 1 1 255\n1 2",
+        b"P3 1 1 255\n1 2 z",
+        b"P3 4294967295 4294967295 255\n1 2 3",
+        b"P3 1 1 255 # comment without end",
+    ];
+    for data in cases {
+        assert!(
+            decode_ppm(data).is_err(),
+            "{}",
+            String::from_utf8_lossy(data)
+        );
+    }
+}
+
+#[test]
+fn encode_invalid_size() {
+    let image = |width, height, len| Image {
+        width,
+        height,
+        rgb: vec![0; len],
+    };
+    assert!(encode_png(&image(0, 1, 0)).is_err());
+    assert!(encode_png(&image(1, 0, 0)).is_err());
+    assert!(encode_png(&image(0, 0, 0)).is_err());
+    assert!(encode_png(&image(2, 2, 11)).is_err());
+    assert!(encode_png(&image(2, 2, 13)).is_err());
+    assert!(encode_png(&image(1 << 31, 1, 0)).is_err());
+    assert!(encode_png(&image(2, 2, 12)).is_ok());
+}
@@ -0,0 +1,107 @@
+use libvirt_remote::socket::*;
+use libvirt_remote::uri::ConnectUri;
+use std::env;
+use std::path::PathBuf;
+
+fn candidates_of(uri: &str, readonly: bool) -> Vec<PathBuf> {
+    candidates(&ConnectUri::parse(uri).unwrap(), readonly)
+}
+
+fn paths(paths: &[&str]) -> Vec<PathBuf> {
+    paths.iter().map(PathBuf::from).collect()
+}
+
+#[test]
+fn daemon_names() {
+    assert_eq!(daemon_name("qemu"), Some("virtqemud"));
+    assert_eq!(daemon_name("libxl"), Some("virtxend"));
+    assert_eq!(daemon_name("network"), Some("virtnetworkd"));
+    assert_eq!(daemon_name("storage"), Some("virtstoraged"));
+    assert_eq!(daemon_name("test"), None);
+}
+
+#[test]
+fn candidates_auto() {
+    assert_eq!(
+        candidates_of("qemu:///system", false),
+        paths(&[
+            "/run/libvirt/virtqemud-sock",
+            "/var/run/libvirt/virtqemud-sock",
+            "/run/libvirt/libvirt-sock",
+            "/var/run/libvirt/libvirt-sock",
+        ])
+    );
+}
+
+#[test]
+fn candidates_direct() {
+    assert_eq!(
+        candidates_of("network:///system?mode=direct", false),
+        paths(&[
+            "/run/libvirt/virtnetworkd-sock",
+            "/var/run/libvirt/virtnetworkd-sock",
+        ])
+    );
+
+    // No modular daemon serves an unknown driver.
+    assert!(candidates_of("test:///system?mode=direct", false).is_empty());
+}
+
+#[test]
+fn candidates_legacy() {
+    assert_eq!(
+        candidates_of("qemu:///system?mode=legacy", false),
+        paths(&["/run/libvirt/libvirt-sock", "/var/run/libvirt/libvirt-sock"])
+    );
+}
+
+#[test]
+fn candidates_unknown_driver() {
+    assert_eq!(
+        candidates_of("test:///system", false),
+        paths(&["/run/libvirt/libvirt-sock", "/var/run/libvirt/libvirt-sock"])
+    );
+}
+
+#[test]
+fn candidates_readonly() {
+    assert_eq!(
+        candidates_of("storage:///system", true),
+        paths(&[
+            "/run/libvirt/virtstoraged-sock-ro",
+            "/var/run/libvirt/virtstoraged-sock-ro",
+            "/run/libvirt/libvirt-sock-ro",
+            "/var/run/libvirt/libvirt-sock-ro",
+        ])
+    );
+}
+
+#[test]
+fn candidates_session() {
+    // The only test which reads the environment, so nothing races with it.
+    unsafe {
+        env::set_var("XDG_RUNTIME_DIR", "/run/user/1000");
+        env::set_var("HOME", "/home/user");
+    }
+
+    let expected = paths(&[
+        "/run/user/1000/libvirt/virtqemud-sock",
+        "/home/user/.cache/libvirt/virtqemud-sock",
+        "/run/user/1000/libvirt/libvirt-sock",
+        "/home/user/.cache/libvirt/libvirt-sock",
+    ]);
+    assert_eq!(candidates_of("qemu:///session", false), expected);
+    // Session daemons have no read-only socket.
+    assert_eq!(candidates_of("qemu:///session", true), expected);
+}
+
+#[test]
+fn discover_missing() {
+    let uri = ConnectUri::parse("qemu:///system?mode=legacy").unwrap();
+    if candidates(&uri, false).iter().any(|p| p.exists()) {
+        return;
+    }
+
+    let err = discover(&uri, false).unwrap_err().to_string();
+    assert!(err.contains("/run/libvirt/libvirt-sock"), "{err}");
+}
@@ -0,0 +1,190 @@
+mod common;
+
+use common::domain;
+use libvirt_remote::binding::RemoteTypedParam;
+use libvirt_remote::domain::DomainState;
+use libvirt_remote::stats::*;
+use std::time::Duration;
+
+fn sample() -> Vec<RemoteTypedParam> {
+    vec![
+        RemoteTypedParam::int("state.state", 1),
+        RemoteTypedParam::int("state.reason", 1),
+        RemoteTypedParam::ullong("cpu.time", 10_000_000_000),
+        RemoteTypedParam::ullong("cpu.user", 6_000_000_000),
+        RemoteTypedParam::ullong("cpu.system", 3_000_000_000),
+        RemoteTypedParam::ullong("balloon.current", 1048576),
+        RemoteTypedParam::ullong("balloon.maximum", 2097152),
+        RemoteTypedParam::ullong("balloon.last-update", 1700000000),
+        RemoteTypedParam::uint("vcpu.current", 2),
+        RemoteTypedParam::uint("vcpu.maximum", 3),
+        RemoteTypedParam::int("vcpu.0.state", 1),
+        RemoteTypedParam::ullong("vcpu.0.time", 4_000_000_000),
+        RemoteTypedParam::boolean("vcpu.0.halted", true),
+        // vcpu 1 is offline and skipped.
+        RemoteTypedParam::int("vcpu.2.state", 1),
+        RemoteTypedParam::ullong("vcpu.2.time", 5_000_000_000),
+        RemoteTypedParam::uint("net.count", 1),
+        RemoteTypedParam::string("net.0.name", "vnet0"),
+        RemoteTypedParam::ullong("net.0.rx.bytes", 1000),
+        RemoteTypedParam::ullong("net.0.tx.bytes", 2000),
+        RemoteTypedParam::uint("block.count", 2),
+        RemoteTypedParam::string("block.0.name", "vda"),
+        RemoteTypedParam::string("block.0.path", "/var/lib/libvirt/images/top.qcow2"),
+        RemoteTypedParam::ullong("block.0.rd.bytes", 4096),
+        RemoteTypedParam::ullong("block.0.capacity", 10737418240),
+        RemoteTypedParam::string("block.1.name", "vda"),
+        RemoteTypedParam::uint("block.1.backingIndex", 1),
+        RemoteTypedParam::ullong("block.1.rd.bytes", 512),
+        RemoteTypedParam::ullong("perf.cpu_cycles", 123),
+        RemoteTypedParam::ullong("iothread.3.poll-max-ns", 32768),
+        RemoteTypedParam::ullong("iothread.1.poll-grow", 2),
+        RemoteTypedParam::int("dirtyrate.calc_status", 2),
+        RemoteTypedParam::string("dirtyrate.calc_mode", "dirty-ring"),
+        RemoteTypedParam::llong("dirtyrate.vcpu.1.megabytes_per_second", 8),
+        RemoteTypedParam::llong("dirtyrate.vcpu.0.megabytes_per_second", 4),
+    ]
+}
+
+#[test]
+fn parse_typed_params() {
+    let stats = DomainStats::parse(domain("guest"), &sample());
+
+    let state = stats.state.as_ref().unwrap();
+    assert_eq!(state.state, DomainState::Running);
+    assert_eq!(state.reason, 1);
+
+    let cpu = stats.cpu.as_ref().unwrap();
+    assert_eq!(cpu.time, Some(10_000_000_000));
+    assert_eq!(cpu.haltpoll_success_time, None);
+
+    let balloon = stats.balloon.as_ref().unwrap();
+    assert_eq!(balloon.current, Some(1048576));
+    assert_eq!(balloon.last_update, Some(1700000000));
+    assert_eq!(balloon.rss, None);
+
+    let vcpu = stats.vcpu.as_ref().unwrap();
+    assert_eq!(vcpu.current, Some(2));
+    assert_eq!(
+        vcpu.vcpus.iter().map(|v| v.index).collect::<Vec<_>>(),
+        vec![0, 2]
+    );
+    assert_eq!(vcpu.vcpus[0].halted, Some(true));
+    assert_eq!(vcpu.vcpus[1].time, Some(5_000_000_000));
+
+    let net = stats.interface("vnet0").unwrap();
+    assert_eq!(net.rx_bytes, Some(1000));
+    assert_eq!(net.tx_bytes, Some(2000));
+    assert_eq!(net.rx_errs, None);
+
+    // The top of the chain is found first.
+    assert_eq!(stats.blocks.len(), 2);
+    let block = stats.block("vda").unwrap();
+    assert_eq!(block.backing_index, None);
+    assert_eq!(block.rd_bytes, Some(4096));
+    assert_eq!(stats.blocks[1].backing_index, Some(1));
+
+    assert_eq!(stats.perf.get("cpu_cycles"), Some(&123));
+
+    let ids: Vec<u64> = stats.iothreads.iter().map(|i| i.id).collect();
+    assert_eq!(ids, vec![1, 3]);
+    assert_eq!(stats.iothreads[1].poll_max_ns, Some(32768));
+
+    let dirty_rate = stats.dirty_rate.unwrap();
+    assert_eq!(dirty_rate.calc_status, Some(2));
+    assert_eq!(dirty_rate.calc_mode.as_deref(), Some("dirty-ring"));
+    assert_eq!(dirty_rate.vcpus, vec![(0, 4), (1, 8)]);
+}
+
+#[test]
+fn parse_missing_groups() {
+    let stats = DomainStats::parse(domain("guest"), &[]);
+    assert!(stats.state.is_none());
+    assert!(stats.cpu.is_none());
+    assert!(stats.balloon.is_none());
+    assert!(stats.vcpu.is_none());
+    assert!(stats.interfaces.is_empty());
+    assert!(stats.blocks.is_empty());
+    assert!(stats.perf.is_empty());
+    assert!(stats.dirty_rate.is_none());
+}
+
+fn advanced(params: &[RemoteTypedParam], field: &str, value: u64) -> Vec<RemoteTypedParam> {
+    let mut params = params.to_vec();
+    libvirt_remote::param::replace(&mut params, RemoteTypedParam::ullong(field, value));
+    params
+}
+
+#[test]
+fn rates_between_samples() {
+    let prev = DomainStats::parse(domain("guest"), &sample());
+    let mut params = advanced(&sample(), "cpu.time", 12_000_000_000);
+    params = advanced(&params, "vcpu.0.time", 5_000_000_000);
+    params = advanced(&params, "net.0.rx.bytes", 3000);
+    params = advanced(&params, "block.0.rd.bytes", 8192);
+    let cur = DomainStats::parse(domain("guest"), &params);
+
+    let rates = DomainRates::between(&prev, &cur, Duration::from_secs(2));
+    assert_eq!(rates.cpu, Some(1.0));
+    assert_eq!(rates.cpu_user, Some(0.0));
+    assert_eq!(rates.vcpus, vec![(0, 0.5), (2, 0.0)]);
+    assert_eq!(rates.interfaces[0].rx_bytes, Some(1000.0));
+    assert_eq!(rates.interfaces[0].tx_bytes, Some(0.0));
+    assert_eq!(rates.interfaces[0].rx_pkts, None);
+
+    // Backing chain entries of the same disk are not reported again.
+    assert_eq!(rates.blocks.len(), 1);
+    assert_eq!(rates.blocks[0].rd_bytes, Some(2048.0));
+}
+
+#[test]
+fn rates_counter_reset() {
+    let prev = DomainStats::parse(domain("guest"), &sample());
+    let mut params = advanced(&sample(), "cpu.time", 1_000_000_000);
+    params = advanced(&params, "net.0.rx.bytes", 10);
+    let cur = DomainStats::parse(domain("guest"), &params);
+
+    let rates = DomainRates::between(&prev, &cur, Duration::from_secs(1));
+    assert_eq!(rates.cpu, None);
+    assert_eq!(rates.interfaces[0].rx_bytes, None);
+    assert_eq!(rates.interfaces[0].tx_bytes, Some(0.0));
+}
+
+#[test]
+fn rates_zero_interval() {
+    let prev = DomainStats::parse(domain("guest"), &sample());
+    let cur = DomainStats::parse(
+        domain("guest"),
+        &advanced(&sample(), "cpu.time", 20_000_000_000),
+    );
+
+    let rates = DomainRates::between(&prev, &cur, Duration::ZERO);
+    assert_eq!(rates.cpu, None);
+    assert!(rates.vcpus.is_empty());
+    assert_eq!(rates.interfaces[0].rx_bytes, None);
+}
+
+#[test]
+fn sample_rates_match_domains() {
+    let mut other = domain("other");
+    other.uuid = [2; 16];
+
+    let prev = StatsSample {
+        time: std::time::Instant::now(),
+        domains: vec![DomainStats::parse(domain("guest"), &sample())],
+    };
+    let cur = StatsSample {
+        time: prev.time + Duration::from_secs(1),
+        domains: vec![
+            DomainStats::parse(other, &sample()),
+            DomainStats::parse(domain("guest"), &sample()),
+        ],
+    };
+
+    // Only domains present in both samples have rates.
+    let rates = cur.rates(&prev);
+    assert_eq!(rates.len(), 1);
+    assert_eq!(rates[0].dom.name, "guest");
+    assert_eq!(rates[0].elapsed, Duration::from_secs(1));
+    assert_eq!(rates[0].cpu, Some(0.0));
+}
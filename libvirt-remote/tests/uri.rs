@@ -0,0 +1,133 @@
+use libvirt_remote::client::Client;
+use libvirt_remote::error::Error;
+use libvirt_remote::uri::*;
+
+#[test]
+fn parse_local() {
+    let uri = ConnectUri::parse("qemu:///system").unwrap();
+    assert_eq!(uri.driver(), "qemu");
+    assert_eq!(uri.transport(), Transport::Unix);
+    assert_eq!(uri.host(), None);
+    assert_eq!(uri.user(), None);
+    assert_eq!(uri.path(), "/system");
+    assert_eq!(uri.mode(), DaemonMode::Auto);
+    assert_eq!(uri.proxy(), ProxyMode::Auto);
+    assert!(!uri.is_session());
+    assert_eq!(uri.name(), "qemu:///system");
+    assert_eq!(uri.to_string(), "qemu:///system");
+}
+
+#[test]
+fn parse_session() {
+    let uri = ConnectUri::parse("qemu:///session").unwrap();
+    assert!(uri.is_session());
+}
+
+#[test]
+fn parse_remote() {
+    let uri = ConnectUri::parse("qemu+tcp://admin@host:1234/system").unwrap();
+    assert_eq!(uri.driver(), "qemu");
+    assert_eq!(uri.transport(), Transport::Tcp);
+    assert_eq!(uri.user(), Some("admin"));
+    assert_eq!(uri.host(), Some("host"));
+    assert_eq!(uri.port(), 1234);
+    assert_eq!(uri.name(), "qemu:///system");
+    assert_eq!(uri.to_string(), "qemu+tcp://admin@host:1234/system");
+}
+
+#[test]
+fn parse_default_port() {
+    let uri = ConnectUri::parse("qemu+tcp://host/system").unwrap();
+    assert_eq!(uri.port(), DEFAULT_TCP_PORT);
+
+    let uri = ConnectUri::parse("qemu+tls://host/system").unwrap();
+    assert_eq!(uri.port(), DEFAULT_TLS_PORT);
+}
+
+#[test]
+fn parse_host_implies_tls() {
+    let uri = ConnectUri::parse("qemu://host/system").unwrap();
+    assert_eq!(uri.transport(), Transport::Tls);
+    assert_eq!(uri.port(), DEFAULT_TLS_PORT);
+    assert_eq!(uri.to_string(), "qemu://host/system");
+}
+
+#[test]
+fn parse_query() {
+    let uri = ConnectUri::parse(
+        "qemu+unix:///system?socket=/tmp/sock&mode=direct&proxy=native&pkipath=/pki&keyfile=/key&foo=bar",
+    )
+    .unwrap();
+    assert_eq!(uri.transport(), Transport::Unix);
+    assert_eq!(uri.socket(), Some("/tmp/sock"));
+    assert_eq!(uri.mode(), DaemonMode::Direct);
+    assert_eq!(uri.proxy(), ProxyMode::Native);
+    assert_eq!(uri.pkipath(), Some("/pki"));
+    assert_eq!(uri.param("keyfile"), Some("/key"));
+    assert_eq!(uri.param("foo"), Some("bar"));
+    assert_eq!(uri.param("none"), None);
+    // Client side parameters are not sent to the daemon.
+    assert_eq!(uri.name(), "qemu:///system?foo=bar");
+    assert_eq!(uri.socket_path(false).unwrap().to_str(), Some("/tmp/sock"));
+}
+
+#[test]
+fn parse_name_override() {
+    let uri = ConnectUri::parse("qemu+tcp://host/system?name=qemu%3A%2F%2F%2Fsession").unwrap();
+    assert_eq!(uri.name(), "qemu:///session");
+}
+
+#[test]
+fn parse_round_trip() {
+    let uri = ConnectUri::parse("qemu+ssh://user@host:22/system?mode=legacy&no_verify=1").unwrap();
+    assert_eq!(ConnectUri::parse(&uri.to_string()).unwrap(), uri);
+    assert_eq!(
+        "qemu+ssh://user@host:22/system?mode=legacy&no_verify=1"
+            .parse::<ConnectUri>()
+            .unwrap(),
+        uri
+    );
+}
+
+#[test]
+fn parse_invalid() {
+    for uri in [
+        "",
+        "/system",
+        "qemu+foo:///system",
+        "qemu:///system?mode=foo",
+        "qemu:///system?proxy=foo",
+    ] {
+        assert!(
+            matches!(ConnectUri::parse(uri), Err(Error::InvalidUriError(_))),
+            "{uri}"
+        );
+    }
+}
+
+#[test]
+fn connect_unsupported_transport() {
+    let uri = ConnectUri::parse("qemu://host/system").unwrap();
+    match Client::connect(&uri) {
+        Err(Error::UnsupportedTransportError(msg)) => {
+            assert!(msg.contains("tls transport is not supported"), "{msg}");
+            assert!(msg.contains("implied"), "{msg}");
+        }
+        _ => panic!("unexpected result"),
+    }
+
+    let uri = ConnectUri::parse("qemu+ssh://host/system").unwrap();
+    match Client::connect(&uri) {
+        Err(Error::UnsupportedTransportError(msg)) => {
+            assert!(msg.contains("ssh transport is not supported"), "{msg}");
+            assert!(!msg.contains("implied"), "{msg}");
+        }
+        _ => panic!("unexpected result"),
+    }
+}
+
+#[test]
+fn resolve_explicit() {
+    let uri = ConnectUri::resolve(Some("lxc:///system")).unwrap();
+    assert_eq!(uri.driver(), "lxc");
+}
@@ -0,0 +1,330 @@
+use libvirt_remote::xml::XmlDocument;
+use libvirt_remote::xml::backup::DomainBackupDef;
+use libvirt_remote::xml::capabilities::Capabilities;
+use libvirt_remote::xml::checkpoint::DomainCheckpointDef;
+use libvirt_remote::xml::domain::DomainDef;
+use libvirt_remote::xml::domain_capabilities::DomainCapabilities;
+use libvirt_remote::xml::network::{NetworkDef, NetworkPortDef};
+use libvirt_remote::xml::nodedev::NodeDeviceDef;
+use libvirt_remote::xml::nwfilter::NwFilterDef;
+use libvirt_remote::xml::secret::SecretDef;
+use libvirt_remote::xml::storage::{StoragePoolDef, StorageVolDef};
+use std::fmt::Debug;
+
+// Sample documents under tests/data follow the libvirt test suite
+// (qemuxml2argvdata, networkxml2xmlin, storagepoolxml2xmlin, ...).
+macro_rules! sample {
+    ($name:literal) => {
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/", $name))
+    };
+}
+
+// Parses a sample, serializes it and parses the result again. The modelled
+// content must survive the trip unchanged.
+fn round_trip<T: XmlDocument + PartialEq + Debug>(xml: &str) -> T {
+    let parsed = T::from_xml(xml).expect("parse sample");
+    let serialized = parsed.to_xml().expect("serialize");
+    let reparsed = T::from_xml(&serialized).expect("parse serialized");
+    assert_eq!(parsed, reparsed, "round trip changed:\n{serialized}");
+    parsed
+}
+
+#[test]
+fn domain_minimal() {
+    let def: DomainDef = round_trip(sample!("domain-minimal.xml"));
+    assert_eq!(def.r#type, "qemu");
+    assert_eq!(def.name, "QEMUGuest1");
+    assert_eq!(def.memory.as_ref().map(|m| m.bytes()), Some(219100 * 1024));
+    assert_eq!(def.vcpu.as_ref().map(|v| v.value), Some(1));
+    assert_eq!(def.on_crash.as_deref(), Some("destroy"));
+    let devices = def.devices.expect("devices");
+    assert_eq!(devices.disk.len(), 1);
+    assert_eq!(devices.disk[0].target.dev, "hda");
+    assert_eq!(devices.controller.len(), 3);
+    assert_eq!(devices.input.len(), 2);
+}
+
+#[test]
+fn domain_disk_network_rbd() {
+    let def: DomainDef = round_trip(sample!("domain-disk-network-rbd-auth.xml"));
+    let devices = def.devices.expect("devices");
+    let disk = &devices.disk[1];
+    assert_eq!(disk.r#type, "network");
+    let source = disk.source.as_ref().expect("source");
+    assert_eq!(source.protocol.as_deref(), Some("rbd"));
+    assert_eq!(source.name.as_deref(), Some("pool/image"));
+    assert_eq!(source.host.len(), 3);
+    assert_eq!(source.host[2].port.as_deref(), Some("6322"));
+}
+
+#[test]
+fn domain_q35_devices() {
+    let def: DomainDef = round_trip(sample!("domain-q35-devices.xml"));
+    assert_eq!(def.id, Some(3));
+    assert_eq!(def.max_memory.as_ref().map(|m| m.slots), Some(Some(16)));
+    assert_eq!(def.vcpu.as_ref().and_then(|v| v.current), Some(2));
+    assert_eq!(def.iothreads, Some(1));
+    let cpu = def.cpu.as_ref().expect("cpu");
+    assert_eq!(cpu.mode.as_deref(), Some("host-passthrough"));
+    assert_eq!(cpu.topology.as_ref().map(|t| t.threads), Some(2));
+    assert_eq!(cpu.feature.len(), 1);
+    assert_eq!(def.clock.as_ref().map(|c| c.timer.len()), Some(3));
+    let devices = def.devices.as_ref().expect("devices");
+    let disk = &devices.disk[0];
+    assert_eq!(
+        disk.driver.as_ref().and_then(|d| d.cache.as_deref()),
+        Some("none")
+    );
+    assert_eq!(disk.boot.as_ref().map(|b| b.order), Some(1));
+    let backing = disk.backing_store.as_ref().expect("backing store");
+    assert_eq!(backing.index, Some(1));
+    assert!(devices.disk[1].readonly.is_some());
+    assert_eq!(devices.filesystem.len(), 1);
+    let iface = &devices.interface[0];
+    assert_eq!(
+        iface.mac.as_ref().map(|m| m.address.as_str()),
+        Some("52:54:00:8b:b0:6a")
+    );
+    assert_eq!(iface.target.as_ref().map(|t| t.dev.as_str()), Some("vnet2"));
+    assert_eq!(iface.driver.as_ref().and_then(|d| d.queues), Some(4));
+    assert_eq!(devices.channel.len(), 1);
+    assert_eq!(devices.graphics[0].port, Some(5900));
+    assert_eq!(devices.tpm.len(), 1);
+    assert_eq!(def.seclabel.len(), 1);
+}
+
+#[test]
+fn network_nat() {
+    let def: NetworkDef = round_trip(sample!("network-nat.xml"));
+    assert_eq!(def.name, "default");
+    let forward = def.forward.as_ref().expect("forward");
+    assert_eq!(forward.mode.as_deref(), Some("nat"));
+    assert!(forward.nat.as_ref().and_then(|n| n.port.as_ref()).is_some());
+    assert_eq!(
+        def.bridge.as_ref().and_then(|b| b.name.as_deref()),
+        Some("virbr0")
+    );
+    let dns = def.dns.as_ref().expect("dns");
+    assert_eq!(dns.forwarder.len(), 2);
+    assert_eq!(dns.host[0].hostname.len(), 2);
+    assert_eq!(def.ip.len(), 3);
+    let dhcp = def.ip[0].dhcp.as_ref().expect("dhcp");
+    assert_eq!(dhcp.host.len(), 2);
+    assert_eq!(def.ip[1].prefix, Some(64));
+    assert!(def.ip[2].tftp.is_some());
+    assert_eq!(def.route.len(), 1);
+}
+
+#[test]
+fn network_port() {
+    let def: NetworkPortDef = round_trip(sample!("networkport.xml"));
+    assert_eq!(def.owner.name, "myguest");
+    assert_eq!(def.group.as_deref(), Some("webfront"));
+    let plug = def.plug.as_ref().expect("plug");
+    assert_eq!(plug.bridge.as_deref(), Some("virbr0"));
+    assert_eq!(plug.mac_table_manager.as_deref(), Some("libvirt"));
+}
+
+#[test]
+fn storage_pool_dir() {
+    let def: StoragePoolDef = round_trip(sample!("pool-dir.xml"));
+    assert_eq!(def.r#type, "dir");
+    let target = def.target.as_ref().expect("target");
+    assert_eq!(
+        target.path.as_deref(),
+        Some("/i/cant/believe/its/not/butter")
+    );
+    assert_eq!(
+        target.permissions.as_ref().and_then(|p| p.mode.as_deref()),
+        Some("0700")
+    );
+}
+
+#[test]
+fn storage_pool_netfs() {
+    let def: StoragePoolDef = round_trip(sample!("pool-netfs.xml"));
+    let source = def.source.as_ref().expect("source");
+    assert_eq!(source.host[0].name, "localhost");
+    assert_eq!(
+        source.dir.as_ref().map(|d| d.path.as_str()),
+        Some("/var/lib/libvirt/images")
+    );
+    assert_eq!(
+        source.format.as_ref().map(|f| f.r#type.as_str()),
+        Some("nfs")
+    );
+}
+
+#[test]
+fn storage_pool_iscsi() {
+    let def: StoragePoolDef = round_trip(sample!("pool-iscsi.xml"));
+    let source = def.source.as_ref().expect("source");
+    assert_eq!(source.host[0].port, Some(3260));
+    assert_eq!(source.device.len(), 1);
+    assert_eq!(
+        source.initiator.as_ref().map(|i| i.iqn.name.as_str()),
+        Some("iqn.2013-06.com.example:iscsi-initiator")
+    );
+}
+
+#[test]
+fn storage_vol_qcow2() {
+    let def: StorageVolDef = round_trip(sample!("vol-qcow2.xml"));
+    assert_eq!(def.name, "OtherDemo.img");
+    assert_eq!(
+        def.capacity.as_ref().map(|c| c.bytes()),
+        Some(5 * 1024 * 1024 * 1024)
+    );
+    let target = def.target.as_ref().expect("target");
+    assert_eq!(
+        target.format.as_ref().map(|f| f.r#type.as_str()),
+        Some("qcow2")
+    );
+    assert_eq!(target.compat.as_deref(), Some("1.1"));
+    assert_eq!(
+        def.backing_store.as_ref().map(|b| b.path.as_str()),
+        Some("/dev/null")
+    );
+}
+
+#[test]
+fn nodedev_pci() {
+    let def: NodeDeviceDef = round_trip(sample!("nodedev-pci.xml"));
+    assert_eq!(def.name.as_deref(), Some("pci_0000_00_02_0"));
+    assert_eq!(def.driver.as_ref().map(|d| d.name.as_str()), Some("i915"));
+    let cap = &def.capability[0];
+    assert_eq!(cap.r#type, "pci");
+    assert_eq!(cap.slot, Some(2));
+    assert_eq!(
+        cap.vendor.as_ref().and_then(|v| v.id.as_deref()),
+        Some("0x8086")
+    );
+    assert_eq!(cap.iommu_group.as_ref().map(|g| g.number), Some(1));
+    let types = &cap.capability[0];
+    assert_eq!(types.r#type, "mdev_types");
+    assert_eq!(types.types[0].id.as_deref(), Some("i915-GVTg_V5_4"));
+    assert_eq!(types.types[0].available_instances, Some(2));
+}
+
+#[test]
+fn nodedev_mdev() {
+    let def: NodeDeviceDef = round_trip(sample!("nodedev-mdev.xml"));
+    let cap = &def.capability[0];
+    assert_eq!(cap.types[0].id.as_deref(), Some("i915-GVTg_V5_4"));
+    assert_eq!(cap.iommu_group.as_ref().map(|g| g.number), Some(12));
+    assert_eq!(cap.attr[0].value, "bar");
+}
+
+#[test]
+fn nodedev_scsi() {
+    let def: NodeDeviceDef = round_trip(sample!("nodedev-scsi.xml"));
+    let cap = &def.capability[0];
+    assert_eq!(cap.bus.as_deref(), Some("0"));
+    assert_eq!(cap.lun, Some(0));
+    assert_eq!(cap.types[0].value.as_deref(), Some("disk"));
+}
+
+#[test]
+fn nodedev_net() {
+    let def: NodeDeviceDef = round_trip(sample!("nodedev-net.xml"));
+    let cap = &def.capability[0];
+    assert_eq!(cap.interface.as_deref(), Some("eth0"));
+    assert_eq!(cap.link.as_ref().and_then(|l| l.speed), Some(1000));
+    assert_eq!(cap.capability[0].r#type, "80203");
+}
+
+#[test]
+fn nodedev_storage() {
+    let def: NodeDeviceDef = round_trip(sample!("nodedev-storage.xml"));
+    assert_eq!(def.devnode.len(), 2);
+    assert_eq!(def.devnode[0].path, "/dev/sda");
+    let cap = &def.capability[0];
+    assert_eq!(cap.block.as_deref(), Some("/dev/sda"));
+    assert_eq!(cap.bus.as_deref(), Some("ata"));
+    assert_eq!(cap.size, Some(1000204886016));
+    assert_eq!(cap.num_blocks, Some(1953525168));
+}
+
+#[test]
+fn secret_usage() {
+    let def: SecretDef = round_trip(sample!("secret-ceph.xml"));
+    let usage = def.usage.as_ref().expect("usage");
+    assert_eq!(usage.r#type, "ceph");
+    assert_eq!(usage.name.as_deref(), Some("CephCephCephCeph"));
+
+    let def: SecretDef = round_trip(sample!("secret-volume.xml"));
+    assert_eq!(def.private.as_deref(), Some("yes"));
+    assert_eq!(
+        def.usage.as_ref().and_then(|u| u.volume.as_deref()),
+        Some("/var/lib/libvirt/images/luks.img")
+    );
+}
+
+#[test]
+fn nwfilter_rules() {
+    let def: NwFilterDef = round_trip(sample!("nwfilter-tcp.xml"));
+    assert_eq!(def.chain.as_deref(), Some("root"));
+    assert_eq!(def.priority, Some(-500));
+    assert_eq!(def.filterref[0].parameter[0].value, "10.0.0.1");
+    assert_eq!(def.rule.len(), 3);
+    let tcp = def.rule[1].tcp.as_ref().expect("tcp");
+    assert_eq!(tcp.dstportstart.as_deref(), Some("22"));
+    assert!(def.rule[2].all.is_some());
+}
+
+#[test]
+fn capabilities_host() {
+    let caps: Capabilities = round_trip(sample!("capabilities.xml"));
+    assert_eq!(caps.host.cpu.arch, "x86_64");
+    assert_eq!(caps.host.cpu.pages.len(), 3);
+    assert_eq!(caps.host.secmodel.len(), 2);
+    let cells = &caps.host.topology.as_ref().expect("topology").cells;
+    assert_eq!(cells.num, 1);
+    assert_eq!(cells.cell[0].cpus.as_ref().map(|c| c.cpu.len()), Some(4));
+    assert_eq!(caps.guest.len(), 1);
+}
+
+#[test]
+fn domain_capabilities() {
+    let caps: DomainCapabilities = round_trip(sample!("domaincaps.xml"));
+    assert_eq!(caps.domain, "kvm");
+    assert_eq!(caps.vcpu.as_ref().map(|v| v.max), Some(4096));
+    let cpu = caps.cpu.as_ref().expect("cpu");
+    assert_eq!(cpu.mode.len(), 4);
+    assert_eq!(cpu.mode[3].model.len(), 2);
+    let disk = caps
+        .devices
+        .as_ref()
+        .and_then(|d| d.disk.as_ref())
+        .expect("disk");
+    assert_eq!(disk.enums.len(), 2);
+}
+
+#[test]
+fn checkpoint() {
+    let def: DomainCheckpointDef = round_trip(sample!("checkpoint.xml"));
+    assert_eq!(def.name.as_deref(), Some("1525889631"));
+    assert_eq!(
+        def.parent.as_ref().map(|p| p.name.as_str()),
+        Some("1525111885")
+    );
+    assert_eq!(def.creation_time, Some(1525889631));
+    let disks = def.disks.as_ref().expect("disks");
+    assert_eq!(disks.disk[0].size, Some(1048576));
+}
+
+#[test]
+fn backup() {
+    let def: DomainBackupDef = round_trip(sample!("backup-pull.xml"));
+    assert_eq!(def.mode.as_deref(), Some("pull"));
+    assert_eq!(def.server.as_ref().and_then(|s| s.port), Some(10809));
+    let disks = def.disks.as_ref().expect("disks");
+    assert_eq!(disks.disk[0].exportbitmap.as_deref(), Some("backup-vda"));
+
+    let def: DomainBackupDef = round_trip(sample!("backup-push.xml"));
+    let disks = def.disks.as_ref().expect("disks");
+    assert_eq!(
+        disks.disk[1].target.as_ref().and_then(|t| t.dev.as_deref()),
+        Some("/dev/sdz")
+    );
+}
@@ -16,6 +16,21 @@ const STREAM_PROCS: [&str; 7] = [
     "DomainOpenChannel",
 ];
 
+// Procedures which reply only after the migration they started finished, so
+// their reply is awaited without the usual timeout.
+const BLOCKING_PROCS: [&str; 3] = [
+    "DomainMigratePerform",
+    "DomainMigratePerform3",
+    "DomainMigratePerform3Params",
+];
+
+// Procedures which pass file descriptors along with their arguments.
+const FD_PROCS: [&str; 3] = [
+    "DomainCreateWithFiles",
+    "DomainCreateXmlWithFiles",
+    "DomainMonitorCommandWithFiles",
+];
+
 const UN_DECONSTRUCTING: [&str; 4] = [
     "RemoteDomainGetJobInfoRet",
     "RemoteDomainInterfaceStatsRet",
@@ -35,9 +50,12 @@ fn main() -> Result<(), Box<dyn Error>> {
     let contents = fs::read_to_string(path)?;
 
     let source = TokenStream::from_str(&contents)?;
-    let client = gen_code(source, false)?;
+    let code = match env::args().nth(2).as_deref() {
+        Some("fuzz") => gen_fuzz_code(source)?,
+        _ => gen_code(source, false)?,
+    };
 
-    println!("{client}");
+    println!("{code}");
     Ok(())
 }
 
@@ -62,6 +80,11 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
     msgs.extend(get_msg_method("Qemu", &models));
     msgs.extend(get_msg_method("Remote", &models));
 
+    let mut callback_events = get_callback_events("Lxc", &lxc_procedures, &models);
+    callback_events.extend(get_callback_events("Qemu", &qemu_procedures, &models));
+    callback_events.extend(get_callback_events("Remote", &remote_procedures, &models));
+    let callback_events_len = callback_events.len();
+
     let client = quote! {
         use crate::binding::*;
         use crate::error::Error;
@@ -69,25 +92,52 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
         use log::trace;
         use serde::{Serialize, de::DeserializeOwned};
         use std::collections::HashMap;
-        use std::io::ErrorKind;
         use std::io::{Read, Write};
-        use std::net::TcpStream;
+        use std::net::{Shutdown, TcpStream};
+        #[cfg(target_family = "unix")]
+        use std::os::unix::io::AsRawFd;
         #[cfg(target_family = "unix")]
         use std::os::unix::net::UnixStream;
         use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
-        use std::sync::mpsc::{Receiver, Sender, channel};
+        use std::sync::mpsc::{Receiver, RecvTimeoutError, Sender, channel};
         use std::sync::{Arc, Mutex};
         use std::thread::{self, JoinHandle};
         use std::time::Duration;
 
+        const CALL_TIMEOUT: Duration = Duration::from_secs(180);
+
+        // Event messages which start with the ID of the callback they were
+        // sent for.
+        const CALLBACK_EVENTS: [(u32, i32); #callback_events_len] = [#(#callback_events),*];
+
+        type EventChannels = Arc<Mutex<EventRoutes>>;
+
+        // Where the receiver delivers event messages.
+        struct EventRoutes {
+            channels: HashMap<(u32, i32), Sender<VirNetResponseRaw>>,
+            // Callback events of programs which have a callback being
+            // registered, kept until its channel is added.
+            held: HashMap<u32, (usize, Vec<VirNetResponseRaw>)>,
+            // The queue read by `get_event`, closed once the receiver stops.
+            events: Option<Sender<VirNetResponseRaw>>,
+        }
+
         pub trait ReadWrite: Read + Write + Send {
             fn clone(&self) -> Result<Box<dyn ReadWrite>, Error>;
+            fn shutdown(&self, how: Shutdown) -> Result<(), Error>;
+            // Only UNIX sockets can pass descriptors.
+            fn send_fds(&self, _fds: &[i32]) -> Result<(), Error> {
+                Err(Error::UnsupportedTransportError("file descriptor passing".to_string()))
+            }
         }
         impl ReadWrite for TcpStream {
             fn clone(&self) -> Result<Box<dyn ReadWrite>, Error> {
                 let s = self.try_clone().map_err(Error::SocketError)?;
                 Ok(Box::new(s))
             }
+            fn shutdown(&self, how: Shutdown) -> Result<(), Error> {
+                TcpStream::shutdown(self, how).map_err(Error::SocketError)
+            }
         }
         #[cfg(target_family = "unix")]
         impl ReadWrite for UnixStream {
@@ -95,17 +145,72 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
                 let s = self.try_clone().map_err(Error::SocketError)?;
                 Ok(Box::new(s))
             }
+            fn shutdown(&self, how: Shutdown) -> Result<(), Error> {
+                UnixStream::shutdown(self, how).map_err(Error::SocketError)
+            }
+            fn send_fds(&self, fds: &[i32]) -> Result<(), Error> {
+                for fd in fds {
+                    send_fd(self.as_raw_fd(), *fd).map_err(Error::SendError)?;
+                }
+                Ok(())
+            }
+        }
+
+        // Sends the descriptor as SCM_RIGHTS ancillary data on a single byte,
+        // which is how libvirt passes descriptors, one at a time.
+        #[cfg(target_family = "unix")]
+        fn send_fd(socket: i32, fd: i32) -> std::io::Result<()> {
+            let mut byte = [0u8; 1];
+            let mut iov = libc::iovec {
+                iov_base: byte.as_mut_ptr().cast(),
+                iov_len: byte.len(),
+            };
+
+            unsafe {
+                let space = libc::CMSG_SPACE(size_of::<i32>() as u32) as usize;
+                // u64 keeps the buffer aligned for the control message header.
+                let mut control = vec![0u64; space.div_ceil(size_of::<u64>())];
+
+                let mut msg: libc::msghdr = std::mem::zeroed();
+                msg.msg_iov = &mut iov;
+                msg.msg_iovlen = 1;
+                msg.msg_control = control.as_mut_ptr().cast();
+                msg.msg_controllen = space as _;
+
+                let cmsg = libc::CMSG_FIRSTHDR(&msg);
+                (*cmsg).cmsg_level = libc::SOL_SOCKET;
+                (*cmsg).cmsg_type = libc::SCM_RIGHTS;
+                (*cmsg).cmsg_len = libc::CMSG_LEN(size_of::<i32>() as u32) as _;
+                std::ptr::write_unaligned(libc::CMSG_DATA(cmsg).cast::<i32>(), fd);
+
+                loop {
+                    if libc::sendmsg(socket, &msg, 0) >= 0 {
+                        return Ok(());
+                    }
+                    let e = std::io::Error::last_os_error();
+                    if e.kind() != std::io::ErrorKind::Interrupted {
+                        return Err(e);
+                    }
+                }
+            }
         }
 
         pub struct Client {
             inner: Box<dyn ReadWrite>,
             serial: Arc<AtomicU32>,
-            receiver: Arc<JoinHandle<()>>,
+            receiver: Arc<ReceiverThread>,
             receiver_run: Arc<AtomicBool>,
             channels: Arc<Mutex<HashMap<u32, Sender<VirNetResponseRaw>>>>,
+            event_channels: EventChannels,
             events: Arc<Mutex<Receiver<VirNetResponseRaw>>>,
         }
 
+        struct ReceiverThread {
+            handle: Option<JoinHandle<()>>,
+            run: Arc<AtomicBool>,
+            socket: Mutex<Box<dyn ReadWrite>>,
+        }
+
         pub struct VirNetStreamResponse<D>
         where
             D: DeserializeOwned,
@@ -117,6 +222,11 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
             body: Option<D>,
         }
 
+        pub struct VirNetStreamSender {
+            inner: Box<dyn ReadWrite>,
+            header: protocol::VirNetMessageHeader,
+        }
+
         pub enum VirNetRequest<S>
         where
             S: Serialize,
@@ -150,27 +260,66 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
         }
 
         impl Client {
-            pub fn new(socket: impl ReadWrite + 'static) -> Self {
+            pub fn new(socket: impl ReadWrite + 'static) -> Result<Self, Error> {
                 let (tx, rx) = channel();
 
                 let receiver_run = Arc::new(AtomicBool::new(true));
                 let channels = Arc::new(Mutex::new(HashMap::new()));
+                let event_channels = Arc::new(Mutex::new(EventRoutes {
+                    channels: HashMap::new(),
+                    held: HashMap::new(),
+                    events: Some(tx),
+                }));
                 let events = Arc::new(Mutex::new(rx));
 
                 let t_receiver_run = Arc::clone(&receiver_run);
-                let t_socket = socket.clone().unwrap();
+                let t_socket = socket.clone()?;
                 let t_channels = Arc::clone(&channels);
-                let receiver = thread::spawn(|| {
-                    recv_thread(t_receiver_run, t_socket, t_channels, tx);
+                let t_event_channels = Arc::clone(&event_channels);
+                let s_socket = socket.clone()?;
+                let handle = thread::spawn(|| {
+                    recv_thread(t_receiver_run, t_socket, t_channels, t_event_channels);
                 });
 
-                Client {
+                let receiver = ReceiverThread {
+                    handle: Some(handle),
+                    run: Arc::clone(&receiver_run),
+                    socket: Mutex::new(s_socket),
+                };
+
+                Ok(Client {
                     inner: Box::new(socket),
                     serial: Arc::new(AtomicU32::new(0)),
                     receiver: Arc::new(receiver),
                     receiver_run,
                     channels,
+                    event_channels,
                     events,
+                })
+            }
+        }
+
+        impl ReceiverThread {
+            fn stop(&mut self) -> Result<(), Error> {
+                if let Some(t) = self.handle.take() {
+                    self.run.fetch_and(false, Ordering::SeqCst);
+
+                    // Unblock the receiver waiting for the next packet.
+                    if let Err(e) = self.socket.lock().unwrap().shutdown(Shutdown::Read) {
+                        trace!("failed to shutdown {}.", e);
+                    }
+
+                    t.join().map_err(|_| Error::ReceiverStopError)?;
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Drop for ReceiverThread {
+            fn drop(&mut self) {
+                if let Err(e) = self.stop() {
+                    trace!("failed to stop receiver {}.", e);
                 }
             }
         }
@@ -182,6 +331,7 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
                 let receiver = Arc::clone(&self.receiver);
                 let receiver_run = Arc::clone(&self.receiver_run);
                 let channels = Arc::clone(&self.channels);
+                let event_channels = Arc::clone(&self.event_channels);
                 let events = Arc::clone(&self.events);
                 Ok(Client {
                     inner,
@@ -189,15 +339,15 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
                     receiver,
                     receiver_run,
                     channels,
+                    event_channels,
                     events,
                 })
             }
 
             fn fin(self) -> Result<(), Error> {
-                if let Some(t) = Arc::into_inner(self.receiver) {
+                if let Some(mut receiver) = Arc::into_inner(self.receiver) {
                     trace!("{}", stringify!(fin));
-                    self.receiver_run.fetch_and(false, Ordering::SeqCst);
-                    t.join().map_err(|_| Error::ReceiverStopError)?;
+                    receiver.stop()?;
                 }
 
                 Ok(())
@@ -234,13 +384,28 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
                 Arc::clone(&self.channels)
             }
 
+            fn add_event_channel(&mut self, program: u32, callback_id: i32, sender: Sender<VirNetResponseRaw>) {
+                let mut routes = self.event_channels.lock().unwrap();
+                routes.channels.insert((program, callback_id), sender);
+            }
+
+            fn remove_event_channel(&mut self, program: u32, callback_id: i32) {
+                let mut routes = self.event_channels.lock().unwrap();
+                routes.channels.remove(&(program, callback_id));
+            }
+
+            fn hold_events(&mut self, program: u32) {
+                let mut routes = self.event_channels.lock().unwrap();
+                routes.held.entry(program).or_default().0 += 1;
+            }
+
+            fn release_events(&mut self, program: u32) {
+                let mut routes = self.event_channels.lock().unwrap();
+                routes.release(program);
+            }
+
             fn get_event(&self, timeout: Duration) -> Result<VirNetResponseRaw, Error> {
-                let raw = self
-                    .events
-                    .lock()
-                    .unwrap()
-                    .recv_timeout(timeout)
-                    .map_err(Error::ReceiveChannelError)?;
+                let raw = recv_response(&self.events.lock().unwrap(), timeout)?;
                 Ok(raw)
             }
         }
@@ -264,11 +429,34 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
 
             fn channel_clone(&self) -> Arc<Mutex<HashMap<u32, Sender<VirNetResponseRaw>>>>;
 
+            // Delivers the events of a callback to `sender` instead of the
+            // queue read by `get_event`.
+            fn add_event_channel(&mut self, program: u32, callback_id: i32, sender: Sender<VirNetResponseRaw>);
+
+            fn remove_event_channel(&mut self, program: u32, callback_id: i32);
+
+            // Keeps the callback events of `program` which no channel claims
+            // until `release_events`, so that a callback being registered
+            // misses none of its events.
+            fn hold_events(&mut self, program: u32);
+
+            fn release_events(&mut self, program: u32);
+
             fn get_event(&self, timeout: Duration) -> Result<VirNetResponseRaw, Error>;
 
             #(#calls)*
         }
 
+        impl VirNetResponseRaw {
+            pub fn program(&self) -> u32 {
+                self.header.prog
+            }
+
+            pub fn procedure(&self) -> i32 {
+                self.header.proc
+            }
+        }
+
         impl<D> VirNetStreamResponse<D>
         where
             D: DeserializeOwned,
@@ -293,7 +481,18 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
             }
 
             pub fn download(&mut self) -> Result<Option<VirNetStream>, Error> {
-                download(self)
+                download(self, CALL_TIMEOUT)
+            }
+
+            pub fn download_timeout(&mut self, timeout: Duration) -> Result<Option<VirNetStream>, Error> {
+                download(self, timeout)
+            }
+
+            pub fn sender(&self) -> Result<VirNetStreamSender, Error> {
+                Ok(VirNetStreamSender {
+                    inner: self.inner.clone()?,
+                    header: self.header.clone(),
+                })
             }
 
             pub fn upload_data(&mut self, buf: &[u8]) -> Result<(), Error> {
@@ -310,6 +509,36 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
                 trace!("{}", stringify!(upload_complete));
                 upload_completed(self)
             }
+
+            pub fn abort(&mut self) -> Result<(), Error> {
+                trace!("{}", stringify!(abort));
+                send_abort(&mut self.inner, &self.header)
+            }
+        }
+
+        impl VirNetStreamSender {
+            pub fn upload_data(&mut self, buf: &[u8]) -> Result<(), Error> {
+                trace!("{}", stringify!(upload_data));
+                let bytes = VirNetStream::Raw(buf.to_vec());
+                let req: Option<VirNetRequest<()>> = Some(VirNetRequest::Stream(bytes));
+                send(
+                    &mut self.inner,
+                    self.header.prog,
+                    self.header.vers,
+                    self.header.proc,
+                    protocol::VirNetMessageType::VirNetStream,
+                    self.header.serial,
+                    protocol::VirNetMessageStatus::VirNetContinue,
+                    req,
+                    &[],
+                )?;
+                Ok(())
+            }
+
+            pub fn abort(&mut self) -> Result<(), Error> {
+                trace!("{}", stringify!(abort));
+                send_abort(&mut self.inner, &self.header)
+            }
         }
 
         #(#msgs)*
@@ -320,21 +549,50 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
             version: u32,
             procedure: i32,
             stream: bool,
+            timeout: Option<Duration>,
             args: Option<S>,
         ) -> Result<VirNetResponseSet<D>, Error>
         where
             S: Serialize,
             D: DeserializeOwned,
         {
+            call_full(client, program, version, procedure, stream, timeout, None, args)
+        }
+
+        // `files` are passed along with the call for procedures which take
+        // descriptors, even when there are none.
+        #[allow(clippy::too_many_arguments)]
+        fn call_full<S, D>(
+            client: &mut impl Libvirt,
+            program: u32,
+            version: u32,
+            procedure: i32,
+            stream: bool,
+            timeout: Option<Duration>,
+            files: Option<&[i32]>,
+            args: Option<S>,
+        ) -> Result<VirNetResponseSet<D>, Error>
+        where
+            S: Serialize,
+            D: DeserializeOwned,
+        {
+            let req_type = match files {
+                Some(_) => protocol::VirNetMessageType::VirNetCallWithFds,
+                None => protocol::VirNetMessageType::VirNetCall,
+            };
+
             let serial = client.serial_add(1);
 
+            let (tx, rx) = channel();
+            client.add_channel(serial, tx);
+
+            // The receiver clears all channels after it stopped, so check it
+            // after registration not to wait for a reply that never comes.
             if !client.receiver_running() {
+                client.remove_channel(serial);
                 return Err(Error::ReceiverNotStartedError);
             }
 
-            let (tx, rx) = channel();
-            client.add_channel(serial, tx);
-
             let socket = client.inner();
 
             if let Err(e) = send(
@@ -342,16 +600,17 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
                 program,
                 version,
                 procedure,
-                protocol::VirNetMessageType::VirNetCall,
+                req_type,
                 serial,
                 protocol::VirNetMessageStatus::VirNetOk,
                 args.map(|a| VirNetRequest::Data(a)),
+                files.unwrap_or_default(),
             ) {
                 client.remove_channel(serial);
                 return Err(e);
             }
 
-            let ret = read_data::<D>(stream, client.channel_clone(), &rx, serial);
+            let ret = read_data::<D>(stream, client.channel_clone(), &rx, serial, timeout);
 
             ret.map(|(header, body)| VirNetResponseSet {
                 receiver: Some(rx),
@@ -360,21 +619,21 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
             })
         }
 
-        fn download<D>(response: &mut VirNetStreamResponse<D>) -> Result<Option<VirNetStream>, Error>
+        fn download<D>(
+            response: &mut VirNetStreamResponse<D>,
+            timeout: Duration,
+        ) -> Result<Option<VirNetStream>, Error>
         where
             D: DeserializeOwned,
         {
             let serial = response.header.serial;
 
-            let res = response
-                .receiver
-                .recv_timeout(Duration::from_secs(180))
-                .map_err(Error::ReceiveChannelError)?;
+            let res = recv_response(&response.receiver, timeout)?;
             if let Some(res_body_bytes) = res.body {
                 match deserialize_body::<()>(&res.header, res_body_bytes) {
                     Ok(res_body) => match res_body {
                         VirNetResponse::Stream(stream) => Ok(Some(stream)),
-                        _ => unreachable!(),
+                        _ => Err(Error::UnexpectedMessageError(res.header)),
                     },
                     Err(e) => Err(e),
                 }
@@ -399,6 +658,7 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
                 response.header.serial,
                 protocol::VirNetMessageStatus::VirNetContinue,
                 req,
+                &[],
             )?;
             Ok(())
         }
@@ -422,6 +682,27 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
                 response.header.serial,
                 protocol::VirNetMessageStatus::VirNetContinue,
                 args,
+                &[],
+            )?;
+            Ok(())
+        }
+
+        // The daemon drops the stream on its side without a reply body.
+        fn send_abort(
+            socket: &mut Box<dyn ReadWrite>,
+            header: &protocol::VirNetMessageHeader,
+        ) -> Result<(), Error> {
+            let req: Option<VirNetRequest<()>> = None;
+            send(
+                socket,
+                header.prog,
+                header.vers,
+                header.proc,
+                protocol::VirNetMessageType::VirNetStream,
+                header.serial,
+                protocol::VirNetMessageStatus::VirNetError,
+                req,
+                &[],
             )?;
             Ok(())
         }
@@ -441,6 +722,7 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
                 response.header.serial,
                 protocol::VirNetMessageStatus::VirNetOk,
                 req,
+                &[],
             )?;
 
             let (_header, _res) = read_data::<()>(
@@ -448,6 +730,7 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
                 Arc::clone(&response.channels),
                 &response.receiver,
                 response.header.serial,
+                Some(CALL_TIMEOUT),
             )?;
 
             Ok(())
@@ -463,10 +746,19 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
             req_serial: u32,
             req_status: protocol::VirNetMessageStatus,
             args: Option<VirNetRequest<S>>,
+            files: &[i32],
         ) -> Result<usize, Error>
         where
             S: Serialize,
         {
+            if files.len() > protocol::VIR_NET_MESSAGE_NUM_FDS_MAX as usize {
+                return Err(Error::SendError(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "too many file descriptors",
+                )));
+            }
+
+            let with_fds = req_type == protocol::VirNetMessageType::VirNetCallWithFds;
             let mut req_len: u32 = 4;
 
             let req_header = protocol::VirNetMessageHeader {
@@ -480,6 +772,11 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
             let req_header_bytes = serde_xdr::to_bytes(&req_header).map_err(Error::SerializeError)?;
             req_len += req_header_bytes.len() as u32;
 
+            // The number of descriptors which follow the message.
+            if with_fds {
+                req_len += 4;
+            }
+
             let mut args_bytes = None;
             match args {
                 Some(VirNetRequest::Data(data)) => {
@@ -499,14 +796,24 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
                 None => { },
             }
 
+            if req_len > protocol::VIR_NET_MESSAGE_MAX {
+                return Err(Error::PacketLengthError(req_len as usize));
+            }
+
             let mut bytes = vec![];
             bytes.extend(req_len.to_be_bytes());
             bytes.extend(req_header_bytes);
+            if with_fds {
+                bytes.extend((files.len() as u32).to_be_bytes());
+            }
             if let Some(args_bytes) = &args_bytes {
                 bytes.extend(args_bytes);
             }
 
             socket.write_all(&bytes).map_err(Error::SendError)?;
+            if !files.is_empty() {
+                socket.send_fds(files)?;
+            }
 
             Ok(bytes.len())
         }
@@ -515,7 +822,7 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
             receiver_run: Arc<AtomicBool>,
             socket: Box<dyn ReadWrite>,
             channels: Arc<Mutex<HashMap<u32, Sender<VirNetResponseRaw>>>>,
-            events: Sender<VirNetResponseRaw>,
+            event_channels: EventChannels,
         ) {
             trace!("receiver started.");
             let mut socket = socket;
@@ -534,39 +841,131 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
                                 trace!("receiver failed to send {}.", e);
                             }
                         } else if raw.header.r#type == protocol::VirNetMessageType::VirNetMessage {
-                            if let Err(e) = events.send(raw) {
-                                trace!("receiver failed to send {}.", e);
-                            }
+                            event_channels.lock().unwrap().route(raw);
                         } else {
                             trace!("receiver not found for serial No.{}.", serial);
                         }
                     }
-                    Err(Error::ReceiveError(e)) => {
-                        trace!("receiver error {}.", e);
-                        if e.kind() == ErrorKind::UnexpectedEof {
-                            receiver_run.fetch_and(false, Ordering::SeqCst);
-                        }
-                    }
                     Err(e) => {
+                        // The stream can not be resynchronized after a read
+                        // failure or a malformed frame.
                         trace!("receiver error {}.", e);
+                        break;
                     }
                 }
             }
+
+            receiver_run.fetch_and(false, Ordering::SeqCst);
+            if let Err(e) = socket.shutdown(Shutdown::Both) {
+                trace!("receiver failed to shutdown {}.", e);
+            }
+            channels.lock().unwrap().clear();
+            {
+                let mut routes = event_channels.lock().unwrap();
+                routes.channels.clear();
+                routes.held.clear();
+                routes.events = None;
+            }
+
             trace!("receiver stopped.");
         }
 
+        impl EventRoutes {
+            fn route(&mut self, raw: VirNetResponseRaw) {
+                let key = event_callback_id(&raw).map(|id| (raw.header.prog, id));
+                let tx = match key.and_then(|k| self.channels.get(&k)) {
+                    Some(tx) => tx,
+                    None => {
+                        if key.is_some() {
+                            if let Some((_, held)) = self.held.get_mut(&raw.header.prog) {
+                                held.push(raw);
+                                return;
+                            }
+                        }
+                        match &self.events {
+                            Some(tx) => tx,
+                            None => return,
+                        }
+                    }
+                };
+
+                if let Err(e) = tx.send(raw) {
+                    trace!("receiver failed to send {}.", e);
+                }
+            }
+
+            // Delivers the held events once the last registration is done,
+            // to their channels if added meanwhile or else to the queue.
+            fn release(&mut self, program: u32) {
+                let Some((count, held)) = self.held.get_mut(&program) else {
+                    return;
+                };
+
+                *count -= 1;
+                let held = std::mem::take(held);
+                if *count == 0 {
+                    self.held.remove(&program);
+                }
+
+                for raw in held {
+                    self.route(raw);
+                }
+            }
+        }
+
+        fn event_callback_id(raw: &VirNetResponseRaw) -> Option<i32> {
+            if !CALLBACK_EVENTS.contains(&(raw.header.prog, raw.header.proc)) {
+                return None;
+            }
+            let bytes = raw.body.as_ref()?.get(..4)?;
+            Some(i32::from_be_bytes(bytes.try_into().ok()?))
+        }
+
         fn recv_raw(
             socket: &mut Box<dyn ReadWrite>,
         ) -> Result<(protocol::VirNetMessageHeader, Option<Vec<u8>>), Error> {
             let res_len = read_pkt_len(socket)?;
+            let min_len = (protocol::VIR_NET_MESSAGE_LEN_MAX + protocol::VIR_NET_MESSAGE_HEADER_MAX) as usize;
+            if res_len < min_len || res_len > protocol::VIR_NET_MESSAGE_MAX as usize {
+                return Err(Error::PacketLengthError(res_len));
+            }
+
             let res_header = read_res_header(socket)?;
-            let body_len = res_len - 28;
+            let body_len = res_len - min_len;
+            if res_header.r#type == protocol::VirNetMessageType::VirNetReplyWithFds {
+                return recv_fds_body(socket, res_header, body_len);
+            }
             if body_len == 0 {
                 return Ok((res_header, None));
             }
             Ok((res_header, Some(read_res_body(socket, body_len)?)))
         }
 
+        // Strips the number of descriptors from the reply. The descriptors
+        // themselves are not kept; reading their bytes closes them.
+        fn recv_fds_body(
+            socket: &mut Box<dyn ReadWrite>,
+            res_header: protocol::VirNetMessageHeader,
+            body_len: usize,
+        ) -> Result<(protocol::VirNetMessageHeader, Option<Vec<u8>>), Error> {
+            if body_len < 4 {
+                return Err(Error::UnexpectedMessageError(res_header));
+            }
+            let mut body = read_res_body(socket, body_len)?;
+            let nfds = u32::from_be_bytes(body[..4].try_into().unwrap());
+            if nfds > protocol::VIR_NET_MESSAGE_NUM_FDS_MAX {
+                return Err(Error::UnexpectedMessageError(res_header));
+            }
+            if nfds > 0 {
+                read_res_body(socket, nfds as usize)?;
+            }
+            body.drain(..4);
+            if body.is_empty() {
+                return Ok((res_header, None));
+            }
+            Ok((res_header, Some(body)))
+        }
+
         fn read_pkt_len(socket: &mut Box<dyn ReadWrite>) -> Result<usize, Error> {
             let mut res_len_bytes = [0; 4];
             socket
@@ -576,7 +975,7 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
         }
 
         fn read_res_header(socket: &mut Box<dyn ReadWrite>) -> Result<protocol::VirNetMessageHeader, Error> {
-            let mut res_header_bytes = [0; 24];
+            let mut res_header_bytes = [0; protocol::VIR_NET_MESSAGE_HEADER_MAX as usize];
             socket
                 .read_exact(&mut res_header_bytes)
                 .map_err(Error::ReceiveError)?;
@@ -597,19 +996,21 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
             channels: Arc<Mutex<HashMap<u32, Sender<VirNetResponseRaw>>>>,
             rx: &Receiver<VirNetResponseRaw>,
             serial: u32,
+            timeout: Option<Duration>,
         ) -> Result<(protocol::VirNetMessageHeader, Option<D>), Error>
         where
             D: DeserializeOwned,
         {
-            let res = rx
-                .recv_timeout(Duration::from_secs(180))
-                .map_err(Error::ReceiveChannelError)?;
+            let res = match timeout {
+                Some(timeout) => recv_response(rx, timeout)?,
+                None => rx.recv().map_err(|_| Error::ConnectionClosedError)?,
+            };
 
             let ret = if let Some(res_body_bytes) = res.body {
                 match deserialize_body(&res.header, res_body_bytes) {
                     Ok(res_body) => match res_body {
                         VirNetResponse::Data(body) => Ok((res.header, Some(body))),
-                        _ => unreachable!(),
+                        _ => Err(Error::UnexpectedMessageError(res.header)),
                     },
                     Err(e) => Err(e),
                 }
@@ -624,6 +1025,17 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
             ret
         }
 
+        fn recv_response(
+            rx: &Receiver<VirNetResponseRaw>,
+            timeout: Duration,
+        ) -> Result<VirNetResponseRaw, Error> {
+            // The receiver drops all senders when the connection is closed.
+            rx.recv_timeout(timeout).map_err(|e| match e {
+                RecvTimeoutError::Disconnected => Error::ConnectionClosedError,
+                e => Error::ReceiveChannelError(e),
+            })
+        }
+
         fn deserialize_body<D>(
             res_header: &protocol::VirNetMessageHeader,
             res_body_bytes: Vec<u8>,
@@ -638,6 +1050,7 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
             } else {
                 match res_header.r#type {
                     protocol::VirNetMessageType::VirNetReply
+                    | protocol::VirNetMessageType::VirNetReplyWithFds
                     | protocol::VirNetMessageType::VirNetMessage => {
                         let data =
                             serde_xdr::from_bytes::<D>(&res_body_bytes).map_err(Error::DeserializeError)?;
@@ -653,15 +1066,81 @@ fn gen_code(stream: TokenStream, wrapped: bool) -> Result<String, Box<dyn Error>
                         let stream = VirNetStream::Hole(hole);
                         Ok(VirNetResponse::Stream(stream))
                     }
-                    _ => unreachable!(),
+                    _ => Err(Error::UnexpectedMessageError(res_header.clone())),
                 }
             }
         }
+
+        // Reads one packet the way the receiver thread does.
+        pub fn read_packet(socket: &mut Box<dyn ReadWrite>) -> Result<VirNetResponseRaw, Error> {
+            let (header, body) = recv_raw(socket)?;
+            Ok(VirNetResponseRaw { header, body })
+        }
+
+        #[cfg(fuzzing)]
+        pub fn fuzz_deserialize_body<D>(
+            res_header: &protocol::VirNetMessageHeader,
+            res_body_bytes: Vec<u8>,
+        ) -> Result<VirNetResponse<D>, Error>
+        where
+            D: DeserializeOwned,
+        {
+            deserialize_body(res_header, res_body_bytes)
+        }
     };
 
     Ok(client.to_string())
 }
 
+fn gen_fuzz_code(stream: TokenStream) -> Result<String, Box<dyn Error>> {
+    let Procedure { models, .. } = parse_file(stream)?;
+
+    let mut names: Vec<&String> = models.keys().collect();
+    names.sort();
+
+    let count = names.len() as u16;
+    let arms = names.iter().enumerate().map(|(index, name)| {
+        let index = index as u16;
+        let model_ident = format_ident!("{}", name);
+        quote! {
+            #index => check::<#model_ident>(data),
+        }
+    });
+
+    let fuzz = quote! {
+        #![no_main]
+
+        use libfuzzer_sys::fuzz_target;
+        use libvirt_remote::binding::*;
+        use serde::{Serialize, de::DeserializeOwned};
+
+        fn check<T>(data: &[u8])
+        where
+            T: Serialize + DeserializeOwned,
+        {
+            if let Ok(value) = serde_xdr::from_bytes::<T>(data) {
+                let bytes = serde_xdr::to_bytes(&value).unwrap();
+                serde_xdr::from_bytes::<T>(&bytes).unwrap();
+            }
+        }
+
+        fuzz_target!(|data: &[u8]| {
+            if data.len() < 2 {
+                return;
+            }
+
+            let index = u16::from_be_bytes([data[0], data[1]]) % #count;
+            let data = &data[2..];
+            match index {
+                #(#arms)*
+                _ => unreachable!(),
+            }
+        });
+    };
+
+    Ok(fuzz.to_string())
+}
+
 fn parse_file(stream: TokenStream) -> Result<Procedure, Box<dyn Error>> {
     let file: syn::File = syn::parse2(stream)?;
 
@@ -717,6 +1196,7 @@ fn get_call_methods(
 
     for (name, args, ret) in parse_call_method(prefix, procedures, models) {
         let stream = stream_procs(&name);
+        let fds = fd_procs(&name);
         let method_name = format_ident!("{}", snake_case(&name));
         let flag = format_ident!("{}Proc{}", prefix, &name);
 
@@ -742,18 +1222,40 @@ fn get_call_methods(
             quote! { false }
         };
 
-        let call_proc = quote! {
-            call::<#xdr_req_type, #xdr_res_type>(
-                self,
-                #program,
-                #proto_version,
-                #procedure::#flag as i32,
-                #stream_arg,
-                req,
-            )?
+        let timeout_arg = if blocking_procs(&name) {
+            quote! { None }
+        } else {
+            quote! { Some(CALL_TIMEOUT) }
         };
 
-        let fn_args = gen_fn_args(&method_name, args.as_deref(), wrapped, models);
+        let call_proc = if fds {
+            quote! {
+                call_full::<#xdr_req_type, #xdr_res_type>(
+                    self,
+                    #program,
+                    #proto_version,
+                    #procedure::#flag as i32,
+                    #stream_arg,
+                    #timeout_arg,
+                    Some(files),
+                    req,
+                )?
+            }
+        } else {
+            quote! {
+                call::<#xdr_req_type, #xdr_res_type>(
+                    self,
+                    #program,
+                    #proto_version,
+                    #procedure::#flag as i32,
+                    #stream_arg,
+                    #timeout_arg,
+                    req,
+                )?
+            }
+        };
+
+        let fn_args = gen_fn_args(&method_name, args.as_deref(), wrapped, fds, models);
         let res_type = gen_res_type(ret.as_deref(), wrapped, stream, models);
         let req_stmt = gen_req_stmt(args.as_deref(), wrapped, models);
         let proc_stmt = gen_proc_stmt(call_proc, ret.as_deref(), wrapped, stream, models);
@@ -780,11 +1282,13 @@ fn get_msg_method(prefix: &str, models: &HashMap<String, syn::ItemStruct>) -> Ve
 
                 fn try_from(value: VirNetResponseRaw) -> Result<Self, Self::Error> {
                     let header = value.header;
-                    let body = value.body.unwrap();
+                    let Some(body) = value.body else {
+                        return Err(Error::UnexpectedMessageError(header));
+                    };
                     match deserialize_body(&header, body) {
                         Ok(res_body) => match res_body {
                             VirNetResponse::Data(body) => Ok(body),
-                            _ => unreachable!(),
+                            _ => Err(Error::UnexpectedMessageError(header)),
                         },
                         Err(e) => Err(e),
                     }
@@ -796,6 +1300,36 @@ fn get_msg_method(prefix: &str, models: &HashMap<String, syn::ItemStruct>) -> Ve
     msgs
 }
 
+fn get_callback_events(
+    prefix: &str,
+    procedures: &syn::ItemEnum,
+    models: &HashMap<String, syn::ItemStruct>,
+) -> Vec<TokenStream> {
+    let mut events = vec![];
+
+    let program = format_ident!("{}_PROGRAM", prefix.to_uppercase());
+
+    let procedure = format_ident!("{}Procedure", prefix);
+
+    for (name, ret) in parse_msg_method(prefix, models) {
+        let model = models.get(&ret).unwrap();
+        let first = model.fields.iter().next().and_then(|f| f.ident.as_ref());
+        if first.is_none_or(|f| f != "callback_id") {
+            continue;
+        }
+
+        let flag = format!("{}Proc{}", prefix, name.strip_suffix("Msg").unwrap());
+        if !procedures.variants.iter().any(|v| v.ident == flag) {
+            continue;
+        }
+
+        let flag = format_ident!("{}", flag);
+        events.push(quote! { (#program, #procedure::#flag as i32) });
+    }
+
+    events
+}
+
 fn parse_call_method(
     prefix: &str,
     procedures: &syn::ItemEnum,
@@ -846,26 +1380,27 @@ fn gen_fn_args(
     name: &Ident,
     model: Option<&str>,
     wrapped: bool,
+    fds: bool,
     models: &HashMap<String, syn::ItemStruct>,
 ) -> TokenStream {
+    let mut params = vec![];
     if let Some(model) = model {
         if wrapped || undeconstructing(model) {
             let model_ident = format_ident!("{}", model);
-            quote! {
-                #name(&mut self, args: #model_ident)
-            }
+            params.push(quote! { args: #model_ident });
         } else {
             let model = models.get(model).unwrap();
-            let params = syn_fields_to_sig_params(model);
-            quote! {
-                #name(&mut self, #(#params),* )
-            }
-        }
-    } else {
-        quote! {
-            #name(&mut self)
+            params.extend(syn_fields_to_sig_params(model));
         }
     }
+
+    if fds {
+        params.push(quote! { files: &[i32] });
+    }
+
+    quote! {
+        #name(&mut self, #(#params),* )
+    }
 }
 
 fn gen_res_type(
@@ -944,7 +1479,7 @@ fn gen_proc_stmt(
         if wrapped || undeconstructing(model) {
             quote! {
                 let res = #proc;
-                Ok(res.body.unwrap())
+                res.body.ok_or(Error::UnexpectedMessageError(res.header))
             }
         } else if stream {
             quote! {
@@ -964,7 +1499,7 @@ fn gen_proc_stmt(
 
             let call_stmt = quote! {
                 let res = #proc;
-                let res = res.body.unwrap();
+                let res = res.body.ok_or(Error::UnexpectedMessageError(res.header))?;
                 let #model_ident { #(#fields),* } = res;
             };
 
@@ -1038,6 +1573,14 @@ fn stream_procs(model: &str) -> bool {
     STREAM_PROCS.contains(&model)
 }
 
+fn fd_procs(model: &str) -> bool {
+    FD_PROCS.contains(&model)
+}
+
+fn blocking_procs(model: &str) -> bool {
+    BLOCKING_PROCS.contains(&model)
+}
+
 fn undeconstructing(model: &str) -> bool {
     UN_DECONSTRUCTING.contains(&model)
 }
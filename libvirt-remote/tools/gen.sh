@@ -50,3 +50,7 @@ rustfmt libvirt-remote/src/binding.rs
 # Generate client.
 cargo run --example libvirt-remote-gen libvirt-remote/src/binding.rs > libvirt-remote/src/client.rs
 rustfmt libvirt-remote/src/client.rs
+
+# Generate fuzz target.
+cargo run --example libvirt-remote-gen libvirt-remote/src/binding.rs fuzz > libvirt-remote/fuzz/fuzz_targets/xdr_structs.rs
+rustfmt libvirt-remote/fuzz/fuzz_targets/xdr_structs.rs
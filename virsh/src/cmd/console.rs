@@ -0,0 +1,54 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::terminal::{Input, RawMode};
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::console::*;
+use std::io;
+
+pub fn cmd() -> Command {
+    Command::new("console")
+        .arg(Arg::new("domain").value_name("domain").required(true))
+        .arg(Arg::new("devname").long("devname").value_name("string"))
+        .arg(Arg::new("force").long("force").num_args(0))
+        .arg(Arg::new("safe").long("safe").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let domain = args.get_one::<String>("domain").unwrap();
+    let devname = args.get_one::<String>("devname");
+
+    let mut flags = 0;
+    if args.get_flag("force") {
+        flags |= VIR_DOMAIN_CONSOLE_FORCE;
+    }
+    if args.get_flag("safe") {
+        flags |= VIR_DOMAIN_CONSOLE_SAFE;
+    }
+
+    let dom = client.domain_lookup_by_name(domain.to_string())?;
+
+    let session =
+        ConsoleSession::open_console(client.as_mut(), dom, devname.map(|s| s.as_str()), flags)?;
+
+    println!(
+        "{}",
+        locale.format_message("FormatConnectedDomain", vec![("name", domain)])
+    );
+    println!("{}", locale.get_message("EscapeCharacter"));
+
+    let ret = {
+        let _raw = RawMode::enable();
+        session.run(Input, io::stdout())
+    };
+
+    println!();
+
+    ret?;
+
+    Ok(())
+}
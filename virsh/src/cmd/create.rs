@@ -0,0 +1,53 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::domain::*;
+use std::fs;
+
+pub fn cmd() -> Command {
+    Command::new("create")
+        .arg(Arg::new("file").value_name("file").required(true).index(1))
+        .arg(Arg::new("paused").long("paused").num_args(0))
+        .arg(Arg::new("autodestroy").long("autodestroy").num_args(0))
+        .arg(Arg::new("validate").long("validate").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let file = args.get_one::<String>("file").unwrap();
+    let paused = args.get_flag("paused");
+    let autodestroy = args.get_flag("autodestroy");
+    let validate = args.get_flag("validate");
+
+    let xml = fs::read_to_string(file)?;
+
+    let mut flags = VIR_DOMAIN_NONE;
+
+    if paused {
+        flags |= VIR_DOMAIN_START_PAUSED;
+    }
+
+    if autodestroy {
+        flags |= VIR_DOMAIN_START_AUTODESTROY;
+    }
+
+    if validate {
+        flags |= VIR_DOMAIN_START_VALIDATE;
+    }
+
+    let dom = client.domain_create_xml(xml, flags)?;
+
+    println!(
+        "{}",
+        locale.format_message(
+            "FormatDomainCreated",
+            vec![("name", &dom.name), ("file", file)]
+        )
+    );
+
+    Ok(())
+}
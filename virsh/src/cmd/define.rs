@@ -0,0 +1,39 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::domain::*;
+use std::fs;
+
+pub fn cmd() -> Command {
+    Command::new("define")
+        .arg(Arg::new("file").value_name("file").required(true).index(1))
+        .arg(Arg::new("validate").long("validate").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let file = args.get_one::<String>("file").unwrap();
+    let validate = args.get_flag("validate");
+
+    let xml = fs::read_to_string(file)?;
+
+    let dom = if validate {
+        client.domain_define_xml_flags(xml, VIR_DOMAIN_DEFINE_VALIDATE)?
+    } else {
+        client.domain_define_xml(xml)?
+    };
+
+    println!(
+        "{}",
+        locale.format_message(
+            "FormatDomainDefined",
+            vec![("name", &dom.name), ("file", file)]
+        )
+    );
+
+    Ok(())
+}
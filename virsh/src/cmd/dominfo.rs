@@ -0,0 +1,115 @@
+use crate::error::Error;
+use crate::kv_view::KeyValueView;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::domain::DomainState;
+
+pub fn cmd() -> Command {
+    Command::new("dominfo").arg(
+        Arg::new("domain")
+            .value_name("domain")
+            .required(true)
+            .index(1),
+    )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let domain = args.get_one::<String>("domain").unwrap();
+
+    let dom = util::lookup_domain(client, domain)?;
+    let (state, max_mem, memory, nr_virt_cpu, cpu_time) = client.domain_get_info(dom.clone())?;
+
+    let mut view = KeyValueView::default();
+
+    let id = if dom.id > 0 {
+        dom.id.to_string()
+    } else {
+        "-".to_string()
+    };
+    view.add_row(&locale.get_message("LabelId"), id);
+    view.add_row(&locale.get_message("LabelName"), &dom.name);
+    view.add_row(
+        &locale.get_message("LabelUuid"),
+        util::format_uuid(&dom.uuid),
+    );
+    if let Ok(os_type) = client.domain_get_os_type(dom.clone()) {
+        view.add_row(&locale.get_message("LabelOsType"), os_type);
+    }
+    view.add_row(
+        &locale.get_message("LabelState"),
+        util::state_label(locale, DomainState::from(state as i32)),
+    );
+    view.add_row(&locale.get_message("LabelCpuNum"), nr_virt_cpu);
+    if cpu_time != 0 {
+        view.add_row(
+            &locale.get_message("LabelCpuTime"),
+            format!("{:.1}s", cpu_time as f64 / 1_000_000_000.0),
+        );
+    }
+    view.add_row(
+        &locale.get_message("LabelMaxMemory"),
+        format!("{max_mem} KiB"),
+    );
+    view.add_row(
+        &locale.get_message("LabelUsedMemory"),
+        format!("{memory} KiB"),
+    );
+
+    let persistent = match client.domain_is_persistent(dom.clone()) {
+        Ok(0) => locale.get_message("No"),
+        Ok(_) => locale.get_message("Yes"),
+        Err(_) => locale.get_message("Unknown"),
+    };
+    view.add_row(&locale.get_message("LabelPersistent"), persistent);
+
+    if let Ok(autostart) = client.domain_get_autostart(dom.clone()) {
+        let autostart = match autostart {
+            0 => locale.get_message("Disable"),
+            _ => locale.get_message("Enable"),
+        };
+        view.add_row(&locale.get_message("LabelAutostart"), autostart);
+    }
+
+    if let Ok(managed_save) = client.domain_has_managed_save_image(dom.clone(), 0) {
+        let managed_save = match managed_save {
+            0 => locale.get_message("No"),
+            _ => locale.get_message("Yes"),
+        };
+        view.add_row(&locale.get_message("LabelManagedSave"), managed_save);
+    }
+
+    // Only shown when the host has a security driver enabled.
+    if let Ok((model, doi)) = client.node_get_security_model() {
+        let model = util::to_utf8_str(&model)?;
+        if !model.is_empty() {
+            view.add_row(&locale.get_message("LabelSecurityModel"), &model);
+            view.add_row(
+                &locale.get_message("LabelSecurityDoi"),
+                util::to_utf8_str(&doi)?,
+            );
+
+            let (label, enforcing) = client.domain_get_security_label(dom)?;
+            let label = util::to_utf8_str(&label)?;
+            if !label.is_empty() {
+                let mode = match enforcing {
+                    0 => locale.get_message("Permissive"),
+                    _ => locale.get_message("Enforcing"),
+                };
+                view.add_row(
+                    &locale.get_message("LabelSecurityLabel"),
+                    format!("{label} ({mode})"),
+                );
+            }
+        }
+    }
+
+    view.print_kv();
+
+    Ok(())
+}
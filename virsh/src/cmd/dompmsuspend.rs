@@ -0,0 +1,54 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::domain::*;
+
+pub fn cmd() -> Command {
+    Command::new("dompmsuspend")
+        .arg(
+            Arg::new("domain")
+                .value_name("domain")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("target")
+                .value_name("target")
+                .value_parser(["mem", "disk", "hybrid"])
+                .required(true)
+                .index(2),
+        )
+        .arg(
+            Arg::new("duration")
+                .long("duration")
+                .value_name("seconds")
+                .value_parser(clap::value_parser!(u64))
+                .default_value("0"),
+        )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let domain = args.get_one::<String>("domain").unwrap();
+    let target = match args.get_one::<String>("target").unwrap().as_str() {
+        "disk" => VIR_NODE_SUSPEND_TARGET_DISK,
+        "hybrid" => VIR_NODE_SUSPEND_TARGET_HYBRID,
+        _ => VIR_NODE_SUSPEND_TARGET_MEM,
+    };
+    let duration = *args.get_one::<u64>("duration").unwrap();
+
+    let dom = util::lookup_domain(client, domain)?;
+    client.domain_pm_suspend_for_duration(dom.clone(), target, duration, 0)?;
+
+    println!(
+        "{}",
+        locale.format_message("FormatDomainPmSuspended", vec![("name", &dom.name)])
+    );
+
+    Ok(())
+}
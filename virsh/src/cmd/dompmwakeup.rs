@@ -0,0 +1,32 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("dompmwakeup").arg(
+        Arg::new("domain")
+            .value_name("domain")
+            .required(true)
+            .index(1),
+    )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let domain = args.get_one::<String>("domain").unwrap();
+
+    let dom = util::lookup_domain(client, domain)?;
+    client.domain_pm_wakeup(dom.clone(), 0)?;
+
+    println!(
+        "{}",
+        locale.format_message("FormatDomainPmWokenUp", vec![("name", &dom.name)])
+    );
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::domain::DomainState;
+
+pub fn cmd() -> Command {
+    Command::new("domstate")
+        .arg(
+            Arg::new("domain")
+                .value_name("domain")
+                .required(true)
+                .index(1),
+        )
+        .arg(Arg::new("reason").long("reason").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let domain = args.get_one::<String>("domain").unwrap();
+    let reason = args.get_flag("reason");
+
+    let dom = util::lookup_domain(client, domain)?;
+    let (state, code) = client.domain_get_state(dom, 0)?;
+    let state = DomainState::from(state);
+
+    if reason {
+        println!(
+            "{} ({})",
+            util::state_label(locale, state),
+            state.reason(code)
+        );
+    } else {
+        println!("{}", util::state_label(locale, state));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("domuuid").arg(
+        Arg::new("domain")
+            .value_name("domain")
+            .required(true)
+            .index(1),
+    )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    _locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let domain = args.get_one::<String>("domain").unwrap();
+
+    let dom = util::lookup_domain(client, domain)?;
+    println!("{}", util::format_uuid(&dom.uuid));
+
+    Ok(())
+}
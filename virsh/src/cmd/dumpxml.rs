@@ -0,0 +1,72 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use crate::xpath;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::domain::*;
+
+const OPTIONS: [(&str, u32); 4] = [
+    ("inactive", VIR_DOMAIN_XML_INACTIVE),
+    ("security-info", VIR_DOMAIN_XML_SECURE),
+    ("update-cpu", VIR_DOMAIN_XML_UPDATE_CPU),
+    ("migratable", VIR_DOMAIN_XML_MIGRATABLE),
+];
+
+pub fn cmd() -> Command {
+    let mut cmd = Command::new("dumpxml").arg(
+        Arg::new("domain")
+            .value_name("domain")
+            .required(true)
+            .index(1),
+    );
+
+    for (name, _) in OPTIONS {
+        cmd = cmd.arg(Arg::new(name).long(name).num_args(0));
+    }
+
+    cmd.arg(Arg::new("xpath").long("xpath").value_name("expression"))
+        .arg(Arg::new("wrap").long("wrap").num_args(0).requires("xpath"))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    _locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let domain = args.get_one::<String>("domain").unwrap();
+
+    let flags = OPTIONS
+        .iter()
+        .filter(|(name, _)| args.get_flag(name))
+        .fold(0, |flags, (_, flag)| flags | flag);
+
+    let dom = util::lookup_domain(client, domain)?;
+    let xml = client.domain_get_xml_desc(dom, flags)?;
+
+    print_xml(&xml, args)
+}
+
+// Prints the whole document, or what `--xpath` selects from it.
+pub fn print_xml(xml: &str, args: &ArgMatches) -> Result<(), Error> {
+    let Some(expr) = args.get_one::<String>("xpath") else {
+        print!("{xml}");
+        return Ok(());
+    };
+
+    let results = xpath::select(xml, expr)?;
+
+    if args.get_flag("wrap") {
+        println!("<nodes>");
+        for result in results {
+            println!("{result}");
+        }
+        println!("</nodes>");
+    } else {
+        for result in results {
+            println!("{result}");
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::editor::{self, EditOutcome};
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::domain::*;
+
+pub fn cmd() -> Command {
+    Command::new("edit").arg(
+        Arg::new("domain")
+            .value_name("domain")
+            .required(true)
+            .index(1),
+    )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let domain = args.get_one::<String>("domain").unwrap();
+
+    let dom = util::lookup_domain(client, domain)?;
+    let xml =
+        client.domain_get_xml_desc(dom.clone(), VIR_DOMAIN_XML_SECURE | VIR_DOMAIN_XML_INACTIVE)?;
+
+    let outcome = editor::edit_xml(locale, &xml, |xml, validate| {
+        let flags = if validate {
+            VIR_DOMAIN_DEFINE_VALIDATE
+        } else {
+            0
+        };
+        client.domain_define_xml_flags(xml.to_string(), flags)?;
+        Ok(())
+    })?;
+
+    let id = match outcome {
+        EditOutcome::Unchanged => "FormatDomainXmlNotChanged",
+        EditOutcome::Edited => "FormatDomainXmlEdited",
+    };
+    println!("{}", locale.format_message(id, vec![("name", &dom.name)]));
+
+    Ok(())
+}
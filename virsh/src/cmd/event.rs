@@ -0,0 +1,391 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::binding::{
+    RemoteDomainEventGraphicsAddress, RemoteTypedParam, RemoteTypedParamValue,
+};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::event::*;
+use log::trace;
+use std::time::{Duration, Instant};
+
+const LIFECYCLE_EVENTS: [&str; 9] = [
+    "Defined",
+    "Undefined",
+    "Started",
+    "Suspended",
+    "Resumed",
+    "Stopped",
+    "Shutdown",
+    "PMSuspended",
+    "Crashed",
+];
+
+const LIFECYCLE_DETAILS: [&[&str]; 9] = [
+    &["Added", "Updated", "Renamed", "Snapshot"],
+    &["Removed", "Renamed"],
+    &["Booted", "Migrated", "Restored", "Snapshot", "Event wakeup"],
+    &[
+        "Paused",
+        "Migrated",
+        "I/O Error",
+        "Watchdog",
+        "Restored",
+        "Snapshot",
+        "API error",
+        "Post-copy",
+        "Post-copy Error",
+    ],
+    &[
+        "Unpaused",
+        "Migrated",
+        "Snapshot",
+        "Post-copy",
+        "Post-copy Error",
+    ],
+    &[
+        "Shutdown",
+        "Destroyed",
+        "Crashed",
+        "Migrated",
+        "Saved",
+        "Failed",
+        "Snapshot",
+    ],
+    &[
+        "Finished",
+        "Finished after guest request",
+        "Finished after host request",
+    ],
+    &["Memory", "Disk"],
+    &["Panicked", "Crashloaded"],
+];
+
+const WATCHDOG_ACTIONS: [&str; 7] = [
+    "none",
+    "pause",
+    "reset",
+    "poweroff",
+    "shutdown",
+    "debug",
+    "inject-nmi",
+];
+
+const IO_ERROR_ACTIONS: [&str; 3] = ["none", "pause", "report"];
+
+const GRAPHICS_PHASES: [&str; 3] = ["connect", "initialize", "disconnect"];
+
+const GRAPHICS_ADDRESS_FAMILIES: [&str; 3] = ["ipv4", "ipv6", "unix"];
+
+const BLOCK_JOB_TYPES: [&str; 6] = [
+    "unknown",
+    "pull",
+    "copy",
+    "commit",
+    "active commit",
+    "backup",
+];
+
+const BLOCK_JOB_STATUSES: [&str; 4] = ["completed", "failed", "canceled", "ready"];
+
+const DISK_CHANGE_REASONS: [&str; 2] = ["missing on start", "missing"];
+
+const TRAY_CHANGE_REASONS: [&str; 2] = ["opened", "closed"];
+
+const AGENT_LIFECYCLE_STATES: [&str; 3] = ["unknown", "connected", "disconnected"];
+
+const AGENT_LIFECYCLE_REASONS: [&str; 3] = ["unknown", "domain started", "channel event"];
+
+const METADATA_TYPES: [&str; 3] = ["description", "title", "element"];
+
+const MEMORY_FAILURE_RECIPIENTS: [&str; 2] = ["hypervisor", "guest"];
+
+const MEMORY_FAILURE_ACTIONS: [&str; 4] = ["ignore", "inject", "fatal", "reset"];
+
+const VIR_DOMAIN_MEMORY_FAILURE_ACTION_REQUIRED: u32 = 1 << 0;
+const VIR_DOMAIN_MEMORY_FAILURE_RECURSIVE: u32 = 1 << 1;
+
+pub fn cmd() -> Command {
+    Command::new("event")
+        .arg(Arg::new("domain").long("domain").value_name("string"))
+        .arg(Arg::new("event").long("event").value_name("string"))
+        .arg(
+            Arg::new("all")
+                .long("all")
+                .num_args(0)
+                .conflicts_with("event"),
+        )
+        .arg(Arg::new("loop").long("loop").num_args(0))
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .value_name("number")
+                .value_parser(clap::value_parser!(u64)),
+        )
+        .arg(Arg::new("list").long("list").num_args(0))
+        .arg(Arg::new("timestamp").long("timestamp").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    _locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    if args.get_flag("list") {
+        for e in DOMAIN_EVENT_NAMES {
+            println!("{e}");
+        }
+        return Ok(());
+    }
+
+    let event_ids = if args.get_flag("all") {
+        (0..DOMAIN_EVENT_NAMES.len() as i32).collect::<Vec<_>>()
+    } else {
+        let Some(name) = args.get_one::<String>("event") else {
+            return Err(Error::Arg("--event or --all".to_string()));
+        };
+        let id = domain_event_id(name).ok_or_else(|| Error::Arg(name.to_string()))?;
+        vec![id]
+    };
+
+    let dom = match args.get_one::<String>("domain") {
+        Some(domain) => Some(util::lookup_domain(client, domain)?),
+        _ => None,
+    };
+
+    let mut callbacks = vec![];
+    for id in event_ids {
+        match client.connect_domain_event_callback_register_any(id, dom.clone()) {
+            Ok(callback_id) => callbacks.push(callback_id),
+            // Older daemons do not know the newer events, so `--all` registers
+            // what is available.
+            Err(e) if args.get_flag("all") => {
+                trace!("skip event {}: {e:?}", DOMAIN_EVENT_NAMES[id as usize]);
+            }
+            Err(e) => return Err(Error::from(e)),
+        }
+    }
+
+    let deadline = args
+        .get_one::<u64>("timeout")
+        .map(|secs| Instant::now() + Duration::from_secs(*secs));
+
+    let mut ret = Ok(());
+    let mut count = 0;
+    loop {
+        let raw = match wait_event(client.as_mut(), deadline) {
+            Ok(Some(raw)) => raw,
+            Ok(None) => {
+                println!("event loop timed out");
+                break;
+            }
+            Err(e) => {
+                ret = Err(Error::from(e));
+                break;
+            }
+        };
+
+        let msg = match DomainEventMsg::from_raw(raw) {
+            Ok(Some(msg)) if callbacks.contains(&msg.callback_id) => msg,
+            Ok(_) => continue,
+            Err(e) => {
+                ret = Err(Error::from(e));
+                break;
+            }
+        };
+
+        let time = util::event_timestamp(args.get_flag("timestamp"));
+        println!("{time}{}", format_event(&msg));
+        count += 1;
+
+        if !args.get_flag("loop") {
+            break;
+        }
+    }
+
+    if args.get_flag("loop") {
+        println!("events received: {count}");
+    }
+
+    for callback_id in callbacks {
+        client.connect_domain_event_callback_deregister_any(callback_id)?;
+    }
+
+    ret
+}
+
+fn format_event(msg: &DomainEventMsg) -> String {
+    let head = format!("event '{}' for domain '{}'", msg.event.name(), msg.dom.name);
+
+    match &msg.event {
+        DomainEvent::Lifecycle { event, detail } => {
+            let details = usize::try_from(*event)
+                .ok()
+                .and_then(|e| LIFECYCLE_DETAILS.get(e))
+                .copied()
+                .unwrap_or_default();
+            format!(
+                "{head}: {} {}",
+                name(&LIFECYCLE_EVENTS, *event),
+                name(details, *detail)
+            )
+        }
+        DomainEvent::Reboot | DomainEvent::ControlError => head,
+        DomainEvent::RtcChange { offset } => format!("{head}: {offset}"),
+        DomainEvent::Watchdog { action } => {
+            format!("{head}: {}", name(&WATCHDOG_ACTIONS, *action))
+        }
+        DomainEvent::IoError {
+            src_path,
+            dev_alias,
+            action,
+        } => format!(
+            "{head}: {src_path} ({dev_alias}) {}",
+            name(&IO_ERROR_ACTIONS, *action)
+        ),
+        DomainEvent::IoErrorReason {
+            src_path,
+            dev_alias,
+            action,
+            reason,
+        } => format!(
+            "{head}: {src_path} ({dev_alias}) {} due to {reason}",
+            name(&IO_ERROR_ACTIONS, *action)
+        ),
+        DomainEvent::Graphics {
+            phase,
+            local,
+            remote,
+            auth_scheme,
+            subject,
+        } => {
+            let mut out = format!(
+                "{head}: {} local[{}] remote[{}] {auth_scheme}",
+                name(&GRAPHICS_PHASES, *phase),
+                graphics_address(local),
+                graphics_address(remote)
+            );
+            for identity in subject {
+                out.push_str(&format!(" {}={}", identity.r#type, identity.name));
+            }
+            out
+        }
+        DomainEvent::BlockJob {
+            path: disk,
+            r#type,
+            status,
+        }
+        | DomainEvent::BlockJob2 {
+            dst: disk,
+            r#type,
+            status,
+        } => format!(
+            "{head}: {} for {disk} {}",
+            name(&BLOCK_JOB_TYPES, *r#type),
+            name(&BLOCK_JOB_STATUSES, *status)
+        ),
+        DomainEvent::DiskChange {
+            old_src_path,
+            new_src_path,
+            dev_alias,
+            reason,
+        } => format!(
+            "{head} disk {dev_alias}: {} -> {}: {}",
+            old_src_path.as_deref().unwrap_or("-"),
+            new_src_path.as_deref().unwrap_or("-"),
+            name(&DISK_CHANGE_REASONS, *reason)
+        ),
+        DomainEvent::TrayChange { dev_alias, reason } => format!(
+            "{head} disk {dev_alias}: {}",
+            name(&TRAY_CHANGE_REASONS, *reason)
+        ),
+        DomainEvent::PmWakeup { .. }
+        | DomainEvent::PmSuspend { .. }
+        | DomainEvent::PmSuspendDisk { .. } => head,
+        DomainEvent::BalloonChange { actual } => format!("{head}: {actual}KiB"),
+        DomainEvent::DeviceRemoved { dev_alias }
+        | DomainEvent::DeviceAdded { dev_alias }
+        | DomainEvent::DeviceRemovalFailed { dev_alias } => format!("{head}: {dev_alias}"),
+        DomainEvent::Tunable { params } | DomainEvent::JobCompleted { params } => {
+            format!("{head}:{}", format_params(params))
+        }
+        DomainEvent::AgentLifecycle { state, reason } => format!(
+            "{head}: state: '{}' reason: '{}'",
+            name(&AGENT_LIFECYCLE_STATES, *state),
+            name(&AGENT_LIFECYCLE_REASONS, *reason)
+        ),
+        DomainEvent::MigrationIteration { iteration } => {
+            format!("{head}: iteration: '{iteration}'")
+        }
+        DomainEvent::MetadataChange { r#type, nsuri } => format!(
+            "{head}: type {}, uri {}",
+            name(&METADATA_TYPES, *r#type),
+            nsuri.as_deref().unwrap_or("n/a")
+        ),
+        DomainEvent::BlockThreshold {
+            dev,
+            path,
+            threshold,
+            excess,
+        } => format!(
+            "{head}: dev: {dev}({}) {threshold} {excess}",
+            path.as_deref().unwrap_or("")
+        ),
+        DomainEvent::MemoryFailure {
+            recipient,
+            action,
+            flags,
+        } => format!(
+            "{head}:\nrecipient: {}\naction: {}\nflags:\n\taction required: {}\n\trecursive: {}",
+            name(&MEMORY_FAILURE_RECIPIENTS, *recipient),
+            name(&MEMORY_FAILURE_ACTIONS, *action),
+            (flags & VIR_DOMAIN_MEMORY_FAILURE_ACTION_REQUIRED != 0) as u8,
+            (flags & VIR_DOMAIN_MEMORY_FAILURE_RECURSIVE != 0) as u8
+        ),
+        DomainEvent::MemoryDeviceSizeChange { alias, size } => {
+            format!("{head}:\nalias: {alias}\nsize: {size}")
+        }
+        DomainEvent::NicMacChange {
+            alias,
+            old_mac,
+            new_mac,
+        } => format!("{head}: alias: {alias} oldMAC: {old_mac} newMAC: {new_mac}"),
+    }
+}
+
+fn name(names: &[&'static str], value: i32) -> &'static str {
+    usize::try_from(value)
+        .ok()
+        .and_then(|v| names.get(v))
+        .copied()
+        .unwrap_or("unknown")
+}
+
+fn graphics_address(address: &RemoteDomainEventGraphicsAddress) -> String {
+    format!(
+        "{} {} {}",
+        name(&GRAPHICS_ADDRESS_FAMILIES, address.family),
+        address.node,
+        address.service
+    )
+}
+
+fn format_params(params: &[RemoteTypedParam]) -> String {
+    params
+        .iter()
+        .map(|p| {
+            let value = match &p.value {
+                RemoteTypedParamValue::VirTypedParamInt(v) => v.to_string(),
+                RemoteTypedParamValue::VirTypedParamUint(v) => v.to_string(),
+                RemoteTypedParamValue::VirTypedParamLlong(v) => v.to_string(),
+                RemoteTypedParamValue::VirTypedParamUllong(v) => v.to_string(),
+                RemoteTypedParamValue::VirTypedParamDouble(v) => v.to_string(),
+                RemoteTypedParamValue::VirTypedParamBoolean(v) => (*v != 0).to_string(),
+                RemoteTypedParamValue::VirTypedParamString(v) => v.clone(),
+                RemoteTypedParamValue::_Reserved0 => String::new(),
+            };
+            format!("\n\t{}: {value}", p.field)
+        })
+        .collect()
+}
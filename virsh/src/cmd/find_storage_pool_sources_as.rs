@@ -0,0 +1,56 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::xml::XmlDocument;
+use libvirt_remote::xml::storage::*;
+
+pub fn cmd() -> Command {
+    Command::new("find-storage-pool-sources-as")
+        .arg(Arg::new("type").value_name("type").required(true).index(1))
+        .arg(Arg::new("host").long("host").value_name("string"))
+        .arg(
+            Arg::new("port")
+                .long("port")
+                .value_name("number")
+                .value_parser(clap::value_parser!(u16))
+                .requires("host"),
+        )
+        .arg(Arg::new("initiator").long("initiator").value_name("string"))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    _locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let r#type = args.get_one::<String>("type").unwrap();
+
+    // Without a host, the local host is searched and no specification is sent.
+    let src_spec = match args.get_one::<String>("host") {
+        Some(host) => {
+            let source = PoolSource {
+                host: vec![PoolSourceHost {
+                    name: host.to_string(),
+                    port: args.get_one::<u16>("port").copied(),
+                }],
+                initiator: args
+                    .get_one::<String>("initiator")
+                    .map(|name| PoolSourceInitiator {
+                        iqn: PoolSourceIqn {
+                            name: name.to_string(),
+                        },
+                    }),
+                ..Default::default()
+            };
+            Some(source.to_xml()?)
+        }
+        None => None,
+    };
+
+    let xml = client.connect_find_storage_pool_sources(r#type.to_string(), src_spec, 0)?;
+
+    print!("{xml}");
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::table_view::TableView;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::binding::RemoteNonnullDomain;
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::domain::*;
+use libvirt_remote::error::Error as LibvirtError;
+
+const VIR_ERR_NO_DOMAIN_METADATA: i32 = 80;
+
+const FILTERS: [(&str, u32); 14] = [
+    ("persistent", VIR_CONNECT_LIST_DOMAINS_PERSISTENT),
+    ("transient", VIR_CONNECT_LIST_DOMAINS_TRANSIENT),
+    ("autostart", VIR_CONNECT_LIST_DOMAINS_AUTOSTART),
+    ("no-autostart", VIR_CONNECT_LIST_DOMAINS_NO_AUTOSTART),
+    ("with-managed-save", VIR_CONNECT_LIST_DOMAINS_MANAGEDSAVE),
+    (
+        "without-managed-save",
+        VIR_CONNECT_LIST_DOMAINS_NO_MANAGEDSAVE,
+    ),
+    ("with-snapshot", VIR_CONNECT_LIST_DOMAINS_HAS_SNAPSHOT),
+    ("without-snapshot", VIR_CONNECT_LIST_DOMAINS_NO_SNAPSHOT),
+    ("with-checkpoint", VIR_CONNECT_LIST_DOMAINS_HAS_CHECKPOINT),
+    ("without-checkpoint", VIR_CONNECT_LIST_DOMAINS_NO_CHECKPOINT),
+    ("state-running", VIR_CONNECT_LIST_DOMAINS_RUNNING),
+    ("state-paused", VIR_CONNECT_LIST_DOMAINS_PAUSED),
+    ("state-shutoff", VIR_CONNECT_LIST_DOMAINS_SHUTOFF),
+    ("state-other", VIR_CONNECT_LIST_DOMAINS_OTHER),
+];
+
+pub fn cmd() -> Command {
+    let mut cmd = Command::new("list")
+        .arg(Arg::new("inactive").long("inactive").num_args(0))
+        .arg(
+            Arg::new("all")
+                .long("all")
+                .num_args(0)
+                .conflicts_with("inactive"),
+        )
+        .arg(Arg::new("name").long("name").num_args(0))
+        .arg(
+            Arg::new("uuid")
+                .long("uuid")
+                .num_args(0)
+                .conflicts_with("name"),
+        )
+        .arg(
+            Arg::new("title")
+                .long("title")
+                .num_args(0)
+                .conflicts_with_all(["name", "uuid"]),
+        );
+
+    for (name, _) in FILTERS {
+        cmd = cmd.arg(Arg::new(name).long(name).num_args(0));
+    }
+
+    cmd
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let mut flags = FILTERS
+        .iter()
+        .filter(|(name, _)| args.get_flag(name))
+        .fold(0, |flags, (_, flag)| flags | flag);
+
+    // Filtering by state or attributes implies inactive domains as well.
+    if args.get_flag("inactive") {
+        flags |= VIR_CONNECT_LIST_DOMAINS_INACTIVE;
+    } else if args.get_flag("all") || flags != 0 {
+        flags |= VIR_CONNECT_LIST_DOMAINS_ACTIVE | VIR_CONNECT_LIST_DOMAINS_INACTIVE;
+    } else {
+        flags |= VIR_CONNECT_LIST_DOMAINS_ACTIVE;
+    }
+
+    let (mut doms, _) = client.connect_list_all_domains(-1, flags)?;
+
+    // Running domains first ordered by ID, then the rest by name.
+    doms.sort_by(|a, b| match (a.id > 0, b.id > 0) {
+        (true, true) => a.id.cmp(&b.id),
+        (true, false) => std::cmp::Ordering::Less,
+        (false, true) => std::cmp::Ordering::Greater,
+        (false, false) => a.name.cmp(&b.name),
+    });
+
+    if args.get_flag("name") {
+        for dom in doms {
+            println!("{}", dom.name);
+        }
+        return Ok(());
+    }
+
+    if args.get_flag("uuid") {
+        for dom in doms {
+            println!("{}", util::format_uuid(&dom.uuid));
+        }
+        return Ok(());
+    }
+
+    let title = args.get_flag("title");
+
+    let mut headers = vec![
+        locale.get_message("Id"),
+        locale.get_message("Name"),
+        locale.get_message("State"),
+    ];
+    if title {
+        headers.push(locale.get_message("Title"));
+    }
+    let mut view = TableView::new(headers.iter().map(|h| h.as_str()).collect());
+
+    for dom in doms {
+        let (state, _) = client.domain_get_state(dom.clone(), 0)?;
+        let state = util::state_label(locale, DomainState::from(state));
+        let id = if dom.id > 0 {
+            dom.id.to_string()
+        } else {
+            "-".to_string()
+        };
+
+        let mut cells = vec![id, dom.name.clone(), state];
+        if title {
+            cells.push(domain_title(client, dom)?);
+        }
+        view.add_row(cells.iter().map(|c| c.as_str()).collect());
+    }
+
+    view.print_table();
+
+    Ok(())
+}
+
+fn domain_title(client: &mut Box<impl Libvirt>, dom: RemoteNonnullDomain) -> Result<String, Error> {
+    match client.domain_get_metadata(dom, VIR_DOMAIN_METADATA_TITLE, None, 0) {
+        Ok(title) => Ok(title),
+        Err(LibvirtError::ProtocolError(e)) if e.code == VIR_ERR_NO_DOMAIN_METADATA => {
+            Ok(String::new())
+        }
+        Err(e) => Err(Error::from(e)),
+    }
+}
@@ -0,0 +1,161 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::binding::RemoteTypedParam;
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::job::JobProgress;
+use libvirt_remote::migrate::*;
+use libvirt_remote::uri::ConnectUri;
+
+pub fn cmd() -> Command {
+    Command::new("migrate")
+        .arg(
+            Arg::new("domain")
+                .value_name("domain")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("desturi")
+                .value_name("desturi")
+                .required(true)
+                .index(2),
+        )
+        .arg(Arg::new("migrateuri").value_name("migrateuri").index(3))
+        .arg(Arg::new("live").long("live").num_args(0))
+        .arg(Arg::new("offline").long("offline").num_args(0))
+        .arg(Arg::new("p2p").long("p2p").num_args(0))
+        .arg(Arg::new("tunnelled").long("tunnelled").num_args(0))
+        .arg(Arg::new("persistent").long("persistent").num_args(0))
+        .arg(
+            Arg::new("undefinesource")
+                .long("undefinesource")
+                .num_args(0),
+        )
+        .arg(Arg::new("suspend").long("suspend").num_args(0))
+        .arg(
+            Arg::new("copy-storage-all")
+                .long("copy-storage-all")
+                .num_args(0),
+        )
+        .arg(
+            Arg::new("copy-storage-inc")
+                .long("copy-storage-inc")
+                .num_args(0),
+        )
+        .arg(Arg::new("unsafe").long("unsafe").num_args(0))
+        .arg(Arg::new("compressed").long("compressed").num_args(0))
+        .arg(
+            Arg::new("abort-on-error")
+                .long("abort-on-error")
+                .num_args(0),
+        )
+        .arg(Arg::new("auto-converge").long("auto-converge").num_args(0))
+        .arg(Arg::new("postcopy").long("postcopy").num_args(0))
+        .arg(Arg::new("tls").long("tls").num_args(0))
+        .arg(Arg::new("verbose").long("verbose").num_args(0))
+        .arg(Arg::new("dname").long("dname").value_name("string"))
+        .arg(
+            Arg::new("listen-address")
+                .long("listen-address")
+                .value_name("string"),
+        )
+        .arg(
+            Arg::new("bandwidth")
+                .long("bandwidth")
+                .value_name("number")
+                .value_parser(clap::value_parser!(u64)),
+        )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let domain = args.get_one::<String>("domain").unwrap();
+    let desturi = args.get_one::<String>("desturi").unwrap();
+    let verbose = args.get_flag("verbose");
+
+    let dom = client.domain_lookup_by_name(domain.to_string())?;
+
+    let mut flags = 0;
+
+    for (name, flag) in [
+        ("live", VIR_MIGRATE_LIVE),
+        ("offline", VIR_MIGRATE_OFFLINE),
+        ("p2p", VIR_MIGRATE_PEER2PEER),
+        ("tunnelled", VIR_MIGRATE_TUNNELLED),
+        ("persistent", VIR_MIGRATE_PERSIST_DEST),
+        ("undefinesource", VIR_MIGRATE_UNDEFINE_SOURCE),
+        ("suspend", VIR_MIGRATE_PAUSED),
+        ("copy-storage-all", VIR_MIGRATE_NON_SHARED_DISK),
+        ("copy-storage-inc", VIR_MIGRATE_NON_SHARED_INC),
+        ("unsafe", VIR_MIGRATE_UNSAFE),
+        ("compressed", VIR_MIGRATE_COMPRESSED),
+        ("abort-on-error", VIR_MIGRATE_ABORT_ON_ERROR),
+        ("auto-converge", VIR_MIGRATE_AUTO_CONVERGE),
+        ("postcopy", VIR_MIGRATE_POSTCOPY),
+        ("tls", VIR_MIGRATE_TLS),
+    ] {
+        if args.get_flag(name) {
+            flags |= flag;
+        }
+    }
+
+    let mut params = MigrateParams::new(flags);
+
+    if flags & VIR_MIGRATE_PEER2PEER != 0 {
+        params = params.dest_uri(desturi);
+    }
+
+    if let Some(uri) = args.get_one::<String>("migrateuri") {
+        params = params.param(RemoteTypedParam::string(VIR_MIGRATE_PARAM_URI, uri));
+    }
+
+    if let Some(dname) = args.get_one::<String>("dname") {
+        params = params.param(RemoteTypedParam::string(VIR_MIGRATE_PARAM_DEST_NAME, dname));
+    }
+
+    if let Some(address) = args.get_one::<String>("listen-address") {
+        params = params.param(RemoteTypedParam::string(
+            VIR_MIGRATE_PARAM_LISTEN_ADDRESS,
+            address,
+        ));
+    }
+
+    if let Some(bandwidth) = args.get_one::<u64>("bandwidth") {
+        params = params.param(RemoteTypedParam::ullong(
+            VIR_MIGRATE_PARAM_BANDWIDTH,
+            *bandwidth,
+        ));
+    }
+
+    let label = locale.get_message("Migration");
+    let progress = |p: &JobProgress| {
+        if verbose {
+            util::print_job_progress(&label, p);
+        }
+    };
+
+    // In peer-to-peer mode the source daemon connects to the destination
+    // itself, so desturi need not be reachable from here.
+    let ret = if flags & VIR_MIGRATE_PEER2PEER != 0 {
+        migrate_p2p(client.as_mut(), dom, &params, progress)
+    } else {
+        let mut dst = crate::connect(&ConnectUri::parse(desturi)?, false)?;
+        let ret = migrate_with_progress(client.as_mut(), dst.as_mut(), dom, &params, progress);
+        dst.connect_close()?;
+        dst.fin()?;
+        ret.map(|_| ())
+    };
+
+    if ret.is_ok() && verbose {
+        util::print_progress(&label, 0, 1);
+    }
+
+    ret?;
+
+    Ok(())
+}
@@ -1,13 +1,96 @@
+mod console;
 mod cpu_stats;
+mod create;
+mod define;
 mod destroy;
+mod domid;
+mod dominfo;
+mod domname;
+mod dompmsuspend;
+mod dompmwakeup;
+mod domstate;
+mod domuuid;
+mod dumpxml;
+mod edit;
+mod event;
+mod find_storage_pool_sources_as;
 mod iface_list;
+mod list;
+mod migrate;
+mod net_autostart;
+mod net_create;
+mod net_define;
+mod net_destroy;
+mod net_dhcp_leases;
+mod net_dumpxml;
+mod net_edit;
+mod net_event;
+mod net_info;
+mod net_list;
+mod net_port_create;
+mod net_port_delete;
+mod net_port_list;
+mod net_start;
+mod net_undefine;
+mod net_update;
+mod nodedev_autostart;
+mod nodedev_create;
+mod nodedev_define;
+mod nodedev_destroy;
+mod nodedev_detach;
+mod nodedev_dumpxml;
+mod nodedev_event;
+mod nodedev_info;
+mod nodedev_list;
+mod nodedev_reattach;
+mod nodedev_reset;
+mod nodedev_start;
+mod nodedev_undefine;
 mod nodeinfo;
+mod pool_autostart;
+mod pool_build;
+mod pool_capabilities;
+mod pool_create;
+mod pool_create_as;
+mod pool_define;
+mod pool_define_as;
+mod pool_delete;
+mod pool_destroy;
+mod pool_dumpxml;
+mod pool_edit;
 mod pool_event;
+mod pool_info;
+mod pool_list;
+mod pool_refresh;
+mod pool_start;
+mod pool_undefine;
 mod qemu_agent_command;
+mod qemu_monitor_command;
+mod qemu_monitor_event;
+mod reboot;
+mod reset;
+mod resume;
+mod screenshot;
+mod secret_event;
+mod shutdown;
 mod start;
+mod suspend;
+mod undefine;
 mod version;
+mod vol_clone;
+mod vol_create_as;
+mod vol_create_from;
+mod vol_delete;
 mod vol_download;
+mod vol_info;
+mod vol_key;
+mod vol_list;
+mod vol_name;
+mod vol_path;
+mod vol_pool;
+mod vol_resize;
 mod vol_upload;
+mod vol_wipe;
 
 use crate::error::Error;
 use crate::locale::Locale;
@@ -21,11 +104,6 @@ pub fn app() -> Command {
             Arg::new("connect")
                 .short('c')
                 .long("connect")
-                .default_value(if cfg!(unix) {
-                    "qemu+unix:///system"
-                } else {
-                    "qemu+tcp:///system"
-                })
                 .value_name("URI")
                 .help("hypvervisor connection URI"),
         )
@@ -37,16 +115,99 @@ pub fn app() -> Command {
                 .num_args(0)
                 .help("connect readonly"),
         )
+        .subcommand(console::cmd())
         .subcommand(cpu_stats::cmd())
+        .subcommand(create::cmd())
+        .subcommand(define::cmd())
         .subcommand(destroy::cmd())
+        .subcommand(domid::cmd())
+        .subcommand(dominfo::cmd())
+        .subcommand(domname::cmd())
+        .subcommand(dompmsuspend::cmd())
+        .subcommand(dompmwakeup::cmd())
+        .subcommand(domstate::cmd())
+        .subcommand(domuuid::cmd())
+        .subcommand(dumpxml::cmd())
+        .subcommand(edit::cmd())
+        .subcommand(event::cmd())
+        .subcommand(find_storage_pool_sources_as::cmd())
         .subcommand(iface_list::cmd())
+        .subcommand(list::cmd())
+        .subcommand(migrate::cmd())
+        .subcommand(net_autostart::cmd())
+        .subcommand(net_create::cmd())
+        .subcommand(net_define::cmd())
+        .subcommand(net_destroy::cmd())
+        .subcommand(net_dhcp_leases::cmd())
+        .subcommand(net_dumpxml::cmd())
+        .subcommand(net_edit::cmd())
+        .subcommand(net_event::cmd())
+        .subcommand(net_info::cmd())
+        .subcommand(net_list::cmd())
+        .subcommand(net_port_create::cmd())
+        .subcommand(net_port_delete::cmd())
+        .subcommand(net_port_list::cmd())
+        .subcommand(net_start::cmd())
+        .subcommand(net_undefine::cmd())
+        .subcommand(net_update::cmd())
+        .subcommand(nodedev_autostart::cmd())
+        .subcommand(nodedev_create::cmd())
+        .subcommand(nodedev_define::cmd())
+        .subcommand(nodedev_destroy::cmd())
+        .subcommand(nodedev_detach::cmd())
+        .subcommand(nodedev_dumpxml::cmd())
+        .subcommand(nodedev_event::cmd())
+        .subcommand(nodedev_info::cmd())
+        .subcommand(nodedev_list::cmd())
+        .subcommand(nodedev_reattach::cmd())
+        .subcommand(nodedev_reset::cmd())
+        .subcommand(nodedev_start::cmd())
+        .subcommand(nodedev_undefine::cmd())
         .subcommand(nodeinfo::cmd())
+        .subcommand(pool_autostart::cmd())
+        .subcommand(pool_build::cmd())
+        .subcommand(pool_capabilities::cmd())
+        .subcommand(pool_create::cmd())
+        .subcommand(pool_create_as::cmd())
+        .subcommand(pool_define::cmd())
+        .subcommand(pool_define_as::cmd())
+        .subcommand(pool_delete::cmd())
+        .subcommand(pool_destroy::cmd())
+        .subcommand(pool_dumpxml::cmd())
+        .subcommand(pool_edit::cmd())
         .subcommand(pool_event::cmd())
+        .subcommand(pool_info::cmd())
+        .subcommand(pool_list::cmd())
+        .subcommand(pool_refresh::cmd())
+        .subcommand(pool_start::cmd())
+        .subcommand(pool_undefine::cmd())
         .subcommand(qemu_agent_command::cmd())
+        .subcommand(qemu_monitor_command::cmd())
+        .subcommand(qemu_monitor_event::cmd())
+        .subcommand(reboot::cmd())
+        .subcommand(reset::cmd())
+        .subcommand(resume::cmd())
+        .subcommand(screenshot::cmd())
+        .subcommand(secret_event::cmd())
+        .subcommand(shutdown::cmd())
         .subcommand(start::cmd())
+        .subcommand(suspend::cmd())
+        .subcommand(undefine::cmd())
         .subcommand(version::cmd())
+        .subcommand(vol_clone::cmd())
+        .subcommand(vol_create_as::cmd())
+        .subcommand(vol_create_from::cmd())
+        .subcommand(vol_delete::cmd())
         .subcommand(vol_download::cmd())
+        .subcommand(vol_info::cmd())
+        .subcommand(vol_key::cmd())
+        .subcommand(vol_list::cmd())
+        .subcommand(vol_name::cmd())
+        .subcommand(vol_path::cmd())
+        .subcommand(vol_pool::cmd())
+        .subcommand(vol_resize::cmd())
         .subcommand(vol_upload::cmd())
+        .subcommand(vol_wipe::cmd())
 }
 
 pub fn run(
@@ -55,16 +216,101 @@ pub fn run(
     gargs: &ArgMatches,
 ) -> Result<(), Error> {
     match gargs.subcommand() {
+        Some(("console", args)) => console::run(client, locale, args),
         Some(("cpu-stats", args)) => cpu_stats::run(client, locale, args),
+        Some(("create", args)) => create::run(client, locale, args),
+        Some(("define", args)) => define::run(client, locale, args),
         Some(("destroy", args)) => destroy::run(client, locale, args),
+        Some(("domid", args)) => domid::run(client, locale, args),
+        Some(("dominfo", args)) => dominfo::run(client, locale, args),
+        Some(("domname", args)) => domname::run(client, locale, args),
+        Some(("dompmsuspend", args)) => dompmsuspend::run(client, locale, args),
+        Some(("dompmwakeup", args)) => dompmwakeup::run(client, locale, args),
+        Some(("domstate", args)) => domstate::run(client, locale, args),
+        Some(("domuuid", args)) => domuuid::run(client, locale, args),
+        Some(("dumpxml", args)) => dumpxml::run(client, locale, args),
+        Some(("edit", args)) => edit::run(client, locale, args),
+        Some(("event", args)) => event::run(client, locale, args),
+        Some(("find-storage-pool-sources-as", args)) => {
+            find_storage_pool_sources_as::run(client, locale, args)
+        }
         Some(("iface-list", args)) => iface_list::run(client, locale, args),
+        Some(("list", args)) => list::run(client, locale, args),
+        Some(("migrate", args)) => migrate::run(client, locale, args),
+        Some(("net-autostart", args)) => net_autostart::run(client, locale, args),
+        Some(("net-create", args)) => net_create::run(client, locale, args),
+        Some(("net-define", args)) => net_define::run(client, locale, args),
+        Some(("net-destroy", args)) => net_destroy::run(client, locale, args),
+        Some(("net-dhcp-leases", args)) => net_dhcp_leases::run(client, locale, args),
+        Some(("net-dumpxml", args)) => net_dumpxml::run(client, locale, args),
+        Some(("net-edit", args)) => net_edit::run(client, locale, args),
+        Some(("net-event", args)) => net_event::run(client, locale, args),
+        Some(("net-info", args)) => net_info::run(client, locale, args),
+        Some(("net-list", args)) => net_list::run(client, locale, args),
+        Some(("net-port-create", args)) => net_port_create::run(client, locale, args),
+        Some(("net-port-delete", args)) => net_port_delete::run(client, locale, args),
+        Some(("net-port-list", args)) => net_port_list::run(client, locale, args),
+        Some(("net-start", args)) => net_start::run(client, locale, args),
+        Some(("net-undefine", args)) => net_undefine::run(client, locale, args),
+        Some(("net-update", args)) => net_update::run(client, locale, args),
+        Some(("nodedev-autostart", args)) => nodedev_autostart::run(client, locale, args),
+        Some(("nodedev-create", args)) => nodedev_create::run(client, locale, args),
+        Some(("nodedev-define", args)) => nodedev_define::run(client, locale, args),
+        Some(("nodedev-destroy", args)) => nodedev_destroy::run(client, locale, args),
+        Some(("nodedev-detach", args)) => nodedev_detach::run(client, locale, args),
+        Some(("nodedev-dumpxml", args)) => nodedev_dumpxml::run(client, locale, args),
+        Some(("nodedev-event", args)) => nodedev_event::run(client, locale, args),
+        Some(("nodedev-info", args)) => nodedev_info::run(client, locale, args),
+        Some(("nodedev-list", args)) => nodedev_list::run(client, locale, args),
+        Some(("nodedev-reattach", args)) => nodedev_reattach::run(client, locale, args),
+        Some(("nodedev-reset", args)) => nodedev_reset::run(client, locale, args),
+        Some(("nodedev-start", args)) => nodedev_start::run(client, locale, args),
+        Some(("nodedev-undefine", args)) => nodedev_undefine::run(client, locale, args),
         Some(("nodeinfo", _)) => nodeinfo::run(client, locale),
+        Some(("pool-autostart", args)) => pool_autostart::run(client, locale, args),
+        Some(("pool-build", args)) => pool_build::run(client, locale, args),
+        Some(("pool-capabilities", _)) => pool_capabilities::run(client),
+        Some(("pool-create", args)) => pool_create::run(client, locale, args),
+        Some(("pool-create-as", args)) => pool_create_as::run(client, locale, args),
+        Some(("pool-define", args)) => pool_define::run(client, locale, args),
+        Some(("pool-define-as", args)) => pool_define_as::run(client, locale, args),
+        Some(("pool-delete", args)) => pool_delete::run(client, locale, args),
+        Some(("pool-destroy", args)) => pool_destroy::run(client, locale, args),
+        Some(("pool-dumpxml", args)) => pool_dumpxml::run(client, locale, args),
+        Some(("pool-edit", args)) => pool_edit::run(client, locale, args),
         Some(("pool-event", args)) => pool_event::run(client, locale, args),
+        Some(("pool-info", args)) => pool_info::run(client, locale, args),
+        Some(("pool-list", args)) => pool_list::run(client, locale, args),
+        Some(("pool-refresh", args)) => pool_refresh::run(client, locale, args),
+        Some(("pool-start", args)) => pool_start::run(client, locale, args),
+        Some(("pool-undefine", args)) => pool_undefine::run(client, locale, args),
         Some(("qemu-agent-command", args)) => qemu_agent_command::run(client, locale, args),
+        Some(("qemu-monitor-command", args)) => qemu_monitor_command::run(client, locale, args),
+        Some(("qemu-monitor-event", args)) => qemu_monitor_event::run(client, locale, args),
+        Some(("reboot", args)) => reboot::run(client, locale, args),
+        Some(("reset", args)) => reset::run(client, locale, args),
+        Some(("resume", args)) => resume::run(client, locale, args),
+        Some(("screenshot", args)) => screenshot::run(client, locale, args),
+        Some(("secret-event", args)) => secret_event::run(client, locale, args),
+        Some(("shutdown", args)) => shutdown::run(client, locale, args),
         Some(("start", args)) => start::run(client, locale, args),
+        Some(("suspend", args)) => suspend::run(client, locale, args),
+        Some(("undefine", args)) => undefine::run(client, locale, args),
         Some(("version", _)) => version::run(client, locale),
+        Some(("vol-clone", args)) => vol_clone::run(client, locale, args),
+        Some(("vol-create-as", args)) => vol_create_as::run(client, locale, args),
+        Some(("vol-create-from", args)) => vol_create_from::run(client, locale, args),
+        Some(("vol-delete", args)) => vol_delete::run(client, locale, args),
         Some(("vol-download", args)) => vol_download::run(client, locale, args),
+        Some(("vol-info", args)) => vol_info::run(client, locale, args),
+        Some(("vol-key", args)) => vol_key::run(client, locale, args),
+        Some(("vol-list", args)) => vol_list::run(client, locale, args),
+        Some(("vol-name", args)) => vol_name::run(client, locale, args),
+        Some(("vol-path", args)) => vol_path::run(client, locale, args),
+        Some(("vol-pool", args)) => vol_pool::run(client, locale, args),
+        Some(("vol-resize", args)) => vol_resize::run(client, locale, args),
         Some(("vol-upload", args)) => vol_upload::run(client, locale, args),
+        Some(("vol-wipe", args)) => vol_wipe::run(client, locale, args),
         _ => app().print_long_help().map_err(Error::from),
     }
 }
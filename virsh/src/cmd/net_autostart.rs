@@ -0,0 +1,37 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("net-autostart")
+        .arg(
+            Arg::new("network")
+                .value_name("network")
+                .required(true)
+                .index(1),
+        )
+        .arg(Arg::new("disable").long("disable").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let network = args.get_one::<String>("network").unwrap();
+    let disable = args.get_flag("disable");
+
+    let net = util::lookup_network(client, network)?;
+    client.network_set_autostart(net.clone(), if disable { 0 } else { 1 })?;
+
+    let id = if disable {
+        "FormatNetworkAutostartDisabled"
+    } else {
+        "FormatNetworkAutostartEnabled"
+    };
+    println!("{}", locale.format_message(id, vec![("name", &net.name)]));
+
+    Ok(())
+}
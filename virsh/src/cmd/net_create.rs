@@ -0,0 +1,39 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::network::*;
+use std::fs;
+
+pub fn cmd() -> Command {
+    Command::new("net-create")
+        .arg(Arg::new("file").value_name("file").required(true).index(1))
+        .arg(Arg::new("validate").long("validate").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let file = args.get_one::<String>("file").unwrap();
+    let validate = args.get_flag("validate");
+
+    let xml = fs::read_to_string(file)?;
+
+    let net = if validate {
+        client.network_create_xml_flags(xml, VIR_NETWORK_CREATE_VALIDATE)?
+    } else {
+        client.network_create_xml(xml)?
+    };
+
+    println!(
+        "{}",
+        locale.format_message(
+            "FormatNetworkCreated",
+            vec![("name", &net.name), ("file", file)]
+        )
+    );
+
+    Ok(())
+}
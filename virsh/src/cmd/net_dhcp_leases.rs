@@ -0,0 +1,70 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::table_view::TableView;
+use crate::util;
+use chrono::{DateTime, Local};
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::network::*;
+
+pub fn cmd() -> Command {
+    Command::new("net-dhcp-leases")
+        .arg(
+            Arg::new("network")
+                .value_name("network")
+                .required(true)
+                .index(1),
+        )
+        .arg(Arg::new("mac").long("mac").value_name("string"))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let network = args.get_one::<String>("network").unwrap();
+    let mac = args.get_one::<String>("mac").map(|s| s.to_lowercase());
+
+    let net = util::lookup_network(client, network)?;
+    let (mut leases, _) = client.network_get_dhcp_leases(net, mac, -1, 0)?;
+    leases.sort_by(|a, b| a.mac.cmp(&b.mac));
+
+    let mut view = TableView::new(vec![
+        &locale.get_message("ExpiryTime"),
+        &locale.get_message("MacAddress"),
+        &locale.get_message("Protocol"),
+        &locale.get_message("IpAddress"),
+        &locale.get_message("Hostname"),
+        &locale.get_message("ClientId"),
+    ]);
+
+    for lease in leases {
+        let expiry = DateTime::from_timestamp(lease.expirytime, 0)
+            .map(|t| {
+                t.with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            })
+            .unwrap_or_else(|| "-".to_string());
+        let protocol = match lease.r#type {
+            VIR_IP_ADDR_TYPE_IPV4 => "ipv4",
+            VIR_IP_ADDR_TYPE_IPV6 => "ipv6",
+            _ => "unknown",
+        };
+        let address = format!("{}/{}", lease.ipaddr, lease.prefix);
+
+        view.add_row(vec![
+            &expiry,
+            lease.mac.as_deref().unwrap_or("-"),
+            protocol,
+            &address,
+            lease.hostname.as_deref().unwrap_or("-"),
+            lease.clientid.as_deref().unwrap_or("-"),
+        ]);
+    }
+
+    view.print_table();
+
+    Ok(())
+}
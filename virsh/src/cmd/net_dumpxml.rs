@@ -0,0 +1,39 @@
+use crate::cmd::dumpxml;
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::network::*;
+
+pub fn cmd() -> Command {
+    Command::new("net-dumpxml")
+        .arg(
+            Arg::new("network")
+                .value_name("network")
+                .required(true)
+                .index(1),
+        )
+        .arg(Arg::new("inactive").long("inactive").num_args(0))
+        .arg(Arg::new("xpath").long("xpath").value_name("expression"))
+        .arg(Arg::new("wrap").long("wrap").num_args(0).requires("xpath"))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    _locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let network = args.get_one::<String>("network").unwrap();
+
+    let flags = if args.get_flag("inactive") {
+        VIR_NETWORK_XML_INACTIVE
+    } else {
+        0
+    };
+
+    let net = util::lookup_network(client, network)?;
+    let xml = client.network_get_xml_desc(net, flags)?;
+
+    dumpxml::print_xml(&xml, args)
+}
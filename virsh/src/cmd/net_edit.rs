@@ -0,0 +1,45 @@
+use crate::editor::{self, EditOutcome};
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::network::*;
+
+pub fn cmd() -> Command {
+    Command::new("net-edit").arg(
+        Arg::new("network")
+            .value_name("network")
+            .required(true)
+            .index(1),
+    )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let network = args.get_one::<String>("network").unwrap();
+
+    let net = util::lookup_network(client, network)?;
+    let xml = client.network_get_xml_desc(net.clone(), VIR_NETWORK_XML_INACTIVE)?;
+
+    let outcome = editor::edit_xml(locale, &xml, |xml, validate| {
+        let flags = if validate {
+            VIR_NETWORK_DEFINE_VALIDATE
+        } else {
+            0
+        };
+        client.network_define_xml_flags(xml.to_string(), flags)?;
+        Ok(())
+    })?;
+
+    let id = match outcome {
+        EditOutcome::Unchanged => "FormatNetworkXmlNotChanged",
+        EditOutcome::Edited => "FormatNetworkXmlEdited",
+    };
+    println!("{}", locale.format_message(id, vec![("name", &net.name)]));
+
+    Ok(())
+}
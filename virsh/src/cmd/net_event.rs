@@ -0,0 +1,171 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::binding::{
+    RemoteNetworkEventCallbackMetadataChangeMsg, RemoteNetworkEventLifecycleMsg, RemoteProcedure,
+};
+use libvirt_remote::client::{Libvirt, VirNetResponseRaw};
+use libvirt_remote::event::wait_event;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+static EVENTS: OnceLock<HashMap<&'static str, i32>> = OnceLock::new();
+
+fn init_events() -> HashMap<&'static str, i32> {
+    let mut e = HashMap::new();
+    e.insert("lifecycle", 0);
+    e.insert("metadata-change", 1);
+    e
+}
+
+static LIFECYCLE_EVENTS: OnceLock<HashMap<i32, &'static str>> = OnceLock::new();
+
+fn init_lifecycle_events() -> HashMap<i32, &'static str> {
+    let mut e = HashMap::new();
+    e.insert(0, "Defined");
+    e.insert(1, "Undefined");
+    e.insert(2, "Started");
+    e.insert(3, "Stopped");
+    e
+}
+
+pub fn cmd() -> Command {
+    Command::new("net-event")
+        .arg(Arg::new("network").long("network").value_name("string"))
+        .arg(Arg::new("event").long("event").value_name("string"))
+        .arg(Arg::new("loop").long("loop").num_args(0))
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .value_name("number")
+                .value_parser(clap::value_parser!(u64)),
+        )
+        .arg(Arg::new("list").long("list").num_args(0))
+        .arg(Arg::new("timestamp").long("timestamp").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    _locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    if args.get_flag("list") {
+        for e in EVENTS.get_or_init(init_events).keys() {
+            println!("{e}");
+        }
+        return Ok(());
+    }
+
+    let Some(event_name) = args.get_one::<String>("event") else {
+        return Err(Error::Arg("--event".to_string()));
+    };
+    let event_id = *EVENTS
+        .get_or_init(init_events)
+        .get(event_name.as_str())
+        .ok_or_else(|| Error::Arg(event_name.to_string()))?;
+
+    let net = match args.get_one::<String>("network") {
+        Some(name) => {
+            let net = client.network_lookup_by_name(name.to_string())?;
+            Some(net)
+        }
+        _ => None,
+    };
+
+    let callback_id = client.connect_network_event_register_any(event_id, net)?;
+
+    let deadline = args
+        .get_one::<u64>("timeout")
+        .map(|secs| Instant::now() + Duration::from_secs(*secs));
+
+    loop {
+        let Some(raw) = wait_event(client.as_mut(), deadline)? else {
+            println!("event loop timed out");
+            break;
+        };
+
+        let msg = match event_id {
+            0 => handle_lifecycle_event(raw, callback_id)?,
+            1 => handle_metadata_change_event(raw, callback_id)?,
+            _ => unreachable!(),
+        };
+
+        let Some(msg) = msg else {
+            continue;
+        };
+
+        let time = util::event_timestamp(args.get_flag("timestamp"));
+
+        println!("{time}{msg}");
+
+        if !args.get_flag("loop") {
+            break;
+        }
+    }
+
+    client.connect_network_event_deregister_any(callback_id)?;
+
+    Ok(())
+}
+
+fn handle_lifecycle_event(
+    raw: VirNetResponseRaw,
+    callback_id: i32,
+) -> Result<Option<String>, Error> {
+    if raw.procedure() != RemoteProcedure::RemoteProcNetworkEventLifecycle as i32 {
+        return Ok(None);
+    }
+
+    let RemoteNetworkEventLifecycleMsg {
+        callback_id: id,
+        net,
+        event,
+        detail: _,
+    } = raw.try_into()?;
+    if id != callback_id {
+        return Ok(None);
+    }
+
+    let id = LIFECYCLE_EVENTS
+        .get_or_init(init_lifecycle_events)
+        .get(&event)
+        .unwrap_or(&"Unknown");
+    Ok(Some(format!(
+        "event 'lifecycle' for network {}: {}",
+        net.name, id
+    )))
+}
+
+fn handle_metadata_change_event(
+    raw: VirNetResponseRaw,
+    callback_id: i32,
+) -> Result<Option<String>, Error> {
+    if raw.procedure() != RemoteProcedure::RemoteProcNetworkEventCallbackMetadataChange as i32 {
+        return Ok(None);
+    }
+
+    let RemoteNetworkEventCallbackMetadataChangeMsg {
+        callback_id: id,
+        net,
+        r#type,
+        nsuri,
+    } = raw.try_into()?;
+    if id != callback_id {
+        return Ok(None);
+    }
+
+    let r#type = match r#type {
+        0 => "description",
+        1 => "title",
+        2 => "element",
+        _ => "unknown",
+    };
+    Ok(Some(format!(
+        "event 'metadata-change' for network {}: type {}, uri {}",
+        net.name,
+        r#type,
+        nsuri.as_deref().unwrap_or("n/a")
+    )))
+}
@@ -0,0 +1,60 @@
+use crate::error::Error;
+use crate::kv_view::KeyValueView;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("net-info").arg(
+        Arg::new("network")
+            .value_name("network")
+            .required(true)
+            .index(1),
+    )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let network = args.get_one::<String>("network").unwrap();
+
+    let net = util::lookup_network(client, network)?;
+
+    let mut view = KeyValueView::default();
+
+    view.add_row(&locale.get_message("LabelName"), &net.name);
+    view.add_row(
+        &locale.get_message("LabelUuid"),
+        util::format_uuid(&net.uuid),
+    );
+
+    let yes_no = |value: Result<i32, _>| match value {
+        Ok(0) => locale.get_message("No"),
+        Ok(_) => locale.get_message("Yes"),
+        Err(_) => locale.get_message("Unknown"),
+    };
+    view.add_row(
+        &locale.get_message("LabelActive"),
+        yes_no(client.network_is_active(net.clone())),
+    );
+    view.add_row(
+        &locale.get_message("LabelPersistent"),
+        yes_no(client.network_is_persistent(net.clone())),
+    );
+    view.add_row(
+        &locale.get_message("LabelAutostart"),
+        yes_no(client.network_get_autostart(net.clone())),
+    );
+
+    // Networks without a bridge, such as macvtap ones, have no name to show.
+    if let Ok(bridge) = client.network_get_bridge_name(net) {
+        view.add_row(&locale.get_message("LabelBridge"), bridge);
+    }
+
+    view.print_kv();
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::table_view::TableView;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::network::*;
+
+const FILTERS: [(&str, u32); 4] = [
+    ("persistent", VIR_CONNECT_LIST_NETWORKS_PERSISTENT),
+    ("transient", VIR_CONNECT_LIST_NETWORKS_TRANSIENT),
+    ("autostart", VIR_CONNECT_LIST_NETWORKS_AUTOSTART),
+    ("no-autostart", VIR_CONNECT_LIST_NETWORKS_NO_AUTOSTART),
+];
+
+pub fn cmd() -> Command {
+    let mut cmd = Command::new("net-list")
+        .arg(Arg::new("inactive").long("inactive").num_args(0))
+        .arg(
+            Arg::new("all")
+                .long("all")
+                .num_args(0)
+                .conflicts_with("inactive"),
+        )
+        .arg(Arg::new("name").long("name").num_args(0))
+        .arg(
+            Arg::new("uuid")
+                .long("uuid")
+                .num_args(0)
+                .conflicts_with("name"),
+        );
+
+    for (name, _) in FILTERS {
+        cmd = cmd.arg(Arg::new(name).long(name).num_args(0));
+    }
+
+    cmd
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let mut flags = FILTERS
+        .iter()
+        .filter(|(name, _)| args.get_flag(name))
+        .fold(0, |flags, (_, flag)| flags | flag);
+
+    if args.get_flag("inactive") {
+        flags |= VIR_CONNECT_LIST_NETWORKS_INACTIVE;
+    } else if args.get_flag("all") {
+        flags |= VIR_CONNECT_LIST_NETWORKS_ACTIVE | VIR_CONNECT_LIST_NETWORKS_INACTIVE;
+    } else {
+        flags |= VIR_CONNECT_LIST_NETWORKS_ACTIVE;
+    }
+
+    let (mut nets, _) = client.connect_list_all_networks(-1, flags)?;
+    nets.sort_by(|a, b| a.name.cmp(&b.name));
+
+    if args.get_flag("name") {
+        for net in nets {
+            println!("{}", net.name);
+        }
+        return Ok(());
+    }
+
+    if args.get_flag("uuid") {
+        for net in nets {
+            println!("{}", util::format_uuid(&net.uuid));
+        }
+        return Ok(());
+    }
+
+    let mut view = TableView::new(vec![
+        &locale.get_message("Name"),
+        &locale.get_message("State"),
+        &locale.get_message("Autostart"),
+        &locale.get_message("Persistent"),
+    ]);
+
+    for net in nets {
+        let state = match client.network_is_active(net.clone())? {
+            0 => locale.get_message("Inactive"),
+            _ => locale.get_message("Active"),
+        };
+        let autostart = match client.network_get_autostart(net.clone())? {
+            0 => locale.get_message("No"),
+            _ => locale.get_message("Yes"),
+        };
+        let persistent = match client.network_is_persistent(net.clone())? {
+            0 => locale.get_message("No"),
+            _ => locale.get_message("Yes"),
+        };
+
+        view.add_row(vec![&net.name, &state, &autostart, &persistent]);
+    }
+
+    view.print_table();
+
+    Ok(())
+}
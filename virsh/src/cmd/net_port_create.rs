@@ -0,0 +1,58 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::network::*;
+use std::fs;
+
+const OPTIONS: [(&str, u32); 2] = [
+    ("validate", VIR_NETWORK_PORT_CREATE_VALIDATE),
+    ("reclaim", VIR_NETWORK_PORT_CREATE_RECLAIM),
+];
+
+pub fn cmd() -> Command {
+    let mut cmd = Command::new("net-port-create")
+        .arg(
+            Arg::new("network")
+                .value_name("network")
+                .required(true)
+                .index(1),
+        )
+        .arg(Arg::new("file").value_name("file").required(true).index(2));
+
+    for (name, _) in OPTIONS {
+        cmd = cmd.arg(Arg::new(name).long(name).num_args(0));
+    }
+
+    cmd
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let network = args.get_one::<String>("network").unwrap();
+    let file = args.get_one::<String>("file").unwrap();
+
+    let flags = OPTIONS
+        .iter()
+        .filter(|(name, _)| args.get_flag(name))
+        .fold(0, |flags, (_, flag)| flags | flag);
+
+    let xml = fs::read_to_string(file)?;
+
+    let net = util::lookup_network(client, network)?;
+    let port = client.network_port_create_xml(net, xml, flags)?;
+
+    println!(
+        "{}",
+        locale.format_message(
+            "FormatNetworkPortCreated",
+            vec![("uuid", &util::format_uuid(&port.uuid)), ("file", file)]
+        )
+    );
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("net-port-delete")
+        .arg(
+            Arg::new("network")
+                .value_name("network")
+                .required(true)
+                .index(1),
+        )
+        .arg(Arg::new("port").value_name("port").required(true).index(2))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let network = args.get_one::<String>("network").unwrap();
+    let port = args.get_one::<String>("port").unwrap();
+
+    let uuid = util::parse_uuid(port).ok_or_else(|| Error::Arg(port.to_string()))?;
+
+    let net = util::lookup_network(client, network)?;
+    let port = client.network_port_lookup_by_uuid(net, uuid)?;
+    client.network_port_delete(port.clone(), 0)?;
+
+    println!(
+        "{}",
+        locale.format_message(
+            "FormatNetworkPortDeleted",
+            vec![("uuid", &util::format_uuid(&port.uuid))]
+        )
+    );
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::table_view::TableView;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::xml::XmlDocument;
+use libvirt_remote::xml::network::NetworkPortDef;
+
+pub fn cmd() -> Command {
+    Command::new("net-port-list")
+        .arg(
+            Arg::new("network")
+                .value_name("network")
+                .required(true)
+                .index(1),
+        )
+        .arg(Arg::new("uuid").long("uuid").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let network = args.get_one::<String>("network").unwrap();
+
+    let net = util::lookup_network(client, network)?;
+    let (ports, _) = client.network_list_all_ports(net, -1, 0)?;
+
+    if args.get_flag("uuid") {
+        for port in ports {
+            println!("{}", util::format_uuid(&port.uuid));
+        }
+        return Ok(());
+    }
+
+    let mut view = TableView::new(vec![
+        &locale.get_message("Uuid"),
+        &locale.get_message("Owner"),
+        &locale.get_message("MacAddress"),
+    ]);
+
+    for port in ports {
+        let uuid = util::format_uuid(&port.uuid);
+        let xml = client.network_port_get_xml_desc(port, 0)?;
+        let def = NetworkPortDef::from_xml(&xml)?;
+
+        view.add_row(vec![&uuid, &def.owner.name, &def.mac.address]);
+    }
+
+    view.print_table();
+
+    Ok(())
+}
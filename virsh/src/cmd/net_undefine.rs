@@ -0,0 +1,32 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("net-undefine").arg(
+        Arg::new("network")
+            .value_name("network")
+            .required(true)
+            .index(1),
+    )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let network = args.get_one::<String>("network").unwrap();
+
+    let net = util::lookup_network(client, network)?;
+    client.network_undefine(net.clone())?;
+
+    println!(
+        "{}",
+        locale.format_message("FormatNetworkUndefined", vec![("name", &net.name)])
+    );
+
+    Ok(())
+}
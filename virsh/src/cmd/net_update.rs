@@ -0,0 +1,95 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::network::*;
+use std::fs;
+
+pub fn cmd() -> Command {
+    Command::new("net-update")
+        .arg(
+            Arg::new("network")
+                .value_name("network")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("command")
+                .value_name("command")
+                .required(true)
+                .value_parser(NETWORK_UPDATE_COMMANDS.map(|(name, _)| name))
+                .index(2),
+        )
+        .arg(
+            Arg::new("section")
+                .value_name("section")
+                .required(true)
+                .value_parser(NETWORK_SECTIONS.map(|(name, _)| name))
+                .index(3),
+        )
+        .arg(Arg::new("xml").value_name("xml").required(true).index(4))
+        .arg(
+            Arg::new("parent-index")
+                .long("parent-index")
+                .value_name("number")
+                .value_parser(clap::value_parser!(i32)),
+        )
+        .arg(Arg::new("config").long("config").num_args(0))
+        .arg(Arg::new("live").long("live").num_args(0))
+        .arg(
+            Arg::new("current")
+                .long("current")
+                .num_args(0)
+                .conflicts_with_all(["config", "live"]),
+        )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let network = args.get_one::<String>("network").unwrap();
+    let command = args.get_one::<String>("command").unwrap();
+    let section = args.get_one::<String>("section").unwrap();
+    let xml = args.get_one::<String>("xml").unwrap();
+    let parent_index = args.get_one::<i32>("parent-index").copied().unwrap_or(-1);
+    let config = args.get_flag("config");
+    let live = args.get_flag("live");
+
+    let command = util::parse_flags(command, &NETWORK_UPDATE_COMMANDS)?;
+    let section = util::parse_flags(section, &NETWORK_SECTIONS)?;
+
+    // The XML is given either inline or as the name of a file holding it.
+    let xml = if xml.trim_start().starts_with('<') {
+        xml.to_string()
+    } else {
+        fs::read_to_string(xml)?
+    };
+
+    let mut flags = VIR_NETWORK_UPDATE_AFFECT_CURRENT;
+    if config {
+        flags |= VIR_NETWORK_UPDATE_AFFECT_CONFIG;
+    }
+    if live {
+        flags |= VIR_NETWORK_UPDATE_AFFECT_LIVE;
+    }
+
+    let net = util::lookup_network(client, network)?;
+    client.network_update(net.clone(), command, section, parent_index, xml, flags)?;
+
+    let id = match (config, live) {
+        (true, true) => "FormatNetworkUpdatedConfigLive",
+        (true, false) => "FormatNetworkUpdatedConfig",
+        (false, true) => "FormatNetworkUpdatedLive",
+        // The current state is the live one while the network is running.
+        (false, false) => match client.network_is_active(net.clone())? {
+            0 => "FormatNetworkUpdatedConfig",
+            _ => "FormatNetworkUpdatedLive",
+        },
+    };
+    println!("{}", locale.format_message(id, vec![("name", &net.name)]));
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("nodedev-autostart")
+        .arg(
+            Arg::new("device")
+                .value_name("device")
+                .required(true)
+                .index(1),
+        )
+        .arg(Arg::new("disable").long("disable").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let device = args.get_one::<String>("device").unwrap();
+    let disable = args.get_flag("disable");
+
+    let dev = util::lookup_node_device(client, device)?;
+    client.node_device_set_autostart(dev.name.clone(), if disable { 0 } else { 1 })?;
+
+    let id = if disable {
+        "FormatNodeDeviceAutostartDisabled"
+    } else {
+        "FormatNodeDeviceAutostartEnabled"
+    };
+    println!("{}", locale.format_message(id, vec![("name", &dev.name)]));
+
+    Ok(())
+}
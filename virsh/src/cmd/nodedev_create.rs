@@ -0,0 +1,40 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::nodedev::*;
+use std::fs;
+
+pub fn cmd() -> Command {
+    Command::new("nodedev-create")
+        .arg(Arg::new("file").value_name("file").required(true).index(1))
+        .arg(Arg::new("validate").long("validate").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let file = args.get_one::<String>("file").unwrap();
+    let validate = args.get_flag("validate");
+
+    let xml = fs::read_to_string(file)?;
+
+    let flags = if validate {
+        VIR_NODE_DEVICE_CREATE_XML_VALIDATE
+    } else {
+        0
+    };
+    let dev = client.node_device_create_xml(xml, flags)?;
+
+    println!(
+        "{}",
+        locale.format_message(
+            "FormatNodeDeviceCreated",
+            vec![("name", &dev.name), ("file", file)]
+        )
+    );
+
+    Ok(())
+}
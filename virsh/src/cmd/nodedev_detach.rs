@@ -0,0 +1,35 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("nodedev-detach")
+        .arg(
+            Arg::new("device")
+                .value_name("device")
+                .required(true)
+                .index(1),
+        )
+        .arg(Arg::new("driver").long("driver").value_name("string"))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let device = args.get_one::<String>("device").unwrap();
+    let driver = args.get_one::<String>("driver").cloned();
+
+    let dev = util::lookup_node_device(client, device)?;
+    client.node_device_detach_flags(dev.name.clone(), driver, 0)?;
+
+    println!(
+        "{}",
+        locale.format_message("FormatNodeDeviceDetached", vec![("name", &dev.name)])
+    );
+
+    Ok(())
+}
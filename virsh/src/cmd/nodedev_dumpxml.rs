@@ -0,0 +1,39 @@
+use crate::cmd::dumpxml;
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::nodedev::*;
+
+pub fn cmd() -> Command {
+    Command::new("nodedev-dumpxml")
+        .arg(
+            Arg::new("device")
+                .value_name("device")
+                .required(true)
+                .index(1),
+        )
+        .arg(Arg::new("inactive").long("inactive").num_args(0))
+        .arg(Arg::new("xpath").long("xpath").value_name("expression"))
+        .arg(Arg::new("wrap").long("wrap").num_args(0).requires("xpath"))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    _locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let device = args.get_one::<String>("device").unwrap();
+
+    let flags = if args.get_flag("inactive") {
+        VIR_NODE_DEVICE_XML_INACTIVE
+    } else {
+        0
+    };
+
+    let dev = util::lookup_node_device(client, device)?;
+    let xml = client.node_device_get_xml_desc(dev.name, flags)?;
+
+    dumpxml::print_xml(&xml, args)
+}
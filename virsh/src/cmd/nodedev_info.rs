@@ -0,0 +1,56 @@
+use crate::error::Error;
+use crate::kv_view::KeyValueView;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("nodedev-info").arg(
+        Arg::new("device")
+            .value_name("device")
+            .required(true)
+            .index(1),
+    )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let device = args.get_one::<String>("device").unwrap();
+
+    let dev = util::lookup_node_device(client, device)?;
+    let parent = client.node_device_get_parent(dev.name.clone())?;
+
+    let mut view = KeyValueView::default();
+
+    view.add_row(&locale.get_message("LabelName"), &dev.name);
+    view.add_row(
+        &locale.get_message("LabelParent"),
+        parent.as_deref().unwrap_or("-"),
+    );
+
+    let yes_no = |value: Result<i32, _>| match value {
+        Ok(0) => locale.get_message("No"),
+        Ok(_) => locale.get_message("Yes"),
+        Err(_) => locale.get_message("Unknown"),
+    };
+    view.add_row(
+        &locale.get_message("LabelActive"),
+        yes_no(client.node_device_is_active(dev.name.clone())),
+    );
+    view.add_row(
+        &locale.get_message("LabelPersistent"),
+        yes_no(client.node_device_is_persistent(dev.name.clone())),
+    );
+    view.add_row(
+        &locale.get_message("LabelAutostart"),
+        yes_no(client.node_device_get_autostart(dev.name)),
+    );
+
+    view.print_kv();
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::nodedev::*;
+
+const FILTERS: [(&str, u32); 2] = [
+    ("persistent", VIR_CONNECT_LIST_NODE_DEVICES_PERSISTENT),
+    ("transient", VIR_CONNECT_LIST_NODE_DEVICES_TRANSIENT),
+];
+
+pub fn cmd() -> Command {
+    let mut cmd = Command::new("nodedev-list")
+        .arg(Arg::new("cap").long("cap").value_name("string"))
+        .arg(
+            Arg::new("tree")
+                .long("tree")
+                .num_args(0)
+                .conflicts_with("cap"),
+        )
+        .arg(Arg::new("inactive").long("inactive").num_args(0))
+        .arg(
+            Arg::new("all")
+                .long("all")
+                .num_args(0)
+                .conflicts_with("inactive"),
+        );
+
+    for (name, _) in FILTERS {
+        cmd = cmd.arg(Arg::new(name).long(name).num_args(0));
+    }
+
+    cmd.mut_arg("transient", |a| a.conflicts_with("persistent"))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    _locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let mut flags = FILTERS
+        .iter()
+        .filter(|(name, _)| args.get_flag(name))
+        .fold(0, |flags, (_, flag)| flags | flag);
+
+    if let Some(caps) = args.get_one::<String>("cap") {
+        flags |= util::parse_flags(caps, &NODE_DEVICE_CAPS)?;
+    }
+
+    if args.get_flag("inactive") {
+        flags |= VIR_CONNECT_LIST_NODE_DEVICES_INACTIVE;
+    } else if args.get_flag("all") {
+        flags |= VIR_CONNECT_LIST_NODE_DEVICES_ACTIVE | VIR_CONNECT_LIST_NODE_DEVICES_INACTIVE;
+    } else {
+        flags |= VIR_CONNECT_LIST_NODE_DEVICES_ACTIVE;
+    }
+
+    let (devs, _) = client.connect_list_all_node_devices(-1, flags)?;
+    let mut names = devs.into_iter().map(|d| d.name).collect::<Vec<_>>();
+    names.sort();
+
+    if !args.get_flag("tree") {
+        for name in names {
+            println!("{name}");
+        }
+        return Ok(());
+    }
+
+    let mut parents = Vec::with_capacity(names.len());
+    for name in &names {
+        parents.push(client.node_device_get_parent(name.clone())?);
+    }
+
+    let tree = DeviceTree {
+        names: &names,
+        parents: &parents,
+    };
+
+    // Devices whose parent is not listed, such as `computer`, are the roots.
+    let roots = (0..names.len())
+        .filter(|&idx| tree.index_of(parents[idx].as_deref()).is_none())
+        .collect::<Vec<_>>();
+    for root in roots {
+        tree.print(root, true, true, &mut String::new());
+    }
+
+    Ok(())
+}
+
+struct DeviceTree<'a> {
+    names: &'a [String],
+    parents: &'a [Option<String>],
+}
+
+impl DeviceTree<'_> {
+    fn index_of(&self, name: Option<&str>) -> Option<usize> {
+        name.and_then(|name| self.names.iter().position(|n| n == name))
+    }
+
+    fn children(&self, idx: usize) -> Vec<usize> {
+        (0..self.names.len())
+            .filter(|&child| self.parents[child].as_deref() == Some(self.names[idx].as_str()))
+            .collect()
+    }
+
+    // Prints a device and its descendants the way virsh does:
+    //
+    //   computer
+    //     |
+    //     +- pci_0000_00_00_0
+    //     +- pci_0000_00_01_0
+    //         |
+    //         +- pci_0000_01_00_0
+    fn print(&self, idx: usize, root: bool, last: bool, indent: &mut String) {
+        let branch = if root { "" } else { "+- " };
+        println!("{indent}{branch}{}", self.names[idx]);
+
+        if !root {
+            indent.push_str(if last { "  " } else { "| " });
+        }
+
+        let children = self.children(idx);
+        if !children.is_empty() {
+            println!("{indent}  |");
+        }
+
+        indent.push_str("  ");
+        for (pos, child) in children.iter().enumerate() {
+            self.print(*child, false, pos + 1 == children.len(), indent);
+        }
+        indent.truncate(indent.len() - 2);
+
+        if children.is_empty() && last {
+            println!("{indent}");
+        }
+
+        if !root {
+            indent.truncate(indent.len() - 2);
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("nodedev-start").arg(
+        Arg::new("device")
+            .value_name("device")
+            .required(true)
+            .index(1),
+    )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let device = args.get_one::<String>("device").unwrap();
+
+    let dev = util::lookup_node_device(client, device)?;
+    client.node_device_create(dev.name.clone(), 0)?;
+
+    println!(
+        "{}",
+        locale.format_message("FormatNodeDeviceStarted", vec![("name", &dev.name)])
+    );
+
+    Ok(())
+}
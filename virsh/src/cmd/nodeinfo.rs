@@ -1,6 +1,7 @@
 use crate::error::Error;
 use crate::kv_view::KeyValueView;
 use crate::locale::Locale;
+use crate::util::to_utf8_str;
 use clap::Command;
 use libvirt_remote::client::Libvirt;
 
@@ -35,12 +36,3 @@ pub fn run(client: &mut Box<impl Libvirt>, locale: &Locale) -> Result<(), Error>
 
     Ok(())
 }
-
-fn to_utf8_str(value: &[i8]) -> Result<String, Error> {
-    let bytes: Vec<u8> = value
-        .iter()
-        .map(|&c| c as u8)
-        .take_while(|&c| c != 0)
-        .collect();
-    String::from_utf8(bytes).map_err(Error::from)
-}
@@ -0,0 +1,32 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("pool-autostart")
+        .arg(Arg::new("pool").value_name("pool").required(true).index(1))
+        .arg(Arg::new("disable").long("disable").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let pool = args.get_one::<String>("pool").unwrap();
+    let disable = args.get_flag("disable");
+
+    let pool = util::lookup_pool(client, pool)?;
+    client.storage_pool_set_autostart(pool.clone(), if disable { 0 } else { 1 })?;
+
+    let id = if disable {
+        "FormatPoolAutostartDisabled"
+    } else {
+        "FormatPoolAutostartEnabled"
+    };
+    println!("{}", locale.format_message(id, vec![("name", &pool.name)]));
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::storage::*;
+
+pub fn cmd() -> Command {
+    Command::new("pool-build")
+        .arg(Arg::new("pool").value_name("pool").required(true).index(1))
+        .arg(Arg::new("overwrite").long("overwrite").num_args(0))
+        .arg(
+            Arg::new("no-overwrite")
+                .long("no-overwrite")
+                .num_args(0)
+                .conflicts_with("overwrite"),
+        )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let pool = args.get_one::<String>("pool").unwrap();
+
+    let mut flags = VIR_STORAGE_POOL_BUILD_NEW;
+    if args.get_flag("overwrite") {
+        flags |= VIR_STORAGE_POOL_BUILD_OVERWRITE;
+    }
+    if args.get_flag("no-overwrite") {
+        flags |= VIR_STORAGE_POOL_BUILD_NO_OVERWRITE;
+    }
+
+    let pool = util::lookup_pool(client, pool)?;
+    client.storage_pool_build(pool.clone(), flags)?;
+
+    println!(
+        "{}",
+        locale.format_message("FormatPoolBuilt", vec![("name", &pool.name)])
+    );
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use crate::error::Error;
+use clap::Command;
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("pool-capabilities")
+}
+
+pub fn run(client: &mut Box<impl Libvirt>) -> Result<(), Error> {
+    let xml = client.connect_get_storage_pool_capabilities(0)?;
+
+    print!("{xml}");
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use crate::cmd::pool_start;
+use crate::error::Error;
+use crate::locale::Locale;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use std::fs;
+
+pub fn cmd() -> Command {
+    pool_start::build_args(
+        Command::new("pool-create")
+            .arg(Arg::new("file").value_name("file").required(true).index(1)),
+    )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let file = args.get_one::<String>("file").unwrap();
+
+    let xml = fs::read_to_string(file)?;
+    let pool = client.storage_pool_create_xml(xml, pool_start::build_flags(args))?;
+
+    println!(
+        "{}",
+        locale.format_message(
+            "FormatPoolCreatedFrom",
+            vec![("name", &pool.name), ("file", file)]
+        )
+    );
+
+    Ok(())
+}
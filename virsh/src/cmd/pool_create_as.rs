@@ -0,0 +1,33 @@
+use crate::cmd::{pool_define_as, pool_start};
+use crate::error::Error;
+use crate::locale::Locale;
+use clap::{ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::xml::XmlDocument;
+
+pub fn cmd() -> Command {
+    pool_start::build_args(pool_define_as::pool_args(Command::new("pool-create-as")))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let def = pool_define_as::pool_definition(args)?;
+    let xml = def.to_xml()?;
+
+    if args.get_flag("print-xml") {
+        println!("{xml}");
+        return Ok(());
+    }
+
+    let pool = client.storage_pool_create_xml(xml, pool_start::build_flags(args))?;
+
+    println!(
+        "{}",
+        locale.format_message("FormatPoolCreated", vec![("name", &pool.name)])
+    );
+
+    Ok(())
+}
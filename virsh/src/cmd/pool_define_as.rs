@@ -0,0 +1,129 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::xml::storage::*;
+use libvirt_remote::xml::{FormatType, XmlDocument};
+
+const OPTIONS: [&str; 11] = [
+    "source-host",
+    "source-path",
+    "source-dev",
+    "source-name",
+    "target",
+    "source-format",
+    "source-initiator",
+    "adapter-name",
+    "adapter-wwnn",
+    "adapter-wwpn",
+    "adapter-parent",
+];
+
+pub fn cmd() -> Command {
+    pool_args(Command::new("pool-define-as"))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let def = pool_definition(args)?;
+    let xml = def.to_xml()?;
+
+    if args.get_flag("print-xml") {
+        println!("{xml}");
+        return Ok(());
+    }
+
+    let pool = client.storage_pool_define_xml(xml, 0)?;
+
+    println!(
+        "{}",
+        locale.format_message("FormatPoolDefined", vec![("name", &pool.name)])
+    );
+
+    Ok(())
+}
+
+// The options describing a pool, shared with pool-create-as.
+pub fn pool_args(mut cmd: Command) -> Command {
+    cmd = cmd
+        .arg(Arg::new("name").value_name("name").required(true).index(1))
+        .arg(Arg::new("type").value_name("type").required(true).index(2));
+
+    for name in OPTIONS {
+        cmd = cmd.arg(Arg::new(name).long(name).value_name("string"));
+    }
+
+    cmd.arg(Arg::new("print-xml").long("print-xml").num_args(0))
+}
+
+pub fn pool_definition(args: &ArgMatches) -> Result<StoragePoolDef, Error> {
+    let arg = |name: &str| args.get_one::<String>(name).cloned();
+
+    let mut source = PoolSource::default();
+
+    if let Some(host) = arg("source-host") {
+        // A port may follow the host name, as in `host:port`.
+        let host = match host.rsplit_once(':') {
+            Some((name, port)) if !name.contains(':') => PoolSourceHost {
+                name: name.to_string(),
+                port: Some(port.parse().map_err(|_| Error::Arg(host.clone()))?),
+            },
+            _ => PoolSourceHost {
+                name: host,
+                port: None,
+            },
+        };
+        source.host.push(host);
+    }
+    if let Some(path) = arg("source-path") {
+        source.dir = Some(PoolSourcePath { path });
+    }
+    if let Some(path) = arg("source-dev") {
+        source.device.push(PoolSourceDevice { path });
+    }
+    source.name = arg("source-name");
+    source.format = arg("source-format").map(|r#type| FormatType { r#type });
+    source.initiator = arg("source-initiator").map(|name| PoolSourceInitiator {
+        iqn: PoolSourceIqn { name },
+    });
+
+    let wwnn = arg("adapter-wwnn");
+    let wwpn = arg("adapter-wwpn");
+    if wwnn.is_some() != wwpn.is_some() {
+        return Err(Error::Arg(
+            "--adapter-wwnn and --adapter-wwpn must be used together".to_string(),
+        ));
+    }
+
+    if let Some(name) = arg("adapter-name") {
+        source.adapter = Some(PoolSourceAdapter {
+            r#type: Some("scsi_host".to_string()),
+            name: Some(name),
+            ..Default::default()
+        });
+    } else if wwnn.is_some() {
+        source.adapter = Some(PoolSourceAdapter {
+            r#type: Some("fc_host".to_string()),
+            parent: arg("adapter-parent"),
+            wwnn,
+            wwpn,
+            ..Default::default()
+        });
+    }
+
+    let target = arg("target").map(|path| PoolTarget {
+        path: Some(path),
+        permissions: None,
+    });
+
+    Ok(StoragePoolDef {
+        r#type: arg("type").unwrap(),
+        name: arg("name").unwrap(),
+        source: (source != PoolSource::default()).then_some(source),
+        target,
+        ..Default::default()
+    })
+}
@@ -0,0 +1,36 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::storage::*;
+
+pub fn cmd() -> Command {
+    Command::new("pool-delete")
+        .arg(Arg::new("pool").value_name("pool").required(true).index(1))
+        .arg(Arg::new("zeroed").long("zeroed").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let pool = args.get_one::<String>("pool").unwrap();
+
+    let flags = if args.get_flag("zeroed") {
+        VIR_STORAGE_POOL_DELETE_ZEROED
+    } else {
+        VIR_STORAGE_POOL_DELETE_NORMAL
+    };
+
+    let pool = util::lookup_pool(client, pool)?;
+    client.storage_pool_delete(pool.clone(), flags)?;
+
+    println!(
+        "{}",
+        locale.format_message("FormatPoolDeleted", vec![("name", &pool.name)])
+    );
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use crate::cmd::dumpxml;
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::storage::*;
+
+pub fn cmd() -> Command {
+    Command::new("pool-dumpxml")
+        .arg(Arg::new("pool").value_name("pool").required(true).index(1))
+        .arg(Arg::new("inactive").long("inactive").num_args(0))
+        .arg(Arg::new("xpath").long("xpath").value_name("expression"))
+        .arg(Arg::new("wrap").long("wrap").num_args(0).requires("xpath"))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    _locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let pool = args.get_one::<String>("pool").unwrap();
+
+    let flags = if args.get_flag("inactive") {
+        VIR_STORAGE_XML_INACTIVE
+    } else {
+        0
+    };
+
+    let pool = util::lookup_pool(client, pool)?;
+    let xml = client.storage_pool_get_xml_desc(pool, flags)?;
+
+    dumpxml::print_xml(&xml, args)
+}
@@ -0,0 +1,40 @@
+use crate::editor::{self, EditOutcome};
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::storage::*;
+
+pub fn cmd() -> Command {
+    Command::new("pool-edit").arg(Arg::new("pool").value_name("pool").required(true).index(1))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let pool = args.get_one::<String>("pool").unwrap();
+
+    let pool = util::lookup_pool(client, pool)?;
+    let xml = client.storage_pool_get_xml_desc(pool.clone(), VIR_STORAGE_XML_INACTIVE)?;
+
+    let outcome = editor::edit_xml(locale, &xml, |xml, validate| {
+        let flags = if validate {
+            VIR_STORAGE_POOL_DEFINE_VALIDATE
+        } else {
+            0
+        };
+        client.storage_pool_define_xml(xml.to_string(), flags)?;
+        Ok(())
+    })?;
+
+    let id = match outcome {
+        EditOutcome::Unchanged => "FormatPoolXmlNotChanged",
+        EditOutcome::Edited => "FormatPoolXmlEdited",
+    };
+    println!("{}", locale.format_message(id, vec![("name", &pool.name)]));
+
+    Ok(())
+}
@@ -1,14 +1,15 @@
 use crate::error::Error;
 use crate::locale::Locale;
-use chrono::Utc;
+use crate::util;
 use clap::{Arg, ArgMatches, Command};
 use libvirt_remote::binding::{
-    RemoteStoragePoolEventLifecycleMsg, RemoteStoragePoolEventRefreshMsg,
+    RemoteProcedure, RemoteStoragePoolEventLifecycleMsg, RemoteStoragePoolEventRefreshMsg,
 };
-use libvirt_remote::client::Libvirt;
+use libvirt_remote::client::{Libvirt, VirNetResponseRaw};
+use libvirt_remote::event::wait_event;
 use std::collections::HashMap;
 use std::sync::OnceLock;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 static EVENTS: OnceLock<HashMap<&'static str, i32>> = OnceLock::new();
 
@@ -37,7 +38,12 @@ pub fn cmd() -> Command {
         .arg(Arg::new("pool").long("pool").value_name("string"))
         .arg(Arg::new("event").long("event").value_name("string"))
         .arg(Arg::new("loop").long("loop").num_args(0))
-        .arg(Arg::new("timeout").long("timeout").value_name("number"))
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .value_name("number")
+                .value_parser(clap::value_parser!(u64)),
+        )
         .arg(Arg::new("list").long("list").num_args(0))
         .arg(Arg::new("timestamp").long("timestamp").num_args(0))
 }
@@ -54,13 +60,13 @@ pub fn run(
         return Ok(());
     }
 
-    let event_name = args
-        .get_one::<String>("event")
-        .expect("must specify --event.");
+    let Some(event_name) = args.get_one::<String>("event") else {
+        return Err(Error::Arg("--event".to_string()));
+    };
     let event_id = *EVENTS
         .get_or_init(init_events)
         .get(event_name.as_str())
-        .expect("not found event name.");
+        .ok_or_else(|| Error::Arg(event_name.to_string()))?;
 
     let pool = match args.get_one::<String>("pool") {
         Some(name) => {
@@ -72,20 +78,28 @@ pub fn run(
 
     let callback_id = client.connect_storage_pool_event_register_any(event_id, pool)?;
 
-    // TODO: timeout
+    let deadline = args
+        .get_one::<u64>("timeout")
+        .map(|secs| Instant::now() + Duration::from_secs(*secs));
+
     loop {
+        let Some(raw) = wait_event(client.as_mut(), deadline)? else {
+            println!("event loop timed out");
+            break;
+        };
+
         let msg = match event_id {
-            0 => handle_lifecycle_event(client)?,
-            1 => handle_refresh_event(client)?,
+            0 => handle_lifecycle_event(raw, callback_id)?,
+            1 => handle_refresh_event(raw, callback_id)?,
             _ => unreachable!(),
         };
 
-        let time = if args.get_flag("timestamp") {
-            format!("{}: ", Utc::now().format("%Y-%m-%d %H:%M:%S%.3f%z"))
-        } else {
-            "".to_string()
+        let Some(msg) = msg else {
+            continue;
         };
 
+        let time = util::event_timestamp(args.get_flag("timestamp"));
+
         println!("{time}{msg}");
 
         if !args.get_flag("loop") {
@@ -98,29 +112,49 @@ pub fn run(
     Ok(())
 }
 
-fn handle_lifecycle_event(client: &mut Box<impl Libvirt>) -> Result<String, Error> {
-    let raw = client.get_event(Duration::from_secs(1800))?;
+fn handle_lifecycle_event(
+    raw: VirNetResponseRaw,
+    callback_id: i32,
+) -> Result<Option<String>, Error> {
+    if raw.procedure() != RemoteProcedure::RemoteProcStoragePoolEventLifecycle as i32 {
+        return Ok(None);
+    }
+
     let RemoteStoragePoolEventLifecycleMsg {
-        callback_id: _,
+        callback_id: id,
         pool,
         event,
         detail: _,
     } = raw.try_into()?;
+    if id != callback_id {
+        return Ok(None);
+    }
+
     let id = LIFECYCLE_EVENTS
         .get_or_init(init_lifecycle_events)
         .get(&event)
-        .unwrap();
-    Ok(format!(
+        .unwrap_or(&"Unknown");
+    Ok(Some(format!(
         "event 'lifecycle' for storage pool {}: {}",
         pool.name, id
-    ))
+    )))
 }
 
-fn handle_refresh_event(client: &mut Box<impl Libvirt>) -> Result<String, Error> {
-    let raw = client.get_event(Duration::from_secs(1800))?;
+fn handle_refresh_event(raw: VirNetResponseRaw, callback_id: i32) -> Result<Option<String>, Error> {
+    if raw.procedure() != RemoteProcedure::RemoteProcStoragePoolEventRefresh as i32 {
+        return Ok(None);
+    }
+
     let RemoteStoragePoolEventRefreshMsg {
-        callback_id: _,
+        callback_id: id,
         pool,
     } = raw.try_into()?;
-    Ok(format!("event 'refresh' for storage pool {}", pool.name))
+    if id != callback_id {
+        return Ok(None);
+    }
+
+    Ok(Some(format!(
+        "event 'refresh' for storage pool {}",
+        pool.name
+    )))
 }
@@ -0,0 +1,69 @@
+use crate::error::Error;
+use crate::kv_view::KeyValueView;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::storage::StoragePoolState;
+
+pub fn cmd() -> Command {
+    Command::new("pool-info")
+        .arg(Arg::new("pool").value_name("pool").required(true).index(1))
+        .arg(Arg::new("bytes").long("bytes").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let pool = args.get_one::<String>("pool").unwrap();
+    let bytes = args.get_flag("bytes");
+
+    let pool = util::lookup_pool(client, pool)?;
+    let (state, capacity, allocation, available) = client.storage_pool_get_info(pool.clone())?;
+    let state = StoragePoolState::from(state);
+
+    let mut view = KeyValueView::default();
+
+    view.add_row(&locale.get_message("LabelName"), &pool.name);
+    view.add_row(
+        &locale.get_message("LabelUuid"),
+        util::format_uuid(&pool.uuid),
+    );
+    view.add_row(
+        &locale.get_message("LabelState"),
+        util::pool_state_label(locale, state),
+    );
+
+    let persistent = match client.storage_pool_is_persistent(pool.clone()) {
+        Ok(0) => locale.get_message("No"),
+        Ok(_) => locale.get_message("Yes"),
+        Err(_) => locale.get_message("Unknown"),
+    };
+    view.add_row(&locale.get_message("LabelPersistent"), persistent);
+
+    let autostart = match client.storage_pool_get_autostart(pool) {
+        Ok(0) => locale.get_message("No"),
+        Ok(_) => locale.get_message("Yes"),
+        Err(_) => locale.get_message("Unknown"),
+    };
+    view.add_row(&locale.get_message("LabelAutostart"), autostart);
+
+    if state.is_active() {
+        let size = |value: u64| {
+            if bytes {
+                format!("{value} bytes")
+            } else {
+                util::format_size(value)
+            }
+        };
+        view.add_row(&locale.get_message("LabelCapacity"), size(capacity));
+        view.add_row(&locale.get_message("LabelAllocation"), size(allocation));
+        view.add_row(&locale.get_message("LabelAvailable"), size(available));
+    }
+
+    view.print_kv();
+
+    Ok(())
+}
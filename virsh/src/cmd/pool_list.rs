@@ -0,0 +1,136 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::table_view::TableView;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::storage::*;
+
+const FILTERS: [(&str, u32); 4] = [
+    ("persistent", VIR_CONNECT_LIST_STORAGE_POOLS_PERSISTENT),
+    ("transient", VIR_CONNECT_LIST_STORAGE_POOLS_TRANSIENT),
+    ("autostart", VIR_CONNECT_LIST_STORAGE_POOLS_AUTOSTART),
+    ("no-autostart", VIR_CONNECT_LIST_STORAGE_POOLS_NO_AUTOSTART),
+];
+
+pub fn cmd() -> Command {
+    let mut cmd = Command::new("pool-list")
+        .arg(Arg::new("inactive").long("inactive").num_args(0))
+        .arg(
+            Arg::new("all")
+                .long("all")
+                .num_args(0)
+                .conflicts_with("inactive"),
+        )
+        .arg(Arg::new("type").long("type").value_name("types"))
+        .arg(Arg::new("details").long("details").num_args(0))
+        .arg(
+            Arg::new("name")
+                .long("name")
+                .num_args(0)
+                .conflicts_with("details"),
+        )
+        .arg(
+            Arg::new("uuid")
+                .long("uuid")
+                .num_args(0)
+                .conflicts_with_all(["details", "name"]),
+        );
+
+    for (name, _) in FILTERS {
+        cmd = cmd.arg(Arg::new(name).long(name).num_args(0));
+    }
+
+    cmd
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let mut flags = FILTERS
+        .iter()
+        .filter(|(name, _)| args.get_flag(name))
+        .fold(0, |flags, (_, flag)| flags | flag);
+
+    if let Some(types) = args.get_one::<String>("type") {
+        flags |= util::parse_flags(types, &STORAGE_POOL_TYPES)?;
+    }
+
+    if args.get_flag("inactive") {
+        flags |= VIR_CONNECT_LIST_STORAGE_POOLS_INACTIVE;
+    } else if args.get_flag("all") {
+        flags |= VIR_CONNECT_LIST_STORAGE_POOLS_ACTIVE | VIR_CONNECT_LIST_STORAGE_POOLS_INACTIVE;
+    } else {
+        flags |= VIR_CONNECT_LIST_STORAGE_POOLS_ACTIVE;
+    }
+
+    let (mut pools, _) = client.connect_list_all_storage_pools(-1, flags)?;
+    pools.sort_by(|a, b| a.name.cmp(&b.name));
+
+    if args.get_flag("name") {
+        for pool in pools {
+            println!("{}", pool.name);
+        }
+        return Ok(());
+    }
+
+    if args.get_flag("uuid") {
+        for pool in pools {
+            println!("{}", util::format_uuid(&pool.uuid));
+        }
+        return Ok(());
+    }
+
+    let details = args.get_flag("details");
+
+    let mut headers = vec![
+        locale.get_message("Name"),
+        locale.get_message("State"),
+        locale.get_message("Autostart"),
+    ];
+    if details {
+        headers.push(locale.get_message("Persistent"));
+        headers.push(locale.get_message("Capacity"));
+        headers.push(locale.get_message("Allocation"));
+        headers.push(locale.get_message("Available"));
+    }
+    let mut view = TableView::new(headers.iter().map(|h| h.as_str()).collect());
+
+    for pool in pools {
+        let (state, capacity, allocation, available) =
+            client.storage_pool_get_info(pool.clone())?;
+        let state = StoragePoolState::from(state);
+        let autostart = match client.storage_pool_get_autostart(pool.clone())? {
+            0 => locale.get_message("No"),
+            _ => locale.get_message("Yes"),
+        };
+
+        let mut cells = vec![
+            pool.name.clone(),
+            util::pool_state_label(locale, state),
+            autostart,
+        ];
+        if details {
+            let persistent = match client.storage_pool_is_persistent(pool)? {
+                0 => locale.get_message("No"),
+                _ => locale.get_message("Yes"),
+            };
+            cells.push(persistent);
+
+            if state.is_active() {
+                cells.push(util::format_size(capacity));
+                cells.push(util::format_size(allocation));
+                cells.push(util::format_size(available));
+            } else {
+                cells.extend(["-".to_string(), "-".to_string(), "-".to_string()]);
+            }
+        }
+        view.add_row(cells.iter().map(|c| c.as_str()).collect());
+    }
+
+    view.print_table();
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("pool-refresh").arg(Arg::new("pool").value_name("pool").required(true).index(1))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let pool = args.get_one::<String>("pool").unwrap();
+
+    let pool = util::lookup_pool(client, pool)?;
+    client.storage_pool_refresh(pool.clone(), 0)?;
+
+    println!(
+        "{}",
+        locale.format_message("FormatPoolRefreshed", vec![("name", &pool.name)])
+    );
+
+    Ok(())
+}
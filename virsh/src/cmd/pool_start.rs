@@ -0,0 +1,58 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::storage::*;
+
+const OPTIONS: [(&str, u32); 3] = [
+    ("build", VIR_STORAGE_POOL_CREATE_WITH_BUILD),
+    ("overwrite", VIR_STORAGE_POOL_CREATE_WITH_BUILD_OVERWRITE),
+    (
+        "no-overwrite",
+        VIR_STORAGE_POOL_CREATE_WITH_BUILD_NO_OVERWRITE,
+    ),
+];
+
+pub fn cmd() -> Command {
+    build_args(
+        Command::new("pool-start").arg(Arg::new("pool").value_name("pool").required(true).index(1)),
+    )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let pool = args.get_one::<String>("pool").unwrap();
+
+    let pool = util::lookup_pool(client, pool)?;
+    client.storage_pool_create(pool.clone(), build_flags(args))?;
+
+    println!(
+        "{}",
+        locale.format_message("FormatPoolStarted", vec![("name", &pool.name)])
+    );
+
+    Ok(())
+}
+
+// The options which build the pool while starting it, shared by the commands
+// creating a pool.
+pub fn build_args(mut cmd: Command) -> Command {
+    for (name, _) in OPTIONS {
+        cmd = cmd.arg(Arg::new(name).long(name).num_args(0));
+    }
+
+    cmd.mut_arg("overwrite", |a| a.conflicts_with("no-overwrite"))
+}
+
+pub fn build_flags(args: &ArgMatches) -> u32 {
+    OPTIONS
+        .iter()
+        .filter(|(name, _)| args.get_flag(name))
+        .fold(VIR_STORAGE_POOL_CREATE_NORMAL, |flags, (_, flag)| {
+            flags | flag
+        })
+}
@@ -0,0 +1,27 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("pool-undefine").arg(Arg::new("pool").value_name("pool").required(true).index(1))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let pool = args.get_one::<String>("pool").unwrap();
+
+    let pool = util::lookup_pool(client, pool)?;
+    client.storage_pool_undefine(pool.clone())?;
+
+    println!(
+        "{}",
+        locale.format_message("FormatPoolUndefined", vec![("name", &pool.name)])
+    );
+
+    Ok(())
+}
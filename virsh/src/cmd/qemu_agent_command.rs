@@ -1,13 +1,10 @@
 use crate::error::Error;
 use crate::locale::Locale;
 use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::agent::*;
 use libvirt_remote::client::Libvirt;
 use std::str::FromStr;
 
-const DOMAIN_QEMU_AGENT_COMMAND_BLOCK: i32 = -2;
-const DOMAIN_QEMU_AGENT_COMMAND_DEFAULT: i32 = -1;
-const DOMAIN_QEMU_AGENT_COMMAND_NOWAIT: i32 = 0;
-
 pub fn cmd() -> Command {
     Command::new("qemu-agent-command")
         .arg(
@@ -51,12 +48,12 @@ pub fn run(
 
     let dom = client.domain_lookup_by_name(domain.to_string())?;
 
-    let mut timeout = DOMAIN_QEMU_AGENT_COMMAND_DEFAULT;
+    let mut timeout = VIR_DOMAIN_QEMU_AGENT_COMMAND_DEFAULT;
 
     if async_flag {
-        timeout = DOMAIN_QEMU_AGENT_COMMAND_NOWAIT
+        timeout = VIR_DOMAIN_QEMU_AGENT_COMMAND_NOWAIT
     } else if block {
-        timeout = DOMAIN_QEMU_AGENT_COMMAND_BLOCK
+        timeout = VIR_DOMAIN_QEMU_AGENT_COMMAND_BLOCK
     } else if let Some(t) = utimeout {
         timeout = *t as i32;
     }
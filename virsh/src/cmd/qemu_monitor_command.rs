@@ -0,0 +1,111 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::qmp::*;
+use serde_json::Value;
+
+pub fn cmd() -> Command {
+    Command::new("qemu-monitor-command")
+        .arg(
+            Arg::new("domain")
+                .value_name("domain")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("cmd")
+                .value_name("cmd")
+                .required(true)
+                .num_args(1..)
+                .index(2),
+        )
+        .arg(Arg::new("hmp").long("hmp").num_args(0))
+        .arg(
+            Arg::new("pretty")
+                .long("pretty")
+                .num_args(0)
+                .conflicts_with("hmp"),
+        )
+        .arg(
+            Arg::new("pass-fds")
+                .long("pass-fds")
+                .value_name("fds")
+                .help("comma separated list of FDs to pass"),
+        )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    _locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let domain = args.get_one::<String>("domain").unwrap();
+    let cmd = args
+        .get_many::<String>("cmd")
+        .unwrap()
+        .map(|s| s.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let hmp = args.get_flag("hmp");
+    let pretty = args.get_flag("pretty");
+
+    let fds = match args.get_one::<String>("pass-fds") {
+        Some(fds) => util::parse_fds(fds)?,
+        None => vec![],
+    };
+    check_fds(&fds)?;
+
+    // A bare command name such as `query-status` is wrapped into QMP.
+    let cmd = if hmp || cmd.trim_start().starts_with('{') {
+        cmd
+    } else {
+        serde_json::json!({ "execute": cmd.trim() }).to_string()
+    };
+
+    let flags = if hmp {
+        VIR_DOMAIN_QEMU_MONITOR_COMMAND_HMP
+    } else {
+        VIR_DOMAIN_QEMU_MONITOR_COMMAND_DEFAULT
+    };
+
+    let dom = util::lookup_domain(client, domain)?;
+
+    let output = if fds.is_empty() {
+        client.domain_monitor_command(dom, cmd, flags)?
+    } else {
+        client.domain_monitor_command_with_files(dom, cmd, flags, &fds)?
+    };
+
+    if pretty {
+        let value: Value =
+            serde_json::from_str(&output).map_err(libvirt_remote::error::Error::JsonError)?;
+        println!("{}", serde_json::to_string_pretty(&value).unwrap_or(output));
+    } else {
+        println!("{output}");
+    }
+
+    Ok(())
+}
+
+// Fails before anything is sent if a descriptor is not open.
+#[cfg(target_family = "unix")]
+fn check_fds(fds: &[i32]) -> Result<(), Error> {
+    for fd in fds {
+        if unsafe { libc::fcntl(*fd, libc::F_GETFD) } < 0 {
+            return Err(Error::Arg(fd.to_string()));
+        }
+    }
+    Ok(())
+}
+
+// Descriptors can be passed on UNIX sockets only.
+#[cfg(target_family = "windows")]
+fn check_fds(fds: &[i32]) -> Result<(), Error> {
+    if fds.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::NotSupported)
+    }
+}
@@ -0,0 +1,90 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::qmp::*;
+use std::time::{Duration, Instant};
+
+pub fn cmd() -> Command {
+    Command::new("qemu-monitor-event")
+        .arg(Arg::new("domain").long("domain").value_name("string"))
+        .arg(Arg::new("event").long("event").value_name("string"))
+        .arg(Arg::new("regex").long("regex").num_args(0))
+        .arg(Arg::new("no-case").long("no-case").num_args(0))
+        .arg(Arg::new("loop").long("loop").num_args(0))
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .value_name("number")
+                .value_parser(clap::value_parser!(u64)),
+        )
+        .arg(Arg::new("timestamp").long("timestamp").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    _locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let dom = match args.get_one::<String>("domain") {
+        Some(domain) => Some(util::lookup_domain(client, domain)?),
+        None => None,
+    };
+    let event = args.get_one::<String>("event").map(|s| s.as_str());
+
+    let mut flags = 0;
+    if args.get_flag("regex") {
+        flags |= VIR_CONNECT_DOMAIN_QEMU_MONITOR_EVENT_REGISTER_REGEX;
+    }
+    if args.get_flag("no-case") {
+        flags |= VIR_CONNECT_DOMAIN_QEMU_MONITOR_EVENT_REGISTER_NOCASE;
+    }
+
+    let deadline = args
+        .get_one::<u64>("timeout")
+        .map(|secs| Instant::now() + Duration::from_secs(*secs));
+
+    let mut listener = QmpEventListener::register(client.as_mut(), dom, event, flags)?;
+    let mut count = 0;
+
+    loop {
+        let timeout = match deadline {
+            Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                Some(remaining) if !remaining.is_zero() => remaining,
+                _ => {
+                    println!("event loop timed out");
+                    break;
+                }
+            },
+            None => Duration::from_secs(1800),
+        };
+
+        let Some(event) = listener.next(timeout)? else {
+            continue;
+        };
+
+        let details = event
+            .details
+            .as_ref()
+            .map(|d| d.to_string())
+            .unwrap_or_else(|| "<null>".to_string());
+        let time = util::event_timestamp(args.get_flag("timestamp"));
+
+        println!(
+            "{time}event {} at {}.{:06} for domain '{}': {details}",
+            event.event, event.seconds, event.micros, event.dom.name
+        );
+        count += 1;
+
+        if !args.get_flag("loop") {
+            break;
+        }
+    }
+
+    listener.close()?;
+
+    println!("events received: {count}");
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::screenshot::*;
+use std::fs;
+use std::path::Path;
+
+const TIME_FORMAT: &str = if cfg!(unix) {
+    "%Y-%m-%d-%H:%M:%S"
+} else {
+    "%Y-%m-%d-%H-%M-%S"
+};
+
+pub fn cmd() -> Command {
+    Command::new("screenshot")
+        .arg(
+            Arg::new("domain")
+                .value_name("domain")
+                .required(true)
+                .index(1),
+        )
+        .arg(Arg::new("file").value_name("file").index(2))
+        .arg(
+            Arg::new("screen")
+                .long("screen")
+                .value_name("number")
+                .value_parser(clap::value_parser!(u32)),
+        )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let domain = args.get_one::<String>("domain").unwrap();
+    let screen = *args.get_one::<u32>("screen").unwrap_or(&0);
+
+    let dom = client.domain_lookup_by_name(domain.to_string())?;
+
+    let shot = screenshot(client.as_mut(), dom, screen)?;
+
+    // Converted to PNG unless the given file name asks for something else.
+    let file = match args.get_one::<String>("file") {
+        Some(file) => file.clone(),
+        None => {
+            let now = chrono::Local::now().format(TIME_FORMAT);
+            format!("{domain}-{now}.png")
+        }
+    };
+
+    let is_png = Path::new(&file)
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("png"));
+
+    let (data, mime) = if is_png {
+        (shot.to_png()?, MIME_PNG)
+    } else {
+        (shot.data.clone(), shot.mime.as_deref().unwrap_or_default())
+    };
+
+    fs::write(&file, data)?;
+
+    println!(
+        "{}",
+        locale.format_message(
+            "FormatScreenshotSaved",
+            vec![("file", &file), ("mime", mime)]
+        )
+    );
+
+    Ok(())
+}
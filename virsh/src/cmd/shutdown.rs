@@ -0,0 +1,57 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::domain::*;
+
+const MODES: [(&str, u32); 5] = [
+    ("acpi", VIR_DOMAIN_SHUTDOWN_ACPI_POWER_BTN),
+    ("agent", VIR_DOMAIN_SHUTDOWN_GUEST_AGENT),
+    ("initctl", VIR_DOMAIN_SHUTDOWN_INITCTL),
+    ("signal", VIR_DOMAIN_SHUTDOWN_SIGNAL),
+    ("paravirt", VIR_DOMAIN_SHUTDOWN_PARAVIRT),
+];
+
+pub fn cmd() -> Command {
+    Command::new("shutdown")
+        .arg(
+            Arg::new("domain")
+                .value_name("domain")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("mode")
+                .long("mode")
+                .value_name("mode")
+                .help("acpi|agent|initctl|signal|paravirt, separated by comma"),
+        )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let domain = args.get_one::<String>("domain").unwrap();
+    let flags = match args.get_one::<String>("mode") {
+        Some(mode) => util::parse_flags(mode, &MODES)?,
+        None => VIR_DOMAIN_SHUTDOWN_DEFAULT,
+    };
+
+    let dom = util::lookup_domain(client, domain)?;
+
+    if flags != VIR_DOMAIN_SHUTDOWN_DEFAULT {
+        client.domain_shutdown_flags(dom.clone(), flags)?;
+    } else {
+        client.domain_shutdown(dom.clone())?;
+    }
+
+    println!(
+        "{}",
+        locale.format_message("FormatDomainShuttingDown", vec![("name", &dom.name)])
+    );
+
+    Ok(())
+}
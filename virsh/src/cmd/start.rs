@@ -2,11 +2,7 @@ use crate::error::Error;
 use crate::locale::Locale;
 use clap::{Arg, ArgMatches, Command};
 use libvirt_remote::client::Libvirt;
-
-const DOMAIN_START_PAUSED: u32 = 1 << 0;
-const DOMAIN_START_AUTODESTROY: u32 = 1 << 1;
-const DOMAIN_START_BYPASS_CACHE: u32 = 1 << 2;
-const DOMAIN_START_FORCE_BOOT: u32 = 1 << 3;
+use libvirt_remote::domain::*;
 
 pub fn cmd() -> Command {
     Command::new("start")
@@ -38,19 +34,19 @@ pub fn run(
     let mut flags = 0;
 
     if paused {
-        flags |= DOMAIN_START_PAUSED;
+        flags |= VIR_DOMAIN_START_PAUSED;
     }
 
     if autodestroy {
-        flags |= DOMAIN_START_AUTODESTROY;
+        flags |= VIR_DOMAIN_START_AUTODESTROY;
     }
 
     if bypass_cache {
-        flags |= DOMAIN_START_BYPASS_CACHE;
+        flags |= VIR_DOMAIN_START_BYPASS_CACHE;
     }
 
     if force_boot {
-        flags |= DOMAIN_START_FORCE_BOOT;
+        flags |= VIR_DOMAIN_START_FORCE_BOOT;
     }
 
     if flags != 0 {
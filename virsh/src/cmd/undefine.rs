@@ -0,0 +1,94 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::domain::*;
+
+const OPTIONS: [(&str, u32); 5] = [
+    ("managed-save", VIR_DOMAIN_UNDEFINE_MANAGED_SAVE),
+    ("snapshots-metadata", VIR_DOMAIN_UNDEFINE_SNAPSHOTS_METADATA),
+    (
+        "checkpoints-metadata",
+        VIR_DOMAIN_UNDEFINE_CHECKPOINTS_METADATA,
+    ),
+    ("nvram", VIR_DOMAIN_UNDEFINE_NVRAM),
+    ("keep-nvram", VIR_DOMAIN_UNDEFINE_KEEP_NVRAM),
+];
+
+pub fn cmd() -> Command {
+    let mut cmd = Command::new("undefine")
+        .arg(
+            Arg::new("domain")
+                .value_name("domain")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("remove-all-storage")
+                .long("remove-all-storage")
+                .num_args(0),
+        );
+
+    for (name, _) in OPTIONS {
+        cmd = cmd.arg(Arg::new(name).long(name).num_args(0));
+    }
+
+    cmd.mut_arg("keep-nvram", |a| a.conflicts_with("nvram"))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let domain = args.get_one::<String>("domain").unwrap();
+    let remove_all_storage = args.get_flag("remove-all-storage");
+
+    let flags = OPTIONS
+        .iter()
+        .filter(|(name, _)| args.get_flag(name))
+        .fold(0, |flags, (_, flag)| flags | flag);
+
+    let dom = util::lookup_domain(client, domain)?;
+
+    // The disks can no longer be looked up once the definition is gone.
+    let volumes = if remove_all_storage {
+        disk_volumes(client.as_mut(), dom.clone())?
+    } else {
+        vec![]
+    };
+
+    if flags != 0 {
+        client.domain_undefine_flags(dom.clone(), flags)?;
+    } else {
+        client.domain_undefine(dom.clone())?;
+    }
+
+    println!(
+        "{}",
+        locale.format_message("FormatDomainUndefined", vec![("name", &dom.name)])
+    );
+
+    for volume in volumes {
+        let args = vec![
+            ("target", volume.target.as_str()),
+            ("path", volume.source.as_str()),
+        ];
+
+        let Some(vol) = volume.vol else {
+            eprintln!("{}", locale.format_message("FormatVolumeNotManaged", args));
+            continue;
+        };
+
+        match client.storage_vol_delete(vol, 0) {
+            Ok(_) => println!("{}", locale.format_message("FormatVolumeRemoved", args)),
+            Err(e) => eprintln!(
+                "{}: {e:?}",
+                locale.format_message("FormatVolumeRemoveFailed", args)
+            ),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::storage::*;
+use libvirt_remote::xml::XmlDocument;
+use libvirt_remote::xml::storage::StorageVolDef;
+
+const OPTIONS: [(&str, u32); 2] = [
+    (
+        "prealloc-metadata",
+        VIR_STORAGE_VOL_CREATE_PREALLOC_METADATA,
+    ),
+    ("reflink", VIR_STORAGE_VOL_CREATE_REFLINK),
+];
+
+pub fn cmd() -> Command {
+    let mut cmd = Command::new("vol-clone")
+        .arg(Arg::new("vol").value_name("vol").required(true).index(1))
+        .arg(
+            Arg::new("newname")
+                .value_name("newname")
+                .required(true)
+                .index(2),
+        )
+        .arg(Arg::new("pool").long("pool").value_name("string"));
+
+    for (name, _) in OPTIONS {
+        cmd = cmd.arg(Arg::new(name).long(name).num_args(0));
+    }
+
+    cmd
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let vol = args.get_one::<String>("vol").unwrap();
+    let new_name = args.get_one::<String>("newname").unwrap();
+    let pool = args.get_one::<String>("pool").map(|s| s.as_str());
+
+    let flags = OPTIONS
+        .iter()
+        .filter(|(name, _)| args.get_flag(name))
+        .fold(0, |flags, (_, flag)| flags | flag);
+
+    let orig = util::lookup_vol(client, vol, pool)?;
+    let pool = client.storage_pool_lookup_by_volume(orig.clone())?;
+
+    // The key and the path belong to the original volume, so the daemon
+    // chooses new ones for the clone.
+    let xml = client.storage_vol_get_xml_desc(orig.clone(), 0)?;
+    let mut def = StorageVolDef::from_xml(&xml)?;
+    def.name = new_name.to_string();
+    def.key = None;
+    if let Some(target) = def.target.as_mut() {
+        target.path = None;
+    }
+
+    let vol = client.storage_vol_create_xml_from(pool, def.to_xml()?, orig.clone(), flags)?;
+
+    println!(
+        "{}",
+        locale.format_message(
+            "FormatVolumeCloned",
+            vec![("name", &vol.name), ("input", &orig.name)]
+        )
+    );
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::storage::*;
+use libvirt_remote::xml::storage::*;
+use libvirt_remote::xml::{FormatType, ScaledInteger, XmlDocument};
+
+pub fn cmd() -> Command {
+    Command::new("vol-create-as")
+        .arg(Arg::new("pool").value_name("pool").required(true).index(1))
+        .arg(Arg::new("name").value_name("name").required(true).index(2))
+        .arg(
+            Arg::new("capacity")
+                .value_name("capacity")
+                .required(true)
+                .index(3),
+        )
+        .arg(Arg::new("allocation").long("allocation").value_name("size"))
+        .arg(Arg::new("format").long("format").value_name("string"))
+        .arg(
+            Arg::new("backing-vol")
+                .long("backing-vol")
+                .value_name("string"),
+        )
+        .arg(
+            Arg::new("backing-vol-format")
+                .long("backing-vol-format")
+                .value_name("string")
+                .requires("backing-vol"),
+        )
+        .arg(
+            Arg::new("prealloc-metadata")
+                .long("prealloc-metadata")
+                .num_args(0),
+        )
+        .arg(Arg::new("print-xml").long("print-xml").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let pool = args.get_one::<String>("pool").unwrap();
+    let name = args.get_one::<String>("name").unwrap();
+    let capacity = util::parse_size(args.get_one::<String>("capacity").unwrap())?;
+    let allocation = match args.get_one::<String>("allocation") {
+        Some(allocation) => Some(util::parse_size(allocation)?),
+        None => None,
+    };
+
+    let pool = util::lookup_pool(client, pool)?;
+
+    let backing_store = match args.get_one::<String>("backing-vol") {
+        Some(backing) => Some(VolBackingStore {
+            path: backing_path(client, &pool.name, backing)?,
+            format: args
+                .get_one::<String>("backing-vol-format")
+                .map(|r#type| FormatType {
+                    r#type: r#type.to_string(),
+                }),
+            permissions: None,
+        }),
+        None => None,
+    };
+
+    let def = StorageVolDef {
+        name: name.to_string(),
+        capacity: Some(ScaledInteger::new(capacity, "bytes")),
+        allocation: allocation.map(|a| ScaledInteger::new(a, "bytes")),
+        target: args.get_one::<String>("format").map(|r#type| VolTarget {
+            format: Some(FormatType {
+                r#type: r#type.to_string(),
+            }),
+            ..Default::default()
+        }),
+        backing_store,
+        ..Default::default()
+    };
+    let xml = def.to_xml()?;
+
+    if args.get_flag("print-xml") {
+        println!("{xml}");
+        return Ok(());
+    }
+
+    let flags = if args.get_flag("prealloc-metadata") {
+        VIR_STORAGE_VOL_CREATE_PREALLOC_METADATA
+    } else {
+        0
+    };
+    let vol = client.storage_vol_create_xml(pool, xml, flags)?;
+
+    println!(
+        "{}",
+        locale.format_message("FormatVolumeCreated", vec![("name", &vol.name)])
+    );
+
+    Ok(())
+}
+
+// The backing volume may be a volume of the same pool, a key or a path. A path
+// which libvirt does not know is used as is.
+fn backing_path(
+    client: &mut Box<impl Libvirt>,
+    pool: &str,
+    backing: &str,
+) -> Result<String, Error> {
+    let vol = match util::lookup_vol(client, backing, Some(pool)) {
+        Ok(vol) => vol,
+        Err(_) => match util::lookup_vol(client, backing, None) {
+            Ok(vol) => vol,
+            Err(_) => return Ok(backing.to_string()),
+        },
+    };
+
+    client.storage_vol_get_path(vol).map_err(Error::from)
+}
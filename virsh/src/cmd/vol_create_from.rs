@@ -0,0 +1,62 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::storage::*;
+use std::fs;
+
+const OPTIONS: [(&str, u32); 3] = [
+    (
+        "prealloc-metadata",
+        VIR_STORAGE_VOL_CREATE_PREALLOC_METADATA,
+    ),
+    ("reflink", VIR_STORAGE_VOL_CREATE_REFLINK),
+    ("validate", VIR_STORAGE_VOL_CREATE_VALIDATE),
+];
+
+pub fn cmd() -> Command {
+    let mut cmd = Command::new("vol-create-from")
+        .arg(Arg::new("pool").value_name("pool").required(true).index(1))
+        .arg(Arg::new("file").value_name("file").required(true).index(2))
+        .arg(Arg::new("vol").value_name("vol").required(true).index(3))
+        .arg(Arg::new("inputpool").long("inputpool").value_name("string"));
+
+    for (name, _) in OPTIONS {
+        cmd = cmd.arg(Arg::new(name).long(name).num_args(0));
+    }
+
+    cmd
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let pool = args.get_one::<String>("pool").unwrap();
+    let file = args.get_one::<String>("file").unwrap();
+    let vol = args.get_one::<String>("vol").unwrap();
+    let input_pool = args.get_one::<String>("inputpool").map(|s| s.as_str());
+
+    let flags = OPTIONS
+        .iter()
+        .filter(|(name, _)| args.get_flag(name))
+        .fold(0, |flags, (_, flag)| flags | flag);
+
+    let xml = fs::read_to_string(file)?;
+
+    let pool = util::lookup_pool(client, pool)?;
+    let input = util::lookup_vol(client, vol, input_pool)?;
+    let vol = client.storage_vol_create_xml_from(pool, xml, input.clone(), flags)?;
+
+    println!(
+        "{}",
+        locale.format_message(
+            "FormatVolumeCreatedFrom",
+            vec![("name", &vol.name), ("input", &input.name)]
+        )
+    );
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::storage::*;
+
+pub fn cmd() -> Command {
+    Command::new("vol-delete")
+        .arg(Arg::new("vol").value_name("vol").required(true).index(1))
+        .arg(Arg::new("pool").long("pool").value_name("string"))
+        .arg(
+            Arg::new("delete-snapshots")
+                .long("delete-snapshots")
+                .num_args(0),
+        )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let vol = args.get_one::<String>("vol").unwrap();
+    let pool = args.get_one::<String>("pool").map(|s| s.as_str());
+
+    let flags = if args.get_flag("delete-snapshots") {
+        VIR_STORAGE_VOL_DELETE_WITH_SNAPSHOTS
+    } else {
+        VIR_STORAGE_VOL_DELETE_NORMAL
+    };
+
+    let vol = util::lookup_vol(client, vol, pool)?;
+    client.storage_vol_delete(vol.clone(), flags)?;
+
+    println!(
+        "{}",
+        locale.format_message("FormatVolumeDeleted", vec![("name", &vol.name)])
+    );
+
+    Ok(())
+}
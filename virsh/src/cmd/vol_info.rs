@@ -0,0 +1,61 @@
+use crate::error::Error;
+use crate::kv_view::KeyValueView;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::storage::*;
+
+pub fn cmd() -> Command {
+    Command::new("vol-info")
+        .arg(Arg::new("vol").value_name("vol").required(true).index(1))
+        .arg(Arg::new("pool").long("pool").value_name("string"))
+        .arg(Arg::new("bytes").long("bytes").num_args(0))
+        .arg(Arg::new("physical").long("physical").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let vol = args.get_one::<String>("vol").unwrap();
+    let pool = args.get_one::<String>("pool").map(|s| s.as_str());
+    let bytes = args.get_flag("bytes");
+    let physical = args.get_flag("physical");
+
+    let vol = util::lookup_vol(client, vol, pool)?;
+    let (r#type, capacity, allocation) = if physical {
+        client.storage_vol_get_info_flags(vol.clone(), VIR_STORAGE_VOL_GET_PHYSICAL)?
+    } else {
+        client.storage_vol_get_info(vol.clone())?
+    };
+
+    let size = |value: u64| {
+        if bytes {
+            format!("{value} bytes")
+        } else {
+            util::format_size(value)
+        }
+    };
+
+    let mut view = KeyValueView::default();
+
+    view.add_row(&locale.get_message("LabelName"), &vol.name);
+    view.add_row(
+        &locale.get_message("LabelType"),
+        StorageVolType::from(r#type).name(),
+    );
+    view.add_row(&locale.get_message("LabelCapacity"), size(capacity));
+    // With --physical the last figure is the host size of the volume.
+    let label = if physical {
+        "LabelPhysical"
+    } else {
+        "LabelAllocation"
+    };
+    view.add_row(&locale.get_message(label), size(allocation));
+
+    view.print_kv();
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::table_view::TableView;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::storage::StorageVolType;
+
+pub fn cmd() -> Command {
+    Command::new("vol-list")
+        .arg(Arg::new("pool").value_name("pool").required(true).index(1))
+        .arg(Arg::new("details").long("details").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let pool = args.get_one::<String>("pool").unwrap();
+    let details = args.get_flag("details");
+
+    let pool = util::lookup_pool(client, pool)?;
+    let (mut vols, _) = client.storage_pool_list_all_volumes(pool, -1, 0)?;
+    vols.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut headers = vec![locale.get_message("Name"), locale.get_message("Path")];
+    if details {
+        headers.push(locale.get_message("Type"));
+        headers.push(locale.get_message("Capacity"));
+        headers.push(locale.get_message("Allocation"));
+    }
+    let mut view = TableView::new(headers.iter().map(|h| h.as_str()).collect());
+
+    for vol in vols {
+        let path = client.storage_vol_get_path(vol.clone())?;
+
+        let mut cells = vec![vol.name.clone(), path];
+        if details {
+            let (r#type, capacity, allocation) = client.storage_vol_get_info(vol)?;
+            cells.push(StorageVolType::from(r#type).name().to_string());
+            cells.push(util::format_size(capacity));
+            cells.push(util::format_size(allocation));
+        }
+        view.add_row(cells.iter().map(|c| c.as_str()).collect());
+    }
+
+    view.print_table();
+
+    Ok(())
+}
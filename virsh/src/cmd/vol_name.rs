@@ -0,0 +1,28 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("vol-name").arg(
+        Arg::new("vol")
+            .value_name("vol-key-or-path")
+            .required(true)
+            .index(1),
+    )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    _locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let vol = args.get_one::<String>("vol").unwrap();
+
+    let vol = util::lookup_vol(client, vol, None)?;
+
+    println!("{}", vol.name);
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("vol-path")
+        .arg(Arg::new("vol").value_name("vol").required(true).index(1))
+        .arg(Arg::new("pool").long("pool").value_name("string"))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    _locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let vol = args.get_one::<String>("vol").unwrap();
+    let pool = args.get_one::<String>("pool").map(|s| s.as_str());
+
+    let vol = util::lookup_vol(client, vol, pool)?;
+    let path = client.storage_vol_get_path(vol)?;
+
+    println!("{path}");
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+
+pub fn cmd() -> Command {
+    Command::new("vol-pool")
+        .arg(
+            Arg::new("vol")
+                .value_name("vol-key-or-path")
+                .required(true)
+                .index(1),
+        )
+        .arg(Arg::new("uuid").long("uuid").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    _locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let vol = args.get_one::<String>("vol").unwrap();
+
+    let vol = util::lookup_vol(client, vol, None)?;
+    let pool = client.storage_pool_lookup_by_volume(vol)?;
+
+    if args.get_flag("uuid") {
+        println!("{}", util::format_uuid(&pool.uuid));
+    } else {
+        println!("{}", pool.name);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::storage::*;
+
+pub fn cmd() -> Command {
+    Command::new("vol-resize")
+        .arg(Arg::new("vol").value_name("vol").required(true).index(1))
+        .arg(
+            Arg::new("capacity")
+                .value_name("capacity")
+                .required(true)
+                .allow_hyphen_values(true)
+                .index(2),
+        )
+        .arg(Arg::new("pool").long("pool").value_name("string"))
+        .arg(Arg::new("allocate").long("allocate").num_args(0))
+        .arg(Arg::new("delta").long("delta").num_args(0))
+        .arg(Arg::new("shrink").long("shrink").num_args(0))
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let vol = args.get_one::<String>("vol").unwrap();
+    let capacity = args.get_one::<String>("capacity").unwrap();
+    let pool = args.get_one::<String>("pool").map(|s| s.as_str());
+    let shrink = args.get_flag("shrink");
+
+    let mut flags = 0;
+    if args.get_flag("allocate") {
+        flags |= VIR_STORAGE_VOL_RESIZE_ALLOCATE;
+    }
+    if args.get_flag("delta") {
+        flags |= VIR_STORAGE_VOL_RESIZE_DELTA;
+    }
+    if shrink {
+        flags |= VIR_STORAGE_VOL_RESIZE_SHRINK;
+    }
+
+    // A negative size is a delta to shrink by, which must be asked for.
+    let size = match capacity.strip_prefix('-') {
+        Some(size) if shrink => {
+            flags |= VIR_STORAGE_VOL_RESIZE_DELTA;
+            util::parse_size(size)?
+        }
+        Some(_) => {
+            return Err(Error::Arg(format!(
+                "negative size '{capacity}' requires --shrink"
+            )));
+        }
+        None => util::parse_size(capacity)?,
+    };
+
+    let vol = util::lookup_vol(client, vol, pool)?;
+    client.storage_vol_resize(vol.clone(), size, flags)?;
+
+    println!(
+        "{}",
+        locale.format_message(
+            "FormatVolumeResized",
+            vec![("name", &vol.name), ("size", capacity)]
+        )
+    );
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::util;
+use clap::{Arg, ArgMatches, Command};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::storage::*;
+
+pub fn cmd() -> Command {
+    Command::new("vol-wipe")
+        .arg(Arg::new("vol").value_name("vol").required(true).index(1))
+        .arg(Arg::new("pool").long("pool").value_name("string"))
+        .arg(
+            Arg::new("algorithm")
+                .long("algorithm")
+                .value_name("string")
+                .value_parser(STORAGE_VOL_WIPE_ALGORITHMS.map(|(name, _)| name)),
+        )
+}
+
+pub fn run(
+    client: &mut Box<impl Libvirt>,
+    locale: &Locale,
+    args: &ArgMatches,
+) -> Result<(), Error> {
+    let vol = args.get_one::<String>("vol").unwrap();
+    let pool = args.get_one::<String>("pool").map(|s| s.as_str());
+
+    let algorithm = args
+        .get_one::<String>("algorithm")
+        .and_then(|algorithm| {
+            STORAGE_VOL_WIPE_ALGORITHMS
+                .iter()
+                .find(|(name, _)| name == algorithm)
+        })
+        .map_or(VIR_STORAGE_VOL_WIPE_ALG_ZERO, |(_, alg)| *alg);
+
+    let vol = util::lookup_vol(client, vol, pool)?;
+    client.storage_vol_wipe_pattern(vol.clone(), algorithm, 0)?;
+
+    println!(
+        "{}",
+        locale.format_message("FormatVolumeWiped", vec![("name", &vol.name)])
+    );
+
+    Ok(())
+}
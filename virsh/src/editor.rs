@@ -0,0 +1,142 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const DEFAULT_EDITOR: &str = if cfg!(unix) { "vi" } else { "notepad" };
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EditOutcome {
+    Unchanged,
+    Edited,
+}
+
+enum Retry {
+    Edit,
+    NoValidate,
+    Abort,
+}
+
+// Removes the file when dropped so that an error does not leave it behind.
+struct TempFile {
+    path: PathBuf,
+}
+
+impl TempFile {
+    fn create(content: &str) -> Result<Self, Error> {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or_default();
+        let path = env::temp_dir().join(format!("virsh{}-{nanos}.xml", process::id()));
+
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(target_family = "unix")]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut file = options.open(&path)?;
+        file.write_all(content.as_bytes())?;
+
+        Ok(TempFile { path })
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+// Lets the user edit `xml` in $VISUAL or $EDITOR and passes the result to
+// `define` together with whether it should be validated. When `define` fails
+// the user is asked whether to edit again, and the error is returned if not.
+pub fn edit_xml<F>(locale: &Locale, xml: &str, mut define: F) -> Result<EditOutcome, Error>
+where
+    F: FnMut(&str, bool) -> Result<(), Error>,
+{
+    let file = TempFile::create(xml)?;
+    let mut validate = true;
+
+    loop {
+        launch_editor(&file.path)?;
+
+        let edited = fs::read_to_string(&file.path)?;
+        if edited == xml {
+            return Ok(EditOutcome::Unchanged);
+        }
+
+        loop {
+            let err = match define(&edited, validate) {
+                Ok(_) => return Ok(EditOutcome::Edited),
+                Err(e) => e,
+            };
+
+            eprintln!("{err:?}");
+
+            match ask_retry(locale, validate)? {
+                Retry::Edit => break,
+                Retry::NoValidate => validate = false,
+                Retry::Abort => return Err(err),
+            }
+        }
+    }
+}
+
+fn launch_editor(path: &Path) -> Result<(), Error> {
+    let editor = env::var("VISUAL")
+        .or_else(|_| env::var("EDITOR"))
+        .ok()
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_EDITOR.to_string());
+
+    // The editor may be given with arguments, such as `code --wait`.
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or(DEFAULT_EDITOR);
+
+    let status = Command::new(program).args(words).arg(path).status()?;
+    if !status.success() {
+        return Err(Error::Io(io::Error::other(format!("{editor}: {status}"))));
+    }
+
+    Ok(())
+}
+
+fn ask_retry(locale: &Locale, validate: bool) -> Result<Retry, Error> {
+    let stdin = io::stdin();
+    loop {
+        let prompt = if validate {
+            "EditRetryPrompt"
+        } else {
+            "EditRetryPromptNoValidate"
+        };
+        print!("{} ", locale.get_message(prompt));
+        io::stdout().flush()?;
+
+        let mut answer = String::new();
+        if stdin.lock().read_line(&mut answer)? == 0 {
+            return Ok(Retry::Abort);
+        }
+
+        match answer.trim() {
+            "y" | "Y" => return Ok(Retry::Edit),
+            "n" | "N" => return Ok(Retry::Abort),
+            "i" | "I" if validate => return Ok(Retry::NoValidate),
+            _ => {
+                println!("{}", locale.get_message("EditRetryHelpEdit"));
+                println!("{}", locale.get_message("EditRetryHelpAbort"));
+                if validate {
+                    println!("{}", locale.get_message("EditRetryHelpNoValidate"));
+                }
+                println!("{}", locale.get_message("EditRetryHelpHelp"));
+            }
+        }
+    }
+}
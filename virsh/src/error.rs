@@ -10,7 +10,7 @@ pub enum Error {
     Libvirt(Box<libvirt_remote::error::Error>),
     Locale,
     NotSupported,
-    Uri(url::ParseError),
+    Xml(String),
 }
 
 impl From<string::FromUtf8Error> for Error {
@@ -30,9 +30,3 @@ impl From<libvirt_remote::error::Error> for Error {
         Error::Libvirt(Box::new(error))
     }
 }
-
-impl From<url::ParseError> for Error {
-    fn from(error: url::ParseError) -> Self {
-        Error::Uri(error)
-    }
-}
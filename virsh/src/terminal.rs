@@ -0,0 +1,140 @@
+use std::io::{self, ErrorKind, Read};
+use std::time::Duration;
+#[cfg(target_family = "windows")]
+use windows::Win32::Foundation::{WAIT_OBJECT_0, WAIT_TIMEOUT};
+#[cfg(target_family = "windows")]
+use windows::Win32::System::Console::{
+    CONSOLE_MODE, ENABLE_ECHO_INPUT, ENABLE_LINE_INPUT, ENABLE_PROCESSED_INPUT,
+    ENABLE_VIRTUAL_TERMINAL_INPUT, GetConsoleMode, GetStdHandle, STD_INPUT_HANDLE, SetConsoleMode,
+};
+#[cfg(target_family = "windows")]
+use windows::Win32::System::Threading::WaitForSingleObject;
+
+const INPUT_TIMEOUT: Duration = Duration::from_millis(100);
+
+// Puts the terminal on stdin into raw mode until dropped. Does nothing when
+// stdin is not a terminal.
+pub struct RawMode {
+    #[cfg(target_family = "unix")]
+    saved: Option<libc::termios>,
+    #[cfg(target_family = "windows")]
+    saved: Option<CONSOLE_MODE>,
+}
+
+#[cfg(target_family = "unix")]
+impl RawMode {
+    pub fn enable() -> Self {
+        unsafe {
+            if libc::isatty(libc::STDIN_FILENO) != 1 {
+                return RawMode { saved: None };
+            }
+
+            let mut saved: libc::termios = std::mem::zeroed();
+            if libc::tcgetattr(libc::STDIN_FILENO, &mut saved) != 0 {
+                return RawMode { saved: None };
+            }
+
+            let mut raw = saved;
+            libc::cfmakeraw(&mut raw);
+            if libc::tcsetattr(libc::STDIN_FILENO, libc::TCSAFLUSH, &raw) != 0 {
+                return RawMode { saved: None };
+            }
+
+            RawMode { saved: Some(saved) }
+        }
+    }
+}
+
+#[cfg(target_family = "unix")]
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        if let Some(saved) = self.saved.take() {
+            unsafe {
+                libc::tcsetattr(libc::STDIN_FILENO, libc::TCSAFLUSH, &saved);
+            }
+        }
+    }
+}
+
+#[cfg(target_family = "windows")]
+impl RawMode {
+    pub fn enable() -> Self {
+        unsafe {
+            let Ok(handle) = GetStdHandle(STD_INPUT_HANDLE) else {
+                return RawMode { saved: None };
+            };
+
+            let mut saved = CONSOLE_MODE::default();
+            if GetConsoleMode(handle, &mut saved).is_err() {
+                return RawMode { saved: None };
+            }
+
+            let raw = (saved & !(ENABLE_ECHO_INPUT | ENABLE_LINE_INPUT | ENABLE_PROCESSED_INPUT))
+                | ENABLE_VIRTUAL_TERMINAL_INPUT;
+            if SetConsoleMode(handle, raw).is_err() {
+                return RawMode { saved: None };
+            }
+
+            RawMode { saved: Some(saved) }
+        }
+    }
+}
+
+#[cfg(target_family = "windows")]
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        if let Some(saved) = self.saved.take() {
+            unsafe {
+                if let Ok(handle) = GetStdHandle(STD_INPUT_HANDLE) {
+                    let _ = SetConsoleMode(handle, saved);
+                }
+            }
+        }
+    }
+}
+
+// Reads stdin, failing with `ErrorKind::TimedOut` when nothing arrives for a
+// while so that the reader can tell whether it is still needed.
+pub struct Input;
+
+#[cfg(target_family = "unix")]
+impl Read for Input {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut fds = libc::pollfd {
+            fd: libc::STDIN_FILENO,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let timeout = INPUT_TIMEOUT.as_millis() as libc::c_int;
+        unsafe {
+            match libc::poll(&mut fds, 1, timeout) {
+                -1 => Err(io::Error::last_os_error()),
+                0 => Err(ErrorKind::TimedOut.into()),
+                _ => {
+                    // Bypasses the buffer of `io::stdin()`, which poll cannot see.
+                    let n = libc::read(libc::STDIN_FILENO, buf.as_mut_ptr().cast(), buf.len());
+                    if n < 0 {
+                        Err(io::Error::last_os_error())
+                    } else {
+                        Ok(n as usize)
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(target_family = "windows")]
+impl Read for Input {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let timeout = INPUT_TIMEOUT.as_millis() as u32;
+        unsafe {
+            let handle = GetStdHandle(STD_INPUT_HANDLE)?;
+            match WaitForSingleObject(handle, timeout) {
+                WAIT_OBJECT_0 => io::stdin().read(buf),
+                WAIT_TIMEOUT => Err(ErrorKind::TimedOut.into()),
+                _ => Err(io::Error::last_os_error()),
+            }
+        }
+    }
+}
@@ -1,3 +1,15 @@
+use crate::error::Error;
+use crate::locale::Locale;
+use chrono::Utc;
+use libvirt_remote::binding::{
+    RemoteNonnullDomain, RemoteNonnullNetwork, RemoteNonnullNodeDevice, RemoteNonnullStoragePool,
+    RemoteNonnullStorageVol,
+};
+use libvirt_remote::client::Libvirt;
+use libvirt_remote::domain::DomainState;
+use libvirt_remote::job::JobProgress;
+use libvirt_remote::storage::StoragePoolState;
+use std::io::{self, Write};
 use unicode_width::UnicodeWidthStr;
 
 pub fn char_width(value: &str) -> usize {
@@ -7,3 +19,381 @@ pub fn char_width(value: &str) -> usize {
 pub fn padding(ch: char, len: u16) -> String {
     std::iter::repeat_n(ch, len as usize).collect::<String>()
 }
+
+pub fn print_job_progress(label: &str, progress: &JobProgress) {
+    if let (Some(total), Some(remaining)) = (progress.data_total, progress.data_remaining) {
+        print_progress(label, remaining, total);
+    }
+}
+
+pub fn print_progress(label: &str, remaining: u64, total: u64) {
+    if total == 0 {
+        return;
+    }
+
+    if remaining == 0 {
+        println!("\r{label}: [100 %]");
+        return;
+    }
+
+    // Never show 100 % until the job has really finished.
+    let percent = (100 - remaining.min(total) * 100 / total).min(99);
+    print!("\r{label}: [{percent:>3} %]");
+    let _ = io::stdout().flush();
+}
+
+pub fn to_utf8_str(value: &[i8]) -> Result<String, Error> {
+    let bytes: Vec<u8> = value
+        .iter()
+        .map(|&c| c as u8)
+        .take_while(|&c| c != 0)
+        .collect();
+    String::from_utf8(bytes).map_err(Error::from)
+}
+
+pub fn format_uuid(uuid: &[u8]) -> String {
+    let hex: String = uuid.iter().map(|b| format!("{b:02x}")).collect();
+    if hex.len() != 32 {
+        return hex;
+    }
+
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+// Accepts the canonical form as well as the one without hyphens.
+pub fn parse_uuid(value: &str) -> Option<[u8; 16]> {
+    let hex: String = value.chars().filter(|&c| c != '-').collect();
+    if hex.len() != 32 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let mut uuid = [0; 16];
+    for (idx, byte) in uuid.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[idx * 2..idx * 2 + 2], 16).ok()?;
+    }
+    Some(uuid)
+}
+
+// Looks up a domain the same way virsh does: by ID, then UUID, then name.
+pub fn lookup_domain(
+    client: &mut Box<impl Libvirt>,
+    domain: &str,
+) -> Result<RemoteNonnullDomain, Error> {
+    if let Ok(id) = domain.parse::<i32>() {
+        if id >= 0 {
+            if let Ok(dom) = client.domain_lookup_by_id(id) {
+                return Ok(dom);
+            }
+        }
+    }
+
+    if let Some(uuid) = parse_uuid(domain) {
+        if let Ok(dom) = client.domain_lookup_by_uuid(uuid) {
+            return Ok(dom);
+        }
+    }
+
+    client
+        .domain_lookup_by_name(domain.to_string())
+        .map_err(Error::from)
+}
+
+// Looks up a network by UUID, then by name.
+pub fn lookup_network(
+    client: &mut Box<impl Libvirt>,
+    network: &str,
+) -> Result<RemoteNonnullNetwork, Error> {
+    if let Some(uuid) = parse_uuid(network) {
+        if let Ok(net) = client.network_lookup_by_uuid(uuid) {
+            return Ok(net);
+        }
+    }
+
+    client
+        .network_lookup_by_name(network.to_string())
+        .map_err(Error::from)
+}
+
+// Looks up a node device by name, or a SCSI host by `wwnn,wwpn`.
+pub fn lookup_node_device(
+    client: &mut Box<impl Libvirt>,
+    device: &str,
+) -> Result<RemoteNonnullNodeDevice, Error> {
+    if let Some((wwnn, wwpn)) = device.split_once(',') {
+        return client
+            .node_device_lookup_scsi_host_by_wwn(wwnn.to_string(), wwpn.to_string(), 0)
+            .map_err(Error::from);
+    }
+
+    client
+        .node_device_lookup_by_name(device.to_string())
+        .map_err(Error::from)
+}
+
+// Looks up a storage pool by UUID, then by name.
+pub fn lookup_pool(
+    client: &mut Box<impl Libvirt>,
+    pool: &str,
+) -> Result<RemoteNonnullStoragePool, Error> {
+    if let Some(uuid) = parse_uuid(pool) {
+        if let Ok(pool) = client.storage_pool_lookup_by_uuid(uuid) {
+            return Ok(pool);
+        }
+    }
+
+    client
+        .storage_pool_lookup_by_name(pool.to_string())
+        .map_err(Error::from)
+}
+
+// Looks up a storage volume by name within `pool` if given, otherwise by key,
+// then by path.
+pub fn lookup_vol(
+    client: &mut Box<impl Libvirt>,
+    vol: &str,
+    pool: Option<&str>,
+) -> Result<RemoteNonnullStorageVol, Error> {
+    if let Some(pool) = pool {
+        let pool = lookup_pool(client, pool)?;
+        return client
+            .storage_vol_lookup_by_name(pool, vol.to_string())
+            .map_err(Error::from);
+    }
+
+    if let Ok(vol) = client.storage_vol_lookup_by_key(vol.to_string()) {
+        return Ok(vol);
+    }
+
+    client
+        .storage_vol_lookup_by_path(vol.to_string())
+        .map_err(Error::from)
+}
+
+pub fn state_label(locale: &Locale, state: DomainState) -> String {
+    let id = match state {
+        DomainState::NoState => "StateNoState",
+        DomainState::Running => "StateRunning",
+        DomainState::Blocked => "StateBlocked",
+        DomainState::Paused => "StatePaused",
+        DomainState::Shutdown => "StateShutdown",
+        DomainState::Shutoff => "StateShutoff",
+        DomainState::Crashed => "StateCrashed",
+        DomainState::PmSuspended => "StatePmSuspended",
+    };
+    locale.get_message(id)
+}
+
+pub fn pool_state_label(locale: &Locale, state: StoragePoolState) -> String {
+    let id = match state {
+        StoragePoolState::Inactive => "Inactive",
+        StoragePoolState::Building => "StateBuilding",
+        StoragePoolState::Running => "StateRunning",
+        StoragePoolState::Degraded => "StateDegraded",
+        StoragePoolState::Inaccessible => "StateInaccessible",
+    };
+    locale.get_message(id)
+}
+
+// Formats a size in bytes with the largest binary unit below it, such as
+// `1.50 GiB`.
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.2} {}", UNITS[unit])
+}
+
+// Parses a size such as `10G`, `512MiB` or `2TB`. A bare number is in bytes,
+// `K`, `KiB`, `M`, `MiB` and so on are powers of 1024, and `KB`, `MB` and so on
+// are powers of 1000, as with virsh.
+pub fn parse_size(value: &str) -> Result<u64, Error> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, suffix) = value.split_at(split);
+
+    let number = number
+        .parse::<u64>()
+        .map_err(|_| Error::Arg(value.to_string()))?;
+
+    let suffix = suffix.trim().to_ascii_lowercase();
+    let scale = match suffix.as_str() {
+        "" | "b" | "byte" | "bytes" => 1,
+        _ => {
+            let (prefix, rest) = suffix.split_at(1);
+            let exp = match prefix {
+                "k" => 1,
+                "m" => 2,
+                "g" => 3,
+                "t" => 4,
+                "p" => 5,
+                "e" => 6,
+                _ => return Err(Error::Arg(value.to_string())),
+            };
+            let base: u64 = match rest {
+                "" | "ib" => 1024,
+                "b" => 1000,
+                _ => return Err(Error::Arg(value.to_string())),
+            };
+            base.pow(exp)
+        }
+    };
+
+    number
+        .checked_mul(scale)
+        .ok_or_else(|| Error::Arg(value.to_string()))
+}
+
+// Parses a comma separated list of names, such as `--mode acpi,agent`.
+pub fn parse_flags(value: &str, names: &[(&str, u32)]) -> Result<u32, Error> {
+    let mut flags = 0;
+    for item in value.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        match names.iter().find(|(name, _)| *name == item) {
+            Some((_, flag)) => flags |= flag,
+            None => return Err(Error::Arg(item.to_string())),
+        }
+    }
+    Ok(flags)
+}
+
+// Parses a comma separated list of descriptor numbers, such as
+// `--pass-fds 3,4`.
+pub fn parse_fds(value: &str) -> Result<Vec<i32>, Error> {
+    value
+        .split(',')
+        .map(str::trim)
+        .map(|fd| match fd.parse::<i32>() {
+            Ok(fd) if fd >= 0 => Ok(fd),
+            _ => Err(Error::Arg(fd.to_string())),
+        })
+        .collect()
+}
+
+// Prefix printed before each event with `--timestamp`.
+pub fn event_timestamp(enabled: bool) -> String {
+    if enabled {
+        format!("{}: ", Utc::now().format("%Y-%m-%d %H:%M:%S%.3f%z"))
+    } else {
+        "".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UUID: [u8; 16] = [
+        0xc7, 0xa5, 0xfd, 0xbd, 0xed, 0xaf, 0x94, 0x55, 0x92, 0x6a, 0xd6, 0x5c, 0x16, 0xdb, 0x18,
+        0x09,
+    ];
+
+    #[test]
+    fn parse_size_bytes() {
+        assert_eq!(parse_size("0").unwrap(), 0);
+        assert_eq!(parse_size("512").unwrap(), 512);
+        assert_eq!(parse_size(" 512 ").unwrap(), 512);
+        assert_eq!(parse_size("512b").unwrap(), 512);
+        assert_eq!(parse_size("512bytes").unwrap(), 512);
+    }
+
+    #[test]
+    fn parse_size_kilo() {
+        assert_eq!(parse_size("2K").unwrap(), 2048);
+        assert_eq!(parse_size("2k").unwrap(), 2048);
+        assert_eq!(parse_size("2KiB").unwrap(), 2048);
+        assert_eq!(parse_size("2 KiB").unwrap(), 2048);
+        assert_eq!(parse_size("2KB").unwrap(), 2000);
+    }
+
+    #[test]
+    fn parse_size_units() {
+        assert_eq!(parse_size("3M").unwrap(), 3 << 20);
+        assert_eq!(parse_size("3GB").unwrap(), 3_000_000_000);
+        assert_eq!(parse_size("3TiB").unwrap(), 3 << 40);
+        assert_eq!(parse_size("3P").unwrap(), 3 << 50);
+        assert_eq!(parse_size("15E").unwrap(), 15 << 60);
+        assert_eq!(parse_size("18EB").unwrap(), 18_000_000_000_000_000_000);
+    }
+
+    #[test]
+    fn parse_size_invalid() {
+        for value in ["", "K", "-1", "1.5G", "10X", "10KX", "10iB", "10 K B"] {
+            assert!(parse_size(value).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn parse_size_overflow() {
+        assert_eq!(parse_size(&u64::MAX.to_string()).unwrap(), u64::MAX);
+        assert!(parse_size("18446744073709551616").is_err());
+        assert!(parse_size("16E").is_err());
+        assert!(parse_size("19EB").is_err());
+        assert!(parse_size("18446744073709551615K").is_err());
+    }
+
+    #[test]
+    fn uuid_round_trip() {
+        let text = "c7a5fdbd-edaf-9455-926a-d65c16db1809";
+        assert_eq!(format_uuid(&UUID), text);
+        assert_eq!(parse_uuid(text), Some(UUID));
+        assert_eq!(parse_uuid("c7a5fdbdedaf9455926ad65c16db1809"), Some(UUID));
+        assert_eq!(parse_uuid(&text.to_uppercase()), Some(UUID));
+    }
+
+    #[test]
+    fn parse_uuid_invalid() {
+        for value in [
+            "",
+            "c7a5fdbd-edaf-9455-926a-d65c16db180",
+            "c7a5fdbd-edaf-9455-926a-d65c16db18099",
+            "g7a5fdbd-edaf-9455-926a-d65c16db1809",
+            "+7a5fdbd-edaf-9455-926a-d65c16db1809",
+            "c7a5fdbd-edaf-9455-926a-d65c16db180\u{e9}",
+        ] {
+            assert_eq!(parse_uuid(value), None, "{value}");
+        }
+    }
+
+    #[test]
+    fn format_uuid_other_length() {
+        assert_eq!(format_uuid(&[0xab, 0x01]), "ab01");
+    }
+
+    #[test]
+    fn parse_flags_names() {
+        let names = [("acpi", 1), ("agent", 2), ("signal", 8)];
+        assert_eq!(parse_flags("", &names).unwrap(), 0);
+        assert_eq!(parse_flags("agent", &names).unwrap(), 2);
+        assert_eq!(parse_flags("acpi, signal,", &names).unwrap(), 9);
+        assert_eq!(parse_flags("acpi,acpi", &names).unwrap(), 1);
+        assert!(matches!(
+            parse_flags("acpi,paravirt", &names),
+            Err(Error::Arg(name)) if name == "paravirt"
+        ));
+    }
+
+    #[test]
+    fn parse_fds_list() {
+        assert_eq!(parse_fds("3").unwrap(), [3]);
+        assert_eq!(parse_fds("3, 4,0").unwrap(), [3, 4, 0]);
+        for (value, invalid) in [("", ""), ("3,", ""), ("3,-1", "-1"), ("fd", "fd")] {
+            assert!(
+                matches!(parse_fds(value), Err(Error::Arg(fd)) if fd == invalid),
+                "{value}"
+            );
+        }
+    }
+}
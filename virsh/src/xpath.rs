@@ -0,0 +1,101 @@
+use crate::error::Error;
+use sxd_document::dom::{ChildOfElement, Element, ParentOfChild};
+use sxd_document::parser;
+use sxd_xpath::nodeset::Node;
+use sxd_xpath::{Value, evaluate_xpath};
+
+// Evaluates `expr` against `xml`. Matched nodes are returned as XML
+// fragments in document order, any other result as a single string.
+pub fn select(xml: &str, expr: &str) -> Result<Vec<String>, Error> {
+    let package = parser::parse(xml).map_err(|e| Error::Xml(format!("{e:?}")))?;
+    let document = package.as_document();
+
+    let value = evaluate_xpath(&document, expr).map_err(|e| Error::Xml(e.to_string()))?;
+    let results = match value {
+        Value::Nodeset(nodes) => nodes.document_order().iter().map(node_to_string).collect(),
+        Value::Boolean(v) => vec![v.to_string()],
+        Value::Number(v) => vec![v.to_string()],
+        Value::String(v) => vec![v],
+    };
+
+    Ok(results)
+}
+
+fn node_to_string(node: &Node) -> String {
+    match node {
+        Node::Element(e) => {
+            let mut out = String::new();
+            write_element(&mut out, e);
+            out
+        }
+        Node::Attribute(a) => format!("{}=\"{}\"", a.name().local_part(), escape(a.value())),
+        Node::Text(t) => t.text().to_string(),
+        _ => node.string_value(),
+    }
+}
+
+fn write_element(out: &mut String, element: &Element) {
+    let name = qualified_name(element);
+
+    out.push('<');
+    out.push_str(&name);
+    if let Some(uri) = element.default_namespace_uri() {
+        out.push_str(&format!(" xmlns=\"{}\"", escape(uri)));
+    }
+
+    // Only the declarations made on this element, as they appear in the source.
+    let parent = match element.parent() {
+        Some(ParentOfChild::Element(parent)) => Some(parent),
+        _ => None,
+    };
+    for ns in element.namespaces_in_scope() {
+        if ns.prefix() == "xml" {
+            continue;
+        }
+
+        let inherited = parent.and_then(|p| p.namespace_uri_for_prefix(ns.prefix()));
+        if inherited != Some(ns.uri()) {
+            out.push_str(&format!(" xmlns:{}=\"{}\"", ns.prefix(), escape(ns.uri())));
+        }
+    }
+
+    for attr in element.attributes() {
+        let attr_name = match attr.preferred_prefix() {
+            Some(prefix) => format!("{prefix}:{}", attr.name().local_part()),
+            None => attr.name().local_part().to_string(),
+        };
+        out.push_str(&format!(" {attr_name}=\"{}\"", escape(attr.value())));
+    }
+
+    let children = element.children();
+    if children.is_empty() {
+        out.push_str("/>");
+        return;
+    }
+
+    out.push('>');
+    for child in children {
+        match child {
+            ChildOfElement::Element(e) => write_element(out, &e),
+            ChildOfElement::Text(t) => out.push_str(&escape(t.text())),
+            ChildOfElement::Comment(c) => out.push_str(&format!("<!--{}-->", c.text())),
+            ChildOfElement::ProcessingInstruction(_) => {}
+        }
+    }
+    out.push_str(&format!("</{name}>"));
+}
+
+fn qualified_name(element: &Element) -> String {
+    match element.preferred_prefix() {
+        Some(prefix) => format!("{prefix}:{}", element.name().local_part()),
+        None => element.name().local_part().to_string(),
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}